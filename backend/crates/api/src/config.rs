@@ -10,7 +10,7 @@
 // Example: APP__DATABASE__URL=sqlite:///data/budget.db
 
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
@@ -26,6 +26,10 @@ pub struct AppConfig {
     pub cors: CorsConfig,
     #[serde(default = "default_ui")]
     pub ui: UiConfig,
+    #[serde(default = "default_recurring")]
+    pub recurring: RecurringConfig,
+    #[serde(default = "default_storage")]
+    pub storage: StorageConfig,
 }
 
 fn default_server() -> ServerConfig {
@@ -61,6 +65,20 @@ fn default_ui() -> UiConfig {
     }
 }
 
+fn default_recurring() -> RecurringConfig {
+    RecurringConfig {
+        enabled: true,
+        interval_secs: default_recurring_interval_secs(),
+    }
+}
+
+fn default_storage() -> StorageConfig {
+    StorageConfig {
+        attachments_dir: None,
+        max_attachment_bytes: default_max_attachment_bytes(),
+    }
+}
+
 fn default_budget_bars() -> BudgetBarsConfig {
     BudgetBarsConfig {
         green_threshold: 80,
@@ -144,6 +162,40 @@ fn default_yellow_threshold() -> u8 {
     100
 }
 
+/// Background task booking payments of recurring rules as they fall due.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RecurringConfig {
+    #[serde(default = "default_recurring_enabled")]
+    pub enabled: bool,
+    /// Seconds between runs; the first run happens at startup
+    #[serde(default = "default_recurring_interval_secs")]
+    pub interval_secs: u64,
+}
+
+fn default_recurring_enabled() -> bool {
+    true
+}
+
+fn default_recurring_interval_secs() -> u64 {
+    3600
+}
+
+/// Files attached to transactions.
+#[derive(Debug, Deserialize, Clone)]
+pub struct StorageConfig {
+    /// Directory holding attachment files; defaults to `attachments` next to
+    /// the database file
+    #[serde(default)]
+    pub attachments_dir: Option<String>,
+    /// Largest accepted upload, in bytes
+    #[serde(default = "default_max_attachment_bytes")]
+    pub max_attachment_bytes: usize,
+}
+
+fn default_max_attachment_bytes() -> usize {
+    10 * 1024 * 1024
+}
+
 /// Convert flat JSON keys (e.g., "server_host") into nested structure
 /// (e.g., {"server": {"host": ...}}) so they deserialize into `AppConfig`.
 ///
@@ -154,6 +206,8 @@ fn default_yellow_threshold() -> u8 {
 ///   currency_code           -> currency.code
 ///   currency_minor_unit_name -> currency.minor_unit_name (split on first `_` only)
 ///   currency_decimal_places -> currency.decimal_places
+///   recurring_interval_secs -> recurring.interval_secs
+///   storage_attachments_dir -> storage.attachments_dir
 fn convert_flat_json(value: serde_json::Value) -> serde_json::Value {
     let obj = match value {
         serde_json::Value::Object(map) => map,
@@ -161,7 +215,7 @@ fn convert_flat_json(value: serde_json::Value) -> serde_json::Value {
     };
 
     // Known section prefixes for unambiguous splitting
-    let known_sections = ["server", "database", "currency", "cors", "ui"];
+    let known_sections = ["server", "database", "currency", "cors", "ui", "recurring", "storage"];
 
     let mut nested: HashMap<String, HashMap<String, serde_json::Value>> = HashMap::new();
 
@@ -279,8 +333,41 @@ impl AppConfig {
                 bars.green_threshold, bars.yellow_threshold
             )));
         }
+        if self.recurring.interval_secs == 0 {
+            return Err(config::ConfigError::Message(
+                "recurring.interval_secs must be greater than 0".to_string(),
+            ));
+        }
+        if self.storage.max_attachment_bytes == 0 {
+            return Err(config::ConfigError::Message(
+                "storage.max_attachment_bytes must be greater than 0".to_string(),
+            ));
+        }
         Ok(())
     }
+
+    /// Directory for attachment files: `storage.attachments_dir` if set,
+    /// otherwise `attachments` in the directory of the SQLite database file.
+    pub fn attachments_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.storage.attachments_dir {
+            return PathBuf::from(dir);
+        }
+
+        let url = &self.database.url;
+        let path = url
+            .strip_prefix("sqlite://")
+            .or_else(|| url.strip_prefix("sqlite:"))
+            .unwrap_or(url);
+        let path = path.split('?').next().unwrap_or_default();
+        if path.is_empty() || path == ":memory:" {
+            return PathBuf::from("attachments");
+        }
+
+        Path::new(path)
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join("attachments")
+    }
 }
 
 #[cfg(test)]
@@ -373,4 +460,91 @@ green_threshold = 50
         assert_eq!(config.ui.budget_bars.green_threshold, 50);
         assert_eq!(config.ui.budget_bars.yellow_threshold, 100);
     }
+
+    #[test]
+    fn default_recurring_config() {
+        let config = AppConfig::load(None, false).unwrap();
+        assert!(config.recurring.enabled);
+        assert_eq!(config.recurring.interval_secs, 3600);
+    }
+
+    #[test]
+    fn recurring_config_from_toml() {
+        let f = write_toml(
+            r#"
+[recurring]
+enabled = false
+interval_secs = 600
+"#,
+        );
+        let config = AppConfig::load(Some(f.path().to_str().unwrap()), true).unwrap();
+        assert!(!config.recurring.enabled);
+        assert_eq!(config.recurring.interval_secs, 600);
+    }
+
+    #[test]
+    fn rejects_zero_recurring_interval() {
+        let f = write_toml(
+            r#"
+[recurring]
+interval_secs = 0
+"#,
+        );
+        let err = AppConfig::load(Some(f.path().to_str().unwrap()), true).unwrap_err();
+        assert!(err.to_string().contains("interval_secs"));
+    }
+
+    #[test]
+    fn default_storage_config() {
+        let config = AppConfig::load(None, false).unwrap();
+        assert_eq!(config.storage.max_attachment_bytes, 10 * 1024 * 1024);
+        assert_eq!(config.attachments_dir(), PathBuf::from("data/attachments"));
+    }
+
+    #[test]
+    fn attachments_dir_follows_database_file() {
+        let f = write_toml(
+            r#"
+[database]
+url = "sqlite:///var/lib/otter/budget.db?mode=rwc"
+"#,
+        );
+        let config = AppConfig::load(Some(f.path().to_str().unwrap()), true).unwrap();
+        assert_eq!(config.attachments_dir(), PathBuf::from("/var/lib/otter/attachments"));
+
+        let f = write_toml(
+            r#"
+[database]
+url = "sqlite:budget.db"
+"#,
+        );
+        let config = AppConfig::load(Some(f.path().to_str().unwrap()), true).unwrap();
+        assert_eq!(config.attachments_dir(), PathBuf::from("attachments"));
+    }
+
+    #[test]
+    fn storage_config_from_toml() {
+        let f = write_toml(
+            r#"
+[storage]
+attachments_dir = "/srv/receipts"
+max_attachment_bytes = 1048576
+"#,
+        );
+        let config = AppConfig::load(Some(f.path().to_str().unwrap()), true).unwrap();
+        assert_eq!(config.attachments_dir(), PathBuf::from("/srv/receipts"));
+        assert_eq!(config.storage.max_attachment_bytes, 1_048_576);
+    }
+
+    #[test]
+    fn rejects_zero_max_attachment_bytes() {
+        let f = write_toml(
+            r#"
+[storage]
+max_attachment_bytes = 0
+"#,
+        );
+        let err = AppConfig::load(Some(f.path().to_str().unwrap()), true).unwrap_err();
+        assert!(err.to_string().contains("max_attachment_bytes"));
+    }
 }
@@ -0,0 +1,130 @@
+// Splitting uploaded CSV files into records for the import service.
+//
+// Only the bytes are handled here: text encoding, the byte order mark, quoting
+// and line numbers. Reading dates and amounts out of the fields is left to
+// `ImportService::preview`. Mappings are checked with
+// `ImportService::validate_mapping` before they are used here.
+
+use domain::entities::{CsvMapping, CsvRecord};
+use domain::errors::ImportError;
+
+/// Byte order mark some spreadsheet programs put in front of UTF-8 exports
+const BOM: &str = "\u{feff}";
+
+/// Splits a UTF-8 CSV file into records with the delimiter and header setting
+/// of `mapping`, which must be valid. Blank lines are skipped.
+///
+/// # Errors
+///
+/// * `ImportError::InvalidMapping` - Delimiter is not an ASCII character
+/// * `ImportError::InvalidFile` - Not UTF-8 or not CSV
+pub fn read_records(contents: &[u8], mapping: &CsvMapping) -> Result<Vec<CsvRecord>, ImportError> {
+    // Checked again here, since a non-ASCII char would not survive as a single byte
+    if !mapping.delimiter.is_ascii() {
+        return Err(ImportError::InvalidMapping {
+            reason: format!("delimiter {:?} cannot separate fields", mapping.delimiter),
+        });
+    }
+    let text = std::str::from_utf8(contents).map_err(|_| ImportError::InvalidFile {
+        reason: "file is not UTF-8 text".to_string(),
+    })?;
+    let text = text.strip_prefix(BOM).unwrap_or(text);
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(mapping.delimiter as u8)
+        .has_headers(mapping.has_header)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let mut records = Vec::new();
+    // The reader's line numbers leave out blank lines, and a record's position is
+    // where blank lines before it begin, so lines are counted from the bytes
+    let bytes = text.as_bytes();
+    let (mut counted_to, mut line) = (0, 1);
+    for record in reader.records() {
+        let record = record.map_err(|e| ImportError::InvalidFile {
+            reason: e.to_string(),
+        })?;
+        if record.iter().all(|f| f.trim().is_empty()) {
+            continue;
+        }
+        let mut start = record.position().map_or(counted_to, |p| p.byte() as usize);
+        while matches!(bytes.get(start), Some(b'\r' | b'\n')) {
+            start += 1;
+        }
+        line += bytes[counted_to..start].iter().filter(|b| **b == b'\n').count() as u64;
+        counted_to = start;
+        records.push(CsvRecord {
+            line,
+            fields: record.iter().map(str::to_string).collect(),
+        });
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping() -> CsvMapping {
+        CsvMapping {
+            delimiter: ';',
+            has_header: true,
+            date_column: 0,
+            amount_column: 2,
+            description_column: Some(1),
+            decimal_separator: ',',
+            date_format: "%d.%m.%Y".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_read_records() {
+        let contents = "\u{feff}Data;Opis;Kwota\n\
+                        14.03.2026;\"Lidl; Warszawa\";-45,99\n\
+                        \n\
+                        15.03.2026;\"Zwrot\r\nczęściowy\";12,00\r\n\
+                        ;;\n\
+                        17.03.2026;Short\n";
+        let records = read_records(contents.as_bytes(), &mapping()).unwrap();
+        assert_eq!(
+            records,
+            vec![
+                CsvRecord {
+                    line: 2,
+                    fields: vec!["14.03.2026".into(), "Lidl; Warszawa".into(), "-45,99".into()],
+                },
+                CsvRecord {
+                    line: 4,
+                    fields: vec!["15.03.2026".into(), "Zwrot\r\nczęściowy".into(), "12,00".into()],
+                },
+                CsvRecord {
+                    line: 7,
+                    fields: vec!["17.03.2026".into(), "Short".into()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_records_without_header() {
+        let m = CsvMapping { has_header: false, delimiter: ',', ..mapping() };
+        let records = read_records(b"2026-03-14,1.00\n", &m).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].line, 1);
+    }
+
+    #[test]
+    fn test_read_records_invalid() {
+        assert!(matches!(
+            read_records(&[0xff, 0xfe, 0x00], &mapping()),
+            Err(ImportError::InvalidFile { .. })
+        ));
+        assert!(read_records(b"Data;Opis;Kwota\n", &mapping()).unwrap().is_empty());
+        assert!(matches!(
+            read_records(b"a\xc5\x82b\n", &CsvMapping { delimiter: 'ł', ..mapping() }),
+            Err(ImportError::InvalidMapping { .. })
+        ));
+    }
+}
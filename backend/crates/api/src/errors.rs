@@ -3,7 +3,10 @@ use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde_json::{json, Value};
 
-use domain::errors::{CategoryError, EntryError, MonthError, TransactionError};
+use domain::errors::{
+    AccountError, AttachmentError, CategoryError, EntryError, ImportError, MonthError,
+    PayeeError, RecurringError, TagError, TransactionError,
+};
 
 pub struct ApiError {
     pub status: StatusCode,
@@ -58,6 +61,26 @@ impl From<CategoryError> for ApiError {
                 code: "CATEGORY_INVALID_NAME".into(),
                 details: Some(json!({ "reason": reason })),
             },
+            CategoryError::HasEntries { entry_count } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "CATEGORY_HAS_ENTRIES".into(),
+                details: Some(json!({ "entry_count": entry_count })),
+            },
+            CategoryError::HasRecurringRules { rule_count } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "CATEGORY_HAS_RECURRING_RULES".into(),
+                details: Some(json!({ "rule_count": rule_count })),
+            },
+            CategoryError::MergeIntoSelf => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "CATEGORY_MERGE_INTO_SELF".into(),
+                details: None,
+            },
+            CategoryError::MergeKindMismatch { month } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "CATEGORY_MERGE_KIND_MISMATCH".into(),
+                details: Some(json!({ "month": month })),
+            },
             CategoryError::Repository(msg) => {
                 tracing::error!("Category repository error: {}", msg);
                 ApiError {
@@ -88,6 +111,21 @@ impl From<MonthError> for ApiError {
                 code: "MONTH_INVALID_FORMAT".into(),
                 details: Some(json!({ "value": value })),
             },
+            MonthError::HasTransactions { transaction_count } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "MONTH_HAS_TRANSACTIONS".into(),
+                details: Some(json!({ "transaction_count": transaction_count })),
+            },
+            MonthError::CopyFromSelf => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "MONTH_COPY_FROM_SELF".into(),
+                details: None,
+            },
+            MonthError::InvalidRange { from, to } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "MONTH_INVALID_RANGE".into(),
+                details: Some(json!({ "from": from, "to": to })),
+            },
             MonthError::Repository(msg) => {
                 tracing::error!("Month repository error: {}", msg);
                 ApiError {
@@ -123,6 +161,16 @@ impl From<EntryError> for ApiError {
                 code: "ENTRY_INVALID_DUE_DAY".into(),
                 details: Some(json!({ "value": value })),
             },
+            EntryError::InvalidKind { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ENTRY_INVALID_KIND".into(),
+                details: Some(json!({ "value": value })),
+            },
+            EntryError::InvalidRollover { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ENTRY_INVALID_ROLLOVER".into(),
+                details: Some(json!({ "value": value })),
+            },
             EntryError::CategoryNotFound => ApiError {
                 status: StatusCode::NOT_FOUND,
                 code: "CATEGORY_NOT_FOUND".into(),
@@ -173,6 +221,96 @@ impl From<TransactionError> for ApiError {
                 code: "TRANSACTION_TITLE_TOO_LONG".into(),
                 details: Some(json!({ "length": length, "max": max })),
             },
+            TransactionError::InvalidKind { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_INVALID_KIND".into(),
+                details: Some(json!({ "value": value })),
+            },
+            TransactionError::RefundOriginalNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TRANSACTION_REFUND_ORIGINAL_NOT_FOUND".into(),
+                details: None,
+            },
+            TransactionError::InvalidRefund { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_INVALID_REFUND".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            TransactionError::RefundExceedsOriginal { original_amount, refunded } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_REFUND_EXCEEDS_ORIGINAL".into(),
+                details: Some(json!({ "original_amount": original_amount, "refunded": refunded })),
+            },
+            TransactionError::SplitNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TRANSACTION_SPLIT_NOT_FOUND".into(),
+                details: None,
+            },
+            TransactionError::InvalidSplit { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_INVALID_SPLIT".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            TransactionError::SplitTotalMismatch { total, allocated } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_SPLIT_TOTAL_MISMATCH".into(),
+                details: Some(json!({ "total": total, "allocated": allocated })),
+            },
+            TransactionError::PartOfSplit { split_id } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "TRANSACTION_PART_OF_SPLIT".into(),
+                details: Some(json!({ "split_id": split_id })),
+            },
+            TransactionError::EntryAlreadyPaid { available, paid } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "TRANSACTION_ENTRY_ALREADY_PAID".into(),
+                details: Some(json!({ "available": available, "paid": paid })),
+            },
+            TransactionError::EntryHasNoDueDay => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_ENTRY_HAS_NO_DUE_DAY".into(),
+                details: None,
+            },
+            TransactionError::EntryIsIncome => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_ENTRY_IS_INCOME".into(),
+                details: None,
+            },
+            TransactionError::DateOutsideMonth { date, month } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_DATE_OUTSIDE_MONTH".into(),
+                details: Some(json!({ "date": date, "month": month })),
+            },
+            TransactionError::UnknownTag { name } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_UNKNOWN_TAG".into(),
+                details: Some(json!({ "name": name })),
+            },
+            TransactionError::PayeeNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TRANSACTION_PAYEE_NOT_FOUND".into(),
+                details: None,
+            },
+            TransactionError::AccountNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TRANSACTION_ACCOUNT_NOT_FOUND".into(),
+                details: None,
+            },
+            TransactionError::InvalidSearchQuery { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_INVALID_SEARCH_QUERY".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            TransactionError::InvalidFilter { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSACTION_INVALID_FILTER".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            TransactionError::InvalidCursor => ApiError {
+                status: StatusCode::BAD_REQUEST,
+                code: "TRANSACTION_INVALID_CURSOR".into(),
+                details: None,
+            },
             TransactionError::Repository(msg) => {
                 tracing::error!("Transaction repository error: {}", msg);
                 ApiError {
@@ -184,3 +322,286 @@ impl From<TransactionError> for ApiError {
         }
     }
 }
+
+impl From<RecurringError> for ApiError {
+    fn from(err: RecurringError) -> Self {
+        match err {
+            RecurringError::NotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "RECURRING_RULE_NOT_FOUND".into(),
+                details: None,
+            },
+            RecurringError::CategoryNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "RECURRING_CATEGORY_NOT_FOUND".into(),
+                details: None,
+            },
+            RecurringError::InvalidAmount { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "RECURRING_INVALID_AMOUNT".into(),
+                details: Some(json!({ "value": value })),
+            },
+            RecurringError::InvalidMonth { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "RECURRING_INVALID_MONTH".into(),
+                details: Some(json!({ "value": value })),
+            },
+            RecurringError::InvalidDay { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "RECURRING_INVALID_DAY".into(),
+                details: Some(json!({ "value": value })),
+            },
+            RecurringError::InvalidRange { start, end } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "RECURRING_INVALID_RANGE".into(),
+                details: Some(json!({ "start_month": start, "end_month": end })),
+            },
+            RecurringError::TitleTooLong { length, max } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "RECURRING_TITLE_TOO_LONG".into(),
+                details: Some(json!({ "length": length, "max": max })),
+            },
+            RecurringError::Repository(msg) => {
+                tracing::error!("Recurring rule repository error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+        }
+    }
+}
+
+impl From<TagError> for ApiError {
+    fn from(err: TagError) -> Self {
+        match err {
+            TagError::NotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TAG_NOT_FOUND".into(),
+                details: None,
+            },
+            TagError::NameAlreadyExists { name } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "TAG_NAME_ALREADY_EXISTS".into(),
+                details: Some(json!({ "name": name })),
+            },
+            TagError::InvalidName { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TAG_INVALID_NAME".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            TagError::Repository(msg) => {
+                tracing::error!("Tag repository error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+        }
+    }
+}
+
+impl From<PayeeError> for ApiError {
+    fn from(err: PayeeError) -> Self {
+        match err {
+            PayeeError::NotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "PAYEE_NOT_FOUND".into(),
+                details: None,
+            },
+            PayeeError::NameAlreadyExists { name } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "PAYEE_NAME_ALREADY_EXISTS".into(),
+                details: Some(json!({ "name": name })),
+            },
+            PayeeError::InvalidName { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "PAYEE_INVALID_NAME".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            PayeeError::CategoryNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "PAYEE_CATEGORY_NOT_FOUND".into(),
+                details: None,
+            },
+            PayeeError::Repository(msg) => {
+                tracing::error!("Payee repository error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+        }
+    }
+}
+
+impl From<AccountError> for ApiError {
+    fn from(err: AccountError) -> Self {
+        match err {
+            AccountError::NotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "ACCOUNT_NOT_FOUND".into(),
+                details: None,
+            },
+            AccountError::NameAlreadyExists { name } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "ACCOUNT_NAME_ALREADY_EXISTS".into(),
+                details: Some(json!({ "name": name })),
+            },
+            AccountError::InvalidName { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ACCOUNT_INVALID_NAME".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            AccountError::InvalidKind { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ACCOUNT_INVALID_KIND".into(),
+                details: Some(json!({ "value": value })),
+            },
+            AccountError::HasTransfers { transfer_count } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "ACCOUNT_HAS_TRANSFERS".into(),
+                details: Some(json!({ "transfer_count": transfer_count })),
+            },
+            AccountError::TransferNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TRANSFER_NOT_FOUND".into(),
+                details: None,
+            },
+            AccountError::InvalidAmount { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSFER_INVALID_AMOUNT".into(),
+                details: Some(json!({ "value": value })),
+            },
+            AccountError::InvalidDate { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSFER_INVALID_DATE".into(),
+                details: Some(json!({ "value": value })),
+            },
+            AccountError::TitleTooLong { length, max } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSFER_TITLE_TOO_LONG".into(),
+                details: Some(json!({ "length": length, "max": max })),
+            },
+            AccountError::TransferToSelf => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "TRANSFER_TO_SELF".into(),
+                details: None,
+            },
+            AccountError::Repository(msg) => {
+                tracing::error!("Account repository error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+        }
+    }
+}
+
+impl From<AttachmentError> for ApiError {
+    fn from(err: AttachmentError) -> Self {
+        match err {
+            AttachmentError::NotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "ATTACHMENT_NOT_FOUND".into(),
+                details: None,
+            },
+            AttachmentError::TransactionNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "TRANSACTION_NOT_FOUND".into(),
+                details: None,
+            },
+            AttachmentError::EmptyFile => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ATTACHMENT_EMPTY".into(),
+                details: None,
+            },
+            AttachmentError::FileTooLarge { size, max } => ApiError {
+                status: StatusCode::PAYLOAD_TOO_LARGE,
+                code: "ATTACHMENT_TOO_LARGE".into(),
+                details: Some(json!({ "size": size, "max": max })),
+            },
+            AttachmentError::InvalidFileName { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ATTACHMENT_INVALID_FILE_NAME".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            AttachmentError::InvalidContentType { value } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "ATTACHMENT_INVALID_CONTENT_TYPE".into(),
+                details: Some(json!({ "value": value })),
+            },
+            AttachmentError::Storage(msg) => {
+                tracing::error!("Attachment storage error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+            AttachmentError::Repository(msg) => {
+                tracing::error!("Attachment repository error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+        }
+    }
+}
+
+impl From<ImportError> for ApiError {
+    fn from(err: ImportError) -> Self {
+        match err {
+            ImportError::ProfileNotFound => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "IMPORT_PROFILE_NOT_FOUND".into(),
+                details: None,
+            },
+            ImportError::ProfileNameAlreadyExists { name } => ApiError {
+                status: StatusCode::CONFLICT,
+                code: "IMPORT_PROFILE_NAME_ALREADY_EXISTS".into(),
+                details: Some(json!({ "name": name })),
+            },
+            ImportError::InvalidProfileName { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "IMPORT_INVALID_PROFILE_NAME".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            ImportError::InvalidMapping { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "IMPORT_INVALID_MAPPING".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            ImportError::InvalidFile { reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "IMPORT_INVALID_FILE".into(),
+                details: Some(json!({ "reason": reason })),
+            },
+            ImportError::InvalidRow { row, reason } => ApiError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                code: "IMPORT_INVALID_ROW".into(),
+                details: Some(json!({ "row": row, "reason": reason })),
+            },
+            ImportError::EntryNotFound { row } => ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "ENTRY_NOT_FOUND".into(),
+                details: Some(json!({ "row": row })),
+            },
+            ImportError::Repository(msg) => {
+                tracing::error!("Import repository error: {}", msg);
+                ApiError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    code: "INTERNAL_ERROR".into(),
+                    details: None,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::entities::{NewTransfer, TransferUpdate};
+use domain::errors::AccountError;
+use domain::types::{AccountKind, AccountName, Money, TransactionDate};
+
+use crate::errors::ApiError;
+use crate::requests::{
+    CreateAccountRequest, CreateTransferRequest, UpdateAccountRequest, UpdateTransferRequest,
+};
+use crate::responses::{AccountLedgerResponse, AccountResponse, ErrorResponse, TransferResponse};
+
+use super::{parse_ulid, AppState};
+
+fn parse_name(value: String) -> Result<AccountName, AccountError> {
+    AccountName::new(value).map_err(|e| AccountError::InvalidName {
+        reason: e.to_string(),
+    })
+}
+
+fn parse_kind(value: String) -> Result<AccountKind, AccountError> {
+    value
+        .parse::<AccountKind>()
+        .map_err(|_| AccountError::InvalidKind { value })
+}
+
+fn parse_date(value: &str) -> Result<TransactionDate, AccountError> {
+    value.parse().map_err(|_| AccountError::InvalidDate {
+        value: value.to_string(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/accounts",
+    tag = "accounts",
+    responses(
+        (status = 200, description = "Accounts ordered by name, with current balances", body = [AccountResponse]),
+    )
+)]
+pub async fn list_accounts(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<AccountResponse>>, ApiError> {
+    let accounts = state.account_service.list_all().await?;
+    let response: Vec<AccountResponse> = accounts.into_iter().map(|a| a.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/accounts",
+    tag = "accounts",
+    request_body = CreateAccountRequest,
+    responses(
+        (status = 201, description = "Account created", body = AccountResponse),
+        (status = 409, description = "Account name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid name or kind", body = ErrorResponse),
+    )
+)]
+pub async fn create_account(
+    State(state): State<AppState>,
+    Json(req): Json<CreateAccountRequest>,
+) -> Result<(StatusCode, Json<AccountResponse>), ApiError> {
+    let name = parse_name(req.name)?;
+    let kind = match req.kind {
+        Some(k) => parse_kind(k)?,
+        None => AccountKind::default(),
+    };
+
+    let account = state
+        .account_service
+        .create(name, kind, Money::new(req.opening_balance))
+        .await?;
+    Ok((StatusCode::CREATED, Json(account.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/accounts/{id}",
+    tag = "accounts",
+    params(("id" = String, Path, description = "Account ULID")),
+    responses(
+        (status = 200, description = "Account with its current balance", body = AccountResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_account(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<AccountResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let account = state.account_service.get(&ulid).await?;
+    Ok(Json(account.into()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/accounts/{id}",
+    tag = "accounts",
+    params(("id" = String, Path, description = "Account ULID")),
+    request_body = UpdateAccountRequest,
+    responses(
+        (status = 200, description = "Account updated", body = AccountResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 409, description = "Account name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid name or kind", body = ErrorResponse),
+    )
+)]
+pub async fn update_account(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateAccountRequest>,
+) -> Result<Json<AccountResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let name = req.name.map(parse_name).transpose()?;
+    let kind = req.kind.map(parse_kind).transpose()?;
+
+    let account = state
+        .account_service
+        .update(&ulid, name, kind, req.opening_balance.map(Money::new))
+        .await?;
+    Ok(Json(account.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/accounts/{id}",
+    tag = "accounts",
+    params(("id" = String, Path, description = "Account ULID")),
+    responses(
+        (status = 204, description = "Account deleted; its transactions are kept without an account"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 409, description = "Account has transfers", body = ErrorResponse),
+    )
+)]
+pub async fn delete_account(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.account_service.delete(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/accounts/{id}/ledger",
+    tag = "accounts",
+    params(("id" = String, Path, description = "Account ULID")),
+    responses(
+        (status = 200, description = "Transactions and transfers of the account with running balances", body = AccountLedgerResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_account_ledger(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<AccountLedgerResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let ledger = state.account_service.ledger(&ulid).await?;
+    Ok(Json(ledger.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/accounts/transfers",
+    tag = "accounts",
+    responses(
+        (status = 200, description = "Transfers, newest first", body = [TransferResponse]),
+    )
+)]
+pub async fn list_transfers(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TransferResponse>>, ApiError> {
+    let transfers = state.account_service.list_transfers().await?;
+    let response: Vec<TransferResponse> = transfers.into_iter().map(|t| t.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/accounts/transfers",
+    tag = "accounts",
+    request_body = CreateTransferRequest,
+    responses(
+        (status = 201, description = "Transfer created", body = TransferResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, date or title, or both accounts the same", body = ErrorResponse),
+    )
+)]
+pub async fn create_transfer(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTransferRequest>,
+) -> Result<(StatusCode, Json<TransferResponse>), ApiError> {
+    let from_account_id = parse_ulid(&req.from_account_id)?;
+    let to_account_id = parse_ulid(&req.to_account_id)?;
+    let date = parse_date(&req.date)?;
+
+    let transfer = state
+        .account_service
+        .create_transfer(NewTransfer {
+            from_account_id,
+            to_account_id,
+            amount: Money::new(req.amount),
+            date,
+            title: req.title,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(transfer.into())))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/accounts/transfers/{id}",
+    tag = "accounts",
+    params(("id" = String, Path, description = "Transfer ULID")),
+    request_body = UpdateTransferRequest,
+    responses(
+        (status = 200, description = "Transfer updated", body = TransferResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Transfer or account not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, date or title, or both accounts the same", body = ErrorResponse),
+    )
+)]
+pub async fn update_transfer(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateTransferRequest>,
+) -> Result<Json<TransferResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let from_account_id = req.from_account_id.as_deref().map(parse_ulid).transpose()?;
+    let to_account_id = req.to_account_id.as_deref().map(parse_ulid).transpose()?;
+    let date = req.date.as_deref().map(parse_date).transpose()?;
+
+    let transfer = state
+        .account_service
+        .update_transfer(
+            &ulid,
+            TransferUpdate {
+                from_account_id,
+                to_account_id,
+                amount: req.amount.map(Money::new),
+                date,
+                title: req.title,
+            },
+        )
+        .await?;
+    Ok(Json(transfer.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/accounts/transfers/{id}",
+    tag = "accounts",
+    params(("id" = String, Path, description = "Transfer ULID")),
+    responses(
+        (status = 204, description = "Transfer deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Transfer not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_transfer(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.account_service.delete_transfer(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,203 @@
+use axum::extract::{DefaultBodyLimit, Multipart, Path, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::Json;
+use serde_json::json;
+
+use crate::errors::ApiError;
+use crate::requests::UploadAttachmentRequest;
+use crate::responses::{AttachmentResponse, ErrorResponse};
+
+use super::{parse_ulid, AppState};
+
+/// Name of the multipart field carrying the file
+const FILE_FIELD: &str = "file";
+
+/// Room for multipart boundaries and part headers on top of the file itself
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
+/// Request body limit for uploads, replacing axum's 2 MB default so that
+/// files up to the configured maximum get through.
+pub fn upload_body_limit(state: &AppState) -> DefaultBodyLimit {
+    DefaultBodyLimit::max(state.attachment_service.max_size() + MULTIPART_OVERHEAD)
+}
+
+/// Removes files whose attachments went away with deleted transactions.
+/// Called after any request that may delete transactions; failures are only
+/// logged since the files stay queued for the next run.
+pub(crate) async fn purge_files(state: &AppState) {
+    match state.attachment_service.purge_files().await {
+        Ok(0) => {}
+        Ok(removed) => tracing::debug!("Removed {} unreferenced attachment files", removed),
+        Err(e) => tracing::warn!("Failed to remove unreferenced attachment files: {}", e),
+    }
+}
+
+fn multipart_error(err: axum::extract::multipart::MultipartError) -> ApiError {
+    ApiError {
+        status: err.status(),
+        code: "ATTACHMENT_INVALID_UPLOAD".into(),
+        details: Some(json!({ "reason": err.body_text() })),
+    }
+}
+
+/// `Content-Disposition` offering the original name to the browser: a plain
+/// ASCII fallback plus the exact name encoded per RFC 5987.
+fn content_disposition(file_name: &str) -> HeaderValue {
+    let fallback: String = file_name
+        .chars()
+        .map(|c| match c {
+            ' '..='~' if c != '"' && c != '\\' => c,
+            _ => '_',
+        })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+
+    HeaderValue::from_str(&format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        fallback, encoded
+    ))
+    .unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/transactions/{id}/attachments",
+    tag = "attachments",
+    params(("id" = String, Path, description = "Transaction ULID")),
+    request_body(content = UploadAttachmentRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "File attached", body = AttachmentResponse),
+        (status = 400, description = "Invalid ID format, malformed form or no file field", body = ErrorResponse),
+        (status = 404, description = "Transaction not found", body = ErrorResponse),
+        (status = 413, description = "File larger than the configured maximum", body = ErrorResponse),
+        (status = 422, description = "Empty file, invalid file name or content type", body = ErrorResponse),
+    )
+)]
+pub async fn upload_attachment(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<AttachmentResponse>), ApiError> {
+    let ulid = parse_ulid(&id)?;
+
+    while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let content_type = field.content_type().map(str::to_string);
+        let contents = field.bytes().await.map_err(multipart_error)?;
+
+        let attachment = state
+            .attachment_service
+            .upload(&ulid, &file_name, content_type.as_deref(), &contents)
+            .await?;
+        return Ok((StatusCode::CREATED, Json(attachment.into())));
+    }
+
+    Err(ApiError::bad_request("Missing 'file' field"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/transactions/{id}/attachments",
+    tag = "attachments",
+    params(("id" = String, Path, description = "Transaction ULID")),
+    responses(
+        (status = 200, description = "Attachments of the transaction, oldest first", body = [AttachmentResponse]),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Transaction not found", body = ErrorResponse),
+    )
+)]
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<AttachmentResponse>>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let attachments = state.attachment_service.list(&ulid).await?;
+    let response: Vec<AttachmentResponse> = attachments.into_iter().map(|a| a.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/transactions/{id}/attachments/{attachment_id}",
+    tag = "attachments",
+    params(
+        ("id" = String, Path, description = "Transaction ULID"),
+        ("attachment_id" = String, Path, description = "Attachment ULID"),
+    ),
+    responses(
+        (status = 200, description = "File contents, served as a download", content_type = "application/octet-stream"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Attachment not found on this transaction", body = ErrorResponse),
+    )
+)]
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    Path((id, attachment_id)): Path<(String, String)>,
+) -> Result<(HeaderMap, Vec<u8>), ApiError> {
+    let transaction_id = parse_ulid(&id)?;
+    let attachment_id = parse_ulid(&attachment_id)?;
+
+    let (attachment, contents) = state
+        .attachment_service
+        .download(&transaction_id, &attachment_id)
+        .await?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&attachment.content_type)
+            .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream")),
+    );
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        content_disposition(&attachment.file_name),
+    );
+    headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    if let Ok(etag) = HeaderValue::from_str(&format!("\"{}\"", attachment.sha256)) {
+        headers.insert(header::ETAG, etag);
+    }
+
+    Ok((headers, contents))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/transactions/{id}/attachments/{attachment_id}",
+    tag = "attachments",
+    params(
+        ("id" = String, Path, description = "Transaction ULID"),
+        ("attachment_id" = String, Path, description = "Attachment ULID"),
+    ),
+    responses(
+        (status = 204, description = "Attachment deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Attachment not found on this transaction", body = ErrorResponse),
+    )
+)]
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    Path((id, attachment_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    let transaction_id = parse_ulid(&id)?;
+    let attachment_id = parse_ulid(&attachment_id)?;
+
+    state
+        .attachment_service
+        .delete(&transaction_id, &attachment_id)
+        .await?;
+    purge_files(&state).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
@@ -1,4 +1,4 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
 
@@ -6,20 +6,69 @@ use domain::errors::CategoryError;
 use domain::types::CategoryName;
 
 use crate::errors::ApiError;
+use crate::requests::CategoryHistoryQuery;
+use crate::requests::CategoryListQuery;
 use crate::requests::CreateCategoryRequest;
+use crate::requests::MergeCategoryRequest;
+use crate::requests::MoveCategorySubtreeRequest;
 use crate::requests::UpdateCategoryRequest;
-use crate::responses::CategoryResponse;
+use crate::responses::{
+    CategoryHistoryResponse, CategoryResponse, CategoryTreeNodeResponse, ErrorResponse,
+};
 
 use super::{parse_ulid, AppState};
 
+#[utoipa::path(
+    get,
+    path = "/categories",
+    tag = "categories",
+    params(CategoryListQuery),
+    responses(
+        (status = 200, description = "Categories ordered by name", body = [CategoryResponse]),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+    )
+)]
 pub async fn list_categories(
     State(state): State<AppState>,
+    Query(query): Query<CategoryListQuery>,
 ) -> Result<Json<Vec<CategoryResponse>>, ApiError> {
-    let categories = state.category_service.list_all().await?;
+    let include_archived = query.include_archived.unwrap_or(false);
+    let categories = state.category_service.list_all(include_archived).await?;
     let response: Vec<CategoryResponse> = categories.into_iter().map(|c| c.into()).collect();
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    path = "/categories/tree",
+    tag = "categories",
+    params(CategoryListQuery),
+    responses(
+        (status = 200, description = "Top-level nodes of the category hierarchy", body = [CategoryTreeNodeResponse]),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+    )
+)]
+pub async fn get_category_tree(
+    State(state): State<AppState>,
+    Query(query): Query<CategoryListQuery>,
+) -> Result<Json<Vec<CategoryTreeNodeResponse>>, ApiError> {
+    let include_archived = query.include_archived.unwrap_or(false);
+    let tree = state.category_service.tree(include_archived).await?;
+    let response: Vec<CategoryTreeNodeResponse> = tree.roots.into_iter().map(|n| n.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/categories",
+    tag = "categories",
+    request_body = CreateCategoryRequest,
+    responses(
+        (status = 201, description = "Category created", body = CategoryResponse),
+        (status = 409, description = "Category name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid category name", body = ErrorResponse),
+    )
+)]
 pub async fn create_category(
     State(state): State<AppState>,
     Json(req): Json<CreateCategoryRequest>,
@@ -33,6 +82,20 @@ pub async fn create_category(
     Ok((StatusCode::CREATED, Json(category.into())))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/categories/{id}",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ULID")),
+    request_body = UpdateCategoryRequest,
+    responses(
+        (status = 200, description = "Category updated", body = CategoryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 409, description = "Category name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid category name", body = ErrorResponse),
+    )
+)]
 pub async fn update_category(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -52,3 +115,142 @@ pub async fn update_category(
     let category = state.category_service.update(&ulid, name, req.label).await?;
     Ok(Json(category.into()))
 }
+
+#[utoipa::path(
+    post,
+    path = "/categories/{id}/archive",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ULID")),
+    responses(
+        (status = 200, description = "Category archived", body = CategoryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+    )
+)]
+pub async fn archive_category(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let category = state.category_service.archive(&ulid).await?;
+    Ok(Json(category.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/categories/{id}/unarchive",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ULID")),
+    responses(
+        (status = 200, description = "Category restored", body = CategoryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+    )
+)]
+pub async fn unarchive_category(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let category = state.category_service.unarchive(&ulid).await?;
+    Ok(Json(category.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/categories/{id}",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ULID")),
+    responses(
+        (status = 204, description = "Category deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 409, description = "Category is used by budget entries or recurring rules", body = ErrorResponse),
+    )
+)]
+pub async fn delete_category(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.category_service.delete(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/categories/{id}/merge",
+    tag = "categories",
+    params(("id" = String, Path, description = "ULID of the source category, deleted after the merge")),
+    request_body = MergeCategoryRequest,
+    responses(
+        (status = 200, description = "Source merged; returns the target category", body = CategoryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Source or target category not found", body = ErrorResponse),
+        (status = 409, description = "An income and an expense entry of the two categories share a month", body = ErrorResponse),
+        (status = 422, description = "Source and target are the same category", body = ErrorResponse),
+    )
+)]
+pub async fn merge_category(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<MergeCategoryRequest>,
+) -> Result<Json<CategoryResponse>, ApiError> {
+    let source = parse_ulid(&id)?;
+    let target = parse_ulid(&req.target_id)?;
+    let category = state.category_service.merge(&source, &target).await?;
+    Ok(Json(category.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/categories/move",
+    tag = "categories",
+    request_body = MoveCategorySubtreeRequest,
+    responses(
+        (status = 200, description = "Renamed categories ordered by new name", body = [CategoryResponse]),
+        (status = 404, description = "No category matches the `from` prefix", body = ErrorResponse),
+        (status = 409, description = "A renamed category would collide with an existing one", body = ErrorResponse),
+        (status = 422, description = "Invalid category name", body = ErrorResponse),
+    )
+)]
+pub async fn move_category_subtree(
+    State(state): State<AppState>,
+    Json(req): Json<MoveCategorySubtreeRequest>,
+) -> Result<Json<Vec<CategoryResponse>>, ApiError> {
+    let parse = |name: String| {
+        CategoryName::new(name).map_err(|e| CategoryError::InvalidNameFormat {
+            reason: e.to_string(),
+        })
+    };
+    let from = parse(req.from)?;
+    let to = parse(req.to)?;
+
+    let categories = state.category_service.move_subtree(&from, &to).await?;
+    let response: Vec<CategoryResponse> = categories.into_iter().map(|c| c.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/categories/{id}/history",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ULID"), CategoryHistoryQuery),
+    responses(
+        (status = 200, description = "Budgeted vs paid per month with statistics", body = CategoryHistoryResponse),
+        (status = 400, description = "Invalid ID format or `months` of 0", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_category_history(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<CategoryHistoryQuery>,
+) -> Result<Json<CategoryHistoryResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    if query.months == Some(0) {
+        return Err(ApiError::bad_request("months must be at least 1"));
+    }
+    let history = state.history_service.category_history(&ulid, query.months).await?;
+    Ok(Json(history.into()))
+}
@@ -2,15 +2,35 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::Json;
 
-use domain::errors::EntryError;
-use domain::types::{DueDay, Money};
+use chrono::Utc;
+
+use domain::errors::{EntryError, TransactionError};
+use domain::services::PaymentDate;
+use domain::types::{DueDay, EntryKind, Money, RolloverMode};
 
 use crate::errors::ApiError;
-use crate::requests::{CreateEntryRequest, UpdateEntryRequest};
-use crate::responses::EntryResponse;
+use crate::requests::{CreateEntryRequest, PayEntryRequest, UpdateEntryRequest};
+use crate::responses::{EntryResponse, ErrorResponse, TransactionResponse};
 
 use super::{parse_ulid, AppState};
 
+fn parse_rollover(value: String) -> Result<RolloverMode, EntryError> {
+    value
+        .parse::<RolloverMode>()
+        .map_err(|_| EntryError::InvalidRollover { value })
+}
+
+#[utoipa::path(
+    get,
+    path = "/months/{id}/entries",
+    tag = "entries",
+    params(("id" = String, Path, description = "Month ULID")),
+    responses(
+        (status = 200, description = "Entries of the month ordered by due day", body = [EntryResponse]),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Month not found", body = ErrorResponse),
+    )
+)]
 pub async fn list_entries(
     State(state): State<AppState>,
     Path(month_id): Path<String>,
@@ -21,6 +41,20 @@ pub async fn list_entries(
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    post,
+    path = "/months/{id}/entries",
+    tag = "entries",
+    params(("id" = String, Path, description = "Month ULID")),
+    request_body = CreateEntryRequest,
+    responses(
+        (status = 201, description = "Entry created", body = EntryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Month or category not found", body = ErrorResponse),
+        (status = 409, description = "Category already budgeted in this month", body = ErrorResponse),
+        (status = 422, description = "Invalid due day, kind or rollover mode", body = ErrorResponse),
+    )
+)]
 pub async fn create_entry(
     State(state): State<AppState>,
     Path(month_id): Path<String>,
@@ -37,15 +71,41 @@ pub async fn create_entry(
         None => None,
     };
 
+    let kind = match req.kind {
+        Some(k) => k.parse::<EntryKind>().map_err(|_| EntryError::InvalidKind { value: k })?,
+        None => EntryKind::default(),
+    };
+
+    let rollover = match req.rollover {
+        Some(r) => parse_rollover(r)?,
+        None => RolloverMode::default(),
+    };
+
     let budgeted = Money::new(req.budgeted);
 
     let entry = state
         .entry_service
-        .create(month_ulid, category_ulid, budgeted, due_day)
+        .create(month_ulid, category_ulid, kind, budgeted, rollover, due_day)
         .await?;
     Ok((StatusCode::CREATED, Json(entry.into())))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/months/{id}/entries/{entry_id}",
+    tag = "entries",
+    params(
+        ("id" = String, Path, description = "Month ULID"),
+        ("entry_id" = String, Path, description = "Entry ULID"),
+    ),
+    request_body = UpdateEntryRequest,
+    responses(
+        (status = 200, description = "Entry updated", body = EntryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Entry not found", body = ErrorResponse),
+        (status = 422, description = "Invalid due day or rollover mode", body = ErrorResponse),
+    )
+)]
 pub async fn update_entry(
     State(state): State<AppState>,
     Path((_month_id, entry_id)): Path<(String, String)>,
@@ -64,13 +124,33 @@ pub async fn update_entry(
         None => None,
     };
 
+    let rollover = match req.rollover {
+        Some(r) => Some(parse_rollover(r)?),
+        None => None,
+    };
+
     let entry = state
         .entry_service
-        .update(&entry_ulid, budgeted, due_day)
+        .update(&entry_ulid, budgeted, due_day, rollover)
         .await?;
     Ok(Json(entry.into()))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/months/{id}/entries/{entry_id}",
+    tag = "entries",
+    params(
+        ("id" = String, Path, description = "Month ULID"),
+        ("entry_id" = String, Path, description = "Entry ULID"),
+    ),
+    responses(
+        (status = 204, description = "Entry deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Entry not found", body = ErrorResponse),
+        (status = 409, description = "Entry has transactions", body = ErrorResponse),
+    )
+)]
 pub async fn delete_entry(
     State(state): State<AppState>,
     Path((_month_id, entry_id)): Path<(String, String)>,
@@ -79,3 +159,45 @@ pub async fn delete_entry(
     state.entry_service.delete(&entry_ulid).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/months/{id}/entries/{entry_id}/pay",
+    tag = "entries",
+    params(
+        ("id" = String, Path, description = "Month ULID"),
+        ("entry_id" = String, Path, description = "Entry ULID"),
+    ),
+    request_body = PayEntryRequest,
+    responses(
+        (status = 201, description = "Payment of the entry's remaining amount booked", body = TransactionResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Entry not found in the month", body = ErrorResponse),
+        (status = 409, description = "Entry is already on budget or overspent", body = ErrorResponse),
+        (status = 422, description = "Invalid `on`, income entry, entry has no due day, `today` outside the entry's month, or title too long", body = ErrorResponse),
+    )
+)]
+pub async fn pay_entry(
+    State(state): State<AppState>,
+    Path((month_id, entry_id)): Path<(String, String)>,
+    Json(req): Json<PayEntryRequest>,
+) -> Result<(StatusCode, Json<TransactionResponse>), ApiError> {
+    let month_ulid = parse_ulid(&month_id)?;
+    let entry_ulid = parse_ulid(&entry_id)?;
+    let on = match req.on.as_deref() {
+        None | Some("today") => PaymentDate::Today,
+        Some("due_date") => PaymentDate::DueDate,
+        Some(other) => {
+            return Err(TransactionError::InvalidDate {
+                value: other.to_string(),
+            }
+            .into());
+        }
+    };
+
+    let transaction = state
+        .transaction_service
+        .pay_entry(&month_ulid, &entry_ulid, on, Utc::now().date_naive(), req.title)
+        .await?;
+    Ok((StatusCode::CREATED, Json(transaction.into())))
+}
@@ -0,0 +1,242 @@
+use axum::extract::{Multipart, Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde_json::json;
+
+use domain::entities::{CsvMapping, ImportedTransaction};
+use domain::errors::ImportError;
+use domain::types::{ImportProfileName, Money, TransactionDate, TransactionKind};
+
+use crate::csv_file::read_records;
+use crate::errors::ApiError;
+use crate::requests::{
+    CommitImportRequest, CreateImportProfileRequest, CsvMappingRequest, PreviewImportRequest,
+    UpdateImportProfileRequest,
+};
+use crate::responses::{
+    ErrorResponse, ImportPreviewResponse, ImportProfileResponse, TransactionResponse,
+};
+
+use super::{parse_ulid, AppState};
+
+fn parse_name(value: String) -> Result<ImportProfileName, ImportError> {
+    ImportProfileName::new(value).map_err(|e| ImportError::InvalidProfileName {
+        reason: e.to_string(),
+    })
+}
+
+fn parse_char(value: &str, what: &str) -> Result<char, ImportError> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ImportError::InvalidMapping {
+            reason: format!("{} must be one character, got {:?}", what, value),
+        }),
+    }
+}
+
+fn parse_mapping(req: CsvMappingRequest) -> Result<CsvMapping, ImportError> {
+    Ok(CsvMapping {
+        delimiter: parse_char(&req.delimiter, "delimiter")?,
+        has_header: req.has_header,
+        date_column: req.date_column,
+        amount_column: req.amount_column,
+        description_column: req.description_column,
+        decimal_separator: parse_char(&req.decimal_separator, "decimal separator")?,
+        date_format: req.date_format,
+    })
+}
+
+fn multipart_error(err: axum::extract::multipart::MultipartError) -> ApiError {
+    ApiError {
+        status: err.status(),
+        code: "IMPORT_INVALID_UPLOAD".into(),
+        details: Some(json!({ "reason": err.body_text() })),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/imports/profiles",
+    tag = "imports",
+    responses(
+        (status = 200, description = "Import profiles ordered by name", body = [ImportProfileResponse]),
+    )
+)]
+pub async fn list_import_profiles(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ImportProfileResponse>>, ApiError> {
+    let profiles = state.import_service.list_profiles().await?;
+    let response: Vec<ImportProfileResponse> = profiles.into_iter().map(|p| p.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/imports/profiles",
+    tag = "imports",
+    request_body = CreateImportProfileRequest,
+    responses(
+        (status = 201, description = "Import profile created", body = ImportProfileResponse),
+        (status = 409, description = "Profile name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid name or mapping", body = ErrorResponse),
+    )
+)]
+pub async fn create_import_profile(
+    State(state): State<AppState>,
+    Json(req): Json<CreateImportProfileRequest>,
+) -> Result<(StatusCode, Json<ImportProfileResponse>), ApiError> {
+    let name = parse_name(req.name)?;
+    let mapping = parse_mapping(req.mapping)?;
+
+    let profile = state.import_service.create_profile(name, mapping).await?;
+    Ok((StatusCode::CREATED, Json(profile.into())))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/imports/profiles/{id}",
+    tag = "imports",
+    params(("id" = String, Path, description = "Import profile ULID")),
+    request_body = UpdateImportProfileRequest,
+    responses(
+        (status = 200, description = "Import profile updated", body = ImportProfileResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Import profile not found", body = ErrorResponse),
+        (status = 409, description = "Profile name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid name or mapping", body = ErrorResponse),
+    )
+)]
+pub async fn update_import_profile(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateImportProfileRequest>,
+) -> Result<Json<ImportProfileResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let name = req.name.map(parse_name).transpose()?;
+    let mapping = req.mapping.map(parse_mapping).transpose()?;
+
+    let profile = state
+        .import_service
+        .update_profile(&ulid, name, mapping)
+        .await?;
+    Ok(Json(profile.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/imports/profiles/{id}",
+    tag = "imports",
+    params(("id" = String, Path, description = "Import profile ULID")),
+    responses(
+        (status = 204, description = "Import profile deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Import profile not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_import_profile(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.import_service.delete_profile(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/imports/preview",
+    tag = "imports",
+    request_body(content = PreviewImportRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Rows read from the file, and the lines that could not be read; nothing is booked", body = ImportPreviewResponse),
+        (status = 400, description = "Malformed form, no file, neither or both of `profile_id` and `mapping`, or invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Import profile not found", body = ErrorResponse),
+        (status = 422, description = "Invalid mapping, or a file that is not UTF-8 CSV or has no rows", body = ErrorResponse),
+    )
+)]
+pub async fn preview_import(
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> Result<Json<ImportPreviewResponse>, ApiError> {
+    let mut contents = None;
+    let mut profile_id = None;
+    let mut mapping = None;
+
+    while let Some(field) = multipart.next_field().await.map_err(multipart_error)? {
+        match field.name() {
+            Some("file") => contents = Some(field.bytes().await.map_err(multipart_error)?),
+            Some("profile_id") => profile_id = Some(field.text().await.map_err(multipart_error)?),
+            Some("mapping") => {
+                let text = field.text().await.map_err(multipart_error)?;
+                let req: CsvMappingRequest =
+                    serde_json::from_str(&text).map_err(|e| ImportError::InvalidMapping {
+                        reason: e.to_string(),
+                    })?;
+                mapping = Some(parse_mapping(req)?);
+            }
+            _ => {}
+        }
+    }
+
+    let Some(contents) = contents else {
+        return Err(ApiError::bad_request("Missing 'file' field"));
+    };
+    let mapping = match (profile_id, mapping) {
+        (Some(id), None) => {
+            let ulid = parse_ulid(&id)?;
+            state.import_service.get_profile(&ulid).await?.mapping
+        }
+        (None, Some(mapping)) => mapping,
+        _ => {
+            return Err(ApiError::bad_request(
+                "Give either 'profile_id' or 'mapping'",
+            ))
+        }
+    };
+
+    state.import_service.validate_mapping(&mapping)?;
+    let records = read_records(&contents, &mapping)?;
+    let preview = state.import_service.preview(&records, &mapping)?;
+    Ok(Json(preview.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/imports/commit",
+    tag = "imports",
+    request_body = CommitImportRequest,
+    responses(
+        (status = 201, description = "All rows booked as transactions, in request order", body = [TransactionResponse]),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Budget entry of a row not found; nothing is booked", body = ErrorResponse),
+        (status = 422, description = "Invalid kind, amount, date or title, or a date outside the month of the row's entry; nothing is booked", body = ErrorResponse),
+    )
+)]
+pub async fn commit_import(
+    State(state): State<AppState>,
+    Json(req): Json<CommitImportRequest>,
+) -> Result<(StatusCode, Json<Vec<TransactionResponse>>), ApiError> {
+    let mut rows = Vec::with_capacity(req.rows.len());
+    for (row, r) in req.rows.into_iter().enumerate() {
+        let invalid = |reason: String| ImportError::InvalidRow { row, reason };
+        let kind = match r.kind {
+            Some(k) => k.parse::<TransactionKind>().map_err(|e| invalid(e.to_string()))?,
+            None => TransactionKind::default(),
+        };
+        rows.push(ImportedTransaction {
+            entry_id: parse_ulid(&r.entry_id)?,
+            kind,
+            amount: Money::new(r.amount),
+            date: r
+                .date
+                .parse::<TransactionDate>()
+                .map_err(|e| invalid(e.to_string()))?,
+            title: r.title,
+        });
+    }
+
+    let transactions = state.import_service.commit(rows).await?;
+    let response: Vec<TransactionResponse> = transactions.into_iter().map(|t| t.into()).collect();
+    Ok((StatusCode::CREATED, Json(response)))
+}
@@ -1,14 +1,21 @@
+pub mod accounts;
+pub mod attachments;
 pub mod categories;
 pub mod entries;
 pub mod health;
+pub mod imports;
 pub mod months;
+pub mod payees;
+pub mod recurring;
 pub mod summary;
+pub mod tags;
 pub mod transactions;
 
 use std::sync::Arc;
 
 use domain::services::{
-    CategoryService, EntryService, MonthService, SummaryService, TransactionService,
+    AccountService, AttachmentService, CategoryService, EntryService, HistoryService, ImportService,
+    MonthService, PayeeService, RecurringService, SummaryService, TagService, TransactionService,
 };
 
 use crate::config::CurrencyConfig;
@@ -20,6 +27,13 @@ pub struct AppState {
     pub entry_service: Arc<EntryService>,
     pub transaction_service: Arc<TransactionService>,
     pub summary_service: Arc<SummaryService>,
+    pub history_service: Arc<HistoryService>,
+    pub recurring_service: Arc<RecurringService>,
+    pub tag_service: Arc<TagService>,
+    pub payee_service: Arc<PayeeService>,
+    pub account_service: Arc<AccountService>,
+    pub attachment_service: Arc<AttachmentService>,
+    pub import_service: Arc<ImportService>,
     pub currency_config: CurrencyConfig,
 }
 
@@ -1,4 +1,4 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::Json;
 
@@ -6,11 +6,20 @@ use domain::errors::MonthError;
 use domain::types::BudgetMonth;
 
 use crate::errors::ApiError;
-use crate::requests::CreateMonthRequest;
-use crate::responses::MonthResponse;
+use crate::requests::{CreateMonthRequest, DeleteMonthQuery, ResetMonthRequest};
+use crate::responses::{ErrorResponse, MonthResponse};
 
-use super::{parse_ulid, AppState};
+use super::{attachments, parse_ulid, AppState};
 
+#[utoipa::path(
+    get,
+    path = "/months",
+    tag = "months",
+    responses(
+        (status = 200, description = "All months, newest first", body = [MonthResponse]),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+    )
+)]
 pub async fn list_months(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<MonthResponse>>, ApiError> {
@@ -19,6 +28,17 @@ pub async fn list_months(
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    get,
+    path = "/months/{id}",
+    tag = "months",
+    params(("id" = String, Path, description = "Month ULID")),
+    responses(
+        (status = 200, description = "Month found", body = MonthResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Month not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_month(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -28,6 +48,18 @@ pub async fn get_month(
     Ok(Json(month.into()))
 }
 
+#[utoipa::path(
+    post,
+    path = "/months",
+    tag = "months",
+    request_body = CreateMonthRequest,
+    responses(
+        (status = 201, description = "Month created, entries copied unless `empty` is set", body = MonthResponse),
+        (status = 404, description = "Source month for `copy_from` not found", body = ErrorResponse),
+        (status = 409, description = "Month already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid month format", body = ErrorResponse),
+    )
+)]
 pub async fn create_month(
     State(state): State<AppState>,
     Json(req): Json<CreateMonthRequest>,
@@ -52,3 +84,60 @@ pub async fn create_month(
         .await?;
     Ok((StatusCode::CREATED, Json(month.into())))
 }
+
+#[utoipa::path(
+    delete,
+    path = "/months/{id}",
+    tag = "months",
+    params(("id" = String, Path, description = "Month ULID"), DeleteMonthQuery),
+    responses(
+        (status = 204, description = "Month and its entries deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Month not found", body = ErrorResponse),
+        (status = 409, description = "Month has transactions and `cascade` is not set", body = ErrorResponse),
+    )
+)]
+pub async fn delete_month(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<DeleteMonthQuery>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let cascade = query.cascade.unwrap_or(false);
+    state.month_service.delete(&ulid, cascade).await?;
+    attachments::purge_files(&state).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/months/{id}/reset",
+    tag = "months",
+    params(("id" = String, Path, description = "Month ULID")),
+    request_body = ResetMonthRequest,
+    responses(
+        (status = 200, description = "Entries replaced with copies from the source month", body = MonthResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Month or source month not found", body = ErrorResponse),
+        (status = 409, description = "Month has transactions", body = ErrorResponse),
+        (status = 422, description = "Source month is the month being reset", body = ErrorResponse),
+    )
+)]
+pub async fn reset_month(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<ResetMonthRequest>,
+) -> Result<Json<MonthResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let copy_from_ulid = match req.copy_from {
+        Some(ref copy_from_str) => Some(parse_ulid(copy_from_str)?),
+        None => None,
+    };
+
+    let month = state
+        .month_service
+        .reset(&ulid, copy_from_ulid.as_ref())
+        .await?;
+    attachments::purge_files(&state).await;
+    Ok(Json(month.into()))
+}
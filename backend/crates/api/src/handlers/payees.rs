@@ -0,0 +1,167 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::errors::PayeeError;
+use domain::types::PayeeName;
+
+use crate::errors::ApiError;
+use crate::requests::{CreatePayeeRequest, PayeeLookupQuery, UpdatePayeeRequest};
+use crate::responses::{ErrorResponse, PayeeMatchResponse, PayeeReportResponse, PayeeResponse};
+
+use super::{parse_ulid, AppState};
+
+fn parse_name(value: String) -> Result<PayeeName, PayeeError> {
+    PayeeName::new(value).map_err(|e| PayeeError::InvalidName {
+        reason: e.to_string(),
+    })
+}
+
+fn parse_names(values: Vec<String>) -> Result<Vec<PayeeName>, PayeeError> {
+    values.into_iter().map(parse_name).collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/payees",
+    tag = "payees",
+    responses(
+        (status = 200, description = "Payees ordered by name", body = [PayeeResponse]),
+    )
+)]
+pub async fn list_payees(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<PayeeResponse>>, ApiError> {
+    let payees = state.payee_service.list_all().await?;
+    let response: Vec<PayeeResponse> = payees.into_iter().map(|p| p.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/payees",
+    tag = "payees",
+    request_body = CreatePayeeRequest,
+    responses(
+        (status = 201, description = "Payee created", body = PayeeResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Default category not found", body = ErrorResponse),
+        (status = 409, description = "Name or alias already used by another payee", body = ErrorResponse),
+        (status = 422, description = "Invalid name or alias", body = ErrorResponse),
+    )
+)]
+pub async fn create_payee(
+    State(state): State<AppState>,
+    Json(req): Json<CreatePayeeRequest>,
+) -> Result<(StatusCode, Json<PayeeResponse>), ApiError> {
+    let name = parse_name(req.name)?;
+    let default_category_id = req.default_category_id.as_deref().map(parse_ulid).transpose()?;
+    let aliases = parse_names(req.aliases)?;
+
+    let payee = state
+        .payee_service
+        .create(name, default_category_id, aliases)
+        .await?;
+    Ok((StatusCode::CREATED, Json(payee.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/payees/lookup",
+    tag = "payees",
+    params(PayeeLookupQuery),
+    responses(
+        (status = 200, description = "Payee with this name or alias, and the entry to preselect", body = PayeeMatchResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "No payee with this name or alias", body = ErrorResponse),
+        (status = 422, description = "Invalid name", body = ErrorResponse),
+    )
+)]
+pub async fn lookup_payee(
+    State(state): State<AppState>,
+    Query(query): Query<PayeeLookupQuery>,
+) -> Result<Json<PayeeMatchResponse>, ApiError> {
+    let name = parse_name(query.name)?;
+    let month_id = query.month.as_deref().map(parse_ulid).transpose()?;
+
+    let found = state
+        .payee_service
+        .lookup(&name, month_id.as_ref())
+        .await?;
+    Ok(Json(found.into()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/payees/{id}",
+    tag = "payees",
+    params(("id" = String, Path, description = "Payee ULID")),
+    request_body = UpdatePayeeRequest,
+    responses(
+        (status = 200, description = "Payee updated", body = PayeeResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Payee or default category not found", body = ErrorResponse),
+        (status = 409, description = "Name or alias already used by another payee", body = ErrorResponse),
+        (status = 422, description = "Invalid name or alias", body = ErrorResponse),
+    )
+)]
+pub async fn update_payee(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdatePayeeRequest>,
+) -> Result<Json<PayeeResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let name = req.name.map(parse_name).transpose()?;
+    let default_category_id = match req.default_category_id {
+        Some(Some(ref c)) => Some(Some(parse_ulid(c)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+    let aliases = req.aliases.map(parse_names).transpose()?;
+
+    let payee = state
+        .payee_service
+        .update(&ulid, name, default_category_id, aliases)
+        .await?;
+    Ok(Json(payee.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/payees/{id}",
+    tag = "payees",
+    params(("id" = String, Path, description = "Payee ULID")),
+    responses(
+        (status = 204, description = "Payee deleted; its transactions are kept without a payee"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Payee not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_payee(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.payee_service.delete(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/payees/{id}/report",
+    tag = "payees",
+    params(("id" = String, Path, description = "Payee ULID")),
+    responses(
+        (status = 200, description = "Spending paid to the payee, in total and per month", body = PayeeReportResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Payee not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_payee_report(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<PayeeReportResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let report = state.payee_service.report(&ulid).await?;
+    Ok(Json(report.into()))
+}
@@ -0,0 +1,174 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::errors::RecurringError;
+use domain::types::{BudgetMonth, DueDay, Money};
+
+use crate::errors::ApiError;
+use crate::requests::{CreateRecurringRuleRequest, UpdateRecurringRuleRequest};
+use crate::responses::{ErrorResponse, RecurringRuleResponse};
+
+use super::{parse_ulid, AppState};
+
+fn parse_month(value: String) -> Result<BudgetMonth, RecurringError> {
+    value
+        .parse()
+        .map_err(|_| RecurringError::InvalidMonth { value })
+}
+
+fn parse_day(value: u8) -> Result<DueDay, RecurringError> {
+    DueDay::new(value).map_err(|_| RecurringError::InvalidDay { value })
+}
+
+#[utoipa::path(
+    get,
+    path = "/recurring-rules",
+    tag = "recurring",
+    responses(
+        (status = 200, description = "All recurring rules ordered by day of month", body = [RecurringRuleResponse]),
+    )
+)]
+pub async fn list_rules(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<RecurringRuleResponse>>, ApiError> {
+    let rules = state.recurring_service.list_all().await?;
+    let response: Vec<RecurringRuleResponse> = rules.into_iter().map(|r| r.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/recurring-rules",
+    tag = "recurring",
+    request_body = CreateRecurringRuleRequest,
+    responses(
+        (status = 201, description = "Recurring rule created", body = RecurringRuleResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Category not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, title, day or month range", body = ErrorResponse),
+    )
+)]
+pub async fn create_rule(
+    State(state): State<AppState>,
+    Json(req): Json<CreateRecurringRuleRequest>,
+) -> Result<(StatusCode, Json<RecurringRuleResponse>), ApiError> {
+    let category_ulid = parse_ulid(&req.category_id)?;
+    let day_of_month = parse_day(req.day_of_month)?;
+    let start_month = parse_month(req.start_month)?;
+    let end_month = req.end_month.map(parse_month).transpose()?;
+
+    let rule = state
+        .recurring_service
+        .create(
+            category_ulid,
+            Money::new(req.amount),
+            req.title,
+            day_of_month,
+            start_month,
+            end_month,
+        )
+        .await?;
+    Ok((StatusCode::CREATED, Json(rule.into())))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/recurring-rules/{id}",
+    tag = "recurring",
+    params(("id" = String, Path, description = "Recurring rule ULID")),
+    request_body = UpdateRecurringRuleRequest,
+    responses(
+        (status = 200, description = "Recurring rule updated; already booked payments are unchanged", body = RecurringRuleResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Recurring rule not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, title, day or month range", body = ErrorResponse),
+    )
+)]
+pub async fn update_rule(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateRecurringRuleRequest>,
+) -> Result<Json<RecurringRuleResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let day_of_month = req.day_of_month.map(parse_day).transpose()?;
+    let start_month = req.start_month.map(parse_month).transpose()?;
+    let end_month = match req.end_month {
+        Some(Some(m)) => Some(Some(parse_month(m)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    let rule = state
+        .recurring_service
+        .update(
+            &ulid,
+            req.amount.map(Money::new),
+            req.title,
+            day_of_month,
+            start_month,
+            end_month,
+        )
+        .await?;
+    Ok(Json(rule.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/recurring-rules/{id}/pause",
+    tag = "recurring",
+    params(("id" = String, Path, description = "Recurring rule ULID")),
+    responses(
+        (status = 200, description = "Recurring rule paused", body = RecurringRuleResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Recurring rule not found", body = ErrorResponse),
+    )
+)]
+pub async fn pause_rule(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<RecurringRuleResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let rule = state.recurring_service.set_paused(&ulid, true).await?;
+    Ok(Json(rule.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/recurring-rules/{id}/resume",
+    tag = "recurring",
+    params(("id" = String, Path, description = "Recurring rule ULID")),
+    responses(
+        (status = 200, description = "Recurring rule resumed", body = RecurringRuleResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Recurring rule not found", body = ErrorResponse),
+    )
+)]
+pub async fn resume_rule(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<RecurringRuleResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let rule = state.recurring_service.set_paused(&ulid, false).await?;
+    Ok(Json(rule.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/recurring-rules/{id}",
+    tag = "recurring",
+    params(("id" = String, Path, description = "Recurring rule ULID")),
+    responses(
+        (status = 204, description = "Recurring rule deleted; already booked payments are kept"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Recurring rule not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_rule(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.recurring_service.delete(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,16 +1,88 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 
+use chrono::{NaiveDate, Utc};
+
+use domain::errors::MonthError;
+use domain::types::BudgetMonth;
+
 use crate::errors::ApiError;
-use crate::responses::MonthSummaryResponse;
+use crate::requests::{DueBillsQuery, RangeSummaryQuery, SummaryQuery};
+use crate::responses::{DueBillsResponse, ErrorResponse, MonthSummaryResponse, RangeSummaryResponse};
 
 use super::{parse_ulid, AppState};
 
+#[utoipa::path(
+    get,
+    path = "/months/{id}/summary",
+    tag = "summary",
+    params(("id" = String, Path, description = "Month ULID"), SummaryQuery),
+    responses(
+        (status = 200, description = "Budgeted vs paid totals for the month", body = MonthSummaryResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Month not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_month_summary(
     State(state): State<AppState>,
     Path(id): Path<String>,
+    Query(query): Query<SummaryQuery>,
 ) -> Result<Json<MonthSummaryResponse>, ApiError> {
     let ulid = parse_ulid(&id)?;
-    let summary = state.summary_service.get_month_summary(&ulid).await?;
+    let rollup = query.rollup.unwrap_or(false);
+    let summary = state.summary_service.get_month_summary(&ulid, rollup).await?;
     Ok(Json(summary.into()))
 }
+
+#[utoipa::path(
+    get,
+    path = "/months/{id}/due",
+    tag = "summary",
+    params(("id" = String, Path, description = "Month ULID"), DueBillsQuery),
+    responses(
+        (status = 200, description = "Expense entries with a due day, classified as paid, upcoming, due soon, due today or overdue", body = DueBillsResponse),
+        (status = 400, description = "Invalid ID format or `today` date", body = ErrorResponse),
+        (status = 404, description = "Month not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_due_bills(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<DueBillsQuery>,
+) -> Result<Json<DueBillsResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let today = match query.today {
+        Some(ref t) => NaiveDate::parse_from_str(t, "%Y-%m-%d")
+            .map_err(|_| ApiError::bad_request(&format!("Invalid date: {}", t)))?,
+        None => Utc::now().date_naive(),
+    };
+    let soon_days = query.soon_days.unwrap_or(3);
+    let bills = state.summary_service.get_due_bills(&ulid, today, soon_days).await?;
+    Ok(Json(bills.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/summary",
+    tag = "summary",
+    params(RangeSummaryQuery),
+    responses(
+        (status = 200, description = "Per-month, per-year and per-category totals over the range", body = RangeSummaryResponse),
+        (status = 422, description = "Invalid month format or `from` after `to`", body = ErrorResponse),
+    )
+)]
+pub async fn get_range_summary(
+    State(state): State<AppState>,
+    Query(query): Query<RangeSummaryQuery>,
+) -> Result<Json<RangeSummaryResponse>, ApiError> {
+    let from = parse_budget_month(&query.from)?;
+    let to = parse_budget_month(&query.to)?;
+    let summary = state.summary_service.get_range_summary(from, to).await?;
+    Ok(Json(summary.into()))
+}
+
+fn parse_budget_month(value: &str) -> Result<BudgetMonth, MonthError> {
+    value.parse().map_err(|_| MonthError::InvalidFormat {
+        value: value.to_string(),
+    })
+}
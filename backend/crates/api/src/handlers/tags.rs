@@ -0,0 +1,119 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use domain::errors::TagError;
+use domain::types::TagName;
+
+use crate::errors::ApiError;
+use crate::requests::{CreateTagRequest, RenameTagRequest};
+use crate::responses::{ErrorResponse, TagReportResponse, TagResponse};
+
+use super::{parse_ulid, AppState};
+
+fn parse_name(value: String) -> Result<TagName, TagError> {
+    TagName::new(value).map_err(|e| TagError::InvalidName {
+        reason: e.to_string(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/tags",
+    tag = "tags",
+    responses(
+        (status = 200, description = "Tags ordered by name", body = [TagResponse]),
+    )
+)]
+pub async fn list_tags(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TagResponse>>, ApiError> {
+    let tags = state.tag_service.list_all().await?;
+    let response: Vec<TagResponse> = tags.into_iter().map(|t| t.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/tags",
+    tag = "tags",
+    request_body = CreateTagRequest,
+    responses(
+        (status = 201, description = "Tag created", body = TagResponse),
+        (status = 409, description = "Tag name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid tag name", body = ErrorResponse),
+    )
+)]
+pub async fn create_tag(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTagRequest>,
+) -> Result<(StatusCode, Json<TagResponse>), ApiError> {
+    let name = parse_name(req.name)?;
+    let tag = state.tag_service.create(name).await?;
+    Ok((StatusCode::CREATED, Json(tag.into())))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/tags/{id}",
+    tag = "tags",
+    params(("id" = String, Path, description = "Tag ULID")),
+    request_body = RenameTagRequest,
+    responses(
+        (status = 200, description = "Tag renamed", body = TagResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+        (status = 409, description = "Tag name already exists", body = ErrorResponse),
+        (status = 422, description = "Invalid tag name", body = ErrorResponse),
+    )
+)]
+pub async fn rename_tag(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<RenameTagRequest>,
+) -> Result<Json<TagResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let name = parse_name(req.name)?;
+    let tag = state.tag_service.rename(&ulid, name).await?;
+    Ok(Json(tag.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/tags/{id}",
+    tag = "tags",
+    params(("id" = String, Path, description = "Tag ULID")),
+    responses(
+        (status = 204, description = "Tag deleted and removed from all transactions"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_tag(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.tag_service.delete(&ulid).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/tags/{id}/report",
+    tag = "tags",
+    params(("id" = String, Path, description = "Tag ULID")),
+    responses(
+        (status = 200, description = "Spending tagged with the tag, in total and per month", body = TagReportResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Tag not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_tag_report(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TagReportResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let report = state.tag_service.report(&ulid).await?;
+    Ok(Json(report.into()))
+}
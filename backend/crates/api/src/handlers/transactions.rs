@@ -1,23 +1,168 @@
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::response::{IntoResponse, Response};
 use axum::Json;
 
+use domain::entities::{
+    NewTransaction, NewTransactionSplit, SplitAllocation, TransactionFilter, TransactionScope,
+    TransactionSplitUpdate, TransactionUpdate,
+};
 use domain::errors::TransactionError;
-use domain::types::{Money, TransactionDate};
+use domain::types::{
+    CategoryName, Money, SearchQuery, TagName, TransactionCursor, TransactionDate,
+    TransactionKind, TransactionSort,
+};
 
 use crate::errors::ApiError;
-use crate::requests::{CreateTransactionRequest, TransactionListQuery, UpdateTransactionRequest};
-use crate::responses::{PaginatedTransactionsResponse, TransactionResponse};
+use crate::requests::{
+    CreateTransactionRequest, CreateTransactionSplitRequest, SplitAllocationRequest,
+    TransactionListQuery, TransactionSearchQuery, UpdateTransactionRequest,
+    UpdateTransactionSplitRequest,
+};
+use crate::responses::{
+    ErrorResponse, FilteredTransactionsResponse, PaginatedTransactionsResponse,
+    TransactionListResponse, TransactionMatchResponse, TransactionResponse,
+    TransactionSplitResponse,
+};
 
-use super::{parse_ulid, AppState};
+use super::{attachments, parse_ulid, AppState};
 
+/// Matches returned by a search unless `limit` says otherwise
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+const MAX_SEARCH_LIMIT: u32 = 200;
+
+/// A name that is not a valid tag name cannot belong to an existing tag either.
+fn parse_tag(name: String) -> Result<TagName, TransactionError> {
+    TagName::new(name.clone()).map_err(|_| TransactionError::UnknownTag { name })
+}
+
+fn parse_tags(names: Vec<String>) -> Result<Vec<TagName>, TransactionError> {
+    names.into_iter().map(parse_tag).collect()
+}
+
+fn parse_date(value: &str) -> Result<TransactionDate, TransactionError> {
+    value.parse().map_err(|_| TransactionError::InvalidDate {
+        value: value.to_string(),
+    })
+}
+
+fn parse_cursor(value: &str) -> Result<TransactionCursor, TransactionError> {
+    value.parse().map_err(|_| TransactionError::InvalidCursor)
+}
+
+fn parse_filter(
+    query: &TransactionListQuery,
+    tag: Option<TagName>,
+) -> Result<TransactionFilter, TransactionError> {
+    let category = query
+        .category
+        .as_deref()
+        .map(CategoryName::new)
+        .transpose()
+        .map_err(|e| TransactionError::InvalidFilter {
+            reason: e.to_string(),
+        })?;
+    let sort = query
+        .sort
+        .as_deref()
+        .map(str::parse::<TransactionSort>)
+        .transpose()
+        .map_err(|e| TransactionError::InvalidFilter {
+            reason: e.to_string(),
+        })?
+        .unwrap_or_default();
+
+    Ok(TransactionFilter {
+        from: query.from.as_deref().map(parse_date).transpose()?,
+        to: query.to.as_deref().map(parse_date).transpose()?,
+        min_amount: query.min_amount.map(Money::new),
+        max_amount: query.max_amount.map(Money::new),
+        category,
+        title: query.title.clone(),
+        tag,
+        sort,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/transactions",
+    tag = "transactions",
+    params(TransactionListQuery),
+    responses(
+        (status = 200, description = "Page with totals across all months when a filter is given; paginated page when `entry_id` is given, or `month` with `limit` or `cursor`; otherwise all transactions of `month`, or of `tag` across months", body = TransactionListResponse),
+        (status = 400, description = "None of `month`, `entry_id`, `tag` and a filter given, a filter combined with `month` or `entry_id`, `cursor` outside paginated mode or combined with `offset`, or invalid ID format or cursor", body = ErrorResponse),
+        (status = 422, description = "Unknown tag, invalid date, category name or sort, or empty range", body = ErrorResponse),
+    )
+)]
 pub async fn list_transactions(
     State(state): State<AppState>,
     Query(query): Query<TransactionListQuery>,
-) -> Result<Response, ApiError> {
+) -> Result<Json<TransactionListResponse>, ApiError> {
+    let tag = query.tag.clone().map(parse_tag).transpose()?;
+    let cursor = query.cursor.as_deref().map(parse_cursor).transpose()?;
+
+    if cursor.is_some() {
+        if query.has_filters() || (query.month.is_none() && query.entry_id.is_none()) {
+            return Err(ApiError::bad_request(
+                "cursor requires month or entry_id and cannot be combined with filters",
+            ));
+        }
+        if query.offset.is_some() {
+            return Err(ApiError::bad_request("cursor cannot be combined with offset"));
+        }
+    }
+
+    if query.has_filters() {
+        // Filtered mode across all months
+        if query.month.is_some() || query.entry_id.is_some() {
+            return Err(ApiError::bad_request(
+                "Filters cannot be combined with month or entry_id",
+            ));
+        }
+        let filter = parse_filter(&query, tag)?;
+        let limit = query.limit.unwrap_or(100);
+        let offset = query.offset.unwrap_or(0);
+
+        let page = state
+            .transaction_service
+            .list_filtered(filter, limit, offset)
+            .await?;
+
+        let has_more = i64::from(offset) + (page.items.len() as i64) < page.total_count;
+        let response = FilteredTransactionsResponse {
+            items: page.items.into_iter().map(|m| m.into()).collect(),
+            total_count: page.total_count,
+            total_amount: page.total_amount.value(),
+            has_more,
+        };
+        return Ok(Json(TransactionListResponse::Filtered(response)));
+    }
+
+    // Cursor pages of an entry, or of a month when `limit` or `cursor` asks for pages
+    let scope = match (&query.entry_id, &query.month) {
+        (Some(entry_id), _) if query.offset.is_none() => {
+            Some(TransactionScope::Entry(parse_ulid(entry_id)?))
+        }
+        (None, Some(month)) if query.limit.is_some() || cursor.is_some() => {
+            if query.offset.is_some() {
+                return Err(ApiError::bad_request(
+                    "offset pages need entry_id; page a month with cursor",
+                ));
+            }
+            Some(TransactionScope::Month(parse_ulid(month)?))
+        }
+        _ => None,
+    };
+    if let Some(scope) = scope {
+        let page = state
+            .transaction_service
+            .list_page(&scope, tag.as_ref(), cursor.as_ref(), query.limit.unwrap_or(100))
+            .await?;
+        return Ok(Json(TransactionListResponse::Page(page.into())));
+    }
+
     if let Some(ref entry_id_str) = query.entry_id {
-        // Per-entry paginated mode
+        // Legacy per-entry offset mode
         let entry_ulid = parse_ulid(entry_id_str)?;
         let limit = query.limit.unwrap_or(100);
         let offset = query.offset.unwrap_or(0);
@@ -25,7 +170,7 @@ pub async fn list_transactions(
         // Fetch limit + 1 to determine has_more
         let transactions = state
             .transaction_service
-            .list_by_entry(&entry_ulid, limit + 1, offset)
+            .list_by_entry(&entry_ulid, tag.as_ref(), limit + 1, offset)
             .await?;
 
         let has_more = transactions.len() > limit as usize;
@@ -35,24 +180,70 @@ pub async fn list_transactions(
             .map(|t| t.into())
             .collect();
 
-        let response = PaginatedTransactionsResponse { items, has_more };
-        Ok(Json(response).into_response())
+        let response = PaginatedTransactionsResponse {
+            items,
+            has_more,
+            next_cursor: None,
+            prev_cursor: None,
+        };
+        Ok(Json(TransactionListResponse::Page(response)))
     } else {
-        // Legacy month-based mode
-        let month_str = match query.month {
-            Some(m) => m,
-            None => return Err(ApiError::month_required()),
+        // Legacy month-based mode, or all transactions of a tag
+        let transactions = match (query.month, tag) {
+            (Some(month_str), tag) => {
+                let month_ulid = parse_ulid(&month_str)?;
+                state
+                    .transaction_service
+                    .list_by_month(&month_ulid, tag.as_ref())
+                    .await?
+            }
+            (None, Some(tag)) => state.transaction_service.list_by_tag(&tag).await?,
+            (None, None) => return Err(ApiError::month_required()),
         };
-        let month_ulid = parse_ulid(&month_str)?;
-        let transactions = state
-            .transaction_service
-            .list_by_month(&month_ulid)
-            .await?;
         let response: Vec<TransactionResponse> = transactions.into_iter().map(|t| t.into()).collect();
-        Ok(Json(response).into_response())
+        Ok(Json(TransactionListResponse::Month(response)))
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/transactions/search",
+    tag = "transactions",
+    params(TransactionSearchQuery),
+    responses(
+        (status = 200, description = "Transactions of all months with matching titles, best matches first", body = [TransactionMatchResponse]),
+        (status = 422, description = "Query empty, too long or without letters or digits", body = ErrorResponse),
+    )
+)]
+pub async fn search_transactions(
+    State(state): State<AppState>,
+    Query(query): Query<TransactionSearchQuery>,
+) -> Result<Json<Vec<TransactionMatchResponse>>, ApiError> {
+    let search = SearchQuery::new(&query.q).map_err(|e| TransactionError::InvalidSearchQuery {
+        reason: e.to_string(),
+    })?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+
+    let matches = state.transaction_service.search(&search, limit).await?;
+    let response: Vec<TransactionMatchResponse> = matches.into_iter().map(|m| m.into()).collect();
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/transactions",
+    tag = "transactions",
+    request_body = CreateTransactionRequest,
+    responses(
+        (status = 201, description = "Transaction created", body = TransactionResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Budget entry, refunded transaction, payee or account not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, date, title, kind or refund, or unknown tag", body = ErrorResponse),
+    )
+)]
 pub async fn create_transaction(
     State(state): State<AppState>,
     Json(req): Json<CreateTransactionRequest>,
@@ -61,15 +252,51 @@ pub async fn create_transaction(
     let date: TransactionDate = req.date.parse().map_err(|_| TransactionError::InvalidDate {
         value: req.date.clone(),
     })?;
-    let amount = Money::new(req.amount);
+    let kind = match req.kind {
+        Some(k) => k
+            .parse::<TransactionKind>()
+            .map_err(|_| TransactionError::InvalidKind { value: k })?,
+        None => TransactionKind::default(),
+    };
+    let refund_of = match req.refund_of {
+        Some(ref r) => Some(parse_ulid(r)?),
+        None => None,
+    };
+    let payee_id = req.payee_id.as_deref().map(parse_ulid).transpose()?;
+    let account_id = req.account_id.as_deref().map(parse_ulid).transpose()?;
+    let tags = parse_tags(req.tags.unwrap_or_default())?;
 
     let transaction = state
         .transaction_service
-        .create(entry_ulid, amount, date, req.title)
+        .create(NewTransaction {
+            entry_id: entry_ulid,
+            kind,
+            amount: Money::new(req.amount),
+            date,
+            title: req.title,
+            refund_of,
+            payee_id,
+            account_id,
+            tags,
+        })
         .await?;
     Ok((StatusCode::CREATED, Json(transaction.into())))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/transactions/{id}",
+    tag = "transactions",
+    params(("id" = String, Path, description = "Transaction ULID")),
+    request_body = UpdateTransactionRequest,
+    responses(
+        (status = 200, description = "Transaction updated", body = TransactionResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Transaction, budget entry, payee or account not found", body = ErrorResponse),
+        (status = 409, description = "Transaction is an allocation of a split payment", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, date or title, unknown tag, or refund exceeds its original", body = ErrorResponse),
+    )
+)]
 pub async fn update_transaction(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -94,18 +321,213 @@ pub async fn update_transaction(
         None => None,
     };
 
+    let payee_id = match req.payee_id {
+        Some(Some(ref p)) => Some(Some(parse_ulid(p)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    let account_id = match req.account_id {
+        Some(Some(ref a)) => Some(Some(parse_ulid(a)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    let tags = req.tags.map(parse_tags).transpose()?;
+
     let transaction = state
         .transaction_service
-        .update(&ulid, entry_id, amount, date, req.title)
+        .update(
+            &ulid,
+            TransactionUpdate {
+                entry_id,
+                amount,
+                date,
+                title: req.title,
+                payee_id,
+                account_id,
+                tags,
+            },
+        )
         .await?;
     Ok(Json(transaction.into()))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/transactions/{id}",
+    tag = "transactions",
+    params(("id" = String, Path, description = "Transaction ULID")),
+    responses(
+        (status = 204, description = "Transaction deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Transaction not found", body = ErrorResponse),
+        (status = 409, description = "Transaction is an allocation of a split payment", body = ErrorResponse),
+    )
+)]
 pub async fn delete_transaction(
     State(state): State<AppState>,
     Path(id): Path<String>,
 ) -> Result<StatusCode, ApiError> {
     let ulid = parse_ulid(&id)?;
     state.transaction_service.delete(&ulid).await?;
+    attachments::purge_files(&state).await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn parse_allocations(
+    allocations: Vec<SplitAllocationRequest>,
+) -> Result<Vec<SplitAllocation>, ApiError> {
+    allocations
+        .into_iter()
+        .map(|a| {
+            Ok(SplitAllocation {
+                entry_id: parse_ulid(&a.entry_id)?,
+                amount: Money::new(a.amount),
+            })
+        })
+        .collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/transactions/splits",
+    tag = "transactions",
+    request_body = CreateTransactionSplitRequest,
+    responses(
+        (status = 201, description = "Split payment created", body = TransactionSplitResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Budget entry, payee or account not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, date, title or allocations", body = ErrorResponse),
+    )
+)]
+pub async fn create_split(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTransactionSplitRequest>,
+) -> Result<(StatusCode, Json<TransactionSplitResponse>), ApiError> {
+    let date: TransactionDate = req.date.parse().map_err(|_| TransactionError::InvalidDate {
+        value: req.date.clone(),
+    })?;
+    let allocations = parse_allocations(req.allocations)?;
+    let payee_id = req.payee_id.as_deref().map(parse_ulid).transpose()?;
+    let account_id = req.account_id.as_deref().map(parse_ulid).transpose()?;
+
+    let split = state
+        .transaction_service
+        .create_split(NewTransactionSplit {
+            amount: Money::new(req.amount),
+            date,
+            title: req.title,
+            payee_id,
+            account_id,
+            allocations,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(split.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/transactions/splits/{id}",
+    tag = "transactions",
+    params(("id" = String, Path, description = "Split payment ULID")),
+    responses(
+        (status = 200, description = "Split payment with its allocations", body = TransactionSplitResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Split payment not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_split(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TransactionSplitResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    let split = state.transaction_service.get_split(&ulid).await?;
+    Ok(Json(split.into()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/transactions/splits/{id}",
+    tag = "transactions",
+    params(("id" = String, Path, description = "Split payment ULID")),
+    request_body = UpdateTransactionSplitRequest,
+    responses(
+        (status = 200, description = "Split payment updated", body = TransactionSplitResponse),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Split payment, budget entry, payee or account not found", body = ErrorResponse),
+        (status = 422, description = "Invalid amount, date, title or allocations, or allocations replaced while they have refunds or attachments", body = ErrorResponse),
+    )
+)]
+pub async fn update_split(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<UpdateTransactionSplitRequest>,
+) -> Result<Json<TransactionSplitResponse>, ApiError> {
+    let ulid = parse_ulid(&id)?;
+
+    let date = match req.date {
+        Some(ref d) => {
+            let td: TransactionDate =
+                d.parse()
+                    .map_err(|_| TransactionError::InvalidDate { value: d.clone() })?;
+            Some(td)
+        }
+        None => None,
+    };
+
+    let payee_id = match req.payee_id {
+        Some(Some(ref p)) => Some(Some(parse_ulid(p)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    let account_id = match req.account_id {
+        Some(Some(ref a)) => Some(Some(parse_ulid(a)?)),
+        Some(None) => Some(None),
+        None => None,
+    };
+
+    let allocations = match req.allocations {
+        Some(a) => Some(parse_allocations(a)?),
+        None => None,
+    };
+
+    let split = state
+        .transaction_service
+        .update_split(
+            &ulid,
+            TransactionSplitUpdate {
+                amount: req.amount.map(Money::new),
+                date,
+                title: req.title,
+                payee_id,
+                account_id,
+                allocations,
+            },
+        )
+        .await?;
+    attachments::purge_files(&state).await;
+    Ok(Json(split.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/transactions/splits/{id}",
+    tag = "transactions",
+    params(("id" = String, Path, description = "Split payment ULID")),
+    responses(
+        (status = 204, description = "Split payment and its allocations deleted"),
+        (status = 400, description = "Invalid ID format", body = ErrorResponse),
+        (status = 404, description = "Split payment not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_split(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let ulid = parse_ulid(&id)?;
+    state.transaction_service.delete_split(&ulid).await?;
+    attachments::purge_files(&state).await;
     Ok(StatusCode::NO_CONTENT)
 }
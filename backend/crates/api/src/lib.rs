@@ -1,6 +1,8 @@
 pub mod config;
+pub mod csv_file;
 pub mod errors;
 pub mod handlers;
 pub mod middleware;
+pub mod openapi;
 pub mod requests;
 pub mod responses;
@@ -1,14 +1,17 @@
 pub mod config;
+mod csv_file;
 mod errors;
 pub mod handlers;
 mod middleware;
+mod openapi;
 pub mod requests;
 pub mod responses;
 
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::routing::{get, patch};
+use axum::routing::{get, patch, post};
 use axum::Router;
 use clap::Parser;
 use tower_http::cors::{AllowOrigin, CorsLayer};
@@ -22,11 +25,14 @@ use handlers::AppState;
 use middleware::RequestIdGenerator;
 
 use db::repos::{
-    SqliteBudgetEntryRepository, SqliteCategoryRepository, SqliteMonthRepository,
-    SqliteTransactionRepository,
+    SqliteAccountRepository, SqliteAttachmentRepository, SqliteBudgetEntryRepository, SqliteCategoryRepository,
+    SqliteImportProfileRepository, SqliteMonthRepository, SqlitePayeeRepository, SqliteRecurringRuleRepository,
+    SqliteTagRepository, SqliteTransactionRepository, SqliteUnitOfWorkFactory,
 };
+use db::storage::FsAttachmentStore;
 use domain::services::{
-    CategoryService, EntryService, MonthService, SummaryService, TransactionService,
+    AccountService, AttachmentService, CategoryService, EntryService, HistoryService, ImportService, MonthService,
+    PayeeService, RecurringService, SummaryService, TagService, TransactionService,
 };
 
 /// Otter Budget Tracker — a self-hosted household budget application.
@@ -74,12 +80,19 @@ async fn main() {
     let month_repo = Arc::new(SqliteMonthRepository::new(pool.clone()));
     let entry_repo = Arc::new(SqliteBudgetEntryRepository::new(pool.clone()));
     let transaction_repo = Arc::new(SqliteTransactionRepository::new(pool.clone()));
+    let recurring_repo = Arc::new(SqliteRecurringRuleRepository::new(pool.clone()));
+    let tag_repo = Arc::new(SqliteTagRepository::new(pool.clone()));
+    let payee_repo = Arc::new(SqlitePayeeRepository::new(pool.clone()));
+    let account_repo = Arc::new(SqliteAccountRepository::new(pool.clone()));
+    let attachment_repo = Arc::new(SqliteAttachmentRepository::new(pool.clone()));
+    let import_profile_repo = Arc::new(SqliteImportProfileRepository::new(pool.clone()));
+    let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
 
     // Create service instances
     let category_service = Arc::new(CategoryService::new(category_repo.clone()));
     let month_service = Arc::new(MonthService::new(
         month_repo.clone(),
-        entry_repo.clone(),
+        unit_of_work.clone(),
     ));
     let entry_service = Arc::new(EntryService::new(
         entry_repo.clone(),
@@ -88,12 +101,46 @@ async fn main() {
     ));
     let transaction_service = Arc::new(TransactionService::new(
         transaction_repo.clone(),
-        entry_repo.clone(),
+        unit_of_work.clone(),
     ));
     let summary_service = Arc::new(SummaryService::new(
         entry_repo.clone(),
         transaction_repo.clone(),
         month_repo.clone(),
+        unit_of_work.clone(),
+    ));
+    let history_service = Arc::new(HistoryService::new(
+        category_repo.clone(),
+        month_repo.clone(),
+        entry_repo.clone(),
+        transaction_repo.clone(),
+    ));
+    let recurring_service = Arc::new(RecurringService::new(
+        recurring_repo.clone(),
+        category_repo.clone(),
+        unit_of_work.clone(),
+    ));
+    let tag_service = Arc::new(TagService::new(tag_repo.clone()));
+    let payee_service = Arc::new(PayeeService::new(
+        payee_repo.clone(),
+        category_repo.clone(),
+        entry_repo.clone(),
+    ));
+    let account_service = Arc::new(AccountService::new(account_repo.clone()));
+
+    let attachments_dir = app_config.attachments_dir();
+    tracing::info!("Storing attachments in {:?}", attachments_dir);
+    let attachment_service = Arc::new(AttachmentService::new(
+        attachment_repo.clone(),
+        transaction_repo.clone(),
+        Arc::new(FsAttachmentStore::new(attachments_dir)),
+        unit_of_work.clone(),
+        app_config.storage.max_attachment_bytes,
+    ));
+    let import_service = Arc::new(ImportService::new(
+        import_profile_repo.clone(),
+        unit_of_work.clone(),
+        app_config.currency.decimal_places,
     ));
 
     let state = AppState {
@@ -102,9 +149,23 @@ async fn main() {
         entry_service,
         transaction_service,
         summary_service,
+        history_service,
+        recurring_service: recurring_service.clone(),
+        tag_service,
+        payee_service,
+        account_service,
+        attachment_service,
+        import_service,
         currency_config: app_config.currency.clone(),
     };
 
+    // Files left behind by deletions that failed to remove them
+    handlers::attachments::purge_files(&state).await;
+
+    if app_config.recurring.enabled {
+        spawn_recurring_task(recurring_service, app_config.recurring.interval_secs);
+    }
+
     // Configure CORS
     let cors = if app_config.cors.allowed_origins.is_empty() {
         CorsLayer::permissive()
@@ -130,15 +191,44 @@ async fn main() {
             get(handlers::categories::list_categories)
                 .post(handlers::categories::create_category),
         )
+        .route(
+            "/categories/tree",
+            get(handlers::categories::get_category_tree),
+        )
+        .route(
+            "/categories/move",
+            post(handlers::categories::move_category_subtree),
+        )
         .route(
             "/categories/{id}",
-            patch(handlers::categories::update_category),
+            patch(handlers::categories::update_category)
+                .delete(handlers::categories::delete_category),
+        )
+        .route(
+            "/categories/{id}/archive",
+            post(handlers::categories::archive_category),
+        )
+        .route(
+            "/categories/{id}/unarchive",
+            post(handlers::categories::unarchive_category),
+        )
+        .route(
+            "/categories/{id}/merge",
+            post(handlers::categories::merge_category),
+        )
+        .route(
+            "/categories/{id}/history",
+            get(handlers::categories::get_category_history),
         )
         .route(
             "/months",
             get(handlers::months::list_months).post(handlers::months::create_month),
         )
-        .route("/months/{id}", get(handlers::months::get_month))
+        .route(
+            "/months/{id}",
+            get(handlers::months::get_month).delete(handlers::months::delete_month),
+        )
+        .route("/months/{id}/reset", post(handlers::months::reset_month))
         .route(
             "/months/{id}/entries",
             get(handlers::entries::list_entries).post(handlers::entries::create_entry),
@@ -147,20 +237,130 @@ async fn main() {
             "/months/{id}/entries/{entry_id}",
             patch(handlers::entries::update_entry).delete(handlers::entries::delete_entry),
         )
+        .route(
+            "/months/{id}/entries/{entry_id}/pay",
+            post(handlers::entries::pay_entry),
+        )
         .route(
             "/transactions",
             get(handlers::transactions::list_transactions)
                 .post(handlers::transactions::create_transaction),
         )
+        .route(
+            "/transactions/search",
+            get(handlers::transactions::search_transactions),
+        )
         .route(
             "/transactions/{id}",
             patch(handlers::transactions::update_transaction)
                 .delete(handlers::transactions::delete_transaction),
         )
+        .route(
+            "/transactions/{id}/attachments",
+            post(handlers::attachments::upload_attachment)
+                .layer(handlers::attachments::upload_body_limit(&state))
+                .get(handlers::attachments::list_attachments),
+        )
+        .route(
+            "/transactions/{id}/attachments/{attachment_id}",
+            get(handlers::attachments::download_attachment)
+                .delete(handlers::attachments::delete_attachment),
+        )
+        .route(
+            "/transactions/splits",
+            post(handlers::transactions::create_split),
+        )
+        .route(
+            "/transactions/splits/{id}",
+            get(handlers::transactions::get_split)
+                .patch(handlers::transactions::update_split)
+                .delete(handlers::transactions::delete_split),
+        )
+        .route(
+            "/recurring-rules",
+            get(handlers::recurring::list_rules).post(handlers::recurring::create_rule),
+        )
+        .route(
+            "/recurring-rules/{id}",
+            patch(handlers::recurring::update_rule).delete(handlers::recurring::delete_rule),
+        )
+        .route(
+            "/recurring-rules/{id}/pause",
+            post(handlers::recurring::pause_rule),
+        )
+        .route(
+            "/recurring-rules/{id}/resume",
+            post(handlers::recurring::resume_rule),
+        )
+        .route(
+            "/tags",
+            get(handlers::tags::list_tags).post(handlers::tags::create_tag),
+        )
+        .route(
+            "/tags/{id}",
+            patch(handlers::tags::rename_tag).delete(handlers::tags::delete_tag),
+        )
+        .route(
+            "/tags/{id}/report",
+            get(handlers::tags::get_tag_report),
+        )
+        .route(
+            "/payees",
+            get(handlers::payees::list_payees).post(handlers::payees::create_payee),
+        )
+        .route(
+            "/payees/lookup",
+            get(handlers::payees::lookup_payee),
+        )
+        .route(
+            "/payees/{id}",
+            patch(handlers::payees::update_payee).delete(handlers::payees::delete_payee),
+        )
+        .route(
+            "/payees/{id}/report",
+            get(handlers::payees::get_payee_report),
+        )
+        .route(
+            "/accounts",
+            get(handlers::accounts::list_accounts).post(handlers::accounts::create_account),
+        )
+        .route(
+            "/accounts/transfers",
+            get(handlers::accounts::list_transfers).post(handlers::accounts::create_transfer),
+        )
+        .route(
+            "/accounts/transfers/{id}",
+            patch(handlers::accounts::update_transfer).delete(handlers::accounts::delete_transfer),
+        )
+        .route(
+            "/accounts/{id}",
+            get(handlers::accounts::get_account)
+                .patch(handlers::accounts::update_account)
+                .delete(handlers::accounts::delete_account),
+        )
+        .route(
+            "/accounts/{id}/ledger",
+            get(handlers::accounts::get_account_ledger),
+        )
+        .route(
+            "/imports/profiles",
+            get(handlers::imports::list_import_profiles).post(handlers::imports::create_import_profile),
+        )
+        .route(
+            "/imports/profiles/{id}",
+            patch(handlers::imports::update_import_profile).delete(handlers::imports::delete_import_profile),
+        )
+        .route("/imports/preview", post(handlers::imports::preview_import))
+        .route("/imports/commit", post(handlers::imports::commit_import))
         .route(
             "/months/{id}/summary",
             get(handlers::summary::get_month_summary),
-        );
+        )
+        .route(
+            "/months/{id}/due",
+            get(handlers::summary::get_due_bills),
+        )
+        .route("/summary", get(handlers::summary::get_range_summary));
 
     // Static file serving under /ui with SPA fallback.
     // Any request under /ui/ that doesn't match a file returns index.html
@@ -171,6 +371,7 @@ async fn main() {
 
     let app = Router::new()
         .nest("/api/v1", api)
+        .merge(openapi::swagger_ui())
         .nest_service("/ui", spa_service)
         .layer(cors)
         .layer(TraceLayer::new_for_http())
@@ -198,3 +399,22 @@ async fn main() {
         .await
         .expect("Server error");
 }
+
+/// Books payments of recurring rules as they fall due, once at startup and then
+/// every `interval_secs`. Failures are logged and retried on the next tick.
+fn spawn_recurring_task(recurring_service: Arc<RecurringService>, interval_secs: u64) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            let today = chrono::Utc::now().date_naive();
+            match recurring_service.materialize_due(today).await {
+                Ok(booked) if !booked.is_empty() => {
+                    tracing::info!("Booked {} recurring payments", booked.len());
+                }
+                Ok(_) => {}
+                Err(e) => tracing::error!("Failed to book recurring payments: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,194 @@
+// OpenAPI document assembled from the `#[utoipa::path]` annotations on the
+// handlers. Served as JSON together with a bundled Swagger UI:
+//
+//   GET /api/v1/openapi.json  - the document
+//   GET /api/v1/docs/         - interactive explorer
+//
+// Paths are relative to the `/api/v1` server declared below, so they match
+// the route strings registered in `main.rs` one to one.
+
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::handlers;
+use crate::requests;
+use crate::responses;
+
+pub const OPENAPI_JSON_PATH: &str = "/api/v1/openapi.json";
+pub const DOCS_PATH: &str = "/api/v1/docs";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Otter Budget Tracker API"),
+    servers((url = "/api/v1")),
+    paths(
+        handlers::health::health_check,
+        handlers::categories::list_categories,
+        handlers::categories::get_category_tree,
+        handlers::categories::create_category,
+        handlers::categories::update_category,
+        handlers::categories::delete_category,
+        handlers::categories::archive_category,
+        handlers::categories::unarchive_category,
+        handlers::categories::merge_category,
+        handlers::categories::move_category_subtree,
+        handlers::categories::get_category_history,
+        handlers::months::list_months,
+        handlers::months::get_month,
+        handlers::months::create_month,
+        handlers::months::delete_month,
+        handlers::months::reset_month,
+        handlers::entries::list_entries,
+        handlers::entries::create_entry,
+        handlers::entries::update_entry,
+        handlers::entries::delete_entry,
+        handlers::entries::pay_entry,
+        handlers::transactions::list_transactions,
+        handlers::transactions::search_transactions,
+        handlers::transactions::create_transaction,
+        handlers::transactions::update_transaction,
+        handlers::transactions::delete_transaction,
+        handlers::transactions::create_split,
+        handlers::transactions::get_split,
+        handlers::transactions::update_split,
+        handlers::transactions::delete_split,
+        handlers::recurring::list_rules,
+        handlers::recurring::create_rule,
+        handlers::recurring::update_rule,
+        handlers::recurring::pause_rule,
+        handlers::recurring::resume_rule,
+        handlers::recurring::delete_rule,
+        handlers::tags::list_tags,
+        handlers::tags::create_tag,
+        handlers::tags::rename_tag,
+        handlers::tags::delete_tag,
+        handlers::tags::get_tag_report,
+        handlers::payees::list_payees,
+        handlers::payees::create_payee,
+        handlers::payees::lookup_payee,
+        handlers::payees::update_payee,
+        handlers::payees::delete_payee,
+        handlers::payees::get_payee_report,
+        handlers::accounts::list_accounts,
+        handlers::accounts::create_account,
+        handlers::accounts::get_account,
+        handlers::accounts::update_account,
+        handlers::accounts::delete_account,
+        handlers::accounts::get_account_ledger,
+        handlers::accounts::list_transfers,
+        handlers::accounts::create_transfer,
+        handlers::accounts::update_transfer,
+        handlers::accounts::delete_transfer,
+        handlers::attachments::upload_attachment,
+        handlers::attachments::list_attachments,
+        handlers::attachments::download_attachment,
+        handlers::attachments::delete_attachment,
+        handlers::imports::list_import_profiles,
+        handlers::imports::create_import_profile,
+        handlers::imports::update_import_profile,
+        handlers::imports::delete_import_profile,
+        handlers::imports::preview_import,
+        handlers::imports::commit_import,
+        handlers::summary::get_month_summary,
+        handlers::summary::get_range_summary,
+        handlers::summary::get_due_bills,
+    ),
+    components(schemas(
+        requests::CreateCategoryRequest,
+        requests::UpdateCategoryRequest,
+        requests::MergeCategoryRequest,
+        requests::MoveCategorySubtreeRequest,
+        requests::CreateMonthRequest,
+        requests::ResetMonthRequest,
+        requests::CreateEntryRequest,
+        requests::UpdateEntryRequest,
+        requests::PayEntryRequest,
+        requests::CreateTransactionRequest,
+        requests::UpdateTransactionRequest,
+        requests::SplitAllocationRequest,
+        requests::CreateTransactionSplitRequest,
+        requests::UpdateTransactionSplitRequest,
+        requests::CreateRecurringRuleRequest,
+        requests::UpdateRecurringRuleRequest,
+        requests::CreateTagRequest,
+        requests::RenameTagRequest,
+        requests::CreatePayeeRequest,
+        requests::UpdatePayeeRequest,
+        requests::CreateAccountRequest,
+        requests::UpdateAccountRequest,
+        requests::CreateTransferRequest,
+        requests::UpdateTransferRequest,
+        requests::UploadAttachmentRequest,
+        requests::CsvMappingRequest,
+        requests::CreateImportProfileRequest,
+        requests::UpdateImportProfileRequest,
+        requests::PreviewImportRequest,
+        requests::ImportTransactionRequest,
+        requests::CommitImportRequest,
+        responses::CategoryResponse,
+        responses::CategoryTreeNodeResponse,
+        responses::CategoryHistoryResponse,
+        responses::CategoryHistoryPointResponse,
+        responses::HistoryStatsResponse,
+        responses::HistoryExtremeResponse,
+        responses::TrendResponse,
+        responses::MonthResponse,
+        responses::CategorySummaryResponse,
+        responses::EntryResponse,
+        responses::TransactionResponse,
+        responses::PaginatedTransactionsResponse,
+        responses::FilteredTransactionsResponse,
+        responses::TransactionListResponse,
+        responses::TransactionSplitResponse,
+        responses::TransactionMatchResponse,
+        responses::RecurringRuleResponse,
+        responses::TagResponse,
+        responses::TagReportResponse,
+        responses::PayeeResponse,
+        responses::PayeeMatchResponse,
+        responses::PayeeReportResponse,
+        responses::AccountResponse,
+        responses::TransferResponse,
+        responses::LedgerLineResponse,
+        responses::AccountLedgerResponse,
+        responses::AttachmentResponse,
+        responses::CsvMappingResponse,
+        responses::ImportProfileResponse,
+        responses::ImportRowResponse,
+        responses::ImportRowErrorResponse,
+        responses::ImportPreviewResponse,
+        responses::MonthSpendingResponse,
+        responses::MonthSummaryResponse,
+        responses::CategoryBudgetSummaryResponse,
+        responses::CategoryGroupSummaryResponse,
+        responses::RangeSummaryResponse,
+        responses::DueBillsResponse,
+        responses::DueBillResponse,
+        responses::MonthTotalsResponse,
+        responses::YearTotalsResponse,
+        responses::CategorySeriesResponse,
+        responses::CategorySeriesPointResponse,
+        responses::ErrorResponse,
+        responses::ErrorBody,
+    )),
+    tags(
+        (name = "health"),
+        (name = "categories", description = "Budget categories"),
+        (name = "months", description = "Budget months"),
+        (name = "entries", description = "Per-month budget entries"),
+        (name = "transactions", description = "Payments against budget entries"),
+        (name = "recurring", description = "Recurring payments booked every month"),
+        (name = "tags", description = "Labels on transactions cutting across categories"),
+        (name = "payees", description = "Who transactions were paid to"),
+        (name = "accounts", description = "Where money is paid from, with transfers and balances"),
+        (name = "attachments", description = "Receipts and documents kept with transactions"),
+        (name = "imports", description = "Bank statement CSV files booked as transactions"),
+        (name = "summary", description = "Budgeted vs paid reports"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Swagger UI serving the generated document. Merge into the top-level router.
+pub fn swagger_ui() -> SwaggerUi {
+    SwaggerUi::new(DOCS_PATH).url(OPENAPI_JSON_PATH, ApiDoc::openapi())
+}
@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateCategoryRequest {
@@ -16,6 +16,56 @@ pub struct UpdateCategoryRequest {
     pub label: Option<Option<String>>,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveCategorySubtreeRequest {
+    pub from: String, // current prefix, e.g. "utils"
+    pub to: String,   // new prefix, e.g. "utilities"
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergeCategoryRequest {
+    pub target_id: String, // ULID of the category that absorbs the source
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryListQuery {
+    /// Include archived categories (default false)
+    pub include_archived: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryHistoryQuery {
+    /// Only look at this many most recent months (default: all)
+    pub months: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SummaryQuery {
+    /// Add totals per parent category node (default false)
+    pub rollup: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DueBillsQuery {
+    /// Date to classify against, "YYYY-MM-DD" (default: the server's current date)
+    pub today: Option<String>,
+    /// Unpaid bills due within this many days after today are due soon (default 3)
+    pub soon_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RangeSummaryQuery {
+    /// First month of the range, "YYYY-MM"
+    pub from: String,
+    /// Last month of the range (inclusive), "YYYY-MM"
+    pub to: String,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateMonthRequest {
     pub month: String, // "YYYY-MM"
@@ -25,10 +75,27 @@ pub struct CreateMonthRequest {
     pub empty: Option<bool>, // Create month with no entries
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeleteMonthQuery {
+    /// Also delete the month's transactions (default false)
+    pub cascade: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ResetMonthRequest {
+    #[serde(default)]
+    pub copy_from: Option<String>, // ULID of source month; latest other month if omitted
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateEntryRequest {
     pub category_id: String,
+    #[serde(default)]
+    pub kind: Option<String>, // "expense" (default) or "income"
     pub budgeted: i64,
+    #[serde(default)]
+    pub rollover: Option<String>, // "none" (default), "carry_remaining" or "carry_overspend"
     pub due_day: Option<u8>,
 }
 
@@ -38,15 +105,35 @@ pub struct UpdateEntryRequest {
     pub budgeted: Option<i64>,
     #[serde(default, with = "double_option")]
     pub due_day: Option<Option<u8>>, // None = don't change, Some(None) = clear, Some(Some(v)) = set
+    #[serde(default)]
+    pub rollover: Option<String>, // applies from the next copy of the month on
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PayEntryRequest {
+    #[serde(default)]
+    pub on: Option<String>, // "today" (default) or "due_date"
+    #[serde(default)]
+    pub title: Option<String>, // template; {category}, {month} and {date} are filled in
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateTransactionRequest {
     pub entry_id: String,
+    #[serde(default)]
+    pub kind: Option<String>, // "payment" (default) or "refund"
     pub amount: i64,
     pub date: String, // "YYYY-MM-DD"
     #[serde(default)]
     pub title: Option<String>,
+    #[serde(default)]
+    pub refund_of: Option<String>, // ULID of the payment a refund returns money from
+    #[serde(default)]
+    pub payee_id: Option<String>,
+    #[serde(default)]
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>, // names of existing tags
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -59,14 +146,290 @@ pub struct UpdateTransactionRequest {
     pub date: Option<String>, // "YYYY-MM-DD"
     #[serde(default, with = "double_option")]
     pub title: Option<Option<String>>,
+    #[serde(default, with = "double_option")]
+    pub payee_id: Option<Option<String>>,
+    #[serde(default, with = "double_option")]
+    pub account_id: Option<Option<String>>,
+    #[serde(default)]
+    pub tags: Option<Vec<String>>, // replaces all tags; [] removes them
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SplitAllocationRequest {
+    pub entry_id: String,
+    pub amount: i64,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTransactionSplitRequest {
+    pub amount: i64,
+    pub date: String, // "YYYY-MM-DD"
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub payee_id: Option<String>,
+    #[serde(default)]
+    pub account_id: Option<String>, // account paid from, for every allocation
+    pub allocations: Vec<SplitAllocationRequest>, // amounts must add up to `amount`
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTransactionSplitRequest {
+    #[serde(default)]
+    pub amount: Option<i64>,
+    #[serde(default)]
+    pub date: Option<String>, // "YYYY-MM-DD"
+    #[serde(default, with = "double_option")]
+    pub title: Option<Option<String>>,
+    #[serde(default, with = "double_option")]
+    pub payee_id: Option<Option<String>>,
+    #[serde(default, with = "double_option")]
+    pub account_id: Option<Option<String>>,
+    #[serde(default)]
+    pub allocations: Option<Vec<SplitAllocationRequest>>, // replaces all allocations
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateRecurringRuleRequest {
+    pub category_id: String,
+    pub amount: i64,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub day_of_month: u8, // 1-31, clamped to the last day of shorter months
+    pub start_month: String, // "YYYY-MM"
+    #[serde(default)]
+    pub end_month: Option<String>, // "YYYY-MM", inclusive; open-ended if omitted
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateRecurringRuleRequest {
+    #[serde(default)]
+    pub amount: Option<i64>,
+    #[serde(default, with = "double_option")]
+    pub title: Option<Option<String>>,
+    #[serde(default)]
+    pub day_of_month: Option<u8>,
+    #[serde(default)]
+    pub start_month: Option<String>,
+    #[serde(default, with = "double_option")]
+    pub end_month: Option<Option<String>>, // null makes the rule open-ended
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTagRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RenameTagRequest {
+    pub name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreatePayeeRequest {
+    pub name: String,
+    #[serde(default)]
+    pub default_category_id: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdatePayeeRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default, with = "double_option")]
+    pub default_category_id: Option<Option<String>>,
+    #[serde(default)]
+    pub aliases: Option<Vec<String>>, // replaces all aliases
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateAccountRequest {
+    pub name: String,
+    #[serde(default)]
+    pub kind: Option<String>, // "checking" (default), "savings", "credit_card" or "cash"
+    #[serde(default)]
+    pub opening_balance: i64,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateAccountRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub opening_balance: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTransferRequest {
+    pub from_account_id: String,
+    pub to_account_id: String,
+    pub amount: i64,
+    pub date: String, // "YYYY-MM-DD"
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTransferRequest {
+    #[serde(default)]
+    pub from_account_id: Option<String>,
+    #[serde(default)]
+    pub to_account_id: Option<String>,
+    #[serde(default)]
+    pub amount: Option<i64>,
+    #[serde(default)]
+    pub date: Option<String>, // "YYYY-MM-DD"
+    #[serde(default, with = "double_option")]
+    pub title: Option<Option<String>>,
+}
+
+/// Multipart form of an attachment upload; documents the request body only.
+#[derive(Debug, ToSchema)]
+pub struct UploadAttachmentRequest {
+    /// The file; its name and content type come from the part's headers
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+fn default_delimiter() -> String {
+    ",".to_string()
+}
+
+fn default_has_header() -> bool {
+    true
+}
+
+fn default_decimal_separator() -> String {
+    ".".to_string()
+}
+
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CsvMappingRequest {
+    #[serde(default = "default_delimiter")]
+    pub delimiter: String, // one character, "," by default
+    #[serde(default = "default_has_header")]
+    pub has_header: bool, // first line holds column names, true by default
+    pub date_column: usize, // columns count from 0
+    pub amount_column: usize,
+    #[serde(default)]
+    pub description_column: Option<usize>,
+    #[serde(default = "default_decimal_separator")]
+    pub decimal_separator: String, // "." (default) or ","
+    #[serde(default = "default_date_format")]
+    pub date_format: String, // chrono format, "%Y-%m-%d" by default
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateImportProfileRequest {
+    pub name: String,
+    pub mapping: CsvMappingRequest,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateImportProfileRequest {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub mapping: Option<CsvMappingRequest>, // replaces the whole mapping
+}
+
+/// Multipart form of an import preview; documents the request body only.
+#[derive(Debug, ToSchema)]
+pub struct PreviewImportRequest {
+    /// The CSV file, UTF-8 encoded
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    /// ULID of the profile whose mapping reads the file; or give `mapping`
+    pub profile_id: Option<String>,
+    /// Mapping as JSON, for a file without a saved profile
+    pub mapping: Option<CsvMappingRequest>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ImportTransactionRequest {
+    pub entry_id: String, // entry of the month the date falls in
+    #[serde(default)]
+    pub kind: Option<String>, // "payment" (default) or "refund"
+    pub amount: i64, // never negative; the sign of the file goes into `kind`
+    pub date: String, // "YYYY-MM-DD"
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CommitImportRequest {
+    pub rows: Vec<ImportTransactionRequest>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PayeeLookupQuery {
+    /// Payee name or alias; case and spacing are ignored
+    pub name: String,
+    /// ULID of month to pick the entry of the payee's default category from
+    pub month: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct TransactionListQuery {
-    pub month: Option<String>, // ULID of month
+    /// ULID of month; required unless `entry_id` or `tag` is given
+    pub month: Option<String>,
+    /// ULID of budget entry; switches to paginated mode
     pub entry_id: Option<String>,
+    /// Page size in paginated and filtered mode (default 100); with `month`, switches to paginated mode
     pub limit: Option<u32>,
+    /// Page offset in filtered mode, and in paginated mode with `entry_id` instead of `cursor` (default 0)
     pub offset: Option<u32>,
+    /// `next_cursor` or `prev_cursor` of a page in paginated mode; none for the first page
+    pub cursor: Option<String>,
+    /// Only transactions with this tag; across all months if neither `month` nor `entry_id` is given
+    pub tag: Option<String>,
+    /// Filter: first day "YYYY-MM-DD", inclusive
+    pub from: Option<String>,
+    /// Filter: last day "YYYY-MM-DD", inclusive
+    pub to: Option<String>,
+    /// Filter: smallest amount, inclusive; refunds match by their positive amount, though they reduce `total_amount`
+    pub min_amount: Option<i64>,
+    /// Filter: largest amount, inclusive; refunds match by their positive amount, though they reduce `total_amount`
+    pub max_amount: Option<i64>,
+    /// Filter: category name, also matching every category below it (`utils` matches `utils/electricity`)
+    pub category: Option<String>,
+    /// Filter: text the title contains, in either case
+    pub title: Option<String>,
+    /// Filter: `date_desc` (default), `date_asc`, `amount_desc` or `amount_asc`
+    pub sort: Option<String>,
+}
+
+impl TransactionListQuery {
+    /// Any filter parameter switches to filtered mode across all months.
+    pub fn has_filters(&self) -> bool {
+        self.from.is_some()
+            || self.to.is_some()
+            || self.min_amount.is_some()
+            || self.max_amount.is_some()
+            || self.category.is_some()
+            || self.title.is_some()
+            || self.sort.is_some()
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TransactionSearchQuery {
+    /// Words to find in transaction titles; each matches the start of a word,
+    /// ignoring case and diacritics
+    pub q: String,
+    /// Maximum number of matches (default 50, at most 200)
+    pub limit: Option<u32>,
 }
 
 /// Custom serde module for handling `Option<Option<T>>` fields correctly.
@@ -1,21 +1,81 @@
 use serde::Serialize;
+use serde_json::Value;
 use utoipa::ToSchema;
 
 use domain::entities::{
-    BudgetEntryWithCategory, Category, CategorySummary as DomainCategorySummary, Month,
-    Transaction,
+    AccountActivityKind, Attachment, BudgetEntryWithCategory, Category,
+    CategorySummary as DomainCategorySummary, CategoryTreeNode, CsvMapping, ImportPreview,
+    ImportProfile, Month, MonthSpending, Payee,
+    RecurringRule, Tag, Transaction, TransactionCursorPage, TransactionMatch, TransactionSplit,
+    Transfer,
+};
+use domain::services::{
+    AccountBalance, AccountLedger, LedgerLine, BudgetStatus, CategoryBudgetSummary, CategoryGroupSummary, CategoryHistory, DueBill, DueBills,
+    DueStatus,
+    CategoryHistoryPoint, CategorySeries, CategorySeriesPoint, HistoryExtreme, HistoryStats,
+    MonthSummary, MonthTotals, PayeeMatch, PayeeReport, RangeSummary, TagReport, Trend,
+    TrendDirection, YearTotals,
 };
-use domain::services::{BudgetStatus, CategoryBudgetSummary, MonthSummary};
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CategoryResponse {
     pub id: String,
     pub name: String,
     pub label: Option<String>,
+    pub archived_at: Option<String>, // RFC 3339, null when active
     pub created_at: String, // RFC 3339
     pub updated_at: String,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryTreeNodeResponse {
+    pub name: String,    // full path, e.g. "utils/electricity"
+    pub segment: String, // last path segment, e.g. "electricity"
+    pub category: Option<CategoryResponse>, // null for pure group nodes
+    #[schema(no_recursion)]
+    pub children: Vec<CategoryTreeNodeResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryHistoryResponse {
+    pub category: CategoryResponse,
+    pub points: Vec<CategoryHistoryPointResponse>, // months with an entry only, oldest first
+    pub stats: Option<HistoryStatsResponse>,       // null when there are no points
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryHistoryPointResponse {
+    pub month_id: String,
+    pub month: String,
+    pub entry_id: String,
+    pub budgeted: i64,
+    pub carried_in: i64,
+    pub paid: i64,
+    pub remaining: i64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HistoryStatsResponse {
+    pub average_budgeted: i64,
+    pub average_paid: i64,
+    pub min_paid: HistoryExtremeResponse,
+    pub max_paid: HistoryExtremeResponse,
+    pub trend: TrendResponse,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HistoryExtremeResponse {
+    pub month: String,
+    pub amount: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrendResponse {
+    pub per_month: i64,    // least-squares slope of paid amounts
+    pub direction: String, // "rising", "falling", "flat"
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct MonthResponse {
     pub id: String,
@@ -29,13 +89,17 @@ pub struct CategorySummaryResponse {
     pub id: String,
     pub name: String,
     pub label: Option<String>,
+    pub archived: bool,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct EntryResponse {
     pub id: String,
     pub category: CategorySummaryResponse,
+    pub kind: String, // "expense" or "income"
     pub budgeted: i64,
+    pub carried_in: i64,
+    pub rollover: String, // "none", "carry_remaining" or "carry_overspend"
     pub due_day: Option<u8>,
     pub created_at: String,
     pub updated_at: String,
@@ -45,6 +109,100 @@ pub struct EntryResponse {
 pub struct TransactionResponse {
     pub id: String,
     pub entry_id: String,
+    pub kind: String, // "payment" or "refund"
+    pub amount: i64,
+    pub date: String, // "YYYY-MM-DD"
+    pub title: Option<String>,
+    pub refund_of: Option<String>,
+    pub split_id: Option<String>,
+    pub payee_id: Option<String>,
+    pub account_id: Option<String>,
+    pub tags: Vec<String>, // ordered by name
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TransactionSplitResponse {
+    pub id: String,
+    pub amount: i64,
+    pub date: String, // "YYYY-MM-DD"
+    pub title: Option<String>,
+    pub payee_id: Option<String>,
+    pub account_id: Option<String>,
+    pub allocations: Vec<TransactionResponse>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecurringRuleResponse {
+    pub id: String,
+    pub category_id: String,
+    pub amount: i64,
+    pub title: Option<String>,
+    pub day_of_month: u8,
+    pub start_month: String,       // "YYYY-MM"
+    pub end_month: Option<String>, // null when open-ended
+    pub paused: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagResponse {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagReportResponse {
+    pub tag: TagResponse,
+    pub total_spent: i64, // expense entries only, net of refunds
+    pub months: Vec<MonthSpendingResponse>, // oldest first
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PayeeResponse {
+    pub id: String,
+    pub name: String,
+    pub default_category_id: Option<String>,
+    pub aliases: Vec<String>, // ordered by name
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PayeeMatchResponse {
+    pub payee: PayeeResponse,
+    pub entry_id: Option<String>, // entry of the default category in the requested month
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PayeeReportResponse {
+    pub payee: PayeeResponse,
+    pub total_spent: i64, // expense entries only, net of refunds
+    pub months: Vec<MonthSpendingResponse>, // oldest first
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AccountResponse {
+    pub id: String,
+    pub name: String,
+    pub kind: String, // "checking", "savings", "credit_card" or "cash"
+    pub opening_balance: i64,
+    pub balance: i64, // opening balance plus all transactions and transfers
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TransferResponse {
+    pub id: String,
+    pub from_account_id: String,
+    pub to_account_id: String,
     pub amount: i64,
     pub date: String, // "YYYY-MM-DD"
     pub title: Option<String>,
@@ -52,29 +210,262 @@ pub struct TransactionResponse {
     pub updated_at: String,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LedgerLineResponse {
+    pub id: String,   // transaction or transfer ULID
+    pub kind: String, // "transaction", "transfer_in" or "transfer_out"
+    pub entry_id: Option<String>,               // set for transactions
+    pub counterpart_account_id: Option<String>, // set for transfers
+    pub amount: i64, // as booked, never negative
+    pub change: i64, // signed amount added to the balance
+    pub balance: i64, // balance after this line
+    pub date: String, // "YYYY-MM-DD"
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AccountLedgerResponse {
+    pub account: AccountResponse,
+    pub lines: Vec<LedgerLineResponse>, // oldest first
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TransactionMatchResponse {
+    pub transaction: TransactionResponse,
+    pub month_id: String,
+    pub month: String, // "YYYY-MM"
+    pub category_id: String,
+    pub category_name: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: String,
+    pub transaction_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,      // bytes
+    pub sha256: String, // hex-encoded hash of the contents
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CsvMappingResponse {
+    pub delimiter: String,
+    pub has_header: bool,
+    pub date_column: usize, // columns count from 0
+    pub amount_column: usize,
+    pub description_column: Option<usize>,
+    pub decimal_separator: String,
+    pub date_format: String, // chrono format
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportProfileResponse {
+    pub id: String,
+    pub name: String,
+    pub mapping: CsvMappingResponse,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportRowResponse {
+    pub line: u64, // line of the file, from 1
+    pub date: String, // "YYYY-MM-DD"
+    pub amount: i64, // signed as in the file
+    pub description: Option<String>, // cut to the title length limit
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportRowErrorResponse {
+    pub line: u64,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportPreviewResponse {
+    pub rows: Vec<ImportRowResponse>,
+    pub errors: Vec<ImportRowErrorResponse>, // rows that could not be read
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MonthSpendingResponse {
+    pub month_id: String,
+    pub month: String,
+    pub spent: i64,
+    pub transaction_count: i64,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct MonthSummaryResponse {
     pub month: String,
-    pub total_budgeted: i64,
+    pub total_budgeted: i64, // expense entries only
+    pub total_carried_in: i64,
     pub total_paid: i64,
-    pub remaining: i64,
+    pub remaining: i64, // total_budgeted + total_carried_in - total_paid
+    pub expected_income: i64,
+    pub total_income: i64,
+    pub total_spending: i64,
+    pub net_savings: i64, // total_income - total_spending
     pub categories: Vec<CategoryBudgetSummaryResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<CategoryGroupSummaryResponse>>, // only with ?rollup=true
 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CategoryBudgetSummaryResponse {
     pub entry_id: String,
     pub category: CategorySummaryResponse,
+    pub kind: String,
     pub budgeted: i64,
+    pub carried_in: i64, // rolled over from the previous month
+    pub available: i64,  // budgeted + carried_in
     pub paid: i64,
     pub remaining: i64,
     pub status: String, // "unpaid", "underspent", "on_budget", "overspent"
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryGroupSummaryResponse {
+    pub name: String, // parent path, e.g. "utils"
+    pub budgeted: i64,
+    pub carried_in: i64,
+    pub paid: i64,
+    pub remaining: i64,
+    pub status: String,
+    pub entry_count: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DueBillsResponse {
+    pub month_id: String,
+    pub month: String,
+    pub today: String, // "YYYY-MM-DD"
+    pub bills: Vec<DueBillResponse>, // ordered by due date
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DueBillResponse {
+    pub entry_id: String,
+    pub category: CategorySummaryResponse,
+    pub due_date: String, // "YYYY-MM-DD", clamped to the month's last day
+    pub days_until: i64,  // negative once overdue
+    pub available: i64,
+    pub paid: i64,
+    pub remaining: i64,
+    pub status: String, // "paid", "upcoming", "due_soon", "due_today" or "overdue"
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RangeSummaryResponse {
+    pub from: String, // "YYYY-MM"
+    pub to: String,
+    pub total_budgeted: i64,
+    pub total_paid: i64,
+    pub remaining: i64,
+    pub total_income: i64,
+    pub net_savings: i64,
+    pub months: Vec<MonthTotalsResponse>, // existing months only, oldest first
+    pub years: Vec<YearTotalsResponse>,
+    pub categories: Vec<CategorySeriesResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MonthTotalsResponse {
+    pub month_id: String,
+    pub month: String,
+    pub total_budgeted: i64,
+    pub total_carried_in: i64,
+    pub total_paid: i64,
+    pub remaining: i64,
+    pub status: String,
+    pub total_income: i64,
+    pub net_savings: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct YearTotalsResponse {
+    pub year: i32,
+    pub total_budgeted: i64,
+    pub total_paid: i64,
+    pub remaining: i64,
+    pub status: String,
+    pub total_income: i64,
+    pub net_savings: i64,
+    pub month_count: usize,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategorySeriesResponse {
+    pub category: CategorySummaryResponse,
+    pub total_budgeted: i64,
+    pub total_paid: i64,
+    pub remaining: i64,
+    pub points: Vec<CategorySeriesPointResponse>, // months with an entry only
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategorySeriesPointResponse {
+    pub month: String,
+    pub budgeted: i64,
+    pub carried_in: i64,
+    pub paid: i64,
+    pub remaining: i64,
+    pub status: String,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct PaginatedTransactionsResponse {
     pub items: Vec<TransactionResponse>,
     pub has_more: bool,
+    /// Cursor of the page of older transactions; null on the last page and in offset mode
+    pub next_cursor: Option<String>,
+    /// Cursor of the page of newer transactions; null on the first page and in offset mode
+    pub prev_cursor: Option<String>,
+}
+
+impl From<TransactionCursorPage> for PaginatedTransactionsResponse {
+    fn from(page: TransactionCursorPage) -> Self {
+        Self {
+            items: page.items.into_iter().map(|t| t.into()).collect(),
+            has_more: page.next_cursor.is_some(),
+            next_cursor: page.next_cursor.map(|c| c.to_string()),
+            prev_cursor: page.prev_cursor.map(|c| c.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FilteredTransactionsResponse {
+    pub items: Vec<TransactionMatchResponse>,
+    pub total_count: i64,  // matches on all pages
+    pub total_amount: i64, // payments minus refunds of all matches
+    pub has_more: bool,
+}
+
+/// `GET /transactions` returns a plain array in month mode, a page in paginated
+/// mode and a page with totals in filtered mode.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum TransactionListResponse {
+    Month(Vec<TransactionResponse>),
+    Page(PaginatedTransactionsResponse),
+    Filtered(FilteredTransactionsResponse),
+}
+
+/// Error envelope produced by `ApiError`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub code: String, // e.g. "CATEGORY_NOT_FOUND"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub details: Option<Value>,
 }
 
 // --- From impls ---
@@ -85,12 +476,24 @@ impl From<Category> for CategoryResponse {
             id: c.id.to_string(),
             name: c.name.as_str().to_string(),
             label: c.label,
+            archived_at: c.archived_at.map(|dt| dt.to_rfc3339()),
             created_at: c.created_at.to_rfc3339(),
             updated_at: c.updated_at.to_rfc3339(),
         }
     }
 }
 
+impl From<CategoryTreeNode> for CategoryTreeNodeResponse {
+    fn from(n: CategoryTreeNode) -> Self {
+        Self {
+            name: n.name.as_str().to_string(),
+            segment: n.segment,
+            category: n.category.map(CategoryResponse::from),
+            children: n.children.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+}
+
 impl From<Month> for MonthResponse {
     fn from(m: Month) -> Self {
         Self {
@@ -108,6 +511,7 @@ impl From<DomainCategorySummary> for CategorySummaryResponse {
             id: cs.id.to_string(),
             name: cs.name.as_str().to_string(),
             label: cs.label,
+            archived: cs.archived,
         }
     }
 }
@@ -117,7 +521,10 @@ impl From<BudgetEntryWithCategory> for EntryResponse {
         Self {
             id: e.id.to_string(),
             category: CategorySummaryResponse::from(e.category),
+            kind: e.kind.to_string(),
             budgeted: e.budgeted.value(),
+            carried_in: e.carried_in.value(),
+            rollover: e.rollover.to_string(),
             due_day: e.due_day.map(|d| d.value()),
             created_at: e.created_at.to_rfc3339(),
             updated_at: e.updated_at.to_rfc3339(),
@@ -130,6 +537,127 @@ impl From<Transaction> for TransactionResponse {
         Self {
             id: t.id.to_string(),
             entry_id: t.entry_id.to_string(),
+            kind: t.kind.to_string(),
+            amount: t.amount.value(),
+            date: t.date.to_string(),
+            title: t.title,
+            refund_of: t.refund_of.map(|r| r.to_string()),
+            split_id: t.split_id.map(|s| s.to_string()),
+            payee_id: t.payee_id.map(|p| p.to_string()),
+            account_id: t.account_id.map(|a| a.to_string()),
+            tags: t.tags.into_iter().map(|g| g.as_str().to_string()).collect(),
+            created_at: t.created_at.to_rfc3339(),
+            updated_at: t.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<TransactionSplit> for TransactionSplitResponse {
+    fn from(s: TransactionSplit) -> Self {
+        Self {
+            id: s.id.to_string(),
+            amount: s.amount.value(),
+            date: s.date.to_string(),
+            title: s.title,
+            payee_id: s.payee_id.map(|p| p.to_string()),
+            account_id: s.account_id.map(|a| a.to_string()),
+            allocations: s.allocations.into_iter().map(|t| t.into()).collect(),
+            created_at: s.created_at.to_rfc3339(),
+            updated_at: s.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<RecurringRule> for RecurringRuleResponse {
+    fn from(r: RecurringRule) -> Self {
+        Self {
+            id: r.id.to_string(),
+            category_id: r.category_id.to_string(),
+            amount: r.amount.value(),
+            title: r.title,
+            day_of_month: r.day_of_month.value(),
+            start_month: r.start_month.to_string(),
+            end_month: r.end_month.map(|m| m.to_string()),
+            paused: r.paused,
+            created_at: r.created_at.to_rfc3339(),
+            updated_at: r.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<Tag> for TagResponse {
+    fn from(t: Tag) -> Self {
+        Self {
+            id: t.id.to_string(),
+            name: t.name.as_str().to_string(),
+            created_at: t.created_at.to_rfc3339(),
+            updated_at: t.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<TagReport> for TagReportResponse {
+    fn from(r: TagReport) -> Self {
+        Self {
+            tag: r.tag.into(),
+            total_spent: r.total_spent.value(),
+            months: r.months.into_iter().map(|m| m.into()).collect(),
+        }
+    }
+}
+
+impl From<Payee> for PayeeResponse {
+    fn from(p: Payee) -> Self {
+        Self {
+            id: p.id.to_string(),
+            name: p.name.as_str().to_string(),
+            default_category_id: p.default_category_id.map(|c| c.to_string()),
+            aliases: p.aliases.into_iter().map(|a| a.as_str().to_string()).collect(),
+            created_at: p.created_at.to_rfc3339(),
+            updated_at: p.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<PayeeMatch> for PayeeMatchResponse {
+    fn from(m: PayeeMatch) -> Self {
+        Self {
+            payee: m.payee.into(),
+            entry_id: m.entry_id.map(|e| e.to_string()),
+        }
+    }
+}
+
+impl From<PayeeReport> for PayeeReportResponse {
+    fn from(r: PayeeReport) -> Self {
+        Self {
+            payee: r.payee.into(),
+            total_spent: r.total_spent.value(),
+            months: r.months.into_iter().map(|m| m.into()).collect(),
+        }
+    }
+}
+
+impl From<AccountBalance> for AccountResponse {
+    fn from(b: AccountBalance) -> Self {
+        Self {
+            id: b.account.id.to_string(),
+            name: b.account.name.as_str().to_string(),
+            kind: b.account.kind.to_string(),
+            opening_balance: b.account.opening_balance.value(),
+            balance: b.balance.value(),
+            created_at: b.account.created_at.to_rfc3339(),
+            updated_at: b.account.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<Transfer> for TransferResponse {
+    fn from(t: Transfer) -> Self {
+        Self {
+            id: t.id.to_string(),
+            from_account_id: t.from_account_id.to_string(),
+            to_account_id: t.to_account_id.to_string(),
             amount: t.amount.value(),
             date: t.date.to_string(),
             title: t.title,
@@ -139,33 +667,354 @@ impl From<Transaction> for TransactionResponse {
     }
 }
 
+impl From<LedgerLine> for LedgerLineResponse {
+    fn from(l: LedgerLine) -> Self {
+        let (kind, entry_id, counterpart_account_id) = match l.activity.kind {
+            AccountActivityKind::Transaction { entry_id, .. } => {
+                ("transaction", Some(entry_id.to_string()), None)
+            }
+            AccountActivityKind::TransferIn { from_account_id } => {
+                ("transfer_in", None, Some(from_account_id.to_string()))
+            }
+            AccountActivityKind::TransferOut { to_account_id } => {
+                ("transfer_out", None, Some(to_account_id.to_string()))
+            }
+        };
+        Self {
+            id: l.activity.id.to_string(),
+            kind: kind.to_string(),
+            entry_id,
+            counterpart_account_id,
+            amount: l.activity.amount.value(),
+            change: l.change.value(),
+            balance: l.balance.value(),
+            date: l.activity.date.to_string(),
+            title: l.activity.title,
+        }
+    }
+}
+
+impl From<AccountLedger> for AccountLedgerResponse {
+    fn from(l: AccountLedger) -> Self {
+        Self {
+            account: AccountBalance {
+                account: l.account,
+                balance: l.balance,
+            }
+            .into(),
+            lines: l.lines.into_iter().map(|line| line.into()).collect(),
+        }
+    }
+}
+
+impl From<TransactionMatch> for TransactionMatchResponse {
+    fn from(m: TransactionMatch) -> Self {
+        Self {
+            transaction: m.transaction.into(),
+            month_id: m.month_id.to_string(),
+            month: m.month.to_string(),
+            category_id: m.category_id.to_string(),
+            category_name: m.category_name.as_str().to_string(),
+        }
+    }
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(a: Attachment) -> Self {
+        Self {
+            id: a.id.to_string(),
+            transaction_id: a.transaction_id.to_string(),
+            file_name: a.file_name,
+            content_type: a.content_type,
+            size: a.size,
+            sha256: a.sha256,
+            created_at: a.created_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<CsvMapping> for CsvMappingResponse {
+    fn from(m: CsvMapping) -> Self {
+        Self {
+            delimiter: m.delimiter.to_string(),
+            has_header: m.has_header,
+            date_column: m.date_column,
+            amount_column: m.amount_column,
+            description_column: m.description_column,
+            decimal_separator: m.decimal_separator.to_string(),
+            date_format: m.date_format,
+        }
+    }
+}
+
+impl From<ImportProfile> for ImportProfileResponse {
+    fn from(p: ImportProfile) -> Self {
+        Self {
+            id: p.id.to_string(),
+            name: p.name.as_str().to_string(),
+            mapping: p.mapping.into(),
+            created_at: p.created_at.to_rfc3339(),
+            updated_at: p.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+impl From<ImportPreview> for ImportPreviewResponse {
+    fn from(p: ImportPreview) -> Self {
+        Self {
+            rows: p
+                .rows
+                .into_iter()
+                .map(|r| ImportRowResponse {
+                    line: r.line,
+                    date: r.date.to_string(),
+                    amount: r.amount.value(),
+                    description: r.description,
+                })
+                .collect(),
+            errors: p
+                .errors
+                .into_iter()
+                .map(|e| ImportRowErrorResponse {
+                    line: e.line,
+                    reason: e.reason,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<MonthSpending> for MonthSpendingResponse {
+    fn from(m: MonthSpending) -> Self {
+        Self {
+            month_id: m.month_id.to_string(),
+            month: m.month.to_string(),
+            spent: m.spent.value(),
+            transaction_count: m.transaction_count,
+        }
+    }
+}
+
 impl From<MonthSummary> for MonthSummaryResponse {
     fn from(s: MonthSummary) -> Self {
         Self {
             month: s.month.to_string(),
             total_budgeted: s.total_budgeted.value(),
+            total_carried_in: s.total_carried_in.value(),
             total_paid: s.total_paid.value(),
             remaining: s.remaining.value(),
+            expected_income: s.expected_income.value(),
+            total_income: s.total_income.value(),
+            total_spending: s.total_spending.value(),
+            net_savings: s.net_savings.value(),
             categories: s.categories.into_iter().map(|c| c.into()).collect(),
+            groups: s.groups.map(|gs| gs.into_iter().map(|g| g.into()).collect()),
         }
     }
 }
 
+fn budget_status_str(status: BudgetStatus) -> &'static str {
+    match status {
+        BudgetStatus::Unpaid => "unpaid",
+        BudgetStatus::Underspent => "underspent",
+        BudgetStatus::OnBudget => "on_budget",
+        BudgetStatus::Overspent => "overspent",
+    }
+}
+
 impl From<CategoryBudgetSummary> for CategoryBudgetSummaryResponse {
     fn from(c: CategoryBudgetSummary) -> Self {
-        let status = match c.status {
-            BudgetStatus::Unpaid => "unpaid",
-            BudgetStatus::Underspent => "underspent",
-            BudgetStatus::OnBudget => "on_budget",
-            BudgetStatus::Overspent => "overspent",
-        };
+        let status = budget_status_str(c.status);
         Self {
             entry_id: c.entry_id.to_string(),
             category: CategorySummaryResponse::from(c.category),
+            kind: c.kind.to_string(),
             budgeted: c.budgeted.value(),
+            carried_in: c.carried_in.value(),
+            available: c.available.value(),
             paid: c.paid.value(),
             remaining: c.remaining.value(),
             status: status.to_string(),
         }
     }
 }
+
+impl From<CategoryGroupSummary> for CategoryGroupSummaryResponse {
+    fn from(g: CategoryGroupSummary) -> Self {
+        Self {
+            name: g.name.as_str().to_string(),
+            budgeted: g.budgeted.value(),
+            carried_in: g.carried_in.value(),
+            paid: g.paid.value(),
+            remaining: g.remaining.value(),
+            status: budget_status_str(g.status).to_string(),
+            entry_count: g.entry_count,
+        }
+    }
+}
+
+impl From<CategoryHistory> for CategoryHistoryResponse {
+    fn from(h: CategoryHistory) -> Self {
+        Self {
+            category: CategoryResponse::from(h.category),
+            points: h.points.into_iter().map(|p| p.into()).collect(),
+            stats: h.stats.map(|s| s.into()),
+        }
+    }
+}
+
+impl From<CategoryHistoryPoint> for CategoryHistoryPointResponse {
+    fn from(p: CategoryHistoryPoint) -> Self {
+        Self {
+            month_id: p.month_id.to_string(),
+            month: p.month.to_string(),
+            entry_id: p.entry_id.to_string(),
+            budgeted: p.budgeted.value(),
+            carried_in: p.carried_in.value(),
+            paid: p.paid.value(),
+            remaining: p.remaining.value(),
+            status: budget_status_str(p.status).to_string(),
+        }
+    }
+}
+
+impl From<HistoryStats> for HistoryStatsResponse {
+    fn from(s: HistoryStats) -> Self {
+        Self {
+            average_budgeted: s.average_budgeted.value(),
+            average_paid: s.average_paid.value(),
+            min_paid: s.min_paid.into(),
+            max_paid: s.max_paid.into(),
+            trend: s.trend.into(),
+        }
+    }
+}
+
+impl From<HistoryExtreme> for HistoryExtremeResponse {
+    fn from(e: HistoryExtreme) -> Self {
+        Self {
+            month: e.month.to_string(),
+            amount: e.amount.value(),
+        }
+    }
+}
+
+impl From<Trend> for TrendResponse {
+    fn from(t: Trend) -> Self {
+        let direction = match t.direction {
+            TrendDirection::Rising => "rising",
+            TrendDirection::Falling => "falling",
+            TrendDirection::Flat => "flat",
+        };
+        Self {
+            per_month: t.per_month.value(),
+            direction: direction.to_string(),
+        }
+    }
+}
+
+impl From<DueBills> for DueBillsResponse {
+    fn from(d: DueBills) -> Self {
+        Self {
+            month_id: d.month_id.to_string(),
+            month: d.month.to_string(),
+            today: d.today.format("%Y-%m-%d").to_string(),
+            bills: d.bills.into_iter().map(|b| b.into()).collect(),
+        }
+    }
+}
+
+impl From<DueBill> for DueBillResponse {
+    fn from(b: DueBill) -> Self {
+        let status = match b.status {
+            DueStatus::Paid => "paid",
+            DueStatus::Upcoming => "upcoming",
+            DueStatus::DueSoon => "due_soon",
+            DueStatus::DueToday => "due_today",
+            DueStatus::Overdue => "overdue",
+        };
+        Self {
+            entry_id: b.entry_id.to_string(),
+            category: CategorySummaryResponse::from(b.category),
+            due_date: b.due_date.format("%Y-%m-%d").to_string(),
+            days_until: b.days_until,
+            available: b.available.value(),
+            paid: b.paid.value(),
+            remaining: b.remaining.value(),
+            status: status.to_string(),
+        }
+    }
+}
+
+impl From<RangeSummary> for RangeSummaryResponse {
+    fn from(s: RangeSummary) -> Self {
+        Self {
+            from: s.from.to_string(),
+            to: s.to.to_string(),
+            total_budgeted: s.total_budgeted.value(),
+            total_paid: s.total_paid.value(),
+            remaining: s.remaining.value(),
+            total_income: s.total_income.value(),
+            net_savings: s.net_savings.value(),
+            months: s.months.into_iter().map(|m| m.into()).collect(),
+            years: s.years.into_iter().map(|y| y.into()).collect(),
+            categories: s.categories.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+}
+
+impl From<MonthTotals> for MonthTotalsResponse {
+    fn from(m: MonthTotals) -> Self {
+        Self {
+            month_id: m.month_id.to_string(),
+            month: m.month.to_string(),
+            total_budgeted: m.total_budgeted.value(),
+            total_carried_in: m.total_carried_in.value(),
+            total_paid: m.total_paid.value(),
+            remaining: m.remaining.value(),
+            status: budget_status_str(m.status).to_string(),
+            total_income: m.total_income.value(),
+            net_savings: m.net_savings.value(),
+        }
+    }
+}
+
+impl From<YearTotals> for YearTotalsResponse {
+    fn from(y: YearTotals) -> Self {
+        Self {
+            year: y.year,
+            total_budgeted: y.total_budgeted.value(),
+            total_paid: y.total_paid.value(),
+            remaining: y.remaining.value(),
+            status: budget_status_str(y.status).to_string(),
+            total_income: y.total_income.value(),
+            net_savings: y.net_savings.value(),
+            month_count: y.month_count,
+        }
+    }
+}
+
+impl From<CategorySeries> for CategorySeriesResponse {
+    fn from(c: CategorySeries) -> Self {
+        Self {
+            category: CategorySummaryResponse::from(c.category),
+            total_budgeted: c.total_budgeted.value(),
+            total_paid: c.total_paid.value(),
+            remaining: c.remaining.value(),
+            points: c.points.into_iter().map(|p| p.into()).collect(),
+        }
+    }
+}
+
+impl From<CategorySeriesPoint> for CategorySeriesPointResponse {
+    fn from(p: CategorySeriesPoint) -> Self {
+        Self {
+            month: p.month.to_string(),
+            budgeted: p.budgeted.value(),
+            carried_in: p.carried_in.value(),
+            paid: p.paid.value(),
+            remaining: p.remaining.value(),
+            status: budget_status_str(p.status).to_string(),
+        }
+    }
+}
@@ -1,19 +1,23 @@
+use std::path::Path;
 use std::sync::Arc;
 
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
-use axum::routing::{get, patch};
+use axum::routing::{get, patch, post};
 use axum::Router;
 use http_body_util::BodyExt;
 use serde_json::{json, Value};
 use tower::ServiceExt;
 
 use db::repos::{
-    SqliteBudgetEntryRepository, SqliteCategoryRepository, SqliteMonthRepository,
-    SqliteTransactionRepository,
+    SqliteAccountRepository, SqliteAttachmentRepository, SqliteBudgetEntryRepository, SqliteCategoryRepository,
+    SqliteImportProfileRepository, SqliteMonthRepository, SqlitePayeeRepository, SqliteRecurringRuleRepository,
+    SqliteTagRepository, SqliteTransactionRepository, SqliteUnitOfWorkFactory,
 };
+use db::storage::FsAttachmentStore;
 use domain::services::{
-    CategoryService, EntryService, MonthService, SummaryService, TransactionService,
+    AccountService, AttachmentService, CategoryService, EntryService, HistoryService, ImportService, MonthService,
+    PayeeService, RecurringService, SummaryService, TagService, TransactionService,
 };
 
 // Re-use the AppState from the api crate.
@@ -24,6 +28,12 @@ use otter::handlers::AppState;
 // ---------------------------------------------------------------------------
 
 async fn setup() -> Router {
+    // Nothing is written there unless a test uploads a file
+    let attachments_dir = std::env::temp_dir().join("otter-api-tests-unused");
+    setup_with_storage(&attachments_dir, 10 * 1024 * 1024).await
+}
+
+async fn setup_with_storage(attachments_dir: &Path, max_attachment_bytes: usize) -> Router {
     let pool = db::create_pool("sqlite::memory:")
         .await
         .expect("Failed to create in-memory pool");
@@ -36,11 +46,18 @@ async fn setup() -> Router {
     let month_repo = Arc::new(SqliteMonthRepository::new(pool.clone()));
     let entry_repo = Arc::new(SqliteBudgetEntryRepository::new(pool.clone()));
     let transaction_repo = Arc::new(SqliteTransactionRepository::new(pool.clone()));
+    let recurring_repo = Arc::new(SqliteRecurringRuleRepository::new(pool.clone()));
+    let tag_repo = Arc::new(SqliteTagRepository::new(pool.clone()));
+    let payee_repo = Arc::new(SqlitePayeeRepository::new(pool.clone()));
+    let account_repo = Arc::new(SqliteAccountRepository::new(pool.clone()));
+    let attachment_repo = Arc::new(SqliteAttachmentRepository::new(pool.clone()));
+    let import_profile_repo = Arc::new(SqliteImportProfileRepository::new(pool.clone()));
+    let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
 
     let category_service = Arc::new(CategoryService::new(category_repo.clone()));
     let month_service = Arc::new(MonthService::new(
         month_repo.clone(),
-        entry_repo.clone(),
+        unit_of_work.clone(),
     ));
     let entry_service = Arc::new(EntryService::new(
         entry_repo.clone(),
@@ -49,12 +66,43 @@ async fn setup() -> Router {
     ));
     let transaction_service = Arc::new(TransactionService::new(
         transaction_repo.clone(),
-        entry_repo.clone(),
+        unit_of_work.clone(),
     ));
     let summary_service = Arc::new(SummaryService::new(
         entry_repo.clone(),
         transaction_repo.clone(),
         month_repo.clone(),
+        unit_of_work.clone(),
+    ));
+    let history_service = Arc::new(HistoryService::new(
+        category_repo.clone(),
+        month_repo.clone(),
+        entry_repo.clone(),
+        transaction_repo.clone(),
+    ));
+    let recurring_service = Arc::new(RecurringService::new(
+        recurring_repo.clone(),
+        category_repo.clone(),
+        unit_of_work.clone(),
+    ));
+    let tag_service = Arc::new(TagService::new(tag_repo.clone()));
+    let payee_service = Arc::new(PayeeService::new(
+        payee_repo.clone(),
+        category_repo.clone(),
+        entry_repo.clone(),
+    ));
+    let account_service = Arc::new(AccountService::new(account_repo.clone()));
+    let attachment_service = Arc::new(AttachmentService::new(
+        attachment_repo.clone(),
+        transaction_repo.clone(),
+        Arc::new(FsAttachmentStore::new(attachments_dir)),
+        unit_of_work.clone(),
+        max_attachment_bytes,
+    ));
+    let import_service = Arc::new(ImportService::new(
+        import_profile_repo.clone(),
+        unit_of_work.clone(),
+        2,
     ));
 
     let state = AppState {
@@ -63,6 +111,13 @@ async fn setup() -> Router {
         entry_service,
         transaction_service,
         summary_service,
+        history_service,
+        recurring_service,
+        tag_service,
+        payee_service,
+        account_service,
+        attachment_service,
+        import_service,
         currency_config: otter::config::CurrencyConfig {
             code: "PLN".to_string(),
             minor_unit_name: "grosz".to_string(),
@@ -77,15 +132,44 @@ async fn setup() -> Router {
             get(otter::handlers::categories::list_categories)
                 .post(otter::handlers::categories::create_category),
         )
+        .route(
+            "/categories/tree",
+            get(otter::handlers::categories::get_category_tree),
+        )
+        .route(
+            "/categories/move",
+            post(otter::handlers::categories::move_category_subtree),
+        )
         .route(
             "/categories/{id}",
-            patch(otter::handlers::categories::update_category),
+            patch(otter::handlers::categories::update_category)
+                .delete(otter::handlers::categories::delete_category),
+        )
+        .route(
+            "/categories/{id}/archive",
+            post(otter::handlers::categories::archive_category),
+        )
+        .route(
+            "/categories/{id}/unarchive",
+            post(otter::handlers::categories::unarchive_category),
+        )
+        .route(
+            "/categories/{id}/merge",
+            post(otter::handlers::categories::merge_category),
+        )
+        .route(
+            "/categories/{id}/history",
+            get(otter::handlers::categories::get_category_history),
         )
         .route(
             "/months",
             get(otter::handlers::months::list_months).post(otter::handlers::months::create_month),
         )
-        .route("/months/{id}", get(otter::handlers::months::get_month))
+        .route(
+            "/months/{id}",
+            get(otter::handlers::months::get_month).delete(otter::handlers::months::delete_month),
+        )
+        .route("/months/{id}/reset", post(otter::handlers::months::reset_month))
         .route(
             "/months/{id}/entries",
             get(otter::handlers::entries::list_entries).post(otter::handlers::entries::create_entry),
@@ -94,22 +178,135 @@ async fn setup() -> Router {
             "/months/{id}/entries/{entry_id}",
             patch(otter::handlers::entries::update_entry).delete(otter::handlers::entries::delete_entry),
         )
+        .route(
+            "/months/{id}/entries/{entry_id}/pay",
+            post(otter::handlers::entries::pay_entry),
+        )
         .route(
             "/transactions",
             get(otter::handlers::transactions::list_transactions)
                 .post(otter::handlers::transactions::create_transaction),
         )
+        .route(
+            "/transactions/search",
+            get(otter::handlers::transactions::search_transactions),
+        )
         .route(
             "/transactions/{id}",
             patch(otter::handlers::transactions::update_transaction)
                 .delete(otter::handlers::transactions::delete_transaction),
         )
+        .route(
+            "/transactions/{id}/attachments",
+            post(otter::handlers::attachments::upload_attachment)
+                .layer(otter::handlers::attachments::upload_body_limit(&state))
+                .get(otter::handlers::attachments::list_attachments),
+        )
+        .route(
+            "/transactions/{id}/attachments/{attachment_id}",
+            get(otter::handlers::attachments::download_attachment)
+                .delete(otter::handlers::attachments::delete_attachment),
+        )
+        .route(
+            "/transactions/splits",
+            post(otter::handlers::transactions::create_split),
+        )
+        .route(
+            "/transactions/splits/{id}",
+            get(otter::handlers::transactions::get_split)
+                .patch(otter::handlers::transactions::update_split)
+                .delete(otter::handlers::transactions::delete_split),
+        )
+        .route(
+            "/recurring-rules",
+            get(otter::handlers::recurring::list_rules).post(otter::handlers::recurring::create_rule),
+        )
+        .route(
+            "/recurring-rules/{id}",
+            patch(otter::handlers::recurring::update_rule).delete(otter::handlers::recurring::delete_rule),
+        )
+        .route(
+            "/recurring-rules/{id}/pause",
+            post(otter::handlers::recurring::pause_rule),
+        )
+        .route(
+            "/recurring-rules/{id}/resume",
+            post(otter::handlers::recurring::resume_rule),
+        )
+        .route(
+            "/tags",
+            get(otter::handlers::tags::list_tags).post(otter::handlers::tags::create_tag),
+        )
+        .route(
+            "/tags/{id}",
+            patch(otter::handlers::tags::rename_tag).delete(otter::handlers::tags::delete_tag),
+        )
+        .route(
+            "/tags/{id}/report",
+            get(otter::handlers::tags::get_tag_report),
+        )
+        .route(
+            "/payees",
+            get(otter::handlers::payees::list_payees).post(otter::handlers::payees::create_payee),
+        )
+        .route(
+            "/payees/lookup",
+            get(otter::handlers::payees::lookup_payee),
+        )
+        .route(
+            "/payees/{id}",
+            patch(otter::handlers::payees::update_payee).delete(otter::handlers::payees::delete_payee),
+        )
+        .route(
+            "/payees/{id}/report",
+            get(otter::handlers::payees::get_payee_report),
+        )
+        .route(
+            "/accounts",
+            get(otter::handlers::accounts::list_accounts).post(otter::handlers::accounts::create_account),
+        )
+        .route(
+            "/accounts/transfers",
+            get(otter::handlers::accounts::list_transfers).post(otter::handlers::accounts::create_transfer),
+        )
+        .route(
+            "/accounts/transfers/{id}",
+            patch(otter::handlers::accounts::update_transfer).delete(otter::handlers::accounts::delete_transfer),
+        )
+        .route(
+            "/accounts/{id}",
+            get(otter::handlers::accounts::get_account)
+                .patch(otter::handlers::accounts::update_account)
+                .delete(otter::handlers::accounts::delete_account),
+        )
+        .route(
+            "/accounts/{id}/ledger",
+            get(otter::handlers::accounts::get_account_ledger),
+        )
+        .route(
+            "/imports/profiles",
+            get(otter::handlers::imports::list_import_profiles).post(otter::handlers::imports::create_import_profile),
+        )
+        .route(
+            "/imports/profiles/{id}",
+            patch(otter::handlers::imports::update_import_profile).delete(otter::handlers::imports::delete_import_profile),
+        )
+        .route("/imports/preview", post(otter::handlers::imports::preview_import))
+        .route("/imports/commit", post(otter::handlers::imports::commit_import))
         .route(
             "/months/{id}/summary",
             get(otter::handlers::summary::get_month_summary),
-        );
+        )
+        .route(
+            "/months/{id}/due",
+            get(otter::handlers::summary::get_due_bills),
+        )
+        .route("/summary", get(otter::handlers::summary::get_range_summary));
 
-    Router::new().nest("/api/v1", api).with_state(state)
+    Router::new()
+        .nest("/api/v1", api)
+        .merge(otter::openapi::swagger_ui())
+        .with_state(state)
 }
 
 async fn do_get(app: &Router, path: &str) -> (StatusCode, Value) {
@@ -180,6 +377,82 @@ async fn do_delete(app: &Router, path: &str) -> (StatusCode, Value) {
     (status, json)
 }
 
+// Uploads `contents` as the multipart field "file".
+async fn do_upload(
+    app: &Router,
+    path: &str,
+    file_name: &str,
+    content_type: &str,
+    contents: &[u8],
+) -> (StatusCode, Value) {
+    let boundary = "otter-test-boundary";
+    let mut body = format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+         Content-Type: {content_type}\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(contents);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(path)
+                .header(
+                    "content-type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                )
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&bytes).unwrap_or_default();
+    (status, json)
+}
+
+// Helper: POST a CSV file to the import preview with one extra text field
+// (`profile_id` or `mapping`).
+async fn do_import_preview(app: &Router, field: (&str, &str), csv: &str) -> (StatusCode, Value) {
+    let boundary = "otter-test-boundary";
+    let (name, value) = field;
+    let body = format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"{name}\"\r\n\r\n\
+         {value}\r\n\
+         --{boundary}\r\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"statement.csv\"\r\n\
+         Content-Type: text/csv\r\n\r\n\
+         {csv}\r\n\
+         --{boundary}--\r\n"
+    );
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/imports/preview")
+                .header(
+                    "content-type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                )
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let json: Value = serde_json::from_slice(&bytes).unwrap_or_default();
+    (status, json)
+}
+
 // Convenience: create a category and return its id.
 async fn create_category(app: &Router, name: &str) -> String {
     let (status, body) = do_post(app, "/api/v1/categories", json!({ "name": name })).await;
@@ -244,6 +517,22 @@ async fn test_health_check() {
     assert_eq!(body["status"], "ok");
 }
 
+#[tokio::test]
+async fn test_openapi_document() {
+    let app = setup().await;
+    let (status, body) = do_get(&app, "/api/v1/openapi.json").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["servers"][0]["url"], "/api/v1");
+
+    let paths = body["paths"].as_object().expect("expected paths object");
+    assert!(paths["/categories"]["post"].is_object());
+    assert!(paths["/months/{id}/entries/{entry_id}"]["delete"].is_object());
+    assert_eq!(
+        paths["/transactions"]["post"]["responses"]["404"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/ErrorResponse"
+    );
+}
+
 #[tokio::test]
 async fn test_create_and_list_categories() {
     let app = setup().await;
@@ -403,273 +692,2748 @@ async fn test_update_category_name_and_label() {
 }
 
 #[tokio::test]
-async fn test_create_and_list_months() {
+async fn test_archive_category_hides_from_list() {
     let app = setup().await;
 
-    let (status, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
-    assert_eq!(status, StatusCode::CREATED);
-    assert_eq!(body["month"], "2026-02");
+    let food_id = create_category(&app, "food").await;
+    create_category(&app, "rent").await;
 
-    let (status, body) = do_get(&app, "/api/v1/months").await;
+    let (status, body) = do_post(&app, &format!("/api/v1/categories/{food_id}/archive"), json!({})).await;
     assert_eq!(status, StatusCode::OK);
-    let months = body.as_array().unwrap();
-    assert_eq!(months.len(), 1);
-    assert_eq!(months[0]["month"], "2026-02");
-}
+    assert!(body["archived_at"].is_string());
 
-#[tokio::test]
-async fn test_duplicate_month_returns_409() {
-    let app = setup().await;
+    // Default list hides archived categories
+    let (status, body) = do_get(&app, "/api/v1/categories").await;
+    assert_eq!(status, StatusCode::OK);
+    let categories = body.as_array().unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0]["name"], "rent");
 
-    let (status, _) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
-    assert_eq!(status, StatusCode::CREATED);
+    // ...unless explicitly requested
+    let (status, body) = do_get(&app, "/api/v1/categories?include_archived=true").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 2);
 
-    let (status, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
-    assert_eq!(status, StatusCode::CONFLICT);
-    assert_eq!(body["error"]["code"], "MONTH_ALREADY_EXISTS");
+    // Unarchive restores it
+    let (status, body) = do_post(&app, &format!("/api/v1/categories/{food_id}/unarchive"), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["archived_at"].is_null());
+    let (_, body) = do_get(&app, "/api/v1/categories").await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
 }
 
 #[tokio::test]
-async fn test_create_month_copies_entries() {
+async fn test_archived_category_not_copied_to_new_month() {
     let app = setup().await;
 
-    // Set up category and January month with an entry
-    let cat_id = create_category(&app, "food").await;
+    let food_id = create_category(&app, "food").await;
+    let rent_id = create_category(&app, "rent").await;
     let jan_id = create_month(&app, "2026-01").await;
-    create_entry(&app, &jan_id, &cat_id, 10000, Some(15)).await;
-
-    // Create February -- should auto-copy entries from January
-    let feb_id = create_month(&app, "2026-02").await;
+    create_entry(&app, &jan_id, &food_id, 10000, None).await;
+    create_entry(&app, &jan_id, &rent_id, 200000, Some(1)).await;
 
-    // Verify February entries
-    let path = format!("/api/v1/months/{feb_id}/entries");
-    let (status, body) = do_get(&app, &path).await;
+    let (status, _) = do_post(&app, &format!("/api/v1/categories/{food_id}/archive"), json!({})).await;
     assert_eq!(status, StatusCode::OK);
+
+    // Historical entries still show the archived category
+    let (_, body) = do_get(&app, &format!("/api/v1/months/{jan_id}/entries")).await;
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    let food = entries.iter().find(|e| e["category"]["name"] == "food").unwrap();
+    assert_eq!(food["category"]["archived"], true);
+
+    // The new month skips it
+    let feb_id = create_month(&app, "2026-02").await;
+    let (_, body) = do_get(&app, &format!("/api/v1/months/{feb_id}/entries")).await;
     let entries = body.as_array().unwrap();
     assert_eq!(entries.len(), 1);
-    assert_eq!(entries[0]["category"]["name"], "food");
-    assert_eq!(entries[0]["budgeted"], 10000);
-    assert_eq!(entries[0]["due_day"], 15);
+    assert_eq!(entries[0]["category"]["name"], "rent");
 }
 
 #[tokio::test]
-async fn test_create_empty_month() {
+async fn test_delete_unused_category() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "typo").await;
+    let path = format!("/api/v1/categories/{cat_id}");
+
+    let (status, _) = do_delete(&app, &path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = do_get(&app, "/api/v1/categories?include_archived=true").await;
+    assert_eq!(body.as_array().unwrap().len(), 0);
+
+    let (status, body) = do_delete(&app, &path).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "CATEGORY_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_cannot_delete_category_with_entries() {
     let app = setup().await;
 
-    // Set up category and January month with an entry
     let cat_id = create_category(&app, "food").await;
-    let jan_id = create_month(&app, "2026-01").await;
-    create_entry(&app, &jan_id, &cat_id, 10000, Some(15)).await;
+    let month_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &month_id, &cat_id, 10000, None).await;
 
-    // Create February as empty month (should not copy entries)
+    let (status, body) = do_delete(&app, &format!("/api/v1/categories/{cat_id}")).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "CATEGORY_HAS_ENTRIES");
+    assert_eq!(body["error"]["details"]["entry_count"], 1);
+}
+
+#[tokio::test]
+async fn test_cannot_delete_category_with_recurring_rules() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "rent").await;
     let (status, body) = do_post(
         &app,
-        "/api/v1/months",
-        json!({ "month": "2026-02", "empty": true }),
+        "/api/v1/recurring-rules",
+        json!({ "category_id": cat_id, "amount": 150000, "day_of_month": 1, "start_month": "2026-01" }),
     )
     .await;
-    assert_eq!(status, StatusCode::CREATED);
-    let feb_id = body["id"].as_str().unwrap();
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let rule_id = body["id"].as_str().unwrap().to_string();
 
-    // Verify February has no entries
-    let path = format!("/api/v1/months/{feb_id}/entries");
-    let (status, body) = do_get(&app, &path).await;
-    assert_eq!(status, StatusCode::OK);
-    let entries = body.as_array().unwrap();
-    assert_eq!(entries.len(), 0);
+    let path = format!("/api/v1/categories/{cat_id}");
+    let (status, body) = do_delete(&app, &path).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "CATEGORY_HAS_RECURRING_RULES");
+    assert_eq!(body["error"]["details"]["rule_count"], 1);
+
+    // The rule is still there; once it is gone the category can be deleted
+    let (_, rules) = do_get(&app, "/api/v1/recurring-rules").await;
+    assert_eq!(rules.as_array().unwrap().len(), 1);
+    let (status, _) = do_delete(&app, &format!("/api/v1/recurring-rules/{rule_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = do_delete(&app, &path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
 }
 
 #[tokio::test]
-async fn test_create_month_copy_from_specific() {
+async fn test_merge_categories() {
     let app = setup().await;
 
-    // Set up categories
-    let cat1_id = create_category(&app, "food").await;
-    let cat2_id = create_category(&app, "rent").await;
+    let lidl_id = create_category(&app, "food/lidl").await;
+    let groceries_id = create_category(&app, "food/groceries").await;
 
-    // Create January with one entry
+    // January: both categories budgeted, transactions on each
     let jan_id = create_month(&app, "2026-01").await;
-    create_entry(&app, &jan_id, &cat1_id, 10000, Some(15)).await;
-
-    // Create February with different entry
-    let feb_id = create_month(&app, "2026-02").await;
-    create_entry(&app, &feb_id, &cat2_id, 20000, Some(1)).await;
+    let jan_lidl = create_entry(&app, &jan_id, &lidl_id, 30000, None).await;
+    let jan_groceries = create_entry(&app, &jan_id, &groceries_id, 50000, Some(10)).await;
+    create_transaction(&app, &jan_lidl, 12000, "2026-01-05").await;
+    create_transaction(&app, &jan_groceries, 20000, "2026-01-06").await;
 
-    // Create March copying explicitly from January (not the latest February)
-    let (status, body) = do_post(
-        &app,
-        "/api/v1/months",
-        json!({ "month": "2026-03", "copy_from": jan_id }),
-    )
-    .await;
+    // February: only the source category
+    let (status, body) =
+        do_post(&app, "/api/v1/months", json!({ "month": "2026-02", "empty": true })).await;
     assert_eq!(status, StatusCode::CREATED);
-    let march_id = body["id"].as_str().unwrap();
+    let feb_id = body["id"].as_str().unwrap().to_string();
+    let feb_lidl = create_entry(&app, &feb_id, &lidl_id, 25000, Some(3)).await;
+    create_transaction(&app, &feb_lidl, 7000, "2026-02-02").await;
 
-    // Verify March copied from January (food, not rent)
-    let path = format!("/api/v1/months/{march_id}/entries");
-    let (status, body) = do_get(&app, &path).await;
+    let path = format!("/api/v1/categories/{lidl_id}/merge");
+    let (status, body) = do_post(&app, &path, json!({ "target_id": groceries_id })).await;
     assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], groceries_id.as_str());
+
+    // Source category is gone
+    let (_, body) = do_get(&app, "/api/v1/categories?include_archived=true").await;
+    let categories = body.as_array().unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0]["name"], "food/groceries");
+
+    // January: budgets summed, both transactions counted on the target entry
+    let (_, body) = do_get(&app, &format!("/api/v1/months/{jan_id}/summary")).await;
+    let categories = body["categories"].as_array().unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0]["entry_id"], jan_groceries.as_str());
+    assert_eq!(categories[0]["budgeted"], 80000);
+    assert_eq!(categories[0]["paid"], 32000);
+
+    // February: the source entry was re-pointed as is
+    let (_, body) = do_get(&app, &format!("/api/v1/months/{feb_id}/entries")).await;
     let entries = body.as_array().unwrap();
     assert_eq!(entries.len(), 1);
-    assert_eq!(entries[0]["category"]["name"], "food");
-    assert_eq!(entries[0]["budgeted"], 10000);
-    assert_eq!(entries[0]["due_day"], 15);
+    assert_eq!(entries[0]["id"], feb_lidl.as_str());
+    assert_eq!(entries[0]["category"]["name"], "food/groceries");
+    assert_eq!(entries[0]["budgeted"], 25000);
+    assert_eq!(entries[0]["due_day"], 3);
 }
 
 #[tokio::test]
-async fn test_create_month_copy_from_nonexistent() {
+async fn test_merge_categories_keeps_carried_balance() {
     let app = setup().await;
 
-    // Try to create month copying from non-existent ULID
-    // Using a valid ULID format that doesn't exist in the database
-    let fake_ulid = "01HY0000000000000000000000";
-    let (status, _body) = do_post(
+    let lidl_id = create_category(&app, "food/lidl").await;
+    let groceries_id = create_category(&app, "food/groceries").await;
+    let jan_id = create_month(&app, "2026-01").await;
+
+    let (status, body) = do_post(
         &app,
-        "/api/v1/months",
-        json!({ "month": "2026-01", "copy_from": fake_ulid }),
+        &format!("/api/v1/months/{jan_id}/entries"),
+        json!({ "category_id": lidl_id, "budgeted": 10000, "rollover": "carry_remaining" }),
     )
     .await;
-    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    create_transaction(&app, body["id"].as_str().unwrap(), 4000, "2026-01-05").await;
+    create_entry(&app, &jan_id, &groceries_id, 20000, None).await;
+
+    // February is copied from January: 6000 carried into the source entry
+    let feb_id = create_month(&app, "2026-02").await;
+    let (_, before) = do_get(&app, &format!("/api/v1/months/{feb_id}/summary")).await;
+    assert_eq!(before["total_carried_in"], 6000);
+
+    let path = format!("/api/v1/categories/{lidl_id}/merge");
+    let (status, _) = do_post(&app, &path, json!({ "target_id": groceries_id })).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, summary) = do_get(&app, &format!("/api/v1/months/{feb_id}/summary")).await;
+    let categories = summary["categories"].as_array().unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0]["budgeted"], 30000);
+    assert_eq!(categories[0]["carried_in"], 6000);
+    assert_eq!(categories[0]["available"], 36000);
+    assert_eq!(summary["total_carried_in"], 6000);
+
+    // The target entry's rollover mode wins
+    let (_, entries) = do_get(&app, &format!("/api/v1/months/{feb_id}/entries")).await;
+    assert_eq!(entries[0]["rollover"], "none");
 }
 
 #[tokio::test]
-async fn test_create_month_default_behavior() {
+async fn test_merge_categories_rejects_mixed_kinds() {
     let app = setup().await;
 
-    // Set up category and January month with an entry
-    let cat_id = create_category(&app, "food").await;
+    let bonus_id = create_category(&app, "bonus").await;
+    let fun_id = create_category(&app, "fun").await;
     let jan_id = create_month(&app, "2026-01").await;
-    create_entry(&app, &jan_id, &cat_id, 10000, Some(15)).await;
+    let feb_id = create_month(&app, "2026-02").await;
 
-    // Create February without any parameters (backward compatibility)
-    let (status, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
-    assert_eq!(status, StatusCode::CREATED);
-    let feb_id = body["id"].as_str().unwrap();
+    create_entry(&app, &jan_id, &fun_id, 10000, None).await;
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{feb_id}/entries"),
+        json!({ "category_id": bonus_id, "kind": "income", "budgeted": 50000 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let fun_feb = create_entry(&app, &feb_id, &fun_id, 10000, None).await;
+    create_transaction(&app, &fun_feb, 3000, "2026-02-10").await;
 
-    // Verify February copied entries from latest month (January)
-    let path = format!("/api/v1/months/{feb_id}/entries");
-    let (status, body) = do_get(&app, &path).await;
+    let path = format!("/api/v1/categories/{bonus_id}/merge");
+    let (status, body) = do_post(&app, &path, json!({ "target_id": fun_id })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "CATEGORY_MERGE_KIND_MISMATCH");
+    assert_eq!(body["error"]["details"]["month"], "2026-02");
+
+    // Nothing was merged
+    let (_, categories) = do_get(&app, "/api/v1/categories").await;
+    assert_eq!(categories.as_array().unwrap().len(), 2);
+    let (_, summary) = do_get(&app, &format!("/api/v1/months/{feb_id}/summary")).await;
+    assert_eq!(summary["categories"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_merge_category_errors() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "food").await;
+    let path = format!("/api/v1/categories/{cat_id}/merge");
+
+    let (status, body) = do_post(&app, &path, json!({ "target_id": cat_id })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "CATEGORY_MERGE_INTO_SELF");
+
+    let (status, body) = do_post(&app, &path, json!({ "target_id": "01HY0000000000000000000000" })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "CATEGORY_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_category_tree() {
+    let app = setup().await;
+
+    create_category(&app, "utils/electricity").await;
+    create_category(&app, "utils/gas").await;
+    create_category(&app, "food").await;
+
+    let (status, body) = do_get(&app, "/api/v1/categories/tree").await;
     assert_eq!(status, StatusCode::OK);
-    let entries = body.as_array().unwrap();
-    assert_eq!(entries.len(), 1);
-    assert_eq!(entries[0]["category"]["name"], "food");
-    assert_eq!(entries[0]["budgeted"], 10000);
+    let roots = body.as_array().unwrap();
+    assert_eq!(roots.len(), 2);
+
+    assert_eq!(roots[0]["name"], "food");
+    assert_eq!(roots[0]["category"]["name"], "food");
+    assert_eq!(roots[0]["children"].as_array().unwrap().len(), 0);
+
+    // "utils" has no category of its own
+    assert_eq!(roots[1]["name"], "utils");
+    assert!(roots[1]["category"].is_null());
+    let children = roots[1]["children"].as_array().unwrap();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0]["segment"], "electricity");
+    assert_eq!(children[0]["category"]["name"], "utils/electricity");
 }
 
 #[tokio::test]
-async fn test_budget_entry_crud() {
+async fn test_month_summary_rollup() {
     let app = setup().await;
 
-    let cat_id = create_category(&app, "rent").await;
+    let elec_id = create_category(&app, "utils/electricity").await;
+    let gas_id = create_category(&app, "utils/gas").await;
+    let food_id = create_category(&app, "food").await;
     let month_id = create_month(&app, "2026-03").await;
+    let elec_entry = create_entry(&app, &month_id, &elec_id, 30000, None).await;
+    create_entry(&app, &month_id, &gas_id, 20000, None).await;
+    create_entry(&app, &month_id, &food_id, 100000, None).await;
+    create_transaction(&app, &elec_entry, 30000, "2026-03-10").await;
 
-    // Create entry
-    let entry_id = create_entry(&app, &month_id, &cat_id, 5000, None).await;
+    // Without rollup the field is absent
+    let (_, body) = do_get(&app, &format!("/api/v1/months/{month_id}/summary")).await;
+    assert!(body.get("groups").is_none());
 
-    // List entries -> 1
-    let entries_path = format!("/api/v1/months/{month_id}/entries");
-    let (status, body) = do_get(&app, &entries_path).await;
+    let (status, body) = do_get(&app, &format!("/api/v1/months/{month_id}/summary?rollup=true")).await;
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(body.as_array().unwrap().len(), 1);
+    let groups = body["groups"].as_array().unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0]["name"], "utils");
+    assert_eq!(groups[0]["budgeted"], 50000);
+    assert_eq!(groups[0]["paid"], 30000);
+    assert_eq!(groups[0]["remaining"], 20000);
+    assert_eq!(groups[0]["status"], "underspent");
+    assert_eq!(groups[0]["entry_count"], 2);
+}
 
-    // Update entry budgeted amount
-    let entry_path = format!("/api/v1/months/{month_id}/entries/{entry_id}");
-    let (status, body) = do_patch(&app, &entry_path, json!({ "budgeted": 20000 })).await;
-    assert_eq!(status, StatusCode::OK);
-    assert_eq!(body["budgeted"], 20000);
+#[tokio::test]
+async fn test_range_summary() {
+    let app = setup().await;
 
-    // Delete entry
-    let (status, _) = do_delete(&app, &entry_path).await;
-    assert_eq!(status, StatusCode::NO_CONTENT);
+    let rent_id = create_category(&app, "rent").await;
+    let food_id = create_category(&app, "food").await;
+    let dec_id = create_month(&app, "2025-12").await;
+    let dec_rent = create_entry(&app, &dec_id, &rent_id, 200000, None).await;
+    create_transaction(&app, &dec_rent, 200000, "2025-12-01").await;
 
-    // List entries -> 0
-    let (status, body) = do_get(&app, &entries_path).await;
+    // Copies rent from December
+    let jan_id = create_month(&app, "2026-01").await;
+    let (_, entries) = do_get(&app, &format!("/api/v1/months/{jan_id}/entries")).await;
+    let jan_rent = entries[0]["id"].as_str().unwrap().to_string();
+    create_transaction(&app, &jan_rent, 200000, "2026-01-01").await;
+    let jan_food = create_entry(&app, &jan_id, &food_id, 50000, None).await;
+    create_transaction(&app, &jan_food, 60000, "2026-01-15").await;
+
+    let (status, body) = do_get(&app, "/api/v1/summary?from=2025-12&to=2026-12").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["from"], "2025-12");
+    assert_eq!(body["total_budgeted"], 450000);
+    assert_eq!(body["total_paid"], 460000);
+    assert_eq!(body["remaining"], -10000);
+
+    let months = body["months"].as_array().unwrap();
+    assert_eq!(months.len(), 2);
+    assert_eq!(months[0]["month"], "2025-12");
+    assert_eq!(months[0]["status"], "on_budget");
+    assert_eq!(months[1]["month"], "2026-01");
+    assert_eq!(months[1]["total_paid"], 260000);
+    assert_eq!(months[1]["status"], "overspent");
+
+    let years = body["years"].as_array().unwrap();
+    assert_eq!(years.len(), 2);
+    assert_eq!(years[1]["year"], 2026);
+    assert_eq!(years[1]["month_count"], 1);
+
+    let categories = body["categories"].as_array().unwrap();
+    assert_eq!(categories[0]["category"]["name"], "food");
+    assert_eq!(categories[0]["points"].as_array().unwrap().len(), 1);
+    assert_eq!(categories[0]["points"][0]["status"], "overspent");
+    assert_eq!(categories[1]["category"]["name"], "rent");
+    assert_eq!(categories[1]["total_paid"], 400000);
+    assert_eq!(categories[1]["points"].as_array().unwrap().len(), 2);
+
+    // Narrower range leaves December out
+    let (_, body) = do_get(&app, "/api/v1/summary?from=2026-01&to=2026-01").await;
+    assert_eq!(body["months"].as_array().unwrap().len(), 1);
+    assert_eq!(body["total_budgeted"], 250000);
+}
+
+#[tokio::test]
+async fn test_range_summary_invalid() {
+    let app = setup().await;
+
+    let (status, body) = do_get(&app, "/api/v1/summary?from=2026-12&to=2026-01").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "MONTH_INVALID_RANGE");
+
+    let (status, body) = do_get(&app, "/api/v1/summary?from=2026-13&to=2026-12").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "MONTH_INVALID_FORMAT");
+}
+
+#[tokio::test]
+async fn test_category_history() {
+    let app = setup().await;
+
+    let food_id = create_category(&app, "food/groceries").await;
+    let other_id = create_category(&app, "rent").await;
+    let mut paid = 40000;
+    for m in ["2026-01", "2026-02", "2026-03"] {
+        let (_, body) = do_post(&app, "/api/v1/months", json!({ "month": m, "empty": true })).await;
+        let month_id = body["id"].as_str().unwrap().to_string();
+        let entry = create_entry(&app, &month_id, &food_id, 50000, None).await;
+        create_transaction(&app, &entry, paid, &format!("{m}-10")).await;
+        paid += 10000;
+    }
+    // A month without a groceries entry produces no point
+    let (_, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-04", "empty": true })).await;
+    create_entry(&app, body["id"].as_str().unwrap(), &other_id, 100000, None).await;
+
+    let (status, body) = do_get(&app, &format!("/api/v1/categories/{food_id}/history")).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["category"]["name"], "food/groceries");
+    let points = body["points"].as_array().unwrap();
+    let months: Vec<&str> = points.iter().map(|p| p["month"].as_str().unwrap()).collect();
+    assert_eq!(months, vec!["2026-01", "2026-02", "2026-03"]);
+    assert_eq!(points[2]["paid"], 60000);
+    assert_eq!(points[2]["status"], "overspent");
+
+    let stats = &body["stats"];
+    assert_eq!(stats["average_budgeted"], 50000);
+    assert_eq!(stats["average_paid"], 50000);
+    assert_eq!(stats["min_paid"]["month"], "2026-01");
+    assert_eq!(stats["max_paid"]["amount"], 60000);
+    assert_eq!(stats["trend"]["per_month"], 10000);
+    assert_eq!(stats["trend"]["direction"], "rising");
+
+    // The two most recent months are April (no entry) and March
+    let (_, body) = do_get(&app, &format!("/api/v1/categories/{food_id}/history?months=2")).await;
+    assert_eq!(body["points"].as_array().unwrap().len(), 1);
+    assert_eq!(body["stats"]["trend"]["direction"], "flat");
+
+    let (_, body) = do_get(&app, &format!("/api/v1/categories/{other_id}/history?months=1")).await;
+    assert_eq!(body["points"].as_array().unwrap().len(), 1);
+
+    let (status, _) = do_get(&app, &format!("/api/v1/categories/{food_id}/history?months=0")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = do_get(&app, "/api/v1/categories/01HY0000000000000000000000/history").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "CATEGORY_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_move_category_subtree() {
+    let app = setup().await;
+
+    create_category(&app, "utils/electricity").await;
+    create_category(&app, "utils/gas").await;
+    create_category(&app, "utils_old").await;
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/categories/move",
+        json!({ "from": "utils", "to": "home/utilities" }),
+    )
+    .await;
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(body.as_array().unwrap().len(), 0);
+    let renamed: Vec<&str> = body.as_array().unwrap().iter().map(|c| c["name"].as_str().unwrap()).collect();
+    assert_eq!(renamed, vec!["home/utilities/electricity", "home/utilities/gas"]);
+
+    // Sibling sharing the textual prefix is untouched
+    let (_, body) = do_get(&app, "/api/v1/categories").await;
+    let names: Vec<&str> = body.as_array().unwrap().iter().map(|c| c["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["home/utilities/electricity", "home/utilities/gas", "utils_old"]);
 }
 
 #[tokio::test]
-async fn test_cannot_delete_entry_with_transactions() {
+async fn test_move_category_subtree_into_itself() {
     let app = setup().await;
 
-    let cat_id = create_category(&app, "food").await;
-    let month_id = create_month(&app, "2026-04").await;
-    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+    create_category(&app, "a").await;
+    create_category(&app, "a/b").await;
 
-    // Create a transaction on this entry
-    create_transaction(&app, &entry_id, 3000, "2026-04-10").await;
+    // `a` -> `a/b` clashes with the old `a/b` until that one moves to `a/b/b`
+    let (status, body) = do_post(&app, "/api/v1/categories/move", json!({ "from": "a", "to": "a/b" })).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let renamed: Vec<&str> = body.as_array().unwrap().iter().map(|c| c["name"].as_str().unwrap()).collect();
+    assert_eq!(renamed, vec!["a/b", "a/b/b"]);
+}
 
-    // Attempt to delete the entry -- should fail
-    let entry_path = format!("/api/v1/months/{month_id}/entries/{entry_id}");
-    let (status, body) = do_delete(&app, &entry_path).await;
+#[tokio::test]
+async fn test_move_category_subtree_collision() {
+    let app = setup().await;
+
+    create_category(&app, "utils/gas").await;
+    create_category(&app, "utils/water").await;
+    create_category(&app, "utilities/water").await;
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/categories/move",
+        json!({ "from": "utils", "to": "utilities" }),
+    )
+    .await;
     assert_eq!(status, StatusCode::CONFLICT);
-    assert_eq!(body["error"]["code"], "ENTRY_HAS_TRANSACTIONS");
+    assert_eq!(body["error"]["code"], "CATEGORY_NAME_ALREADY_EXISTS");
+    assert_eq!(body["error"]["details"]["name"], "utilities/water");
+
+    // Nothing was renamed
+    let (_, body) = do_get(&app, "/api/v1/categories").await;
+    let names: Vec<&str> = body.as_array().unwrap().iter().map(|c| c["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["utilities/water", "utils/gas", "utils/water"]);
+
+    let (status, _) = do_post(&app, "/api/v1/categories/move", json!({ "from": "nope", "to": "x" })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
 }
 
 #[tokio::test]
-async fn test_transaction_crud() {
+async fn test_create_and_list_months() {
+    let app = setup().await;
+
+    let (status, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["month"], "2026-02");
+
+    let (status, body) = do_get(&app, "/api/v1/months").await;
+    assert_eq!(status, StatusCode::OK);
+    let months = body.as_array().unwrap();
+    assert_eq!(months.len(), 1);
+    assert_eq!(months[0]["month"], "2026-02");
+}
+
+#[tokio::test]
+async fn test_duplicate_month_returns_409() {
+    let app = setup().await;
+
+    let (status, _) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "MONTH_ALREADY_EXISTS");
+}
+
+#[tokio::test]
+async fn test_create_month_copies_entries() {
     let app = setup().await;
 
+    // Set up category and January month with an entry
     let cat_id = create_category(&app, "food").await;
-    let month_id = create_month(&app, "2026-05").await;
-    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+    let jan_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &jan_id, &cat_id, 10000, Some(15)).await;
 
-    // Create transaction
-    let txn_id = create_transaction(&app, &entry_id, 2500, "2026-05-01").await;
+    // Create February -- should auto-copy entries from January
+    let feb_id = create_month(&app, "2026-02").await;
 
-    // List transactions for month
-    let list_path = format!("/api/v1/transactions?month={month_id}");
-    let (status, body) = do_get(&app, &list_path).await;
+    // Verify February entries
+    let path = format!("/api/v1/months/{feb_id}/entries");
+    let (status, body) = do_get(&app, &path).await;
     assert_eq!(status, StatusCode::OK);
-    let transactions = body.as_array().unwrap();
-    assert_eq!(transactions.len(), 1);
-    assert_eq!(transactions[0]["amount"], 2500);
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["category"]["name"], "food");
+    assert_eq!(entries[0]["budgeted"], 10000);
+    assert_eq!(entries[0]["due_day"], 15);
+}
 
-    // Update transaction amount
-    let txn_path = format!("/api/v1/transactions/{txn_id}");
-    let (status, body) = do_patch(&app, &txn_path, json!({ "amount": 4000 })).await;
-    assert_eq!(status, StatusCode::OK);
-    assert_eq!(body["amount"], 4000);
+#[tokio::test]
+async fn test_create_empty_month() {
+    let app = setup().await;
 
-    // Delete transaction
-    let (status, _) = do_delete(&app, &txn_path).await;
-    assert_eq!(status, StatusCode::NO_CONTENT);
+    // Set up category and January month with an entry
+    let cat_id = create_category(&app, "food").await;
+    let jan_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &jan_id, &cat_id, 10000, Some(15)).await;
 
-    // List transactions -> 0
-    let (status, body) = do_get(&app, &list_path).await;
+    // Create February as empty month (should not copy entries)
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/months",
+        json!({ "month": "2026-02", "empty": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let feb_id = body["id"].as_str().unwrap();
+
+    // Verify February has no entries
+    let path = format!("/api/v1/months/{feb_id}/entries");
+    let (status, body) = do_get(&app, &path).await;
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(body.as_array().unwrap().len(), 0);
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 0);
 }
 
 #[tokio::test]
-async fn test_month_summary() {
+async fn test_create_month_copy_from_specific() {
     let app = setup().await;
 
-    let cat_id = create_category(&app, "food").await;
-    let month_id = create_month(&app, "2026-02").await;
+    // Set up categories
+    let cat1_id = create_category(&app, "food").await;
+    let cat2_id = create_category(&app, "rent").await;
 
-    // Add entry with budgeted = 10000
-    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+    // Create January with one entry
+    let jan_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &jan_id, &cat1_id, 10000, Some(15)).await;
 
-    // Create transaction for 5000
-    create_transaction(&app, &entry_id, 5000, "2026-02-15").await;
+    // Create February with different entry
+    let feb_id = create_month(&app, "2026-02").await;
+    create_entry(&app, &feb_id, &cat2_id, 20000, Some(1)).await;
 
-    // Get summary
-    let summary_path = format!("/api/v1/months/{month_id}/summary");
-    let (status, body) = do_get(&app, &summary_path).await;
+    // Create March copying explicitly from January (not the latest February)
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/months",
+        json!({ "month": "2026-03", "copy_from": jan_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let march_id = body["id"].as_str().unwrap();
+
+    // Verify March copied from January (food, not rent)
+    let path = format!("/api/v1/months/{march_id}/entries");
+    let (status, body) = do_get(&app, &path).await;
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(body["total_budgeted"], 10000);
-    assert_eq!(body["total_paid"], 5000);
-    assert_eq!(body["remaining"], 5000);
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["category"]["name"], "food");
+    assert_eq!(entries[0]["budgeted"], 10000);
+    assert_eq!(entries[0]["due_day"], 15);
+}
 
-    // Category status
-    let categories = body["categories"].as_array().unwrap();
-    assert_eq!(categories.len(), 1);
-    assert_eq!(categories[0]["category"]["name"], "food");
-    assert_eq!(categories[0]["budgeted"], 10000);
-    assert_eq!(categories[0]["paid"], 5000);
-    assert_eq!(categories[0]["remaining"], 5000);
-    assert_eq!(categories[0]["status"], "underspent");
+#[tokio::test]
+async fn test_create_month_copy_from_nonexistent() {
+    let app = setup().await;
+
+    // Try to create month copying from non-existent ULID
+    // Using a valid ULID format that doesn't exist in the database
+    let fake_ulid = "01HY0000000000000000000000";
+    let (status, _body) = do_post(
+        &app,
+        "/api/v1/months",
+        json!({ "month": "2026-01", "copy_from": fake_ulid }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_create_month_default_behavior() {
+    let app = setup().await;
+
+    // Set up category and January month with an entry
+    let cat_id = create_category(&app, "food").await;
+    let jan_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &jan_id, &cat_id, 10000, Some(15)).await;
+
+    // Create February without any parameters (backward compatibility)
+    let (status, body) = do_post(&app, "/api/v1/months", json!({ "month": "2026-02" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let feb_id = body["id"].as_str().unwrap();
+
+    // Verify February copied entries from latest month (January)
+    let path = format!("/api/v1/months/{feb_id}/entries");
+    let (status, body) = do_get(&app, &path).await;
+    assert_eq!(status, StatusCode::OK);
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["category"]["name"], "food");
+    assert_eq!(entries[0]["budgeted"], 10000);
+}
+
+#[tokio::test]
+async fn test_delete_month() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "food").await;
+    let month_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &month_id, &cat_id, 10000, None).await;
+
+    // Entries without transactions go away with the month
+    let (status, _) = do_delete(&app, &format!("/api/v1/months/{month_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, body) = do_get(&app, &format!("/api/v1/months/{month_id}")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "MONTH_NOT_FOUND");
+
+    let (status, _) = do_delete(&app, &format!("/api/v1/months/{month_id}")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_delete_month_with_transactions() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "food").await;
+    let month_id = create_month(&app, "2026-01").await;
+    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+    create_transaction(&app, &entry_id, 2500, "2026-01-10").await;
+
+    let path = format!("/api/v1/months/{month_id}");
+    let (status, body) = do_delete(&app, &path).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "MONTH_HAS_TRANSACTIONS");
+    assert_eq!(body["error"]["details"]["transaction_count"], 1);
+
+    let (status, _) = do_delete(&app, &format!("{path}?cascade=true")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, body) = do_get(&app, "/api/v1/months").await;
+    assert_eq!(body.as_array().unwrap().len(), 0);
+
+    // The category is free to be deleted again
+    let (status, _) = do_delete(&app, &format!("/api/v1/categories/{cat_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_reset_month_from_source() {
+    let app = setup().await;
+
+    let food_id = create_category(&app, "food").await;
+    let rent_id = create_category(&app, "rent").await;
+    let jan_id = create_month(&app, "2026-01").await;
+    create_entry(&app, &jan_id, &food_id, 10000, Some(15)).await;
+
+    let (status, body) =
+        do_post(&app, "/api/v1/months", json!({ "month": "2026-02", "empty": true })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let feb_id = body["id"].as_str().unwrap().to_string();
+    create_entry(&app, &feb_id, &rent_id, 200000, Some(1)).await;
+
+    let path = format!("/api/v1/months/{feb_id}/reset");
+    let (status, body) = do_post(&app, &path, json!({ "copy_from": jan_id })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["month"], "2026-02");
+
+    let (_, body) = do_get(&app, &format!("/api/v1/months/{feb_id}/entries")).await;
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["category"]["name"], "food");
+    assert_eq!(entries[0]["budgeted"], 10000);
+    assert_eq!(entries[0]["due_day"], 15);
+
+    let (status, body) = do_post(&app, &path, json!({ "copy_from": feb_id })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "MONTH_COPY_FROM_SELF");
+
+    // Refused once the month has transactions
+    let entry_id = entries[0]["id"].as_str().unwrap();
+    create_transaction(&app, entry_id, 500, "2026-02-03").await;
+    let (status, body) = do_post(&app, &path, json!({ "copy_from": jan_id })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "MONTH_HAS_TRANSACTIONS");
+}
+
+#[tokio::test]
+async fn test_budget_entry_crud() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "rent").await;
+    let month_id = create_month(&app, "2026-03").await;
+
+    // Create entry
+    let entry_id = create_entry(&app, &month_id, &cat_id, 5000, None).await;
+
+    // List entries -> 1
+    let entries_path = format!("/api/v1/months/{month_id}/entries");
+    let (status, body) = do_get(&app, &entries_path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    // Update entry budgeted amount
+    let entry_path = format!("/api/v1/months/{month_id}/entries/{entry_id}");
+    let (status, body) = do_patch(&app, &entry_path, json!({ "budgeted": 20000 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["budgeted"], 20000);
+
+    // Delete entry
+    let (status, _) = do_delete(&app, &entry_path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // List entries -> 0
+    let (status, body) = do_get(&app, &entries_path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_cannot_delete_entry_with_transactions() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "food").await;
+    let month_id = create_month(&app, "2026-04").await;
+    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+
+    // Create a transaction on this entry
+    create_transaction(&app, &entry_id, 3000, "2026-04-10").await;
+
+    // Attempt to delete the entry -- should fail
+    let entry_path = format!("/api/v1/months/{month_id}/entries/{entry_id}");
+    let (status, body) = do_delete(&app, &entry_path).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "ENTRY_HAS_TRANSACTIONS");
+}
+
+#[tokio::test]
+async fn test_transaction_crud() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "food").await;
+    let month_id = create_month(&app, "2026-05").await;
+    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+
+    // Create transaction
+    let txn_id = create_transaction(&app, &entry_id, 2500, "2026-05-01").await;
+
+    // List transactions for month
+    let list_path = format!("/api/v1/transactions?month={month_id}");
+    let (status, body) = do_get(&app, &list_path).await;
+    assert_eq!(status, StatusCode::OK);
+    let transactions = body.as_array().unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0]["amount"], 2500);
+
+    // Update transaction amount
+    let txn_path = format!("/api/v1/transactions/{txn_id}");
+    let (status, body) = do_patch(&app, &txn_path, json!({ "amount": 4000 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["amount"], 4000);
+
+    // Delete transaction
+    let (status, _) = do_delete(&app, &txn_path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // List transactions -> 0
+    let (status, body) = do_get(&app, &list_path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 0);
+}
+
+#[tokio::test]
+async fn test_month_summary() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "food").await;
+    let month_id = create_month(&app, "2026-02").await;
+
+    // Add entry with budgeted = 10000
+    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+
+    // Create transaction for 5000
+    create_transaction(&app, &entry_id, 5000, "2026-02-15").await;
+
+    // Get summary
+    let summary_path = format!("/api/v1/months/{month_id}/summary");
+    let (status, body) = do_get(&app, &summary_path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total_budgeted"], 10000);
+    assert_eq!(body["total_paid"], 5000);
+    assert_eq!(body["remaining"], 5000);
+
+    // Category status
+    let categories = body["categories"].as_array().unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0]["category"]["name"], "food");
+    assert_eq!(categories[0]["budgeted"], 10000);
+    assert_eq!(categories[0]["paid"], 5000);
+    assert_eq!(categories[0]["remaining"], 5000);
+    assert_eq!(categories[0]["status"], "underspent");
+}
+
+#[tokio::test]
+async fn test_month_summary_income() {
+    let app = setup().await;
+
+    let rent_id = create_category(&app, "rent").await;
+    let salary_id = create_category(&app, "income/salary").await;
+    let month_id = create_month(&app, "2026-02").await;
+
+    let rent_entry = create_entry(&app, &month_id, &rent_id, 200000, None).await;
+    create_transaction(&app, &rent_entry, 200000, "2026-02-01").await;
+
+    let path = format!("/api/v1/months/{month_id}/entries");
+    let (status, body) = do_post(
+        &app,
+        &path,
+        json!({ "category_id": salary_id, "kind": "income", "budgeted": 500000 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["kind"], "income");
+    let salary_entry = body["id"].as_str().unwrap().to_string();
+    create_transaction(&app, &salary_entry, 480000, "2026-02-10").await;
+
+    let (status, body) = do_get(&app, &format!("/api/v1/months/{month_id}/summary")).await;
+    assert_eq!(status, StatusCode::OK);
+    // Expense totals leave income out
+    assert_eq!(body["total_budgeted"], 200000);
+    assert_eq!(body["total_paid"], 200000);
+    assert_eq!(body["expected_income"], 500000);
+    assert_eq!(body["total_income"], 480000);
+    assert_eq!(body["total_spending"], 200000);
+    assert_eq!(body["net_savings"], 280000);
+    let salary = body["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["kind"] == "income")
+        .unwrap();
+    assert_eq!(salary["status"], "underspent");
+
+    // The next month copies the entry kind
+    let next_id = create_month(&app, "2026-03").await;
+    let (_, entries) = do_get(&app, &format!("/api/v1/months/{next_id}/entries")).await;
+    let kinds: Vec<&str> = entries.as_array().unwrap().iter().map(|e| e["kind"].as_str().unwrap()).collect();
+    assert!(kinds.contains(&"income") && kinds.contains(&"expense"));
+
+    let (status, body) = do_post(
+        &app,
+        &path,
+        json!({ "category_id": create_category(&app, "bonus").await, "kind": "gift", "budgeted": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "ENTRY_INVALID_KIND");
+}
+
+#[tokio::test]
+async fn test_refunds() {
+    let app = setup().await;
+
+    let cat_id = create_category(&app, "clothing").await;
+    let other_cat = create_category(&app, "shoes").await;
+    let month_id = create_month(&app, "2026-02").await;
+    let entry_id = create_entry(&app, &month_id, &cat_id, 10000, None).await;
+    let other_entry = create_entry(&app, &month_id, &other_cat, 10000, None).await;
+    let original = create_transaction(&app, &entry_id, 6000, "2026-02-03").await;
+
+    let refund = |amount: i64| {
+        json!({
+            "entry_id": entry_id,
+            "kind": "refund",
+            "amount": amount,
+            "date": "2026-02-10",
+            "refund_of": original,
+        })
+    };
+
+    let (status, body) = do_post(&app, "/api/v1/transactions", refund(2500)).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["kind"], "refund");
+    assert_eq!(body["refund_of"], original.as_str());
+    let refund_id = body["id"].as_str().unwrap().to_string();
+
+    // Refunds reduce what was paid on the entry
+    let (_, summary) = do_get(&app, &format!("/api/v1/months/{month_id}/summary")).await;
+    assert_eq!(summary["total_paid"], 3500);
+    let clothing = summary["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["entry_id"] == entry_id.as_str())
+        .unwrap();
+    assert_eq!(clothing["paid"], 3500);
+
+    // Together the refunds cannot exceed the original payment
+    let (status, body) = do_post(&app, "/api/v1/transactions", refund(3501)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_REFUND_EXCEEDS_ORIGINAL");
+    assert_eq!(body["error"]["details"]["refunded"], 6001);
+
+    let refund_path = format!("/api/v1/transactions/{refund_id}");
+    let (status, body) = do_patch(&app, &refund_path, json!({ "amount": 6001 })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_REFUND_EXCEEDS_ORIGINAL");
+
+    let original_path = format!("/api/v1/transactions/{original}");
+    let (status, body) = do_patch(&app, &original_path, json!({ "amount": 2000 })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_REFUND_EXCEEDS_ORIGINAL");
+
+    // A refund stays on its original's entry
+    let (status, body) = do_patch(&app, &refund_path, json!({ "entry_id": other_entry })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_REFUND");
+
+    let mut payload = refund(100);
+    payload["entry_id"] = json!(other_entry);
+    let (status, body) = do_post(&app, "/api/v1/transactions", payload).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_REFUND");
+
+    let mut payload = refund(100);
+    payload["kind"] = json!("payment");
+    let (status, body) = do_post(&app, "/api/v1/transactions", payload).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_REFUND");
+
+    let mut payload = refund(100);
+    payload["refund_of"] = json!(refund_id);
+    let (status, body) = do_post(&app, "/api/v1/transactions", payload).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_REFUND");
+
+    let mut payload = refund(100);
+    payload["refund_of"] = json!("01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    let (status, body) = do_post(&app, "/api/v1/transactions", payload).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TRANSACTION_REFUND_ORIGINAL_NOT_FOUND");
+
+    // Unlinked refunds are allowed, negative amounts still are not
+    let mut payload = refund(100);
+    payload["refund_of"] = Value::Null;
+    let (status, body) = do_post(&app, "/api/v1/transactions", payload).await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["refund_of"], Value::Null);
+
+    let (status, body) = do_post(&app, "/api/v1/transactions", refund(-100)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_AMOUNT");
+
+    // Deleting the original unlinks its refunds
+    let (status, _) = do_delete(&app, &original_path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, body) = do_get(&app, &format!("/api/v1/transactions?entry_id={entry_id}")).await;
+    let items = body["items"].as_array().unwrap();
+    assert!(items.iter().all(|t| t["refund_of"].is_null()));
+}
+
+#[tokio::test]
+async fn test_split_transactions() {
+    let app = setup().await;
+
+    let groceries = create_category(&app, "groceries").await;
+    let household = create_category(&app, "household").await;
+    let kids = create_category(&app, "kids").await;
+    let month_id = create_month(&app, "2026-02").await;
+    let groceries_entry = create_entry(&app, &month_id, &groceries, 50000, None).await;
+    let household_entry = create_entry(&app, &month_id, &household, 10000, None).await;
+    let kids_entry = create_entry(&app, &month_id, &kids, 10000, None).await;
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions/splits",
+        json!({
+            "amount": 9000,
+            "date": "2026-02-07",
+            "title": "Supermarket",
+            "allocations": [
+                { "entry_id": groceries_entry, "amount": 6000 },
+                { "entry_id": household_entry, "amount": 2000 },
+                { "entry_id": kids_entry, "amount": 1000 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let split_id = body["id"].as_str().unwrap().to_string();
+    let allocations = body["allocations"].as_array().unwrap();
+    assert_eq!(allocations.len(), 3);
+    assert!(allocations.iter().all(|a| a["split_id"] == split_id.as_str()));
+    assert!(allocations.iter().all(|a| a["title"] == "Supermarket"));
+
+    // Each allocation counts towards its own entry
+    let paid_of = |summary: &Value, entry_id: &str| {
+        summary["categories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["entry_id"] == entry_id)
+            .unwrap()["paid"]
+            .clone()
+    };
+    let summary_path = format!("/api/v1/months/{month_id}/summary");
+    let (_, summary) = do_get(&app, &summary_path).await;
+    assert_eq!(summary["total_paid"], 9000);
+    assert_eq!(paid_of(&summary, &groceries_entry), 6000);
+    assert_eq!(paid_of(&summary, &household_entry), 2000);
+
+    // Allocations are only changed through their split
+    let allocation_id = allocations[0]["id"].as_str().unwrap();
+    let path = format!("/api/v1/transactions/{allocation_id}");
+    let (status, body) = do_patch(&app, &path, json!({ "amount": 1 })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "TRANSACTION_PART_OF_SPLIT");
+    assert_eq!(body["error"]["details"]["split_id"], split_id.as_str());
+    let (status, _) = do_delete(&app, &path).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let split_path = format!("/api/v1/transactions/splits/{split_id}");
+    let (status, body) = do_patch(&app, &split_path, json!({ "amount": 9500 })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_SPLIT_TOTAL_MISMATCH");
+    assert_eq!(body["error"]["details"]["allocated"], 9000);
+
+    let (status, body) = do_patch(
+        &app,
+        &split_path,
+        json!({
+            "amount": 9500,
+            "date": "2026-02-08",
+            "allocations": [
+                { "entry_id": groceries_entry, "amount": 7000 },
+                { "entry_id": household_entry, "amount": 2500 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["amount"], 9500);
+    let allocations = body["allocations"].as_array().unwrap();
+    assert_eq!(allocations.len(), 2);
+    assert!(allocations.iter().all(|a| a["date"] == "2026-02-08"));
+
+    let (_, summary) = do_get(&app, &summary_path).await;
+    assert_eq!(summary["total_paid"], 9500);
+    assert_eq!(paid_of(&summary, &kids_entry), 0);
+
+    let (status, body) = do_patch(&app, &split_path, json!({ "title": "Market" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["allocations"].as_array().unwrap().iter().all(|a| a["title"] == "Market"));
+
+    // Allocations must stay within one month
+    let other_month = create_month(&app, "2026-03").await;
+    let (_, entries) = do_get(&app, &format!("/api/v1/months/{other_month}/entries")).await;
+    let march_entry = entries[0]["id"].as_str().unwrap();
+    let (status, body) = do_patch(
+        &app,
+        &split_path,
+        json!({
+            "allocations": [
+                { "entry_id": groceries_entry, "amount": 7000 },
+                { "entry_id": march_entry, "amount": 2500 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_SPLIT");
+
+    // ...and on expenses: income is received, not paid
+    let (status, salary) = do_post(
+        &app,
+        &format!("/api/v1/months/{month_id}/entries"),
+        json!({ "category_id": create_category(&app, "salary").await, "kind": "income", "budgeted": 500000 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{salary}");
+    let (status, body) = do_patch(
+        &app,
+        &split_path,
+        json!({
+            "allocations": [
+                { "entry_id": groceries_entry, "amount": 7000 },
+                { "entry_id": salary["id"], "amount": 2500 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_SPLIT");
+    assert_eq!(body["error"]["details"]["reason"], "allocations cannot go to income entries");
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions/splits",
+        json!({
+            "amount": 100,
+            "date": "2026-02-07",
+            "allocations": [{ "entry_id": groceries_entry, "amount": 100 }],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_SPLIT");
+
+    // Deleting the split deletes its allocations
+    let (status, _) = do_delete(&app, &split_path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = do_get(&app, &split_path).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, summary) = do_get(&app, &summary_path).await;
+    assert_eq!(summary["total_paid"], 0);
+}
+
+#[tokio::test]
+async fn test_month_copy_rolls_over_balances() {
+    let app = setup().await;
+
+    let food = create_category(&app, "food").await;
+    let rent = create_category(&app, "rent").await;
+    let misc = create_category(&app, "misc").await;
+    let jan = create_month(&app, "2026-01").await;
+    let entries_path = format!("/api/v1/months/{jan}/entries");
+
+    let (status, body) = do_post(
+        &app,
+        &entries_path,
+        json!({ "category_id": food, "budgeted": 10000, "rollover": "carry_remaining" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["rollover"], "carry_remaining");
+    assert_eq!(body["carried_in"], 0);
+    create_transaction(&app, body["id"].as_str().unwrap(), 7000, "2026-01-10").await;
+
+    let rent_entry = create_entry(&app, &jan, &rent, 5000, None).await;
+    create_transaction(&app, &rent_entry, 6000, "2026-01-01").await;
+    let (status, body) = do_patch(
+        &app,
+        &format!("{entries_path}/{rent_entry}"),
+        json!({ "rollover": "carry_overspend" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["rollover"], "carry_overspend");
+
+    let misc_entry = create_entry(&app, &jan, &misc, 1000, None).await;
+    create_transaction(&app, &misc_entry, 200, "2026-01-05").await;
+
+    let feb = create_month(&app, "2026-02").await;
+    let (status, summary) = do_get(&app, &format!("/api/v1/months/{feb}/summary")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(summary["total_budgeted"], 16000);
+    assert_eq!(summary["total_carried_in"], 2000);
+    assert_eq!(summary["remaining"], 18000);
+
+    let by_name = |name: &str| {
+        summary["categories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["category"]["name"] == name)
+            .unwrap()
+            .clone()
+    };
+    let food_feb = by_name("food");
+    assert_eq!(food_feb["budgeted"], 10000);
+    assert_eq!(food_feb["carried_in"], 3000);
+    assert_eq!(food_feb["available"], 13000);
+    assert_eq!(food_feb["remaining"], 13000);
+    assert_eq!(by_name("rent")["carried_in"], -1000);
+    assert_eq!(by_name("rent")["remaining"], 4000);
+    assert_eq!(by_name("misc")["carried_in"], 0);
+
+    // The rollover mode is copied, so balances keep accumulating
+    let (_, entries) = do_get(&app, &format!("/api/v1/months/{feb}/entries")).await;
+    let food_entry = entries
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["category"]["name"] == "food")
+        .unwrap();
+    assert_eq!(food_entry["rollover"], "carry_remaining");
+    create_transaction(&app, food_entry["id"].as_str().unwrap(), 12000, "2026-02-10").await;
+    let mar = create_month(&app, "2026-03").await;
+    let (_, summary) = do_get(&app, &format!("/api/v1/months/{mar}/summary")).await;
+    let food_mar = summary["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["category"]["name"] == "food")
+        .unwrap();
+    assert_eq!(food_mar["carried_in"], 1000);
+
+    let (status, body) = do_post(
+        &app,
+        &entries_path,
+        json!({ "category_id": create_category(&app, "fun").await, "budgeted": 1, "rollover": "carry" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "ENTRY_INVALID_ROLLOVER");
+}
+
+#[tokio::test]
+async fn test_recurring_rules() {
+    let app = setup().await;
+    let rent = create_category(&app, "rent").await;
+    let jan = create_month(&app, "2025-01").await;
+    create_entry(&app, &jan, &rent, 150000, Some(1)).await;
+
+    // Validation
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/recurring-rules",
+        json!({ "category_id": rent, "amount": 150000, "day_of_month": 32, "start_month": "2025-01" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "RECURRING_INVALID_DAY");
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/recurring-rules",
+        json!({
+            "category_id": rent, "amount": 150000, "day_of_month": 1,
+            "start_month": "2025-03", "end_month": "2025-02"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "RECURRING_INVALID_RANGE");
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/recurring-rules",
+        json!({
+            "category_id": "01HY0000000000000000000000", "amount": 150000,
+            "day_of_month": 1, "start_month": "2025-01"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "RECURRING_CATEGORY_NOT_FOUND");
+
+    let (status, rule) = do_post(
+        &app,
+        "/api/v1/recurring-rules",
+        json!({
+            "category_id": rent, "amount": 150000, "title": "  Rent  ",
+            "day_of_month": 31, "start_month": "2025-02"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{rule}");
+    assert_eq!(rule["title"], "Rent");
+    assert_eq!(rule["end_month"], Value::Null);
+    assert_eq!(rule["paused"], false);
+    let rule_id = rule["id"].as_str().unwrap().to_string();
+
+    let (_, rules) = do_get(&app, "/api/v1/recurring-rules").await;
+    assert_eq!(rules.as_array().unwrap().len(), 1);
+
+    // January is before the rule starts, February gets the payment on its last day
+    let feb = create_month(&app, "2025-02").await;
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={jan}")).await;
+    assert!(txs.as_array().unwrap().is_empty());
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={feb}")).await;
+    let txs = txs.as_array().unwrap();
+    assert_eq!(txs.len(), 1);
+    assert_eq!(txs[0]["amount"], 150000);
+    assert_eq!(txs[0]["date"], "2025-02-28");
+    assert_eq!(txs[0]["title"], "Rent");
+    assert_eq!(txs[0]["kind"], "payment");
+
+    // Paused rules book nothing
+    let (status, paused) = do_post(&app, &format!("/api/v1/recurring-rules/{rule_id}/pause"), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(paused["paused"], true);
+    let mar = create_month(&app, "2025-03").await;
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={mar}")).await;
+    assert!(txs.as_array().unwrap().is_empty());
+
+    let (status, resumed) = do_post(&app, &format!("/api/v1/recurring-rules/{rule_id}/resume"), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(resumed["paused"], false);
+
+    // Edits apply to months created afterwards
+    let (status, updated) = do_patch(
+        &app,
+        &format!("/api/v1/recurring-rules/{rule_id}"),
+        json!({ "amount": 160000, "day_of_month": 5, "title": null, "end_month": "2025-04" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{updated}");
+    assert_eq!(updated["amount"], 160000);
+    assert_eq!(updated["title"], Value::Null);
+    assert_eq!(updated["end_month"], "2025-04");
+
+    let apr = create_month(&app, "2025-04").await;
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={apr}")).await;
+    let txs = txs.as_array().unwrap();
+    assert_eq!(txs.len(), 1);
+    assert_eq!(txs[0]["amount"], 160000);
+    assert_eq!(txs[0]["date"], "2025-04-05");
+
+    // Past the end month
+    let may = create_month(&app, "2025-05").await;
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={may}")).await;
+    assert!(txs.as_array().unwrap().is_empty());
+
+    let (status, body) = do_patch(
+        &app,
+        &format!("/api/v1/recurring-rules/{rule_id}"),
+        json!({ "start_month": "2025-06" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "RECURRING_INVALID_RANGE");
+
+    // Deleting the rule keeps what it booked
+    let (status, _) = do_delete(&app, &format!("/api/v1/recurring-rules/{rule_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={feb}")).await;
+    assert_eq!(txs.as_array().unwrap().len(), 1);
+    let (status, body) = do_post(&app, &format!("/api/v1/recurring-rules/{rule_id}/pause"), json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "RECURRING_RULE_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_recurring_rule_not_booked_before_due() {
+    let app = setup().await;
+    let gym = create_category(&app, "gym").await;
+    let first = create_month(&app, "2099-01").await;
+    create_entry(&app, &first, &gym, 9900, None).await;
+
+    let (status, _) = do_post(
+        &app,
+        "/api/v1/recurring-rules",
+        json!({ "category_id": gym, "amount": 9900, "day_of_month": 1, "start_month": "2099-01" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let feb = create_month(&app, "2099-02").await;
+    let (_, txs) = do_get(&app, &format!("/api/v1/transactions?month={feb}")).await;
+    assert!(txs.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_due_bills() {
+    let app = setup().await;
+    let month = create_month(&app, "2026-04").await;
+
+    let internet = create_category(&app, "internet").await;
+    let electricity = create_category(&app, "electricity").await;
+    let gym = create_category(&app, "gym").await;
+    let phone = create_category(&app, "phone").await;
+    let rent = create_category(&app, "rent").await;
+    let insurance = create_category(&app, "insurance").await;
+    let salary = create_category(&app, "salary").await;
+
+    let internet_entry = create_entry(&app, &month, &internet, 5000, Some(5)).await;
+    create_entry(&app, &month, &electricity, 20000, Some(10)).await;
+    let gym_entry = create_entry(&app, &month, &gym, 10000, Some(18)).await;
+    create_entry(&app, &month, &phone, 4000, Some(20)).await;
+    create_entry(&app, &month, &rent, 150000, Some(31)).await;
+    create_entry(&app, &month, &insurance, 30000, None).await;
+    let (status, _) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries"),
+        json!({ "category_id": salary, "kind": "income", "budgeted": 900000, "due_day": 25 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    create_transaction(&app, &internet_entry, 5000, "2026-04-03").await;
+    create_transaction(&app, &gym_entry, 4000, "2026-04-02").await;
+
+    let (status, body) = do_get(&app, &format!("/api/v1/months/{month}/due?today=2026-04-18")).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["month"], "2026-04");
+    assert_eq!(body["today"], "2026-04-18");
+
+    // Entries without a due day and income entries are not bills
+    let bills = body["bills"].as_array().unwrap();
+    let summary: Vec<(&str, &str, &str, i64)> = bills
+        .iter()
+        .map(|b| {
+            (
+                b["category"]["name"].as_str().unwrap(),
+                b["due_date"].as_str().unwrap(),
+                b["status"].as_str().unwrap(),
+                b["days_until"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("internet", "2026-04-05", "paid", -13),
+            ("electricity", "2026-04-10", "overdue", -8),
+            ("gym", "2026-04-18", "due_today", 0),
+            ("phone", "2026-04-20", "due_soon", 2),
+            ("rent", "2026-04-30", "upcoming", 12),
+        ]
+    );
+    assert_eq!(bills[2]["paid"], 4000);
+    assert_eq!(bills[2]["remaining"], 6000);
+
+    // A wider window pulls the rent in
+    let (_, body) = do_get(
+        &app,
+        &format!("/api/v1/months/{month}/due?today=2026-04-18&soon_days=14"),
+    )
+    .await;
+    assert_eq!(body["bills"][4]["status"], "due_soon");
+
+    let (status, _) = do_get(&app, &format!("/api/v1/months/{month}/due?today=2026-04-31")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = do_get(&app, "/api/v1/months/01HY0000000000000000000000/due").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "MONTH_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_pay_entry() {
+    let app = setup().await;
+    let month = create_month(&app, "2026-04").await;
+    let other_month = create_month(&app, "2026-05").await;
+    let rent = create_category(&app, "rent").await;
+    let phone = create_category(&app, "phone").await;
+    let rent_entry = create_entry(&app, &month, &rent, 150000, Some(31)).await;
+    let phone_entry = create_entry(&app, &month, &phone, 4000, None).await;
+    create_transaction(&app, &rent_entry, 50000, "2026-04-02").await;
+
+    // Books the remaining amount on the due date, clamped to April's last day
+    let (status, tx) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{rent_entry}/pay"),
+        json!({ "on": "due_date", "title": "{category} {month}" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{tx}");
+    assert_eq!(tx["amount"], 100000);
+    assert_eq!(tx["date"], "2026-04-30");
+    assert_eq!(tx["title"], "rent 2026-04");
+    assert_eq!(tx["kind"], "payment");
+
+    let (_, summary) = do_get(&app, &format!("/api/v1/months/{month}/summary")).await;
+    let rent_summary = summary["categories"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["entry_id"] == rent_entry.as_str())
+        .unwrap();
+    assert_eq!(rent_summary["status"], "on_budget");
+
+    // Nothing left to pay
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{rent_entry}/pay"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "TRANSACTION_ENTRY_ALREADY_PAID");
+    assert_eq!(body["error"]["details"]["paid"], 150000);
+
+    // Due date needs a due day
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{phone_entry}/pay"),
+        json!({ "on": "due_date" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_ENTRY_HAS_NO_DUE_DAY");
+
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{phone_entry}/pay"),
+        json!({ "on": "tomorrow" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_DATE");
+
+    // The entry must belong to the month in the path
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{other_month}/entries/{phone_entry}/pay"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TRANSACTION_ENTRY_NOT_FOUND");
+
+    // Today is not in April 2026, so it cannot be booked there
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{phone_entry}/pay"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_DATE_OUTSIDE_MONTH");
+    assert_eq!(body["error"]["details"]["month"], "2026-04");
+}
+
+#[tokio::test]
+async fn test_pay_entry_today() {
+    let app = setup().await;
+    let today = chrono::Utc::now().date_naive();
+    let month = create_month(&app, &today.format("%Y-%m").to_string()).await;
+    let phone = create_category(&app, "phone").await;
+    let phone_entry = create_entry(&app, &month, &phone, 4000, None).await;
+
+    // Defaults to today without a title
+    let (status, tx) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{phone_entry}/pay"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(tx["amount"], 4000);
+    assert_eq!(tx["date"], today.format("%Y-%m-%d").to_string());
+    assert_eq!(tx["title"], Value::Null);
+
+    // Income is received, not paid
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries"),
+        json!({ "category_id": create_category(&app, "salary").await, "budgeted": 500000, "kind": "income" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let (status, body) = do_post(
+        &app,
+        &format!("/api/v1/months/{month}/entries/{}/pay", body["id"].as_str().unwrap()),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_ENTRY_IS_INCOME");
+}
+
+#[tokio::test]
+async fn test_transaction_tags() {
+    let app = setup().await;
+    let april = create_month(&app, "2026-04").await;
+    let may = create_month(&app, "2026-05").await;
+    let food = create_category(&app, "food").await;
+    let salary = create_category(&app, "salary").await;
+    let april_food = create_entry(&app, &april, &food, 50000, None).await;
+    let may_food = create_entry(&app, &may, &food, 50000, None).await;
+
+    let (status, vacation) = do_post(&app, "/api/v1/tags", json!({ "name": "vacation" })).await;
+    assert_eq!(status, StatusCode::CREATED, "{vacation}");
+    let vacation_id = vacation["id"].as_str().unwrap().to_string();
+    let (status, _) = do_post(&app, "/api/v1/tags", json!({ "name": "kids" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = do_post(&app, "/api/v1/tags", json!({ "name": "vacation" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "TAG_NAME_ALREADY_EXISTS");
+    let (status, body) = do_post(&app, "/api/v1/tags", json!({ "name": "two words" })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TAG_INVALID_NAME");
+
+    // Tags come back sorted and deduplicated
+    let (status, tagged) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({
+            "entry_id": april_food,
+            "amount": 12000,
+            "date": "2026-04-10",
+            "tags": ["vacation", "kids", "vacation"],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{tagged}");
+    assert_eq!(tagged["tags"], json!(["kids", "vacation"]));
+    let tagged_id = tagged["id"].as_str().unwrap().to_string();
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({ "entry_id": april_food, "amount": 100, "date": "2026-04-11", "tags": ["unknown"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_UNKNOWN_TAG");
+    assert_eq!(body["error"]["details"]["name"], "unknown");
+
+    create_transaction(&app, &april_food, 3000, "2026-04-12").await;
+    let may_tx = create_transaction(&app, &may_food, 8000, "2026-05-03").await;
+    let (status, may_tagged) = do_patch(
+        &app,
+        &format!("/api/v1/transactions/{may_tx}"),
+        json!({ "tags": ["vacation"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{may_tagged}");
+    assert_eq!(may_tagged["tags"], json!(["vacation"]));
+
+    // Refunds count against the tag's spending
+    let (status, _) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({
+            "entry_id": may_food,
+            "kind": "refund",
+            "amount": 2000,
+            "date": "2026-05-04",
+            "refund_of": may_tx,
+            "tags": ["vacation"],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Income entries are left out of the report
+    let (_, may_salary) = do_post(
+        &app,
+        &format!("/api/v1/months/{may}/entries"),
+        json!({ "category_id": salary, "kind": "income", "budgeted": 500000 }),
+    )
+    .await;
+    let (status, _) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({ "entry_id": may_salary["id"], "amount": 500000, "date": "2026-05-01", "tags": ["vacation"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Filtering by month, by entry and across months
+    let (_, list) = do_get(&app, &format!("/api/v1/transactions?month={april}&tag=vacation")).await;
+    let ids: Vec<&str> = list.as_array().unwrap().iter().map(|t| t["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec![tagged_id.as_str()]);
+
+    let (_, page) = do_get(&app, &format!("/api/v1/transactions?entry_id={april_food}&tag=kids")).await;
+    assert_eq!(page["items"].as_array().unwrap().len(), 1);
+    assert_eq!(page["items"][0]["id"], tagged_id.as_str());
+
+    let (_, all) = do_get(&app, "/api/v1/transactions?tag=vacation").await;
+    assert_eq!(all.as_array().unwrap().len(), 4);
+
+    let (status, body) = do_get(&app, "/api/v1/transactions?tag=nope!").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_UNKNOWN_TAG");
+
+    let (status, report) = do_get(&app, &format!("/api/v1/tags/{vacation_id}/report")).await;
+    assert_eq!(status, StatusCode::OK, "{report}");
+    assert_eq!(report["tag"]["name"], "vacation");
+    assert_eq!(report["total_spent"], 12000 + 8000 - 2000);
+    assert_eq!(report["months"].as_array().unwrap().len(), 2);
+    assert_eq!(report["months"][0]["month"], "2026-04");
+    assert_eq!(report["months"][0]["spent"], 12000);
+    assert_eq!(report["months"][1]["spent"], 6000);
+    assert_eq!(report["months"][1]["transaction_count"], 2);
+
+    // Renaming carries over to tagged transactions; clearing tags with []
+    let (status, renamed) = do_patch(
+        &app,
+        &format!("/api/v1/tags/{vacation_id}"),
+        json!({ "name": "holiday" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(renamed["name"], "holiday");
+    let (_, list) = do_get(&app, &format!("/api/v1/transactions?month={may}&tag=holiday")).await;
+    assert_eq!(list.as_array().unwrap().len(), 3);
+
+    let (_, cleared) = do_patch(&app, &format!("/api/v1/transactions/{tagged_id}"), json!({ "tags": [] })).await;
+    assert_eq!(cleared["tags"], json!([]));
+
+    // Deleting a tag keeps its transactions
+    let (status, _) = do_delete(&app, &format!("/api/v1/tags/{vacation_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, body) = do_get(&app, &format!("/api/v1/tags/{vacation_id}/report")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TAG_NOT_FOUND");
+    let (_, list) = do_get(&app, &format!("/api/v1/transactions?month={may}")).await;
+    assert_eq!(list.as_array().unwrap().len(), 3);
+    assert!(list.as_array().unwrap().iter().all(|t| t["tags"] == json!([])));
+
+    let (_, tags) = do_get(&app, "/api/v1/tags").await;
+    assert_eq!(tags.as_array().unwrap().len(), 1);
+    assert_eq!(tags[0]["name"], "kids");
+}
+
+#[tokio::test]
+async fn test_payees() {
+    let app = setup().await;
+    let april = create_month(&app, "2026-04").await;
+    let may = create_month(&app, "2026-05").await;
+    let food = create_category(&app, "food").await;
+    let april_food = create_entry(&app, &april, &food, 50000, None).await;
+    let may_food = create_entry(&app, &may, &food, 50000, None).await;
+
+    let (status, grocer) = do_post(
+        &app,
+        "/api/v1/payees",
+        json!({ "name": "Green Grocer", "default_category_id": food, "aliases": ["GRNGROCER*123", "green  grocer ltd"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{grocer}");
+    assert_eq!(grocer["default_category_id"], food.as_str());
+    assert_eq!(grocer["aliases"], json!(["GRNGROCER*123", "green  grocer ltd"]));
+    let grocer_id = grocer["id"].as_str().unwrap().to_string();
+
+    // Names and aliases are unique across payees, ignoring case and spacing
+    let (status, body) = do_post(&app, "/api/v1/payees", json!({ "name": "green grocer ltd" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "PAYEE_NAME_ALREADY_EXISTS");
+    let (status, body) = do_post(&app, "/api/v1/payees", json!({ "name": "  " })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "PAYEE_INVALID_NAME");
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/payees",
+        json!({ "name": "Bakery", "default_category_id": ulid::Ulid::new().to_string() }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "PAYEE_CATEGORY_NOT_FOUND");
+
+    // Lookup by alias preselects the default category's entry in the month
+    let (status, found) = do_get(
+        &app,
+        &format!("/api/v1/payees/lookup?name=Green%20Grocer%20LTD&month={may}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{found}");
+    assert_eq!(found["payee"]["id"], grocer_id.as_str());
+    assert_eq!(found["entry_id"], may_food.as_str());
+    let (_, found) = do_get(&app, "/api/v1/payees/lookup?name=grngrocer*123").await;
+    assert_eq!(found["entry_id"], Value::Null);
+    let (status, body) = do_get(&app, "/api/v1/payees/lookup?name=nobody").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "PAYEE_NOT_FOUND");
+
+    let (status, paid) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({ "entry_id": april_food, "amount": 4000, "date": "2026-04-10", "payee_id": grocer_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{paid}");
+    assert_eq!(paid["payee_id"], grocer_id.as_str());
+    let paid_id = paid["id"].as_str().unwrap().to_string();
+    let may_paid = create_transaction(&app, &may_food, 2500, "2026-05-03").await;
+    let (status, body) = do_patch(
+        &app,
+        &format!("/api/v1/transactions/{may_paid}"),
+        json!({ "payee_id": grocer_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["payee_id"], grocer_id.as_str());
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({ "entry_id": april_food, "amount": 100, "date": "2026-04-11", "payee_id": ulid::Ulid::new().to_string() }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TRANSACTION_PAYEE_NOT_FOUND");
+
+    let (status, report) = do_get(&app, &format!("/api/v1/payees/{grocer_id}/report")).await;
+    assert_eq!(status, StatusCode::OK, "{report}");
+    assert_eq!(report["total_spent"], 4000 + 2500);
+    assert_eq!(report["months"].as_array().unwrap().len(), 2);
+    assert_eq!(report["months"][0]["month"], "2026-04");
+    assert_eq!(report["months"][0]["spent"], 4000);
+
+    // Replacing aliases and clearing the default category
+    let (status, updated) = do_patch(
+        &app,
+        &format!("/api/v1/payees/{grocer_id}"),
+        json!({ "default_category_id": null, "aliases": ["GG"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{updated}");
+    assert_eq!(updated["default_category_id"], Value::Null);
+    assert_eq!(updated["aliases"], json!(["GG"]));
+    let (status, _) = do_get(&app, "/api/v1/payees/lookup?name=grngrocer*123").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, cleared) = do_patch(
+        &app,
+        &format!("/api/v1/transactions/{may_paid}"),
+        json!({ "payee_id": null }),
+    )
+    .await;
+    assert_eq!(cleared["payee_id"], Value::Null);
+
+    // Deleting a payee keeps its transactions
+    let (status, _) = do_delete(&app, &format!("/api/v1/payees/{grocer_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, list) = do_get(&app, &format!("/api/v1/transactions?month={april}")).await;
+    assert_eq!(list[0]["id"], paid_id.as_str());
+    assert_eq!(list[0]["payee_id"], Value::Null);
+    let (_, list) = do_get(&app, "/api/v1/payees").await;
+    assert_eq!(list, json!([]));
+}
+
+#[tokio::test]
+async fn test_accounts_and_transfers() {
+    let app = setup().await;
+    let april = create_month(&app, "2026-04").await;
+    let food = create_category(&app, "food").await;
+    let salary = create_category(&app, "salary").await;
+    let april_food = create_entry(&app, &april, &food, 50000, None).await;
+    let (_, april_salary) = do_post(
+        &app,
+        &format!("/api/v1/months/{april}/entries"),
+        json!({ "category_id": salary, "kind": "income", "budgeted": 500000 }),
+    )
+    .await;
+
+    let (status, joint) = do_post(
+        &app,
+        "/api/v1/accounts",
+        json!({ "name": "Joint", "opening_balance": 100000 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{joint}");
+    assert_eq!(joint["kind"], "checking");
+    assert_eq!(joint["balance"], 100000);
+    let joint_id = joint["id"].as_str().unwrap().to_string();
+    let (status, cash) = do_post(&app, "/api/v1/accounts", json!({ "name": "Cash", "kind": "cash" })).await;
+    assert_eq!(status, StatusCode::CREATED, "{cash}");
+    let cash_id = cash["id"].as_str().unwrap().to_string();
+
+    let (status, body) = do_post(&app, "/api/v1/accounts", json!({ "name": "Joint" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "ACCOUNT_NAME_ALREADY_EXISTS");
+    let (status, body) = do_post(&app, "/api/v1/accounts", json!({ "name": "Card", "kind": "debit" })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "ACCOUNT_INVALID_KIND");
+
+    for (entry, amount, date, account) in [
+        (april_salary["id"].as_str().unwrap(), 500000, "2026-04-01", &joint_id),
+        (april_food.as_str(), 4000, "2026-04-05", &joint_id),
+        (april_food.as_str(), 1500, "2026-04-06", &cash_id),
+    ] {
+        let (status, body) = do_post(
+            &app,
+            "/api/v1/transactions",
+            json!({ "entry_id": entry, "amount": amount, "date": date, "account_id": account }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+        assert_eq!(body["account_id"], account.as_str());
+    }
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({ "entry_id": april_food, "amount": 100, "date": "2026-04-07", "account_id": ulid::Ulid::new().to_string() }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TRANSACTION_ACCOUNT_NOT_FOUND");
+
+    let (status, transfer) = do_post(
+        &app,
+        "/api/v1/accounts/transfers",
+        json!({ "from_account_id": joint_id, "to_account_id": cash_id, "amount": 20000, "date": "2026-04-03", "title": "ATM" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{transfer}");
+    let transfer_id = transfer["id"].as_str().unwrap().to_string();
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/accounts/transfers",
+        json!({ "from_account_id": cash_id, "to_account_id": cash_id, "amount": 100, "date": "2026-04-03" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSFER_TO_SELF");
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/accounts/transfers",
+        json!({ "from_account_id": joint_id, "to_account_id": cash_id, "amount": 0, "date": "2026-04-03" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSFER_INVALID_AMOUNT");
+
+    // Balances follow transactions and transfers
+    let (status, accounts) = do_get(&app, "/api/v1/accounts").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(accounts[0]["name"], "Cash");
+    assert_eq!(accounts[0]["balance"], 20000 - 1500);
+    assert_eq!(accounts[1]["balance"], 100000 + 500000 - 20000 - 4000);
+
+    let (status, ledger) = do_get(&app, &format!("/api/v1/accounts/{joint_id}/ledger")).await;
+    assert_eq!(status, StatusCode::OK, "{ledger}");
+    let lines = ledger["lines"].as_array().unwrap();
+    let kinds: Vec<&str> = lines.iter().map(|l| l["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, vec!["transaction", "transfer_out", "transaction"]);
+    let balances: Vec<i64> = lines.iter().map(|l| l["balance"].as_i64().unwrap()).collect();
+    assert_eq!(balances, vec![600000, 580000, 576000]);
+    assert_eq!(lines[1]["change"], -20000);
+    assert_eq!(lines[1]["counterpart_account_id"], cash_id.as_str());
+    assert_eq!(ledger["account"]["balance"], 576000);
+
+    // Transfers are not budget spending
+    let (_, summary) = do_get(&app, &format!("/api/v1/months/{april}/summary")).await;
+    assert_eq!(summary["total_paid"], 4000 + 1500);
+
+    let (status, updated) = do_patch(
+        &app,
+        &format!("/api/v1/accounts/transfers/{transfer_id}"),
+        json!({ "amount": 30000, "title": null }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{updated}");
+    assert_eq!(updated["title"], Value::Null);
+    let (_, cash) = do_get(&app, &format!("/api/v1/accounts/{cash_id}")).await;
+    assert_eq!(cash["balance"], 30000 - 1500);
+
+    let (_, cash) = do_patch(
+        &app,
+        &format!("/api/v1/accounts/{cash_id}"),
+        json!({ "opening_balance": 5000 }),
+    )
+    .await;
+    assert_eq!(cash["balance"], 5000 + 30000 - 1500);
+
+    // Accounts with transfers cannot be deleted; without, their transactions are kept
+    let (status, body) = do_delete(&app, &format!("/api/v1/accounts/{cash_id}")).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "ACCOUNT_HAS_TRANSFERS");
+    let (status, _) = do_delete(&app, &format!("/api/v1/accounts/transfers/{transfer_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = do_delete(&app, &format!("/api/v1/accounts/{cash_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, list) = do_get(&app, &format!("/api/v1/transactions?month={april}")).await;
+    let accounts: Vec<&Value> = list.as_array().unwrap().iter().map(|t| &t["account_id"]).collect();
+    assert_eq!(accounts.iter().filter(|a| a.is_null()).count(), 1);
+    let (_, transfers) = do_get(&app, "/api/v1/accounts/transfers").await;
+    assert_eq!(transfers, json!([]));
+}
+
+#[tokio::test]
+async fn test_split_payment_accounts() {
+    let app = setup().await;
+    let april = create_month(&app, "2026-04").await;
+    let food = create_category(&app, "food").await;
+    let household = create_category(&app, "household").await;
+    let food_entry = create_entry(&app, &april, &food, 50000, None).await;
+    let household_entry = create_entry(&app, &april, &household, 20000, None).await;
+
+    let (status, card) = do_post(
+        &app,
+        "/api/v1/accounts",
+        json!({ "name": "Card", "kind": "credit_card" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{card}");
+    let card_id = card["id"].as_str().unwrap().to_string();
+    let (status, payee) = do_post(&app, "/api/v1/payees", json!({ "name": "Supermarket" })).await;
+    assert_eq!(status, StatusCode::CREATED, "{payee}");
+    let payee_id = payee["id"].as_str().unwrap().to_string();
+
+    let (status, split) = do_post(
+        &app,
+        "/api/v1/transactions/splits",
+        json!({
+            "amount": 7000,
+            "date": "2026-04-04",
+            "payee_id": payee_id,
+            "account_id": card_id,
+            "allocations": [
+                { "entry_id": food_entry, "amount": 5000 },
+                { "entry_id": household_entry, "amount": 2000 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{split}");
+    assert_eq!(split["account_id"], card_id.as_str());
+    assert_eq!(split["payee_id"], payee_id.as_str());
+    let allocations = split["allocations"].as_array().unwrap();
+    assert!(allocations.iter().all(|a| a["account_id"] == card_id.as_str()));
+    assert!(allocations.iter().all(|a| a["payee_id"] == payee_id.as_str()));
+    let split_id = split["id"].as_str().unwrap().to_string();
+
+    // Every allocation is a line of the account's ledger
+    let (status, account) = do_get(&app, &format!("/api/v1/accounts/{card_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(account["balance"], -7000);
+    let (_, ledger) = do_get(&app, &format!("/api/v1/accounts/{card_id}/ledger")).await;
+    let lines = ledger["lines"].as_array().unwrap();
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(|l| l["kind"] == "transaction"));
+    assert_eq!(lines[1]["balance"], -7000);
+
+    // Replaced allocations keep the account; clearing it takes them all off the ledger
+    let path = format!("/api/v1/transactions/splits/{split_id}");
+    let (status, body) = do_patch(
+        &app,
+        &path,
+        json!({ "allocations": [
+            { "entry_id": food_entry, "amount": 4000 },
+            { "entry_id": household_entry, "amount": 3000 },
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body["allocations"].as_array().unwrap().iter().all(|a| a["account_id"] == card_id.as_str()));
+    let (status, body) = do_patch(&app, &path, json!({ "account_id": null })).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["account_id"], Value::Null);
+    assert_eq!(body["payee_id"], payee_id.as_str());
+    let (_, ledger) = do_get(&app, &format!("/api/v1/accounts/{card_id}/ledger")).await;
+    assert_eq!(ledger["lines"].as_array().unwrap().len(), 0);
+    assert_eq!(ledger["account"]["balance"], 0);
+
+    let (status, body) = do_patch(
+        &app,
+        &path,
+        json!({ "account_id": ulid::Ulid::new().to_string() }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TRANSACTION_ACCOUNT_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_split_allocation_attachments_block_replacement() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = setup_with_storage(dir.path(), 1024).await;
+    let april = create_month(&app, "2026-04").await;
+    let food = create_category(&app, "food").await;
+    let household = create_category(&app, "household").await;
+    let food_entry = create_entry(&app, &april, &food, 50000, None).await;
+    let household_entry = create_entry(&app, &april, &household, 20000, None).await;
+
+    let (status, split) = do_post(
+        &app,
+        "/api/v1/transactions/splits",
+        json!({
+            "amount": 7000,
+            "date": "2026-04-04",
+            "allocations": [
+                { "entry_id": food_entry, "amount": 5000 },
+                { "entry_id": household_entry, "amount": 2000 },
+            ],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{split}");
+    let split_id = split["id"].as_str().unwrap().to_string();
+    let allocation_id = split["allocations"][0]["id"].as_str().unwrap().to_string();
+
+    let (status, body) = do_upload(
+        &app,
+        &format!("/api/v1/transactions/{allocation_id}/attachments"),
+        "receipt.txt",
+        "text/plain",
+        b"hello receipt",
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let receipt_hash = body["sha256"].as_str().unwrap().to_string();
+
+    // Replacing the allocations would delete the receipt with them
+    let path = format!("/api/v1/transactions/splits/{split_id}");
+    let (status, body) = do_patch(
+        &app,
+        &path,
+        json!({ "allocations": [
+            { "entry_id": food_entry, "amount": 4000 },
+            { "entry_id": household_entry, "amount": 3000 },
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_SPLIT");
+    assert!(dir.path().join(&receipt_hash).exists());
+    let (_, body) = do_get(&app, &format!("/api/v1/transactions/{allocation_id}/attachments")).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    // Changes that keep the allocations are still allowed
+    let (status, body) = do_patch(&app, &path, json!({ "title": "Groceries" })).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(dir.path().join(&receipt_hash).exists());
+}
+
+#[tokio::test]
+async fn test_transaction_attachments() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = setup_with_storage(dir.path(), 1024).await;
+    let cat = create_category(&app, "electronics").await;
+    let month_id = create_month(&app, "2026-03").await;
+    let entry = create_entry(&app, &month_id, &cat, 50000, None).await;
+    let tx1 = create_transaction(&app, &entry, 20000, "2026-03-05").await;
+    let tx2 = create_transaction(&app, &entry, 15000, "2026-03-12").await;
+
+    let receipt_hash = "eb4171de4c65382b8aa421156087cacd874841cbfe3cece8521b22aaf7e3e4a8";
+    let upload_path = format!("/api/v1/transactions/{tx1}/attachments");
+    let (status, body) = do_upload(
+        &app,
+        &upload_path,
+        "scans/paragon Żabka.txt",
+        "text/plain; charset=utf-8",
+        b"hello receipt",
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["transaction_id"], tx1.as_str());
+    assert_eq!(body["file_name"], "paragon Żabka.txt");
+    assert_eq!(body["content_type"], "text/plain");
+    assert_eq!(body["size"], 13);
+    assert_eq!(body["sha256"], receipt_hash);
+    let attachment_id = body["id"].as_str().unwrap().to_string();
+    assert!(dir.path().join(receipt_hash).exists());
+
+    // The same contents on another transaction share the stored file
+    let (status, body) = do_upload(
+        &app,
+        &format!("/api/v1/transactions/{tx2}/attachments"),
+        "copy.txt",
+        "text/plain",
+        b"hello receipt",
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["sha256"], receipt_hash);
+
+    let (status, body) = do_get(&app, &upload_path).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["id"], attachment_id.as_str());
+
+    // Download with the original name and type
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("{upload_path}/{attachment_id}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers().clone();
+    assert_eq!(headers["content-type"], "text/plain");
+    assert_eq!(
+        headers["content-disposition"],
+        "attachment; filename=\"paragon _abka.txt\"; filename*=UTF-8''paragon%20%C5%BBabka.txt"
+    );
+    assert_eq!(headers["etag"], format!("\"{receipt_hash}\"").as_str());
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&bytes[..], b"hello receipt");
+
+    // Attachments are only reachable through their own transaction
+    let (status, body) = do_get(
+        &app,
+        &format!("/api/v1/transactions/{tx2}/attachments/{attachment_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "ATTACHMENT_NOT_FOUND");
+
+    // Rejected uploads
+    let (status, body) = do_upload(&app, &upload_path, "empty.txt", "text/plain", b"").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "ATTACHMENT_EMPTY");
+
+    let (status, body) =
+        do_upload(&app, &upload_path, "big.bin", "application/octet-stream", &[7u8; 2000]).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(body["error"]["code"], "ATTACHMENT_TOO_LARGE");
+    assert_eq!(body["error"]["details"]["max"], 1024);
+
+    // An unreadable content type is stored as a generic binary file
+    let (status, body) = do_upload(&app, &upload_path, "x.bin", "not a type", b"x").await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    assert_eq!(body["content_type"], "application/octet-stream");
+
+    let missing = ulid::Ulid::new();
+    let (status, body) = do_upload(
+        &app,
+        &format!("/api/v1/transactions/{missing}/attachments"),
+        "a.txt",
+        "text/plain",
+        b"a",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "TRANSACTION_NOT_FOUND");
+
+    let (status, _) = do_post(&app, &upload_path, json!({ "file": "nope" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Deleting an attachment removes its file once nothing else uses it
+    let (status, body) = do_upload(
+        &app,
+        &format!("/api/v1/transactions/{tx2}/attachments"),
+        "warranty.txt",
+        "text/plain",
+        b"warranty card",
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let warranty_hash = "e7105c01ea1c01e26d134e231064a425c90947b3e58208b95c60fe5ab46f8d81";
+    assert!(dir.path().join(warranty_hash).exists());
+    let warranty_path = format!(
+        "/api/v1/transactions/{tx2}/attachments/{}",
+        body["id"].as_str().unwrap()
+    );
+    let (status, _) = do_delete(&app, &warranty_path).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert!(!dir.path().join(warranty_hash).exists());
+    let (status, _) = do_delete(&app, &warranty_path).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Deleting a transaction drops its attachments; the shared file stays
+    let (status, _) = do_delete(&app, &format!("/api/v1/transactions/{tx1}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = do_get(&app, &upload_path).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(dir.path().join(receipt_hash).exists());
+
+    // Deleting the month takes the last reference with it
+    let (status, _) = do_delete(&app, &format!("/api/v1/months/{month_id}?cascade=true")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert!(!dir.path().join(receipt_hash).exists());
+}
+
+#[tokio::test]
+async fn test_search_transactions() {
+    let app = setup().await;
+    let food = create_category(&app, "food").await;
+    let fuel = create_category(&app, "fuel").await;
+    let feb = create_month(&app, "2026-02").await;
+    let mar = create_month(&app, "2026-03").await;
+    let feb_food = create_entry(&app, &feb, &food, 100000, None).await;
+    let mar_food = create_entry(&app, &mar, &food, 100000, None).await;
+    let mar_fuel = create_entry(&app, &mar, &fuel, 50000, None).await;
+
+    let mut ids = Vec::new();
+    for (entry, date, title) in [
+        (&feb_food, "2026-02-03", "Biedronka i inne sklepy"),
+        (&mar_food, "2026-03-04", "Biedronka Biedronka"),
+        (&mar_food, "2026-03-05", "Żabka hot-dog"),
+        (&mar_fuel, "2026-03-06", "Orlen"),
+    ] {
+        let (status, body) = do_post(
+            &app,
+            "/api/v1/transactions",
+            json!({ "entry_id": entry, "amount": 1000, "date": date, "title": title }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+        ids.push(body["id"].as_str().unwrap().to_string());
+    }
+    // Untitled transactions are never found
+    create_transaction(&app, &mar_fuel, 2000, "2026-03-07").await;
+
+    // Prefix match across months, best match first
+    let (status, body) = do_get(&app, "/api/v1/transactions/search?q=biedr").await;
+    assert_eq!(status, StatusCode::OK);
+    let hits = body.as_array().unwrap();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0]["transaction"]["id"], ids[1].as_str());
+    assert_eq!(hits[0]["month_id"], mar.as_str());
+    assert_eq!(hits[0]["month"], "2026-03");
+    assert_eq!(hits[0]["category_id"], food.as_str());
+    assert_eq!(hits[0]["category_name"], "food");
+    assert_eq!(hits[1]["transaction"]["id"], ids[0].as_str());
+    assert_eq!(hits[1]["month"], "2026-02");
+
+    // Case and diacritics are ignored; every word must match
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=ZABKA%20hot").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["transaction"]["id"], ids[2].as_str());
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=zabka%20orlen").await;
+    assert!(body.as_array().unwrap().is_empty());
+
+    // Search syntax in the query is taken literally
+    let (status, body) = do_get(&app, "/api/v1/transactions/search?q=%22orlen%20OR%20NEAR(").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert!(body.as_array().unwrap().is_empty());
+
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=biedronka&limit=1").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    // The index follows title changes and deletions
+    let (status, _) = do_patch(
+        &app,
+        &format!("/api/v1/transactions/{}", ids[3]),
+        json!({ "title": "Shell" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=orlen").await;
+    assert!(body.as_array().unwrap().is_empty());
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=shell").await;
+    assert_eq!(body[0]["category_name"], "fuel");
+
+    let (status, _) = do_patch(
+        &app,
+        &format!("/api/v1/transactions/{}", ids[3]),
+        json!({ "title": null }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=shell").await;
+    assert!(body.as_array().unwrap().is_empty());
+
+    let (status, _) = do_delete(&app, &format!("/api/v1/transactions/{}", ids[2])).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, body) = do_get(&app, "/api/v1/transactions/search?q=zabka").await;
+    assert!(body.as_array().unwrap().is_empty());
+
+    // Queries without words are rejected
+    let (status, body) = do_get(&app, "/api/v1/transactions/search?q=%20-%20").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_SEARCH_QUERY");
+    let (status, _) = do_get(&app, "/api/v1/transactions/search").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_filter_transactions() {
+    let app = setup().await;
+    let utils = create_category(&app, "utils").await;
+    let power = create_category(&app, "utils/electricity").await;
+    // Shares the "utils" prefix without being below it
+    let extra = create_category(&app, "utils-extra").await;
+    let food = create_category(&app, "food").await;
+    let jan = create_month(&app, "2026-01").await;
+    let feb = create_month(&app, "2026-02").await;
+
+    let mut entries = std::collections::HashMap::new();
+    for (month, cat) in [(&jan, &utils), (&jan, &power), (&jan, &extra), (&feb, &food), (&feb, &power)] {
+        let entry = create_entry(&app, month, cat, 100000, None).await;
+        entries.insert((month.clone(), cat.clone()), entry);
+    }
+
+    let mut ids = Vec::new();
+    for (month, cat, date, amount, title) in [
+        (&jan, &power, "2026-01-10", 12000, "Tauron prad"),
+        (&jan, &utils, "2026-01-15", 3000, "Oplata"),
+        (&jan, &extra, "2026-01-20", 5000, "Extra"),
+        (&feb, &food, "2026-02-02", 8000, "Lidl zakupy"),
+    ] {
+        let (status, body) = do_post(
+            &app,
+            "/api/v1/transactions",
+            json!({
+                "entry_id": entries[&(month.clone(), cat.clone())],
+                "amount": amount,
+                "date": date,
+                "title": title,
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+        ids.push(body["id"].as_str().unwrap().to_string());
+    }
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({
+            "entry_id": entries[&(feb.clone(), food.clone())],
+            "kind": "refund",
+            "amount": 2000,
+            "date": "2026-02-05",
+            "title": "LIDL zwrot",
+            "refund_of": ids[3],
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    ids.push(body["id"].as_str().unwrap().to_string());
+    ids.push(create_transaction(&app, &entries[&(feb.clone(), power.clone())], 11000, "2026-02-10").await);
+
+    let ids_of = |body: &Value| -> Vec<String> {
+        body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["transaction"]["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // A category includes everything below it, newest first by default
+    let (status, body) = do_get(&app, "/api/v1/transactions?category=utils").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(ids_of(&body), [ids[5].clone(), ids[1].clone(), ids[0].clone()]);
+    assert_eq!(body["total_count"], 3);
+    assert_eq!(body["total_amount"], 26000);
+    assert_eq!(body["has_more"], false);
+    assert_eq!(body["items"][0]["month"], "2026-02");
+    assert_eq!(body["items"][0]["category_name"], "utils/electricity");
+
+    // Date range; refunds count against the total
+    let (_, body) = do_get(&app, "/api/v1/transactions?from=2026-01-15&to=2026-02-05").await;
+    assert_eq!(
+        ids_of(&body),
+        [ids[4].clone(), ids[3].clone(), ids[2].clone(), ids[1].clone()]
+    );
+    assert_eq!(body["total_amount"], 14000);
+
+    let (_, body) = do_get(
+        &app,
+        "/api/v1/transactions?min_amount=5000&max_amount=11000&sort=amount_desc",
+    )
+    .await;
+    assert_eq!(ids_of(&body), [ids[5].clone(), ids[3].clone(), ids[2].clone()]);
+
+    // Refunds match by their booked amount but still count against the total
+    let (_, body) = do_get(&app, "/api/v1/transactions?min_amount=2000&max_amount=2000").await;
+    assert_eq!(ids_of(&body), [ids[4].clone()]);
+    assert_eq!(body["total_amount"], -2000);
+
+    let (_, body) = do_get(&app, "/api/v1/transactions?title=lidl&sort=date_asc").await;
+    assert_eq!(ids_of(&body), [ids[3].clone(), ids[4].clone()]);
+    assert_eq!(body["total_amount"], 6000);
+
+    // Pages keep the totals of all matches
+    let (_, body) = do_get(&app, "/api/v1/transactions?sort=date_asc&limit=2").await;
+    assert_eq!(ids_of(&body), [ids[0].clone(), ids[1].clone()]);
+    assert_eq!(body["total_count"], 6);
+    assert_eq!(body["has_more"], true);
+    let (_, body) = do_get(&app, "/api/v1/transactions?sort=date_asc&limit=2&offset=4").await;
+    assert_eq!(ids_of(&body), [ids[4].clone(), ids[5].clone()]);
+    assert_eq!(body["total_count"], 6);
+    assert_eq!(body["has_more"], false);
+
+    // Invalid filters
+    for (query, code) in [
+        ("from=2026-02-01&to=2026-01-31", "TRANSACTION_INVALID_FILTER"),
+        ("min_amount=10&max_amount=5", "TRANSACTION_INVALID_FILTER"),
+        ("sort=newest", "TRANSACTION_INVALID_FILTER"),
+        ("category=/utils", "TRANSACTION_INVALID_FILTER"),
+        ("from=2026-13-01", "TRANSACTION_INVALID_DATE"),
+    ] {
+        let (status, body) = do_get(&app, &format!("/api/v1/transactions?{query}")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{query}: {body}");
+        assert_eq!(body["error"]["code"], code, "{query}");
+    }
+    let (status, _) = do_get(&app, &format!("/api/v1/transactions?month={jan}&title=x")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Title case is folded beyond ASCII, and the text is matched literally
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/transactions",
+        json!({
+            "entry_id": entries[&(feb.clone(), food.clone())],
+            "amount": 1500,
+            "date": "2026-02-25",
+            "title": "Żabka (Śródmieście)",
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{body}");
+    let zabka = body["id"].as_str().unwrap().to_string();
+    for title in ["%C5%BCabka", "%C5%BBABKA", "(%C5%9Br%C3%B3dmie%C5%9Bcie)"] {
+        let (_, body) = do_get(&app, &format!("/api/v1/transactions?title={title}")).await;
+        assert_eq!(ids_of(&body), std::slice::from_ref(&zabka), "{title}");
+    }
+    let (_, body) = do_get(&app, "/api/v1/transactions?title=.*").await;
+    assert_eq!(body["total_count"], 0);
+}
+
+#[tokio::test]
+async fn test_transaction_cursor_pages() {
+    let app = setup().await;
+    let food = create_category(&app, "food").await;
+    let fuel = create_category(&app, "fuel").await;
+    let month = create_month(&app, "2026-03").await;
+    let entry = create_entry(&app, &month, &food, 100000, None).await;
+    let other = create_entry(&app, &month, &fuel, 100000, None).await;
+
+    let mut ids = Vec::new();
+    for day in 1..=5 {
+        ids.push(create_transaction(&app, &entry, 1000, &format!("2026-03-0{day}")).await);
+    }
+    let ids_of = |body: &Value| -> Vec<String> {
+        body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+    let page = |cursor: &Value| format!("/api/v1/transactions?entry_id={entry}&limit=2&cursor={}", cursor.as_str().unwrap());
+
+    let (status, first) = do_get(&app, &format!("/api/v1/transactions?entry_id={entry}&limit=2")).await;
+    assert_eq!(status, StatusCode::OK, "{first}");
+    assert_eq!(ids_of(&first), [ids[4].clone(), ids[3].clone()]);
+    assert_eq!(first["has_more"], true);
+    assert!(first["prev_cursor"].is_null());
+
+    // A newer transaction added while paging does not shift the next pages
+    let newest = create_transaction(&app, &entry, 1000, "2026-03-06").await;
+    let (_, second) = do_get(&app, &page(&first["next_cursor"])).await;
+    assert_eq!(ids_of(&second), [ids[2].clone(), ids[1].clone()]);
+    let (_, last) = do_get(&app, &page(&second["next_cursor"])).await;
+    assert_eq!(ids_of(&last), [ids[0].clone()]);
+    assert_eq!(last["has_more"], false);
+    assert!(last["next_cursor"].is_null());
+
+    // Going back reaches the new transaction on a page of its own
+    let (_, back) = do_get(&app, &page(&second["prev_cursor"])).await;
+    assert_eq!(ids_of(&back), [ids[4].clone(), ids[3].clone()]);
+    let (_, front) = do_get(&app, &page(&back["prev_cursor"])).await;
+    assert_eq!(ids_of(&front), [newest]);
+    assert!(front["prev_cursor"].is_null());
+    assert!(!front["next_cursor"].is_null());
+
+    // Offset pages still work, without cursors
+    let (_, legacy) = do_get(&app, &format!("/api/v1/transactions?entry_id={entry}&limit=2&offset=2")).await;
+    assert_eq!(ids_of(&legacy), [ids[3].clone(), ids[2].clone()]);
+    assert_eq!(legacy["has_more"], true);
+    assert!(legacy["next_cursor"].is_null());
+
+    // Month pages go through all entries of the month
+    create_transaction(&app, &other, 500, "2026-03-03").await;
+    let mut seen = Vec::new();
+    let mut url = format!("/api/v1/transactions?month={month}&limit=3");
+    loop {
+        let (status, body) = do_get(&app, &url).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        seen.extend(ids_of(&body));
+        match body["next_cursor"].as_str() {
+            Some(cursor) => url = format!("/api/v1/transactions?month={month}&limit=3&cursor={cursor}"),
+            None => break,
+        }
+    }
+    let (_, all) = do_get(&app, &format!("/api/v1/transactions?month={month}")).await;
+    let all: Vec<String> = all
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(seen.len(), 7);
+    assert_eq!(seen, all);
+
+    let cursor = first["next_cursor"].as_str().unwrap();
+    let (status, body) = do_get(&app, &format!("/api/v1/transactions?entry_id={entry}&cursor=zz")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["code"], "TRANSACTION_INVALID_CURSOR");
+    for query in [
+        format!("entry_id={entry}&cursor={cursor}&offset=2"),
+        format!("month={month}&limit=2&offset=2"),
+        format!("tag=kids&cursor={cursor}"),
+    ] {
+        let (status, _) = do_get(&app, &format!("/api/v1/transactions?{query}")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{query}");
+    }
+}
+
+#[tokio::test]
+async fn test_csv_import() {
+    let app = setup().await;
+    let food = create_category(&app, "food").await;
+    let month = create_month(&app, "2026-03").await;
+    let entry = create_entry(&app, &month, &food, 100000, None).await;
+
+    let mapping = json!({
+        "delimiter": ";",
+        "date_column": 0,
+        "amount_column": 1,
+        "description_column": 2,
+        "decimal_separator": ",",
+        "date_format": "%d.%m.%Y"
+    });
+    let (status, profile) = do_post(
+        &app,
+        "/api/v1/imports/profiles",
+        json!({ "name": "My bank", "mapping": mapping }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{profile}");
+    assert_eq!(profile["mapping"]["delimiter"], ";");
+    assert_eq!(profile["mapping"]["has_header"], true);
+    let profile_id = profile["id"].as_str().unwrap();
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/imports/profiles",
+        json!({ "name": "My bank", "mapping": mapping }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"]["code"], "IMPORT_PROFILE_NAME_ALREADY_EXISTS");
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/imports/profiles",
+        json!({ "name": "Broken", "mapping": { "date_column": 1, "amount_column": 1 } }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "IMPORT_INVALID_MAPPING");
+
+    let csv = "Date;Amount;Description\n\
+               14.03.2026;-1 234,50;Grocery store\n\
+               \n\
+               bad;-5,00;Oops\n\
+               20.03.2026;-12,00;Fuel\n";
+    let (status, preview) = do_import_preview(&app, ("profile_id", profile_id), csv).await;
+    assert_eq!(status, StatusCode::OK, "{preview}");
+    let rows = preview["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["line"], 2);
+    assert_eq!(rows[0]["date"], "2026-03-14");
+    assert_eq!(rows[0]["amount"], -123450);
+    assert_eq!(rows[0]["description"], "Grocery store");
+    assert_eq!(rows[1]["line"], 5);
+    assert_eq!(preview["errors"].as_array().unwrap().len(), 1);
+    assert_eq!(preview["errors"][0]["line"], 4);
+
+    // An unsaved mapping reads the same file the same way
+    let (status, adhoc) = do_import_preview(&app, ("mapping", &mapping.to_string()), csv).await;
+    assert_eq!(status, StatusCode::OK, "{adhoc}");
+    assert_eq!(adhoc["rows"], preview["rows"]);
+
+    // The mapping is checked before the file is split with it
+    for delimiter in ["ł", "\""] {
+        let mut broken = mapping.clone();
+        broken["delimiter"] = json!(delimiter);
+        let (status, body) = do_import_preview(&app, ("mapping", &broken.to_string()), csv).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{delimiter}: {body}");
+        assert_eq!(body["error"]["code"], "IMPORT_INVALID_MAPPING", "{delimiter}");
+    }
+
+    // A date outside the entry's month rolls back the whole import
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/imports/commit",
+        json!({ "rows": [
+            { "entry_id": entry, "amount": 123450, "date": "2026-03-14", "title": "Grocery store" },
+            { "entry_id": entry, "amount": 1200, "date": "2026-04-01", "title": "Fuel" },
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"]["code"], "IMPORT_INVALID_ROW");
+    assert_eq!(body["error"]["details"]["row"], 1);
+    let (_, listed) = do_get(&app, &format!("/api/v1/transactions?entry_id={entry}")).await;
+    assert_eq!(listed["items"].as_array().unwrap().len(), 0);
+
+    let (status, booked) = do_post(
+        &app,
+        "/api/v1/imports/commit",
+        json!({ "rows": [
+            { "entry_id": entry, "amount": 123450, "date": "2026-03-14", "title": "Grocery store" },
+            { "entry_id": entry, "amount": 1200, "date": "2026-03-20", "title": "Fuel" },
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{booked}");
+    assert_eq!(booked.as_array().unwrap().len(), 2);
+    assert_eq!(booked[0]["title"], "Grocery store");
+    let (_, listed) = do_get(&app, &format!("/api/v1/transactions?entry_id={entry}")).await;
+    assert_eq!(listed["items"].as_array().unwrap().len(), 2);
+
+    let (status, body) = do_post(
+        &app,
+        "/api/v1/imports/commit",
+        json!({ "rows": [
+            { "entry_id": ulid::Ulid::new().to_string(), "amount": 100, "date": "2026-03-14" },
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "ENTRY_NOT_FOUND");
+
+    let (status, _) = do_delete(&app, &format!("/api/v1/imports/profiles/{profile_id}")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, body) = do_import_preview(&app, ("profile_id", profile_id), csv).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["code"], "IMPORT_PROFILE_NOT_FOUND");
 }
 
 #[tokio::test]
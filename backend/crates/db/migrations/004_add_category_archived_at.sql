@@ -0,0 +1,3 @@
+-- Archived categories are hidden from category lists and skipped when a new
+-- month copies entries, but stay referenced by historical budget entries.
+ALTER TABLE categories ADD COLUMN archived_at TEXT;
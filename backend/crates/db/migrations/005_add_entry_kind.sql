@@ -0,0 +1,5 @@
+-- Entries either plan spending (the default, and all entries before this
+-- migration) or expected income; transactions against income entries record
+-- money received.
+ALTER TABLE budget_entries ADD COLUMN kind TEXT NOT NULL DEFAULT 'expense'
+    CHECK (kind IN ('expense', 'income'));
@@ -0,0 +1,8 @@
+-- Refunds return money from an earlier payment on the same entry. Amounts stay
+-- positive; refunds are subtracted when summing what was paid.
+ALTER TABLE transactions ADD COLUMN kind TEXT NOT NULL DEFAULT 'payment'
+    CHECK (kind IN ('payment', 'refund'));
+ALTER TABLE transactions ADD COLUMN refund_of TEXT
+    REFERENCES transactions(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_transactions_refund_of ON transactions(refund_of);
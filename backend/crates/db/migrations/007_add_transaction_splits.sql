@@ -0,0 +1,23 @@
+-- A split payment covers several budget entries of one month. Its allocations
+-- are ordinary transactions pointing at the split, so paid totals per entry
+-- need no changes; deleting the split deletes its allocations.
+CREATE TABLE transaction_splits (
+    id TEXT PRIMARY KEY,
+    amount INTEGER NOT NULL,
+    date TEXT NOT NULL,
+    title TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TRIGGER trg_transaction_splits_updated_at
+BEFORE UPDATE ON transaction_splits
+BEGIN
+    UPDATE transaction_splits SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = NEW.id;
+END;
+
+ALTER TABLE transactions ADD COLUMN split_id TEXT
+    REFERENCES transaction_splits(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_transactions_split_id ON transactions(split_id);
@@ -0,0 +1,6 @@
+-- Per-entry rollover: when a month is copied, an entry may carry its balance
+-- into the new month's entry. `carried_in` is fixed when the entry is created
+-- and counts on top of `budgeted`.
+ALTER TABLE budget_entries ADD COLUMN rollover TEXT NOT NULL DEFAULT 'none'
+    CHECK (rollover IN ('none', 'carry_remaining', 'carry_overspend'));
+ALTER TABLE budget_entries ADD COLUMN carried_in INTEGER NOT NULL DEFAULT 0;
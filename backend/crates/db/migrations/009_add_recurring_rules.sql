@@ -0,0 +1,34 @@
+-- Recurring rules book one payment per month on the entry of their category.
+-- Each booking is recorded in recurring_rule_runs so a rule books at most once
+-- per month, even after the booked transaction has been deleted.
+CREATE TABLE recurring_rules (
+    id TEXT PRIMARY KEY,
+    category_id TEXT NOT NULL REFERENCES categories(id) ON DELETE CASCADE,
+    amount INTEGER NOT NULL,
+    title TEXT,
+    day_of_month INTEGER NOT NULL CHECK (day_of_month BETWEEN 1 AND 31),
+    start_month TEXT NOT NULL,
+    end_month TEXT,
+    paused INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_recurring_rules_category_id ON recurring_rules(category_id);
+
+CREATE TRIGGER trg_recurring_rules_updated_at
+BEFORE UPDATE ON recurring_rules
+BEGIN
+    UPDATE recurring_rules SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = NEW.id;
+END;
+
+CREATE TABLE recurring_rule_runs (
+    rule_id TEXT NOT NULL REFERENCES recurring_rules(id) ON DELETE CASCADE,
+    month_id TEXT NOT NULL REFERENCES months(id) ON DELETE CASCADE,
+    transaction_id TEXT REFERENCES transactions(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (rule_id, month_id)
+);
+
+CREATE INDEX idx_recurring_rule_runs_month_id ON recurring_rule_runs(month_id);
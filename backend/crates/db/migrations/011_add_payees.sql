@@ -0,0 +1,30 @@
+-- Payees are who a transaction paid. Names and aliases are matched by key
+-- (lowercased, whitespace collapsed), which is unique across both tables.
+CREATE TABLE payees (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    name_key TEXT NOT NULL UNIQUE,
+    default_category_id TEXT REFERENCES categories(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TRIGGER trg_payees_updated_at
+BEFORE UPDATE ON payees
+BEGIN
+    UPDATE payees SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = NEW.id;
+END;
+
+CREATE TABLE payee_aliases (
+    alias_key TEXT PRIMARY KEY,
+    alias TEXT NOT NULL,
+    payee_id TEXT NOT NULL REFERENCES payees(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_payee_aliases_payee_id ON payee_aliases(payee_id);
+
+ALTER TABLE transactions ADD COLUMN payee_id TEXT
+    REFERENCES payees(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_transactions_payee_id ON transactions(payee_id);
@@ -0,0 +1,46 @@
+-- Accounts hold the money transactions are paid from or into. Balances are
+-- not stored: they are the opening balance plus the account's transactions
+-- and transfers. Transfers move money between accounts without touching any
+-- budget entry, so they never count as spending.
+CREATE TABLE accounts (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    kind TEXT NOT NULL CHECK (kind IN ('checking', 'savings', 'credit_card', 'cash')),
+    opening_balance INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TRIGGER trg_accounts_updated_at
+BEFORE UPDATE ON accounts
+BEGIN
+    UPDATE accounts SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = NEW.id;
+END;
+
+CREATE TABLE transfers (
+    id TEXT PRIMARY KEY,
+    from_account_id TEXT NOT NULL REFERENCES accounts(id),
+    to_account_id TEXT NOT NULL REFERENCES accounts(id),
+    amount INTEGER NOT NULL CHECK (amount > 0),
+    date TEXT NOT NULL,
+    title TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    CHECK (from_account_id <> to_account_id)
+);
+
+CREATE TRIGGER trg_transfers_updated_at
+BEFORE UPDATE ON transfers
+BEGIN
+    UPDATE transfers SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = NEW.id;
+END;
+
+CREATE INDEX idx_transfers_from_account_id ON transfers(from_account_id);
+CREATE INDEX idx_transfers_to_account_id ON transfers(to_account_id);
+
+ALTER TABLE transactions ADD COLUMN account_id TEXT
+    REFERENCES accounts(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_transactions_account_id ON transactions(account_id);
@@ -0,0 +1,29 @@
+-- Attachments are files kept with a transaction, such as receipts. Only the
+-- metadata lives here; the contents are stored on disk under their SHA-256,
+-- so identical uploads share one file.
+CREATE TABLE attachments (
+    id TEXT PRIMARY KEY,
+    transaction_id TEXT NOT NULL REFERENCES transactions(id) ON DELETE CASCADE,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size INTEGER NOT NULL CHECK (size > 0),
+    sha256 TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_attachments_transaction_id ON attachments(transaction_id);
+CREATE INDEX idx_attachments_sha256 ON attachments(sha256);
+
+-- Files that may no longer be needed. Attachments disappear with their
+-- transactions through several cascades (transaction, split and month
+-- deletes), so the trigger records every removed file and the application
+-- deletes those no attachment refers to any more.
+CREATE TABLE attachment_purge_queue (
+    sha256 TEXT PRIMARY KEY
+);
+
+CREATE TRIGGER trg_attachments_purge_queue
+AFTER DELETE ON attachments
+BEGIN
+    INSERT OR IGNORE INTO attachment_purge_queue (sha256) VALUES (OLD.sha256);
+END;
@@ -0,0 +1,35 @@
+-- Full-text index over transaction titles, kept in sync by triggers.
+-- Diacritics are folded so "zabka" finds "Żabka". The index is a standalone
+-- FTS5 table keyed by transaction id rather than an external-content table:
+-- transactions have no INTEGER PRIMARY KEY, so their rowids may change on
+-- VACUUM. Transactions without a title are not indexed.
+CREATE VIRTUAL TABLE transactions_fts USING fts5(
+    transaction_id UNINDEXED,
+    title,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO transactions_fts (transaction_id, title)
+SELECT id, title FROM transactions WHERE title IS NOT NULL;
+
+CREATE TRIGGER trg_transactions_fts_insert
+AFTER INSERT ON transactions
+WHEN NEW.title IS NOT NULL
+BEGIN
+    INSERT INTO transactions_fts (transaction_id, title) VALUES (NEW.id, NEW.title);
+END;
+
+CREATE TRIGGER trg_transactions_fts_delete
+AFTER DELETE ON transactions
+WHEN OLD.title IS NOT NULL
+BEGIN
+    DELETE FROM transactions_fts WHERE transaction_id = OLD.id;
+END;
+
+CREATE TRIGGER trg_transactions_fts_update
+AFTER UPDATE OF title ON transactions
+BEGIN
+    DELETE FROM transactions_fts WHERE transaction_id = OLD.id;
+    INSERT INTO transactions_fts (transaction_id, title)
+    SELECT NEW.id, NEW.title WHERE NEW.title IS NOT NULL;
+END;
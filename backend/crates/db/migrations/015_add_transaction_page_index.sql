@@ -0,0 +1,4 @@
+-- Cursor pages of an entry's transactions seek on (date, created_at, id) in
+-- this order, so the index serves both the lookup and the sort.
+DROP INDEX idx_transactions_entry_id;
+CREATE INDEX idx_transactions_entry_page ON transactions(entry_id, date, created_at, id);
@@ -0,0 +1,23 @@
+-- Saved column mappings for CSV bank statement imports, one per bank export
+-- layout. Column indexes count from 0; the date format is a chrono format
+-- string. Imported rows become ordinary transactions and keep no link here.
+CREATE TABLE import_profiles (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    delimiter TEXT NOT NULL,
+    has_header INTEGER NOT NULL,
+    date_column INTEGER NOT NULL CHECK (date_column >= 0),
+    amount_column INTEGER NOT NULL CHECK (amount_column >= 0),
+    description_column INTEGER CHECK (description_column >= 0),
+    decimal_separator TEXT NOT NULL CHECK (decimal_separator IN ('.', ',')),
+    date_format TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TRIGGER trg_import_profiles_updated_at
+BEFORE UPDATE ON import_profiles
+BEGIN
+    UPDATE import_profiles SET updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+    WHERE id = NEW.id;
+END;
@@ -0,0 +1,8 @@
+-- A split payment is one purchase, so it has one payee and is paid from one
+-- account; both are copied onto every allocation, which is what payee reports
+-- and account balances read.
+ALTER TABLE transaction_splits ADD COLUMN payee_id TEXT
+    REFERENCES payees(id) ON DELETE SET NULL;
+
+ALTER TABLE transaction_splits ADD COLUMN account_id TEXT
+    REFERENCES accounts(id) ON DELETE SET NULL;
@@ -0,0 +1,194 @@
+// Connection handle shared by all Sqlite repositories.
+//
+// A repository either runs each query on its own pooled connection, or, when it
+// was handed out by a unit of work, on that unit's transaction. Repository code
+// does not care which: it executes on `&mut *self.conn.acquire().await?` and opens
+// multi-statement transactions with `self.conn.begin()`, which joins the unit of
+// work's transaction instead of nesting one.
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use sqlx::pool::PoolConnection;
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
+use sqlx::{Sqlite, Transaction};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+use domain::errors::{
+    AccountError, AttachmentError, CategoryError, EntryError, ImportError, MonthError,
+    PayeeError, RecurringError, TagError, TransactionError,
+};
+
+/// Transaction of a unit of work; `None` once committed.
+pub(crate) type SharedTx = Arc<Mutex<Option<Transaction<'static, Sqlite>>>>;
+
+#[derive(Clone)]
+pub(crate) enum Conn {
+    Pool(SqlitePool),
+    Tx(SharedTx),
+}
+
+#[derive(Debug)]
+pub(crate) enum ConnError {
+    Sqlx(sqlx::Error),
+    Finished,
+}
+
+impl fmt::Display for ConnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnError::Sqlx(e) => write!(f, "{}", e),
+            ConnError::Finished => write!(f, "unit of work already finished"),
+        }
+    }
+}
+
+/// Exclusive access to the underlying connection for one or more queries.
+///
+/// In a unit of work this holds the transaction lock, so drop it before calling
+/// another repository method.
+pub(crate) enum ConnGuard {
+    Pool(PoolConnection<Sqlite>),
+    Tx(OwnedMutexGuard<Option<Transaction<'static, Sqlite>>>),
+}
+
+impl Deref for ConnGuard {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ConnGuard::Pool(conn) => conn,
+            ConnGuard::Tx(tx) => tx.as_deref().expect("checked in Conn::acquire"),
+        }
+    }
+}
+
+impl DerefMut for ConnGuard {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            ConnGuard::Pool(conn) => conn,
+            ConnGuard::Tx(tx) => tx.as_deref_mut().expect("checked in Conn::acquire"),
+        }
+    }
+}
+
+/// Transaction opened by a repository method.
+///
+/// Inside a unit of work it joins the outer transaction: `commit` is a no-op and
+/// an early return leaves rolling back to whoever owns the unit of work.
+pub(crate) enum ConnTx {
+    Owned(Transaction<'static, Sqlite>),
+    Joined(ConnGuard),
+}
+
+impl ConnTx {
+    pub(crate) async fn commit(self) -> Result<(), ConnError> {
+        match self {
+            ConnTx::Owned(tx) => tx.commit().await.map_err(ConnError::Sqlx),
+            ConnTx::Joined(_) => Ok(()),
+        }
+    }
+}
+
+impl Deref for ConnTx {
+    type Target = SqliteConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            ConnTx::Owned(tx) => tx,
+            ConnTx::Joined(guard) => guard,
+        }
+    }
+}
+
+impl DerefMut for ConnTx {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            ConnTx::Owned(tx) => tx,
+            ConnTx::Joined(guard) => guard,
+        }
+    }
+}
+
+impl Conn {
+    pub(crate) async fn acquire(&self) -> Result<ConnGuard, ConnError> {
+        match self {
+            Conn::Pool(pool) => pool.acquire().await.map(ConnGuard::Pool).map_err(ConnError::Sqlx),
+            Conn::Tx(tx) => {
+                let guard = tx.clone().lock_owned().await;
+                if guard.is_none() {
+                    return Err(ConnError::Finished);
+                }
+                Ok(ConnGuard::Tx(guard))
+            }
+        }
+    }
+
+    pub(crate) async fn begin(&self) -> Result<ConnTx, ConnError> {
+        match self {
+            Conn::Pool(pool) => pool.begin().await.map(ConnTx::Owned).map_err(ConnError::Sqlx),
+            Conn::Tx(_) => self.acquire().await.map(ConnTx::Joined),
+        }
+    }
+}
+
+impl From<ConnError> for CategoryError {
+    fn from(e: ConnError) -> Self {
+        CategoryError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for MonthError {
+    fn from(e: ConnError) -> Self {
+        MonthError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for EntryError {
+    fn from(e: ConnError) -> Self {
+        EntryError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for TransactionError {
+    fn from(e: ConnError) -> Self {
+        TransactionError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for RecurringError {
+    fn from(e: ConnError) -> Self {
+        RecurringError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for TagError {
+    fn from(e: ConnError) -> Self {
+        TagError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for PayeeError {
+    fn from(e: ConnError) -> Self {
+        PayeeError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for AccountError {
+    fn from(e: ConnError) -> Self {
+        AccountError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for AttachmentError {
+    fn from(e: ConnError) -> Self {
+        AttachmentError::Repository(e.to_string())
+    }
+}
+
+impl From<ConnError> for ImportError {
+    fn from(e: ConnError) -> Self {
+        ImportError::Repository(e.to_string())
+    }
+}
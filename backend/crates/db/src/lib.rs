@@ -1,4 +1,6 @@
+mod conn;
 pub mod repos;
+pub mod storage;
 
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
 use std::str::FromStr;
@@ -6,7 +8,9 @@ use std::str::FromStr;
 pub async fn create_pool(database_url: &str) -> Result<SqlitePool, sqlx::Error> {
     let options = SqliteConnectOptions::from_str(database_url)?
         .create_if_missing(true)
-        .pragma("foreign_keys", "ON");
+        .pragma("foreign_keys", "ON")
+        // SQLite's lower() and LIKE only fold ASCII; REGEXP runs Rust's Unicode-aware regex
+        .with_regexp();
 
     let pool = SqlitePoolOptions::new()
         .max_connections(5)
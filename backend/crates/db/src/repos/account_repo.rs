@@ -0,0 +1,464 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+use crate::conn::Conn;
+use std::str::FromStr;
+
+use domain::entities::{
+    Account, AccountActivity, AccountActivityKind, NewAccount, NewTransfer, Transfer,
+    TransferUpdate,
+};
+use domain::errors::AccountError;
+use domain::ports::AccountRepository;
+use domain::types::{
+    AccountKind, AccountName, EntryKind, Money, TransactionDate, TransactionKind,
+};
+
+pub struct SqliteAccountRepository {
+    conn: Conn,
+}
+
+impl SqliteAccountRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
+    }
+}
+
+fn parse_ulid(row: &sqlx::sqlite::SqliteRow, column: &str) -> Result<ulid::Ulid, AccountError> {
+    let value: String = row.get(column);
+    ulid::Ulid::from_string(&value)
+        .map_err(|e| AccountError::Repository(format!("invalid {} ULID: {}", column, e)))
+}
+
+fn parse_timestamp(
+    row: &sqlx::sqlite::SqliteRow,
+    column: &str,
+) -> Result<DateTime<Utc>, AccountError> {
+    let value: String = row.get(column);
+    DateTime::parse_from_rfc3339(&value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| AccountError::Repository(format!("invalid {}: {}", column, e)))
+}
+
+fn parse_date(row: &sqlx::sqlite::SqliteRow) -> Result<TransactionDate, AccountError> {
+    let date_str: String = row.get("date");
+    TransactionDate::from_str(&date_str)
+        .map_err(|e| AccountError::Repository(format!("invalid date: {}", e)))
+}
+
+fn map_row_to_account(row: &sqlx::sqlite::SqliteRow) -> Result<Account, AccountError> {
+    let name_str: String = row.get("name");
+    let name = AccountName::new(name_str.clone()).map_err(|e| {
+        AccountError::Repository(format!("invalid account name '{}': {}", name_str, e))
+    })?;
+
+    let kind_str: String = row.get("kind");
+    let kind = AccountKind::from_str(&kind_str)
+        .map_err(|e| AccountError::Repository(format!("invalid kind: {}", e)))?;
+
+    let opening_balance: i64 = row.get("opening_balance");
+
+    Ok(Account {
+        id: parse_ulid(row, "id")?,
+        name,
+        kind,
+        opening_balance: Money::new(opening_balance),
+        created_at: parse_timestamp(row, "created_at")?,
+        updated_at: parse_timestamp(row, "updated_at")?,
+    })
+}
+
+fn map_row_to_transfer(row: &sqlx::sqlite::SqliteRow) -> Result<Transfer, AccountError> {
+    let amount: i64 = row.get("amount");
+
+    Ok(Transfer {
+        id: parse_ulid(row, "id")?,
+        from_account_id: parse_ulid(row, "from_account_id")?,
+        to_account_id: parse_ulid(row, "to_account_id")?,
+        amount: Money::new(amount),
+        date: parse_date(row)?,
+        title: row.get("title"),
+        created_at: parse_timestamp(row, "created_at")?,
+        updated_at: parse_timestamp(row, "updated_at")?,
+    })
+}
+
+/// Maps a row of the `activity` query, whose `source` column tells transactions
+/// and both directions of transfers apart.
+fn map_row_to_activity(row: &sqlx::sqlite::SqliteRow) -> Result<AccountActivity, AccountError> {
+    let source: String = row.get("source");
+    let kind = match source.as_str() {
+        "transaction" => {
+            let entry_kind_str: String = row.get("entry_kind");
+            let entry_kind = EntryKind::from_str(&entry_kind_str)
+                .map_err(|e| AccountError::Repository(format!("invalid entry kind: {}", e)))?;
+            let transaction_kind_str: String = row.get("transaction_kind");
+            let transaction_kind = TransactionKind::from_str(&transaction_kind_str).map_err(|e| {
+                AccountError::Repository(format!("invalid transaction kind: {}", e))
+            })?;
+            AccountActivityKind::Transaction {
+                entry_id: parse_ulid(row, "other_id")?,
+                entry_kind,
+                transaction_kind,
+            }
+        }
+        "transfer_in" => AccountActivityKind::TransferIn {
+            from_account_id: parse_ulid(row, "other_id")?,
+        },
+        "transfer_out" => AccountActivityKind::TransferOut {
+            to_account_id: parse_ulid(row, "other_id")?,
+        },
+        other => {
+            return Err(AccountError::Repository(format!(
+                "invalid activity source: {}",
+                other
+            )))
+        }
+    };
+
+    let amount: i64 = row.get("amount");
+
+    Ok(AccountActivity {
+        id: parse_ulid(row, "id")?,
+        kind,
+        amount: Money::new(amount),
+        date: parse_date(row)?,
+        title: row.get("title"),
+        created_at: parse_timestamp(row, "created_at")?,
+    })
+}
+
+/// Maps a failed account write; a name conflict is reported for `name`.
+fn map_account_write_error(err: sqlx::Error, name: Option<&AccountName>) -> AccountError {
+    match (err, name) {
+        (sqlx::Error::Database(ref db_err), Some(name))
+            if db_err.message().contains("UNIQUE constraint failed") =>
+        {
+            AccountError::NameAlreadyExists {
+                name: name.as_str().to_string(),
+            }
+        }
+        (e, _) => AccountError::Repository(e.to_string()),
+    }
+}
+
+/// Maps a failed transfer write; a missing account shows up as a foreign key failure.
+fn map_transfer_write_error(err: sqlx::Error) -> AccountError {
+    match err {
+        sqlx::Error::Database(ref db_err)
+            if db_err.message().contains("FOREIGN KEY constraint failed") =>
+        {
+            AccountError::NotFound
+        }
+        e => AccountError::Repository(e.to_string()),
+    }
+}
+
+#[async_trait]
+impl AccountRepository for SqliteAccountRepository {
+    async fn list_all(&self) -> Result<Vec<Account>, AccountError> {
+        let rows = sqlx::query("SELECT * FROM accounts ORDER BY name ASC")
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_account)
+            .collect()
+    }
+
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Account>, AccountError> {
+        let row = sqlx::query("SELECT * FROM accounts WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(map_row_to_account(r)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, account: NewAccount) -> Result<Account, AccountError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        sqlx::query(
+            "INSERT INTO accounts (id, name, kind, opening_balance, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(account.name.as_str())
+        .bind(account.kind.as_str())
+        .bind(account.opening_balance.value())
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| map_account_write_error(e, Some(&account.name)))?;
+
+        self.find_by_id(&id)
+            .await?
+            .ok_or_else(|| AccountError::Repository("failed to fetch created account".to_string()))
+    }
+
+    async fn update(
+        &self,
+        id: &ulid::Ulid,
+        name: Option<AccountName>,
+        kind: Option<AccountKind>,
+        opening_balance: Option<Money>,
+    ) -> Result<Account, AccountError> {
+        let mut set_clauses: Vec<&str> = Vec::new();
+
+        if name.is_some() {
+            set_clauses.push("name = ?");
+        }
+        if kind.is_some() {
+            set_clauses.push("kind = ?");
+        }
+        if opening_balance.is_some() {
+            set_clauses.push("opening_balance = ?");
+        }
+
+        if set_clauses.is_empty() {
+            return self.find_by_id(id).await?.ok_or(AccountError::NotFound);
+        }
+
+        let sql = format!(
+            "UPDATE accounts SET {} WHERE id = ?",
+            set_clauses.join(", ")
+        );
+
+        let mut query = sqlx::query(&sql);
+
+        if let Some(ref n) = name {
+            query = query.bind(n.as_str());
+        }
+        if let Some(ref k) = kind {
+            query = query.bind(k.as_str());
+        }
+        if let Some(ref b) = opening_balance {
+            query = query.bind(b.value());
+        }
+
+        query = query.bind(id.to_string());
+
+        let result = query
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| map_account_write_error(e, name.as_ref()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AccountError::NotFound);
+        }
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| AccountError::Repository("failed to fetch updated account".to_string()))
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), AccountError> {
+        let result = sqlx::query("DELETE FROM accounts WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AccountError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn transfer_count(&self, id: &ulid::Ulid) -> Result<i64, AccountError> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) AS cnt FROM transfers WHERE from_account_id = ? OR to_account_id = ?",
+        )
+        .bind(id.to_string())
+        .bind(id.to_string())
+        .fetch_one(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        let count: i64 = row.get("cnt");
+        Ok(count)
+    }
+
+    async fn activity(&self, id: &ulid::Ulid) -> Result<Vec<AccountActivity>, AccountError> {
+        let rows = sqlx::query(
+            "SELECT t.id, 'transaction' AS source, t.entry_id AS other_id, \
+             e.kind AS entry_kind, t.kind AS transaction_kind, \
+             t.amount, t.date, t.title, t.created_at \
+             FROM transactions t \
+             JOIN budget_entries e ON e.id = t.entry_id \
+             WHERE t.account_id = ? \
+             UNION ALL \
+             SELECT id, 'transfer_in', from_account_id, NULL, NULL, amount, date, title, created_at \
+             FROM transfers WHERE to_account_id = ? \
+             UNION ALL \
+             SELECT id, 'transfer_out', to_account_id, NULL, NULL, amount, date, title, created_at \
+             FROM transfers WHERE from_account_id = ? \
+             ORDER BY date ASC, created_at ASC, id ASC",
+        )
+        .bind(id.to_string())
+        .bind(id.to_string())
+        .bind(id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_activity)
+            .collect()
+    }
+
+    async fn list_transfers(&self) -> Result<Vec<Transfer>, AccountError> {
+        let rows = sqlx::query("SELECT * FROM transfers ORDER BY date DESC, created_at DESC, id DESC")
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_transfer)
+            .collect()
+    }
+
+    async fn find_transfer(&self, id: &ulid::Ulid) -> Result<Option<Transfer>, AccountError> {
+        let row = sqlx::query("SELECT * FROM transfers WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(map_row_to_transfer(r)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create_transfer(&self, transfer: NewTransfer) -> Result<Transfer, AccountError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        sqlx::query(
+            "INSERT INTO transfers \
+             (id, from_account_id, to_account_id, amount, date, title, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(transfer.from_account_id.to_string())
+        .bind(transfer.to_account_id.to_string())
+        .bind(transfer.amount.value())
+        .bind(transfer.date.to_string())
+        .bind(&transfer.title)
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(map_transfer_write_error)?;
+
+        self.find_transfer(&id)
+            .await?
+            .ok_or_else(|| AccountError::Repository("failed to fetch created transfer".to_string()))
+    }
+
+    async fn update_transfer(
+        &self,
+        id: &ulid::Ulid,
+        changes: TransferUpdate,
+    ) -> Result<Transfer, AccountError> {
+        let TransferUpdate {
+            from_account_id,
+            to_account_id,
+            amount,
+            date,
+            title,
+        } = changes;
+
+        let mut set_clauses: Vec<&str> = Vec::new();
+
+        if from_account_id.is_some() {
+            set_clauses.push("from_account_id = ?");
+        }
+        if to_account_id.is_some() {
+            set_clauses.push("to_account_id = ?");
+        }
+        if amount.is_some() {
+            set_clauses.push("amount = ?");
+        }
+        if date.is_some() {
+            set_clauses.push("date = ?");
+        }
+        if title.is_some() {
+            set_clauses.push("title = ?");
+        }
+
+        if set_clauses.is_empty() {
+            return self
+                .find_transfer(id)
+                .await?
+                .ok_or(AccountError::TransferNotFound);
+        }
+
+        let sql = format!(
+            "UPDATE transfers SET {} WHERE id = ?",
+            set_clauses.join(", ")
+        );
+
+        let mut query = sqlx::query(&sql);
+
+        if let Some(ref f) = from_account_id {
+            query = query.bind(f.to_string());
+        }
+        if let Some(ref t) = to_account_id {
+            query = query.bind(t.to_string());
+        }
+        if let Some(ref a) = amount {
+            query = query.bind(a.value());
+        }
+        if let Some(ref d) = date {
+            query = query.bind(d.to_string());
+        }
+        if let Some(ref t) = title {
+            query = query.bind(t);
+        }
+
+        query = query.bind(id.to_string());
+
+        let result = query
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(map_transfer_write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AccountError::TransferNotFound);
+        }
+
+        self.find_transfer(id)
+            .await?
+            .ok_or_else(|| AccountError::Repository("failed to fetch updated transfer".to_string()))
+    }
+
+    async fn delete_transfer(&self, id: &ulid::Ulid) -> Result<(), AccountError> {
+        let result = sqlx::query("DELETE FROM transfers WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AccountError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AccountError::TransferNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+use crate::conn::Conn;
+
+use domain::entities::{Attachment, NewAttachment};
+use domain::errors::AttachmentError;
+use domain::ports::AttachmentRepository;
+
+pub struct SqliteAttachmentRepository {
+    conn: Conn,
+}
+
+impl SqliteAttachmentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
+    }
+}
+
+fn map_row_to_attachment(row: &sqlx::sqlite::SqliteRow) -> Result<Attachment, AttachmentError> {
+    let id_str: String = row.get("id");
+    let id = ulid::Ulid::from_string(&id_str)
+        .map_err(|e| AttachmentError::Repository(format!("invalid ULID: {}", e)))?;
+
+    let transaction_id_str: String = row.get("transaction_id");
+    let transaction_id = ulid::Ulid::from_string(&transaction_id_str)
+        .map_err(|e| AttachmentError::Repository(format!("invalid transaction ULID: {}", e)))?;
+
+    let created_at_str: String = row.get("created_at");
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| AttachmentError::Repository(format!("invalid created_at: {}", e)))?;
+
+    Ok(Attachment {
+        id,
+        transaction_id,
+        file_name: row.get("file_name"),
+        content_type: row.get("content_type"),
+        size: row.get("size"),
+        sha256: row.get("sha256"),
+        created_at,
+    })
+}
+
+#[async_trait]
+impl AttachmentRepository for SqliteAttachmentRepository {
+    async fn list_by_transaction(&self, transaction_id: &ulid::Ulid) -> Result<Vec<Attachment>, AttachmentError> {
+        let rows = sqlx::query(
+            "SELECT * FROM attachments WHERE transaction_id = ? ORDER BY created_at ASC, id ASC",
+        )
+        .bind(transaction_id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| AttachmentError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_attachment)
+            .collect()
+    }
+
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Attachment>, AttachmentError> {
+        let row = sqlx::query("SELECT * FROM attachments WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AttachmentError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(map_row_to_attachment(r)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, attachment: NewAttachment) -> Result<Attachment, AttachmentError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        let mut tx = self.conn.begin().await?;
+
+        sqlx::query("DELETE FROM attachment_purge_queue WHERE sha256 = ?")
+            .bind(&attachment.sha256)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AttachmentError::Repository(e.to_string()))?;
+
+        let result = sqlx::query(
+            "INSERT INTO attachments (id, transaction_id, file_name, content_type, size, sha256, created_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(attachment.transaction_id.to_string())
+        .bind(&attachment.file_name)
+        .bind(&attachment.content_type)
+        .bind(attachment.size)
+        .bind(&attachment.sha256)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(sqlx::Error::Database(ref db_err))
+                if db_err.message().contains("FOREIGN KEY constraint failed") =>
+            {
+                return Err(AttachmentError::TransactionNotFound);
+            }
+            Err(e) => return Err(AttachmentError::Repository(e.to_string())),
+        }
+
+        tx.commit().await?;
+
+        self.find_by_id(&id)
+            .await?
+            .ok_or_else(|| AttachmentError::Repository("failed to fetch created attachment".to_string()))
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), AttachmentError> {
+        let result = sqlx::query("DELETE FROM attachments WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| AttachmentError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AttachmentError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn unreferenced_files(&self) -> Result<Vec<String>, AttachmentError> {
+        let rows = sqlx::query(
+            "SELECT q.sha256 FROM attachment_purge_queue q \
+             WHERE NOT EXISTS (SELECT 1 FROM attachments a WHERE a.sha256 = q.sha256) \
+             ORDER BY q.sha256",
+        )
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| AttachmentError::Repository(e.to_string()))?;
+
+        Ok(rows.iter().map(|row| row.get("sha256")).collect())
+    }
+
+    async fn forget_unreferenced_file(&self, sha256: &str) -> Result<bool, AttachmentError> {
+        let result = sqlx::query(
+            "DELETE FROM attachment_purge_queue WHERE sha256 = ? \
+             AND NOT EXISTS (SELECT 1 FROM attachments a WHERE a.sha256 = ?)",
+        )
+        .bind(sha256)
+        .bind(sha256)
+        .execute(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| AttachmentError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -3,18 +3,24 @@ use chrono::{DateTime, Utc};
 use sqlx::sqlite::SqlitePool;
 use sqlx::Row;
 
+use crate::conn::Conn;
+
 use domain::entities::{Category, NewCategory};
 use domain::errors::CategoryError;
 use domain::ports::CategoryRepository;
 use domain::types::CategoryName;
 
 pub struct SqliteCategoryRepository {
-    pool: SqlitePool,
+    conn: Conn,
 }
 
 impl SqliteCategoryRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
     }
 }
 
@@ -29,6 +35,15 @@ fn map_row_to_category(row: &sqlx::sqlite::SqliteRow) -> Result<Category, Catego
 
     let label: Option<String> = row.get("label");
 
+    let archived_at_str: Option<String> = row.get("archived_at");
+    let archived_at = archived_at_str
+        .map(|s| {
+            DateTime::parse_from_rfc3339(&s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| CategoryError::Repository(format!("invalid archived_at: {}", e)))
+        })
+        .transpose()?;
+
     let created_at_str: String = row.get("created_at");
     let created_at = DateTime::parse_from_rfc3339(&created_at_str)
         .map(|dt| dt.with_timezone(&Utc))
@@ -43,6 +58,7 @@ fn map_row_to_category(row: &sqlx::sqlite::SqliteRow) -> Result<Category, Catego
         id,
         name,
         label,
+        archived_at,
         created_at,
         updated_at,
     })
@@ -50,9 +66,14 @@ fn map_row_to_category(row: &sqlx::sqlite::SqliteRow) -> Result<Category, Catego
 
 #[async_trait]
 impl CategoryRepository for SqliteCategoryRepository {
-    async fn list_all(&self) -> Result<Vec<Category>, CategoryError> {
-        let rows = sqlx::query("SELECT * FROM categories ORDER BY name ASC")
-            .fetch_all(&self.pool)
+    async fn list_all(&self, include_archived: bool) -> Result<Vec<Category>, CategoryError> {
+        let sql = if include_archived {
+            "SELECT * FROM categories ORDER BY name ASC"
+        } else {
+            "SELECT * FROM categories WHERE archived_at IS NULL ORDER BY name ASC"
+        };
+        let rows = sqlx::query(sql)
+            .fetch_all(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| CategoryError::Repository(e.to_string()))?;
 
@@ -64,7 +85,7 @@ impl CategoryRepository for SqliteCategoryRepository {
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Category>, CategoryError> {
         let row = sqlx::query("SELECT * FROM categories WHERE id = ?")
             .bind(id.to_string())
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| CategoryError::Repository(e.to_string()))?;
 
@@ -86,7 +107,7 @@ impl CategoryRepository for SqliteCategoryRepository {
         .bind(&category.label)
         .bind(&now)
         .bind(&now)
-        .execute(&self.pool)
+        .execute(&mut *self.conn.acquire().await?)
         .await;
 
         match result {
@@ -114,7 +135,7 @@ impl CategoryRepository for SqliteCategoryRepository {
         let result = sqlx::query("UPDATE categories SET name = ? WHERE id = ?")
             .bind(name.as_str())
             .bind(id.to_string())
-            .execute(&self.pool)
+            .execute(&mut *self.conn.acquire().await?)
             .await;
 
         match result {
@@ -175,7 +196,7 @@ impl CategoryRepository for SqliteCategoryRepository {
         }
         q = q.bind(id.to_string());
 
-        let result = q.execute(&self.pool).await;
+        let result = q.execute(&mut *self.conn.acquire().await?).await;
 
         match result {
             Ok(r) => {
@@ -197,4 +218,263 @@ impl CategoryRepository for SqliteCategoryRepository {
             .await?
             .ok_or_else(|| CategoryError::Repository("failed to fetch updated category".to_string()))
     }
+
+    async fn set_archived(&self, id: &ulid::Ulid, archived: bool) -> Result<Category, CategoryError> {
+        // Re-archiving keeps the original timestamp
+        let result = if archived {
+            let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+            sqlx::query("UPDATE categories SET archived_at = COALESCE(archived_at, ?) WHERE id = ?")
+                .bind(now)
+                .bind(id.to_string())
+                .execute(&mut *self.conn.acquire().await?)
+                .await
+        } else {
+            sqlx::query("UPDATE categories SET archived_at = NULL WHERE id = ?")
+                .bind(id.to_string())
+                .execute(&mut *self.conn.acquire().await?)
+                .await
+        }
+        .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(CategoryError::NotFound);
+        }
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| CategoryError::Repository("failed to fetch updated category".to_string()))
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), CategoryError> {
+        let result = sqlx::query("DELETE FROM categories WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(CategoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn entry_count(&self, category_id: &ulid::Ulid) -> Result<i64, CategoryError> {
+        let row = sqlx::query("SELECT COUNT(*) AS cnt FROM budget_entries WHERE category_id = ?")
+            .bind(category_id.to_string())
+            .fetch_one(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        let count: i64 = row.get("cnt");
+        Ok(count)
+    }
+
+    async fn recurring_rule_count(&self, category_id: &ulid::Ulid) -> Result<i64, CategoryError> {
+        let row = sqlx::query("SELECT COUNT(*) AS cnt FROM recurring_rules WHERE category_id = ?")
+            .bind(category_id.to_string())
+            .fetch_one(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        let count: i64 = row.get("cnt");
+        Ok(count)
+    }
+
+    async fn merge(&self, source_id: &ulid::Ulid, target_id: &ulid::Ulid) -> Result<Category, CategoryError> {
+        let source = source_id.to_string();
+        let target = target_id.to_string();
+
+        let mut tx = self.conn.begin().await?;
+
+        let found = sqlx::query("SELECT COUNT(*) AS cnt FROM categories WHERE id IN (?, ?)")
+            .bind(&source)
+            .bind(&target)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+        let found: i64 = found.get("cnt");
+        if found != 2 {
+            return Err(CategoryError::NotFound);
+        }
+
+        // Income and spending cannot share an entry
+        let mismatch = sqlx::query(
+            "SELECT m.month FROM budget_entries s \
+             JOIN budget_entries t ON t.month_id = s.month_id \
+             JOIN months m ON m.id = s.month_id \
+             WHERE s.category_id = ? AND t.category_id = ? AND s.kind <> t.kind \
+             ORDER BY m.month LIMIT 1",
+        )
+        .bind(&source)
+        .bind(&target)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| CategoryError::Repository(e.to_string()))?;
+        if let Some(row) = mismatch {
+            return Err(CategoryError::MergeKindMismatch { month: row.get("month") });
+        }
+
+        // Months where both categories have an entry: add the source budget and carried
+        // balance to the target entry, which keeps its own rollover mode
+        sqlx::query(
+            "UPDATE budget_entries \
+             SET budgeted = budgeted + ( \
+                 SELECT s.budgeted FROM budget_entries s \
+                 WHERE s.category_id = ? AND s.month_id = budget_entries.month_id), \
+             carried_in = carried_in + ( \
+                 SELECT s.carried_in FROM budget_entries s \
+                 WHERE s.category_id = ? AND s.month_id = budget_entries.month_id) \
+             WHERE category_id = ? \
+             AND month_id IN (SELECT month_id FROM budget_entries WHERE category_id = ?)",
+        )
+        .bind(&source)
+        .bind(&source)
+        .bind(&target)
+        .bind(&source)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        // ...move the source entry's transactions onto the target entry of the same month
+        sqlx::query(
+            "UPDATE transactions \
+             SET entry_id = ( \
+                 SELECT t.id FROM budget_entries t \
+                 JOIN budget_entries s ON s.month_id = t.month_id \
+                 WHERE s.id = transactions.entry_id AND t.category_id = ?) \
+             WHERE entry_id IN ( \
+                 SELECT s.id FROM budget_entries s \
+                 JOIN budget_entries t ON t.month_id = s.month_id \
+                 WHERE s.category_id = ? AND t.category_id = ?)",
+        )
+        .bind(&target)
+        .bind(&source)
+        .bind(&target)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        // ...and drop the now empty source entry
+        sqlx::query(
+            "DELETE FROM budget_entries \
+             WHERE category_id = ? \
+             AND month_id IN (SELECT month_id FROM budget_entries WHERE category_id = ?)",
+        )
+        .bind(&source)
+        .bind(&target)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        // Remaining source entries have no counterpart and can simply be re-pointed
+        sqlx::query("UPDATE budget_entries SET category_id = ? WHERE category_id = ?")
+            .bind(&target)
+            .bind(&source)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        sqlx::query("UPDATE recurring_rules SET category_id = ? WHERE category_id = ?")
+            .bind(&target)
+            .bind(&source)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        sqlx::query("UPDATE payees SET default_category_id = ? WHERE default_category_id = ?")
+            .bind(&target)
+            .bind(&source)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        sqlx::query("DELETE FROM categories WHERE id = ?")
+            .bind(&source)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        tx.commit().await?;
+
+        self.find_by_id(target_id)
+            .await?
+            .ok_or_else(|| CategoryError::Repository("failed to fetch merged category".to_string()))
+    }
+
+    async fn rename_prefix(&self, from: &CategoryName, to: &CategoryName) -> Result<Vec<Category>, CategoryError> {
+        let mut tx = self.conn.begin().await?;
+
+        // substr() counts characters, and names may contain non-ASCII alphanumerics
+        let subtree_prefix = format!("{}/", from.as_str());
+        let rows = sqlx::query("SELECT id, name FROM categories WHERE name = ? OR substr(name, 1, ?) = ?")
+            .bind(from.as_str())
+            .bind(subtree_prefix.chars().count() as i64)
+            .bind(&subtree_prefix)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| CategoryError::Repository(e.to_string()))?;
+
+        if rows.is_empty() {
+            return Err(CategoryError::NotFound);
+        }
+
+        let mut renames = Vec::with_capacity(rows.len());
+        for row in &rows {
+            let id: String = row.get("id");
+            let name: String = row.get("name");
+            let new_name = format!("{}{}", to.as_str(), &name[from.as_str().len()..]);
+            CategoryName::new(new_name.clone())
+                .map_err(|e| CategoryError::InvalidNameFormat { reason: e.to_string() })?;
+            renames.push((id, new_name));
+        }
+
+        // Collisions can only come from outside the subtree, since prefix replacement is injective
+        for (_, new_name) in &renames {
+            let existing = sqlx::query("SELECT id FROM categories WHERE name = ?")
+                .bind(new_name)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| CategoryError::Repository(e.to_string()))?;
+            if let Some(row) = existing {
+                let existing_id: String = row.get("id");
+                if !renames.iter().any(|(id, _)| *id == existing_id) {
+                    return Err(CategoryError::NameAlreadyExists { name: new_name.clone() });
+                }
+            }
+        }
+
+        // SQLite checks UNIQUE per row, so moving e.g. `a` under `a/b` would clash midway.
+        // Park every name on a placeholder that no valid CategoryName can equal first.
+        for (id, _) in &renames {
+            sqlx::query("UPDATE categories SET name = '/' || id WHERE id = ?")
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| CategoryError::Repository(e.to_string()))?;
+        }
+        for (id, new_name) in &renames {
+            sqlx::query("UPDATE categories SET name = ? WHERE id = ?")
+                .bind(new_name)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| CategoryError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await?;
+
+        let mut renamed = Vec::with_capacity(renames.len());
+        for (id, _) in &renames {
+            let ulid = ulid::Ulid::from_string(id)
+                .map_err(|e| CategoryError::Repository(format!("invalid ULID: {}", e)))?;
+            let category = self
+                .find_by_id(&ulid)
+                .await?
+                .ok_or_else(|| CategoryError::Repository("failed to fetch renamed category".to_string()))?;
+            renamed.push(category);
+        }
+        renamed.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        Ok(renamed)
+    }
 }
@@ -1,23 +1,45 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::sqlite::SqlitePool;
 use sqlx::Row;
 
+use crate::conn::Conn;
+
 use domain::entities::{BudgetEntry, BudgetEntryWithCategory, CategorySummary, NewBudgetEntry};
 use domain::errors::EntryError;
 use domain::ports::BudgetEntryRepository;
-use domain::types::{CategoryName, DueDay, Money};
+use domain::types::{BudgetMonth, CategoryName, DueDay, EntryKind, Money, RolloverMode};
 
 pub struct SqliteBudgetEntryRepository {
-    pool: SqlitePool,
+    conn: Conn,
 }
 
 impl SqliteBudgetEntryRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
     }
 }
 
+fn map_kind(row: &sqlx::sqlite::SqliteRow) -> Result<EntryKind, EntryError> {
+    let kind_str: String = row.get("kind");
+    kind_str
+        .parse()
+        .map_err(|e| EntryError::Repository(format!("invalid kind: {}", e)))
+}
+
+fn map_rollover(row: &sqlx::sqlite::SqliteRow) -> Result<RolloverMode, EntryError> {
+    let rollover_str: String = row.get("rollover");
+    rollover_str
+        .parse()
+        .map_err(|e| EntryError::Repository(format!("invalid rollover: {}", e)))
+}
+
 fn map_row_to_entry(row: &sqlx::sqlite::SqliteRow) -> Result<BudgetEntry, EntryError> {
     let id_str: String = row.get("id");
     let id = ulid::Ulid::from_string(&id_str)
@@ -31,7 +53,10 @@ fn map_row_to_entry(row: &sqlx::sqlite::SqliteRow) -> Result<BudgetEntry, EntryE
     let category_id = ulid::Ulid::from_string(&category_id_str)
         .map_err(|e| EntryError::Repository(format!("invalid category_id ULID: {}", e)))?;
 
+    let kind = map_kind(row)?;
     let budgeted: i64 = row.get("budgeted");
+    let carried_in: i64 = row.get("carried_in");
+    let rollover = map_rollover(row)?;
 
     let due_day_raw: Option<i32> = row.get("due_day");
     let due_day = match due_day_raw {
@@ -56,7 +81,10 @@ fn map_row_to_entry(row: &sqlx::sqlite::SqliteRow) -> Result<BudgetEntry, EntryE
         id,
         month_id,
         category_id,
+        kind,
         budgeted: Money::new(budgeted),
+        carried_in: Money::new(carried_in),
+        rollover,
         due_day,
         created_at,
         updated_at,
@@ -79,8 +107,12 @@ fn map_row_to_entry_with_category(
         .map_err(|e| EntryError::Repository(format!("invalid category name: {}", e)))?;
 
     let category_label: Option<String> = row.get("category_label");
+    let category_archived: bool = row.get("category_archived");
 
+    let kind = map_kind(row)?;
     let budgeted: i64 = row.get("budgeted");
+    let carried_in: i64 = row.get("carried_in");
+    let rollover = map_rollover(row)?;
 
     let due_day_raw: Option<i32> = row.get("due_day");
     let due_day = match due_day_raw {
@@ -107,8 +139,12 @@ fn map_row_to_entry_with_category(
             id: category_id,
             name: category_name,
             label: category_label,
+            archived: category_archived,
         },
+        kind,
         budgeted: Money::new(budgeted),
+        carried_in: Money::new(carried_in),
+        rollover,
         due_day,
         created_at,
         updated_at,
@@ -116,18 +152,19 @@ fn map_row_to_entry_with_category(
 }
 
 async fn fetch_entry_with_category(
-    pool: &SqlitePool,
+    conn: &Conn,
     entry_id: &ulid::Ulid,
 ) -> Result<BudgetEntryWithCategory, EntryError> {
     let row = sqlx::query(
-        "SELECT e.id, e.category_id, c.name AS category_name, c.label AS category_label, e.budgeted, e.due_day, \
+        "SELECT e.id, e.category_id, c.name AS category_name, c.label AS category_label, \
+         c.archived_at IS NOT NULL AS category_archived, e.kind, e.budgeted, e.carried_in, e.rollover, e.due_day, \
          e.created_at, e.updated_at \
          FROM budget_entries e \
          JOIN categories c ON e.category_id = c.id \
          WHERE e.id = ?",
     )
     .bind(entry_id.to_string())
-    .fetch_optional(pool)
+    .fetch_optional(&mut *conn.acquire().await?)
     .await
     .map_err(|e| EntryError::Repository(e.to_string()))?;
 
@@ -144,7 +181,8 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
         month_id: &ulid::Ulid,
     ) -> Result<Vec<BudgetEntryWithCategory>, EntryError> {
         let rows = sqlx::query(
-            "SELECT e.id, e.category_id, c.name AS category_name, c.label AS category_label, e.budgeted, e.due_day, \
+            "SELECT e.id, e.category_id, c.name AS category_name, c.label AS category_label, \
+             c.archived_at IS NOT NULL AS category_archived, e.kind, e.budgeted, e.carried_in, e.rollover, e.due_day, \
              e.created_at, e.updated_at \
              FROM budget_entries e \
              JOIN categories c ON e.category_id = c.id \
@@ -152,7 +190,7 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
              ORDER BY e.due_day ASC NULLS LAST, c.name ASC",
         )
         .bind(month_id.to_string())
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.conn.acquire().await?)
         .await
         .map_err(|e| EntryError::Repository(e.to_string()))?;
 
@@ -161,10 +199,44 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
             .collect()
     }
 
+    async fn list_by_month_range(
+        &self,
+        from: &BudgetMonth,
+        to: &BudgetMonth,
+    ) -> Result<HashMap<ulid::Ulid, Vec<BudgetEntryWithCategory>>, EntryError> {
+        let rows = sqlx::query(
+            "SELECT e.id, e.month_id, e.category_id, c.name AS category_name, c.label AS category_label, \
+             c.archived_at IS NOT NULL AS category_archived, e.kind, e.budgeted, e.carried_in, e.rollover, e.due_day, \
+             e.created_at, e.updated_at \
+             FROM budget_entries e \
+             JOIN categories c ON e.category_id = c.id \
+             JOIN months m ON e.month_id = m.id \
+             WHERE m.month >= ? AND m.month <= ? \
+             ORDER BY e.month_id, e.due_day ASC NULLS LAST, c.name ASC",
+        )
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| EntryError::Repository(e.to_string()))?;
+
+        let mut by_month: HashMap<ulid::Ulid, Vec<BudgetEntryWithCategory>> = HashMap::new();
+        for row in &rows {
+            let month_id_str: String = row.get("month_id");
+            let month_id = ulid::Ulid::from_string(&month_id_str)
+                .map_err(|e| EntryError::Repository(format!("invalid month_id ULID: {}", e)))?;
+            by_month
+                .entry(month_id)
+                .or_default()
+                .push(map_row_to_entry_with_category(row)?);
+        }
+        Ok(by_month)
+    }
+
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<BudgetEntry>, EntryError> {
         let row = sqlx::query("SELECT * FROM budget_entries WHERE id = ?")
             .bind(id.to_string())
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| EntryError::Repository(e.to_string()))?;
 
@@ -174,6 +246,19 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
         }
     }
 
+    async fn list_by_category(
+        &self,
+        category_id: &ulid::Ulid,
+    ) -> Result<Vec<BudgetEntry>, EntryError> {
+        let rows = sqlx::query("SELECT * FROM budget_entries WHERE category_id = ?")
+            .bind(category_id.to_string())
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| EntryError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_entry).collect()
+    }
+
     async fn create(
         &self,
         entry: NewBudgetEntry,
@@ -184,17 +269,21 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
         let due_day_val = entry.due_day.map(|d| d.value() as i32);
 
         let result = sqlx::query(
-            "INSERT INTO budget_entries (id, month_id, category_id, budgeted, due_day, created_at, updated_at) \
-             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO budget_entries \
+             (id, month_id, category_id, kind, budgeted, carried_in, rollover, due_day, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(id.to_string())
         .bind(entry.month_id.to_string())
         .bind(entry.category_id.to_string())
+        .bind(entry.kind.as_str())
         .bind(entry.budgeted.value())
+        .bind(entry.carried_in.value())
+        .bind(entry.rollover.as_str())
         .bind(due_day_val)
         .bind(&now)
         .bind(&now)
-        .execute(&self.pool)
+        .execute(&mut *self.conn.acquire().await?)
         .await;
 
         match result {
@@ -212,7 +301,7 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
             {
                 let month_exists = sqlx::query("SELECT 1 FROM months WHERE id = ?")
                     .bind(entry.month_id.to_string())
-                    .fetch_optional(&self.pool)
+                    .fetch_optional(&mut *self.conn.acquire().await?)
                     .await
                     .map_err(|e| EntryError::Repository(e.to_string()))?;
                 if month_exists.is_none() {
@@ -223,7 +312,7 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
             Err(e) => return Err(EntryError::Repository(e.to_string())),
         }
 
-        fetch_entry_with_category(&self.pool, &id).await
+        fetch_entry_with_category(&self.conn, &id).await
     }
 
     async fn update(
@@ -231,6 +320,7 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
         id: &ulid::Ulid,
         budgeted: Option<Money>,
         due_day: Option<Option<DueDay>>,
+        rollover: Option<RolloverMode>,
     ) -> Result<BudgetEntryWithCategory, EntryError> {
         // Build dynamic UPDATE query
         let mut set_clauses: Vec<String> = Vec::new();
@@ -243,8 +333,12 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
             set_clauses.push("due_day = ?".to_string());
         }
 
+        if rollover.is_some() {
+            set_clauses.push("rollover = ?".to_string());
+        }
+
         if set_clauses.is_empty() {
-            return fetch_entry_with_category(&self.pool, id).await;
+            return fetch_entry_with_category(&self.conn, id).await;
         }
 
         let sql = format!(
@@ -261,11 +355,14 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
         if let Some(dd) = &due_day {
             query = query.bind(dd.as_ref().map(|d| d.value() as i32));
         }
+        if let Some(r) = rollover {
+            query = query.bind(r.as_str());
+        }
 
         query = query.bind(id.to_string());
 
         let result = query
-            .execute(&self.pool)
+            .execute(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| EntryError::Repository(e.to_string()))?;
 
@@ -273,13 +370,13 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
             return Err(EntryError::NotFound);
         }
 
-        fetch_entry_with_category(&self.pool, id).await
+        fetch_entry_with_category(&self.conn, id).await
     }
 
     async fn delete(&self, id: &ulid::Ulid) -> Result<(), EntryError> {
         let result = sqlx::query("DELETE FROM budget_entries WHERE id = ?")
             .bind(id.to_string())
-            .execute(&self.pool)
+            .execute(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| EntryError::Repository(e.to_string()))?;
 
@@ -293,7 +390,7 @@ impl BudgetEntryRepository for SqliteBudgetEntryRepository {
     async fn transaction_count(&self, entry_id: &ulid::Ulid) -> Result<i64, EntryError> {
         let row = sqlx::query("SELECT COUNT(*) AS cnt FROM transactions WHERE entry_id = ?")
             .bind(entry_id.to_string())
-            .fetch_one(&self.pool)
+            .fetch_one(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| EntryError::Repository(e.to_string()))?;
 
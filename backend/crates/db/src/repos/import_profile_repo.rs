@@ -0,0 +1,221 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+use crate::conn::Conn;
+
+use domain::entities::{CsvMapping, ImportProfile, NewImportProfile};
+use domain::errors::ImportError;
+use domain::ports::ImportProfileRepository;
+use domain::types::ImportProfileName;
+
+pub struct SqliteImportProfileRepository {
+    conn: Conn,
+}
+
+impl SqliteImportProfileRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { conn: Conn::Pool(pool) }
+    }
+}
+
+fn map_single_char(row: &sqlx::sqlite::SqliteRow, column: &str) -> Result<char, ImportError> {
+    let value: String = row.get(column);
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ImportError::Repository(format!("invalid {}: {:?}", column, value))),
+    }
+}
+
+fn map_row_to_profile(row: &sqlx::sqlite::SqliteRow) -> Result<ImportProfile, ImportError> {
+    let id_str: String = row.get("id");
+    let id = ulid::Ulid::from_string(&id_str)
+        .map_err(|e| ImportError::Repository(format!("invalid ULID: {}", e)))?;
+
+    let name_str: String = row.get("name");
+    let name = ImportProfileName::new(name_str.clone()).map_err(|e| {
+        ImportError::Repository(format!("invalid import profile name '{}': {}", name_str, e))
+    })?;
+
+    let date_column: i64 = row.get("date_column");
+    let amount_column: i64 = row.get("amount_column");
+    let description_column: Option<i64> = row.get("description_column");
+    let mapping = CsvMapping {
+        delimiter: map_single_char(row, "delimiter")?,
+        has_header: row.get("has_header"),
+        date_column: date_column as usize,
+        amount_column: amount_column as usize,
+        description_column: description_column.map(|c| c as usize),
+        decimal_separator: map_single_char(row, "decimal_separator")?,
+        date_format: row.get("date_format"),
+    };
+
+    let created_at_str: String = row.get("created_at");
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ImportError::Repository(format!("invalid created_at: {}", e)))?;
+
+    let updated_at_str: String = row.get("updated_at");
+    let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ImportError::Repository(format!("invalid updated_at: {}", e)))?;
+
+    Ok(ImportProfile {
+        id,
+        name,
+        mapping,
+        created_at,
+        updated_at,
+    })
+}
+
+/// Maps a failed profile write; a taken name shows up as a unique constraint failure.
+fn map_profile_write_error(err: sqlx::Error, name: Option<&ImportProfileName>) -> ImportError {
+    match (err, name) {
+        (sqlx::Error::Database(ref db_err), Some(name))
+            if db_err.message().contains("UNIQUE constraint failed") =>
+        {
+            ImportError::ProfileNameAlreadyExists {
+                name: name.as_str().to_string(),
+            }
+        }
+        (e, _) => ImportError::Repository(e.to_string()),
+    }
+}
+
+#[async_trait]
+impl ImportProfileRepository for SqliteImportProfileRepository {
+    async fn list_all(&self) -> Result<Vec<ImportProfile>, ImportError> {
+        let rows = sqlx::query("SELECT * FROM import_profiles ORDER BY name ASC")
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| ImportError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_profile)
+            .collect()
+    }
+
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<ImportProfile>, ImportError> {
+        let row = sqlx::query("SELECT * FROM import_profiles WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| ImportError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(map_row_to_profile(r)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, profile: NewImportProfile) -> Result<ImportProfile, ImportError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+        let mapping = &profile.mapping;
+
+        sqlx::query(
+            "INSERT INTO import_profiles (id, name, delimiter, has_header, date_column, \
+             amount_column, description_column, decimal_separator, date_format, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(profile.name.as_str())
+        .bind(mapping.delimiter.to_string())
+        .bind(mapping.has_header)
+        .bind(mapping.date_column as i64)
+        .bind(mapping.amount_column as i64)
+        .bind(mapping.description_column.map(|c| c as i64))
+        .bind(mapping.decimal_separator.to_string())
+        .bind(&mapping.date_format)
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| map_profile_write_error(e, Some(&profile.name)))?;
+
+        self.find_by_id(&id)
+            .await?
+            .ok_or_else(|| ImportError::Repository("failed to fetch created import profile".to_string()))
+    }
+
+    async fn update(
+        &self,
+        id: &ulid::Ulid,
+        name: Option<ImportProfileName>,
+        mapping: Option<CsvMapping>,
+    ) -> Result<ImportProfile, ImportError> {
+        let mut set_clauses: Vec<&str> = Vec::new();
+
+        if name.is_some() {
+            set_clauses.push("name = ?");
+        }
+        if mapping.is_some() {
+            set_clauses.extend([
+                "delimiter = ?",
+                "has_header = ?",
+                "date_column = ?",
+                "amount_column = ?",
+                "description_column = ?",
+                "decimal_separator = ?",
+                "date_format = ?",
+            ]);
+        }
+
+        if set_clauses.is_empty() {
+            return self.find_by_id(id).await?.ok_or(ImportError::ProfileNotFound);
+        }
+
+        let sql = format!(
+            "UPDATE import_profiles SET {} WHERE id = ?",
+            set_clauses.join(", ")
+        );
+
+        let mut query = sqlx::query(&sql);
+
+        if let Some(ref n) = name {
+            query = query.bind(n.as_str());
+        }
+        if let Some(ref m) = mapping {
+            query = query
+                .bind(m.delimiter.to_string())
+                .bind(m.has_header)
+                .bind(m.date_column as i64)
+                .bind(m.amount_column as i64)
+                .bind(m.description_column.map(|c| c as i64))
+                .bind(m.decimal_separator.to_string())
+                .bind(&m.date_format);
+        }
+
+        query = query.bind(id.to_string());
+
+        let result = query
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| map_profile_write_error(e, name.as_ref()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(ImportError::ProfileNotFound);
+        }
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| ImportError::Repository("failed to fetch updated import profile".to_string()))
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), ImportError> {
+        let result = sqlx::query("DELETE FROM import_profiles WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| ImportError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(ImportError::ProfileNotFound);
+        }
+
+        Ok(())
+    }
+}
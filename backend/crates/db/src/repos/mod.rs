@@ -2,8 +2,22 @@ mod category_repo;
 mod month_repo;
 mod entry_repo;
 mod transaction_repo;
+mod recurring_repo;
+mod tag_repo;
+mod payee_repo;
+mod account_repo;
+mod attachment_repo;
+mod import_profile_repo;
+mod unit_of_work;
 
 pub use category_repo::SqliteCategoryRepository;
 pub use month_repo::SqliteMonthRepository;
 pub use entry_repo::SqliteBudgetEntryRepository;
 pub use transaction_repo::SqliteTransactionRepository;
+pub use recurring_repo::SqliteRecurringRuleRepository;
+pub use tag_repo::SqliteTagRepository;
+pub use payee_repo::SqlitePayeeRepository;
+pub use account_repo::SqliteAccountRepository;
+pub use attachment_repo::SqliteAttachmentRepository;
+pub use import_profile_repo::SqliteImportProfileRepository;
+pub use unit_of_work::SqliteUnitOfWorkFactory;
@@ -1,21 +1,27 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::sqlite::SqlitePool;
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
 use sqlx::Row;
+
+use crate::conn::Conn;
 use std::str::FromStr;
 
-use domain::entities::{Month, NewMonth};
+use domain::entities::{Month, MonthSpending, NewMonth};
 use domain::errors::MonthError;
 use domain::ports::MonthRepository;
-use domain::types::BudgetMonth;
+use domain::types::{BudgetMonth, Money};
 
 pub struct SqliteMonthRepository {
-    pool: SqlitePool,
+    conn: Conn,
 }
 
 impl SqliteMonthRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
     }
 }
 
@@ -46,11 +52,33 @@ fn map_row_to_month(row: &sqlx::sqlite::SqliteRow) -> Result<Month, MonthError>
     })
 }
 
+/// Maps a row of `month_id`, `month`, `spent` and `transaction_count`, as selected
+/// by the spending reports of tags and payees.
+pub(super) fn map_row_to_month_spending(row: &sqlx::sqlite::SqliteRow) -> Result<MonthSpending, String> {
+    let month_id_str: String = row.get("month_id");
+    let month_id = ulid::Ulid::from_string(&month_id_str)
+        .map_err(|e| format!("invalid month_id ULID: {}", e))?;
+
+    let month_str: String = row.get("month");
+    let month = BudgetMonth::from_str(&month_str)
+        .map_err(|e| format!("invalid month: {}", e))?;
+
+    let spent: i64 = row.get("spent");
+    let transaction_count: i64 = row.get("transaction_count");
+
+    Ok(MonthSpending {
+        month_id,
+        month,
+        spent: Money::new(spent),
+        transaction_count,
+    })
+}
+
 #[async_trait]
 impl MonthRepository for SqliteMonthRepository {
     async fn list_all(&self) -> Result<Vec<Month>, MonthError> {
         let rows = sqlx::query("SELECT * FROM months ORDER BY month DESC")
-            .fetch_all(&self.pool)
+            .fetch_all(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| MonthError::Repository(e.to_string()))?;
 
@@ -62,7 +90,7 @@ impl MonthRepository for SqliteMonthRepository {
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Month>, MonthError> {
         let row = sqlx::query("SELECT * FROM months WHERE id = ?")
             .bind(id.to_string())
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| MonthError::Repository(e.to_string()))?;
 
@@ -72,10 +100,23 @@ impl MonthRepository for SqliteMonthRepository {
         }
     }
 
+    async fn list_in_range(&self, from: &BudgetMonth, to: &BudgetMonth) -> Result<Vec<Month>, MonthError> {
+        let rows = sqlx::query("SELECT * FROM months WHERE month >= ? AND month <= ? ORDER BY month ASC")
+            .bind(from.to_string())
+            .bind(to.to_string())
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| MonthError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_month)
+            .collect()
+    }
+
     async fn find_by_month(&self, month: &BudgetMonth) -> Result<Option<Month>, MonthError> {
         let row = sqlx::query("SELECT * FROM months WHERE month = ?")
             .bind(month.to_string())
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| MonthError::Repository(e.to_string()))?;
 
@@ -96,7 +137,7 @@ impl MonthRepository for SqliteMonthRepository {
         .bind(month.month.to_string())
         .bind(&now)
         .bind(&now)
-        .execute(&self.pool)
+        .execute(&mut *self.conn.acquire().await?)
         .await;
 
         match result {
@@ -118,7 +159,7 @@ impl MonthRepository for SqliteMonthRepository {
 
     async fn find_latest(&self) -> Result<Option<Month>, MonthError> {
         let row = sqlx::query("SELECT * FROM months ORDER BY month DESC LIMIT 1")
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| MonthError::Repository(e.to_string()))?;
 
@@ -131,7 +172,7 @@ impl MonthRepository for SqliteMonthRepository {
     async fn find_latest_excluding(&self, exclude_id: &ulid::Ulid) -> Result<Option<Month>, MonthError> {
         let row = sqlx::query("SELECT * FROM months WHERE id != ? ORDER BY month DESC LIMIT 1")
             .bind(exclude_id.to_string())
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| MonthError::Repository(e.to_string()))?;
 
@@ -140,4 +181,80 @@ impl MonthRepository for SqliteMonthRepository {
             None => Ok(None),
         }
     }
+
+    async fn transaction_count(&self, month_id: &ulid::Ulid) -> Result<i64, MonthError> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) AS cnt FROM transactions t \
+             JOIN budget_entries e ON t.entry_id = e.id \
+             WHERE e.month_id = ?",
+        )
+        .bind(month_id.to_string())
+        .fetch_one(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| MonthError::Repository(e.to_string()))?;
+
+        let count: i64 = row.get("cnt");
+        Ok(count)
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), MonthError> {
+        let mut tx = self.conn.begin().await?;
+
+        delete_month_entries(&mut tx, id).await?;
+
+        let result = sqlx::query("DELETE FROM months WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| MonthError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(MonthError::NotFound);
+        }
+
+        tx.commit().await.map_err(MonthError::from)
+    }
+
+    async fn delete_entries(&self, month_id: &ulid::Ulid) -> Result<(), MonthError> {
+        let mut tx = self.conn.begin().await?;
+
+        delete_month_entries(&mut tx, month_id).await?;
+
+        tx.commit().await.map_err(MonthError::from)
+    }
+}
+
+/// Removes a month's transactions, then its entries (foreign keys are RESTRICT).
+/// Split payments go first, taking their allocations with them.
+async fn delete_month_entries(
+    conn: &mut SqliteConnection,
+    month_id: &ulid::Ulid,
+) -> Result<(), MonthError> {
+    sqlx::query(
+        "DELETE FROM transaction_splits WHERE id IN ( \
+             SELECT t.split_id FROM transactions t \
+             JOIN budget_entries e ON t.entry_id = e.id \
+             WHERE e.month_id = ?)",
+    )
+    .bind(month_id.to_string())
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| MonthError::Repository(e.to_string()))?;
+
+    sqlx::query(
+        "DELETE FROM transactions \
+         WHERE entry_id IN (SELECT id FROM budget_entries WHERE month_id = ?)",
+    )
+    .bind(month_id.to_string())
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| MonthError::Repository(e.to_string()))?;
+
+    sqlx::query("DELETE FROM budget_entries WHERE month_id = ?")
+        .bind(month_id.to_string())
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| MonthError::Repository(e.to_string()))?;
+
+    Ok(())
 }
@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
+use sqlx::Row;
+
+use crate::conn::Conn;
+use super::month_repo::map_row_to_month_spending;
+
+use domain::entities::{MonthSpending, NewPayee, Payee};
+use domain::errors::PayeeError;
+use domain::ports::PayeeRepository;
+use domain::types::PayeeName;
+
+pub struct SqlitePayeeRepository {
+    conn: Conn,
+}
+
+impl SqlitePayeeRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
+    }
+
+    /// Aliases by payee ID, ordered by alias; only those of `payee_id` if given.
+    async fn aliases(
+        &self,
+        payee_id: Option<&str>,
+    ) -> Result<HashMap<String, Vec<PayeeName>>, PayeeError> {
+        let rows = sqlx::query(
+            "SELECT payee_id, alias FROM payee_aliases \
+             WHERE ? IS NULL OR payee_id = ? \
+             ORDER BY alias ASC",
+        )
+        .bind(payee_id)
+        .bind(payee_id)
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+        let mut aliases: HashMap<String, Vec<PayeeName>> = HashMap::new();
+        for row in &rows {
+            let payee_id: String = row.get("payee_id");
+            let alias_str: String = row.get("alias");
+            let alias = PayeeName::new(alias_str.clone()).map_err(|e| {
+                PayeeError::Repository(format!("invalid payee alias '{}': {}", alias_str, e))
+            })?;
+            aliases.entry(payee_id).or_default().push(alias);
+        }
+        Ok(aliases)
+    }
+
+    /// Payee of `row` with its aliases.
+    async fn with_aliases(&self, row: &sqlx::sqlite::SqliteRow) -> Result<Payee, PayeeError> {
+        let id_str: String = row.get("id");
+        let aliases = self
+            .aliases(Some(&id_str))
+            .await?
+            .remove(&id_str)
+            .unwrap_or_default();
+        map_row_to_payee(row, aliases)
+    }
+}
+
+fn map_row_to_payee(
+    row: &sqlx::sqlite::SqliteRow,
+    aliases: Vec<PayeeName>,
+) -> Result<Payee, PayeeError> {
+    let id_str: String = row.get("id");
+    let id = ulid::Ulid::from_string(&id_str)
+        .map_err(|e| PayeeError::Repository(format!("invalid ULID: {}", e)))?;
+
+    let name_str: String = row.get("name");
+    let name = PayeeName::new(name_str.clone())
+        .map_err(|e| PayeeError::Repository(format!("invalid payee name '{}': {}", name_str, e)))?;
+
+    let default_category_id_str: Option<String> = row.get("default_category_id");
+    let default_category_id = default_category_id_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| PayeeError::Repository(format!("invalid default_category_id ULID: {}", e)))?;
+
+    let created_at_str: String = row.get("created_at");
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| PayeeError::Repository(format!("invalid created_at: {}", e)))?;
+
+    let updated_at_str: String = row.get("updated_at");
+    let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| PayeeError::Repository(format!("invalid updated_at: {}", e)))?;
+
+    Ok(Payee {
+        id,
+        name,
+        default_category_id,
+        aliases,
+        created_at,
+        updated_at,
+    })
+}
+
+/// Maps a failed write; a key conflict is reported for `name`.
+fn map_write_error(err: sqlx::Error, name: Option<&PayeeName>) -> PayeeError {
+    match (err, name) {
+        (sqlx::Error::Database(ref db_err), Some(name))
+            if db_err.message().contains("UNIQUE constraint failed") =>
+        {
+            PayeeError::NameAlreadyExists {
+                name: name.as_str().to_string(),
+            }
+        }
+        (sqlx::Error::Database(ref db_err), _)
+            if db_err.message().contains("FOREIGN KEY constraint failed") =>
+        {
+            PayeeError::CategoryNotFound
+        }
+        (e, _) => PayeeError::Repository(e.to_string()),
+    }
+}
+
+/// Fails with `NameAlreadyExists` for the first of `names` whose key is a name or
+/// alias of a payee other than `payee_id`.
+async fn check_names_free(
+    conn: &mut SqliteConnection,
+    payee_id: &str,
+    names: &[&PayeeName],
+) -> Result<(), PayeeError> {
+    if names.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; names.len()].join(", ");
+    let sql = format!(
+        "SELECT name_key AS key FROM payees WHERE id != ? AND name_key IN ({0}) \
+         UNION ALL \
+         SELECT alias_key AS key FROM payee_aliases WHERE payee_id != ? AND alias_key IN ({0})",
+        placeholders
+    );
+
+    let mut query = sqlx::query(&sql).bind(payee_id);
+    for name in names {
+        query = query.bind(name.key());
+    }
+    query = query.bind(payee_id);
+    for name in names {
+        query = query.bind(name.key());
+    }
+
+    let rows = query
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+    let taken: Vec<String> = rows.iter().map(|r| r.get("key")).collect();
+    if let Some(name) = names.iter().find(|n| taken.contains(&n.key())) {
+        return Err(PayeeError::NameAlreadyExists {
+            name: name.as_str().to_string(),
+        });
+    }
+    Ok(())
+}
+
+async fn insert_aliases(
+    conn: &mut SqliteConnection,
+    payee_id: &str,
+    aliases: &[PayeeName],
+) -> Result<(), PayeeError> {
+    for alias in aliases {
+        sqlx::query("INSERT INTO payee_aliases (alias_key, alias, payee_id) VALUES (?, ?, ?)")
+            .bind(alias.key())
+            .bind(alias.as_str())
+            .bind(payee_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| map_write_error(e, Some(alias)))?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl PayeeRepository for SqlitePayeeRepository {
+    async fn list_all(&self) -> Result<Vec<Payee>, PayeeError> {
+        let rows = sqlx::query("SELECT * FROM payees ORDER BY name_key ASC")
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+        let mut aliases = self.aliases(None).await?;
+
+        rows.iter()
+            .map(|row| {
+                let id: String = row.get("id");
+                map_row_to_payee(row, aliases.remove(&id).unwrap_or_default())
+            })
+            .collect()
+    }
+
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Payee>, PayeeError> {
+        let row = sqlx::query("SELECT * FROM payees WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(self.with_aliases(r).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_key(&self, key: &str) -> Result<Option<Payee>, PayeeError> {
+        let row = sqlx::query(
+            "SELECT * FROM payees \
+             WHERE name_key = ? OR id IN (SELECT payee_id FROM payee_aliases WHERE alias_key = ?)",
+        )
+        .bind(key)
+        .bind(key)
+        .fetch_optional(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(self.with_aliases(r).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, payee: NewPayee) -> Result<Payee, PayeeError> {
+        let id = ulid::Ulid::new();
+        let id_str = id.to_string();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        let mut tx = self.conn.begin().await?;
+
+        let names: Vec<&PayeeName> = std::iter::once(&payee.name).chain(&payee.aliases).collect();
+        check_names_free(&mut tx, &id_str, &names).await?;
+
+        sqlx::query(
+            "INSERT INTO payees (id, name, name_key, default_category_id, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id_str)
+        .bind(payee.name.as_str())
+        .bind(payee.name.key())
+        .bind(payee.default_category_id.map(|c| c.to_string()))
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| map_write_error(e, Some(&payee.name)))?;
+
+        insert_aliases(&mut tx, &id_str, &payee.aliases).await?;
+
+        tx.commit().await?;
+
+        self.find_by_id(&id)
+            .await?
+            .ok_or_else(|| PayeeError::Repository("failed to fetch created payee".to_string()))
+    }
+
+    async fn update(
+        &self,
+        id: &ulid::Ulid,
+        name: Option<PayeeName>,
+        default_category_id: Option<Option<ulid::Ulid>>,
+        aliases: Option<Vec<PayeeName>>,
+    ) -> Result<Payee, PayeeError> {
+        let id_str = id.to_string();
+        let mut tx = self.conn.begin().await?;
+
+        let exists = sqlx::query("SELECT 1 FROM payees WHERE id = ?")
+            .bind(&id_str)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| PayeeError::Repository(e.to_string()))?;
+        if exists.is_none() {
+            return Err(PayeeError::NotFound);
+        }
+
+        let names: Vec<&PayeeName> = name.iter().chain(aliases.iter().flatten()).collect();
+        check_names_free(&mut tx, &id_str, &names).await?;
+
+        let mut set_clauses: Vec<String> = Vec::new();
+
+        if name.is_some() {
+            set_clauses.push("name = ?".to_string());
+            set_clauses.push("name_key = ?".to_string());
+        }
+        if default_category_id.is_some() {
+            set_clauses.push("default_category_id = ?".to_string());
+        }
+
+        if !set_clauses.is_empty() {
+            let sql = format!(
+                "UPDATE payees SET {} WHERE id = ?",
+                set_clauses.join(", ")
+            );
+
+            let mut query = sqlx::query(&sql);
+
+            if let Some(ref n) = name {
+                query = query.bind(n.as_str()).bind(n.key());
+            }
+            if let Some(ref c) = default_category_id {
+                query = query.bind(c.map(|c| c.to_string()));
+            }
+
+            query = query.bind(&id_str);
+
+            query
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_write_error(e, name.as_ref()))?;
+        }
+
+        if let Some(ref aliases) = aliases {
+            sqlx::query("DELETE FROM payee_aliases WHERE payee_id = ?")
+                .bind(&id_str)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+            insert_aliases(&mut tx, &id_str, aliases).await?;
+        } else if let Some(ref n) = name {
+            // The new name replaces an alias spelled the same way
+            sqlx::query("DELETE FROM payee_aliases WHERE payee_id = ? AND alias_key = ?")
+                .bind(&id_str)
+                .bind(n.key())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| PayeeError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await?;
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| PayeeError::Repository("failed to fetch updated payee".to_string()))
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), PayeeError> {
+        let result = sqlx::query("DELETE FROM payees WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(PayeeError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn spending_by_month(&self, id: &ulid::Ulid) -> Result<Vec<MonthSpending>, PayeeError> {
+        let rows = sqlx::query(
+            "SELECT m.id AS month_id, m.month, \
+             SUM(CASE WHEN t.kind = 'refund' THEN -t.amount ELSE t.amount END) AS spent, \
+             COUNT(*) AS transaction_count \
+             FROM transactions t \
+             JOIN budget_entries e ON e.id = t.entry_id \
+             JOIN months m ON m.id = e.month_id \
+             WHERE t.payee_id = ? AND e.kind = 'expense' \
+             GROUP BY m.id, m.month \
+             ORDER BY m.month ASC",
+        )
+        .bind(id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| PayeeError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| map_row_to_month_spending(row).map_err(PayeeError::Repository))
+            .collect()
+    }
+}
@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+use crate::conn::Conn;
+use std::str::FromStr;
+
+use domain::entities::{NewRecurringRule, RecurringRule};
+use domain::errors::RecurringError;
+use domain::ports::RecurringRuleRepository;
+use domain::types::{BudgetMonth, DueDay, Money};
+
+pub struct SqliteRecurringRuleRepository {
+    conn: Conn,
+}
+
+impl SqliteRecurringRuleRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
+    }
+}
+
+fn map_row_to_rule(row: &sqlx::sqlite::SqliteRow) -> Result<RecurringRule, RecurringError> {
+    let id_str: String = row.get("id");
+    let id = ulid::Ulid::from_string(&id_str)
+        .map_err(|e| RecurringError::Repository(format!("invalid ULID: {}", e)))?;
+
+    let category_id_str: String = row.get("category_id");
+    let category_id = ulid::Ulid::from_string(&category_id_str)
+        .map_err(|e| RecurringError::Repository(format!("invalid category_id ULID: {}", e)))?;
+
+    let amount: i64 = row.get("amount");
+    let title: Option<String> = row.get("title");
+
+    let day_raw: i32 = row.get("day_of_month");
+    let day_of_month = DueDay::new(day_raw as u8)
+        .map_err(|e| RecurringError::Repository(format!("invalid day_of_month: {}", e)))?;
+
+    let start_str: String = row.get("start_month");
+    let start_month = BudgetMonth::from_str(&start_str)
+        .map_err(|e| RecurringError::Repository(format!("invalid start_month: {}", e)))?;
+
+    let end_str: Option<String> = row.get("end_month");
+    let end_month = end_str
+        .map(|s| BudgetMonth::from_str(&s))
+        .transpose()
+        .map_err(|e| RecurringError::Repository(format!("invalid end_month: {}", e)))?;
+
+    let paused: bool = row.get("paused");
+
+    let created_at_str: String = row.get("created_at");
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| RecurringError::Repository(format!("invalid created_at: {}", e)))?;
+
+    let updated_at_str: String = row.get("updated_at");
+    let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| RecurringError::Repository(format!("invalid updated_at: {}", e)))?;
+
+    Ok(RecurringRule {
+        id,
+        category_id,
+        amount: Money::new(amount),
+        title,
+        day_of_month,
+        start_month,
+        end_month,
+        paused,
+        created_at,
+        updated_at,
+    })
+}
+
+#[async_trait]
+impl RecurringRuleRepository for SqliteRecurringRuleRepository {
+    async fn list_all(&self) -> Result<Vec<RecurringRule>, RecurringError> {
+        let rows = sqlx::query("SELECT * FROM recurring_rules ORDER BY day_of_month ASC, id ASC")
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_rule).collect()
+    }
+
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<RecurringRule>, RecurringError> {
+        let row = sqlx::query("SELECT * FROM recurring_rules WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(map_row_to_rule(r)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn create(&self, rule: NewRecurringRule) -> Result<RecurringRule, RecurringError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        sqlx::query(
+            "INSERT INTO recurring_rules \
+             (id, category_id, amount, title, day_of_month, start_month, end_month, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(rule.category_id.to_string())
+        .bind(rule.amount.value())
+        .bind(&rule.title)
+        .bind(rule.day_of_month.value() as i32)
+        .bind(rule.start_month.to_string())
+        .bind(rule.end_month.map(|m| m.to_string()))
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        self.find_by_id(&id)
+            .await?
+            .ok_or_else(|| RecurringError::Repository("failed to fetch created rule".to_string()))
+    }
+
+    async fn update(
+        &self,
+        id: &ulid::Ulid,
+        amount: Option<Money>,
+        title: Option<Option<String>>,
+        day_of_month: Option<DueDay>,
+        start_month: Option<BudgetMonth>,
+        end_month: Option<Option<BudgetMonth>>,
+    ) -> Result<RecurringRule, RecurringError> {
+        // Build dynamic UPDATE query
+        let mut set_clauses: Vec<&str> = Vec::new();
+
+        if amount.is_some() {
+            set_clauses.push("amount = ?");
+        }
+        if title.is_some() {
+            set_clauses.push("title = ?");
+        }
+        if day_of_month.is_some() {
+            set_clauses.push("day_of_month = ?");
+        }
+        if start_month.is_some() {
+            set_clauses.push("start_month = ?");
+        }
+        if end_month.is_some() {
+            set_clauses.push("end_month = ?");
+        }
+
+        if !set_clauses.is_empty() {
+            let sql = format!(
+                "UPDATE recurring_rules SET {} WHERE id = ?",
+                set_clauses.join(", ")
+            );
+
+            let mut query = sqlx::query(&sql);
+
+            // Bind values in order
+            if let Some(a) = amount {
+                query = query.bind(a.value());
+            }
+            if let Some(t) = title {
+                query = query.bind(t);
+            }
+            if let Some(d) = day_of_month {
+                query = query.bind(d.value() as i32);
+            }
+            if let Some(s) = start_month {
+                query = query.bind(s.to_string());
+            }
+            if let Some(e) = end_month {
+                query = query.bind(e.map(|m| m.to_string()));
+            }
+
+            query = query.bind(id.to_string());
+
+            let result = query
+                .execute(&mut *self.conn.acquire().await?)
+                .await
+                .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RecurringError::NotFound);
+            }
+        }
+
+        self.find_by_id(id).await?.ok_or(RecurringError::NotFound)
+    }
+
+    async fn set_paused(&self, id: &ulid::Ulid, paused: bool) -> Result<RecurringRule, RecurringError> {
+        let result = sqlx::query("UPDATE recurring_rules SET paused = ? WHERE id = ?")
+            .bind(paused)
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RecurringError::NotFound);
+        }
+
+        self.find_by_id(id).await?.ok_or(RecurringError::NotFound)
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), RecurringError> {
+        let result = sqlx::query("DELETE FROM recurring_rules WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RecurringError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn list_unapplied(&self, month_id: &ulid::Ulid) -> Result<Vec<RecurringRule>, RecurringError> {
+        let rows = sqlx::query(
+            "SELECT r.* FROM recurring_rules r \
+             WHERE NOT EXISTS ( \
+                 SELECT 1 FROM recurring_rule_runs rr \
+                 WHERE rr.rule_id = r.id AND rr.month_id = ?) \
+             ORDER BY r.day_of_month ASC, r.id ASC",
+        )
+        .bind(month_id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_rule).collect()
+    }
+
+    async fn record_run(
+        &self,
+        rule_id: &ulid::Ulid,
+        month_id: &ulid::Ulid,
+        transaction_id: &ulid::Ulid,
+    ) -> Result<(), RecurringError> {
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        sqlx::query(
+            "INSERT INTO recurring_rule_runs (rule_id, month_id, transaction_id, created_at) \
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(rule_id.to_string())
+        .bind(month_id.to_string())
+        .bind(transaction_id.to_string())
+        .bind(&now)
+        .execute(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| RecurringError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+use crate::conn::Conn;
+use super::month_repo::map_row_to_month_spending;
+
+use domain::entities::{MonthSpending, NewTag, Tag};
+use domain::errors::TagError;
+use domain::ports::TagRepository;
+use domain::types::TagName;
+
+pub struct SqliteTagRepository {
+    conn: Conn,
+}
+
+impl SqliteTagRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
+    }
+}
+
+fn map_row_to_tag(row: &sqlx::sqlite::SqliteRow) -> Result<Tag, TagError> {
+    let id_str: String = row.get("id");
+    let id = ulid::Ulid::from_string(&id_str)
+        .map_err(|e| TagError::Repository(format!("invalid ULID: {}", e)))?;
+
+    let name_str: String = row.get("name");
+    let name = TagName::new(name_str.clone())
+        .map_err(|e| TagError::Repository(format!("invalid tag name '{}': {}", name_str, e)))?;
+
+    let created_at_str: String = row.get("created_at");
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| TagError::Repository(format!("invalid created_at: {}", e)))?;
+
+    let updated_at_str: String = row.get("updated_at");
+    let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| TagError::Repository(format!("invalid updated_at: {}", e)))?;
+
+    Ok(Tag {
+        id,
+        name,
+        created_at,
+        updated_at,
+    })
+}
+
+#[async_trait]
+impl TagRepository for SqliteTagRepository {
+    async fn list_all(&self) -> Result<Vec<Tag>, TagError> {
+        let rows = sqlx::query("SELECT * FROM tags ORDER BY name ASC")
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TagError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_tag)
+            .collect()
+    }
+
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Tag>, TagError> {
+        let row = sqlx::query("SELECT * FROM tags WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TagError::Repository(e.to_string()))?;
+
+        match row {
+            Some(ref r) => Ok(Some(map_row_to_tag(r)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_names(&self, names: &[TagName]) -> Result<Vec<Tag>, TagError> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; names.len()].join(", ");
+        let sql = format!(
+            "SELECT * FROM tags WHERE name IN ({}) ORDER BY name ASC",
+            placeholders
+        );
+
+        let mut query = sqlx::query(&sql);
+        for name in names {
+            query = query.bind(name.as_str());
+        }
+
+        let rows = query
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TagError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_tag)
+            .collect()
+    }
+
+    async fn create(&self, tag: NewTag) -> Result<Tag, TagError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+        let result = sqlx::query(
+            "INSERT INTO tags (id, name, created_at, updated_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(tag.name.as_str())
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *self.conn.acquire().await?)
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(sqlx::Error::Database(ref db_err))
+                if db_err.message().contains("UNIQUE constraint failed") =>
+            {
+                return Err(TagError::NameAlreadyExists {
+                    name: tag.name.as_str().to_string(),
+                });
+            }
+            Err(e) => return Err(TagError::Repository(e.to_string())),
+        }
+
+        self.find_by_id(&id)
+            .await?
+            .ok_or_else(|| TagError::Repository("failed to fetch created tag".to_string()))
+    }
+
+    async fn rename(&self, id: &ulid::Ulid, name: TagName) -> Result<Tag, TagError> {
+        let result = sqlx::query("UPDATE tags SET name = ? WHERE id = ?")
+            .bind(name.as_str())
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await;
+
+        match result {
+            Ok(r) => {
+                if r.rows_affected() == 0 {
+                    return Err(TagError::NotFound);
+                }
+            }
+            Err(sqlx::Error::Database(ref db_err))
+                if db_err.message().contains("UNIQUE constraint failed") =>
+            {
+                return Err(TagError::NameAlreadyExists {
+                    name: name.as_str().to_string(),
+                });
+            }
+            Err(e) => return Err(TagError::Repository(e.to_string())),
+        }
+
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| TagError::Repository("failed to fetch updated tag".to_string()))
+    }
+
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), TagError> {
+        let result = sqlx::query("DELETE FROM tags WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TagError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(TagError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn spending_by_month(&self, id: &ulid::Ulid) -> Result<Vec<MonthSpending>, TagError> {
+        let rows = sqlx::query(
+            "SELECT m.id AS month_id, m.month, \
+             SUM(CASE WHEN t.kind = 'refund' THEN -t.amount ELSE t.amount END) AS spent, \
+             COUNT(*) AS transaction_count \
+             FROM transaction_tags tt \
+             JOIN transactions t ON t.id = tt.transaction_id \
+             JOIN budget_entries e ON e.id = t.entry_id \
+             JOIN months m ON m.id = e.month_id \
+             WHERE tt.tag_id = ? AND e.kind = 'expense' \
+             GROUP BY m.id, m.month \
+             ORDER BY m.month ASC",
+        )
+        .bind(id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| TagError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| map_row_to_month_spending(row).map_err(TagError::Repository))
+            .collect()
+    }
+}
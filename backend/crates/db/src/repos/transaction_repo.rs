@@ -1,24 +1,45 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::sqlite::SqlitePool;
-use sqlx::Row;
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
+use sqlx::{QueryBuilder, Row, Sqlite};
+
+use crate::conn::Conn;
 use std::str::FromStr;
 
-use domain::entities::{NewTransaction, Transaction};
+use domain::entities::{
+    NewTransaction, NewTransactionSplit, SplitAllocation, Transaction, TransactionFilter,
+    TransactionMatch, TransactionPage, TransactionScope, TransactionSplit, TransactionSplitUpdate,
+    TransactionUpdate,
+};
 use domain::errors::TransactionError;
 use domain::ports::TransactionRepository;
-use domain::types::{Money, TransactionDate};
+use domain::types::{
+    BudgetMonth, CategoryName, CursorDirection, Money, SearchQuery, TagName, TransactionCursor,
+    TransactionDate, TransactionKind, TransactionSort,
+};
 
 pub struct SqliteTransactionRepository {
-    pool: SqlitePool,
+    conn: Conn,
 }
 
 impl SqliteTransactionRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self { conn: Conn::Pool(pool) }
+    }
+
+    pub(crate) fn with_conn(conn: Conn) -> Self {
+        Self { conn }
     }
 }
 
+/// Transaction columns, selected from `transactions t`, with its tag names joined by commas.
+const TRANSACTION_COLUMNS: &str = "t.id, t.entry_id, t.kind, t.amount, t.date, t.title, t.refund_of, \
+     t.split_id, t.payee_id, t.account_id, t.created_at, t.updated_at, \
+     (SELECT group_concat(g.name, ',') FROM transaction_tags tt JOIN tags g ON g.id = tt.tag_id \
+     WHERE tt.transaction_id = t.id) AS tags";
+
 fn map_row_to_transaction(
     row: &sqlx::sqlite::SqliteRow,
 ) -> Result<Transaction, TransactionError> {
@@ -30,6 +51,10 @@ fn map_row_to_transaction(
     let entry_id = ulid::Ulid::from_string(&entry_id_str)
         .map_err(|e| TransactionError::Repository(format!("invalid entry_id ULID: {}", e)))?;
 
+    let kind_str: String = row.get("kind");
+    let kind = TransactionKind::from_str(&kind_str)
+        .map_err(|e| TransactionError::Repository(format!("invalid kind: {}", e)))?;
+
     let amount: i64 = row.get("amount");
 
     let date_str: String = row.get("date");
@@ -38,6 +63,39 @@ fn map_row_to_transaction(
 
     let title: Option<String> = row.get("title");
 
+    let refund_of_str: Option<String> = row.get("refund_of");
+    let refund_of = refund_of_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid refund_of ULID: {}", e)))?;
+
+    let split_id_str: Option<String> = row.get("split_id");
+    let split_id = split_id_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid split_id ULID: {}", e)))?;
+
+    let payee_id_str: Option<String> = row.get("payee_id");
+    let payee_id = payee_id_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid payee_id ULID: {}", e)))?;
+
+    let account_id_str: Option<String> = row.get("account_id");
+    let account_id = account_id_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid account_id ULID: {}", e)))?;
+
+    let tags_str: Option<String> = row.get("tags");
+    let mut tags = tags_str
+        .as_deref()
+        .map(|s| s.split(',').map(TagName::new).collect::<Result<Vec<_>, _>>())
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid tag name: {}", e)))?
+        .unwrap_or_default();
+    tags.sort();
+
     let created_at_str: String = row.get("created_at");
     let created_at = DateTime::parse_from_rfc3339(&created_at_str)
         .map(|dt| dt.with_timezone(&Utc))
@@ -51,31 +109,293 @@ fn map_row_to_transaction(
     Ok(Transaction {
         id,
         entry_id,
+        kind,
+        amount: Money::new(amount),
+        date,
+        title,
+        refund_of,
+        split_id,
+        payee_id,
+        account_id,
+        tags,
+        created_at,
+        updated_at,
+    })
+}
+
+/// Month and category of a transaction, selected with `budget_entries e`, `months m`
+/// and `categories c` joined; read by `map_row_to_match`.
+const MATCH_CONTEXT_COLUMNS: &str = "e.month_id, m.month, e.category_id, c.name AS category_name";
+
+/// Transaction columns plus `MATCH_CONTEXT_COLUMNS`.
+fn map_row_to_match(row: &sqlx::sqlite::SqliteRow) -> Result<TransactionMatch, TransactionError> {
+    let transaction = map_row_to_transaction(row)?;
+
+    let month_id_str: String = row.get("month_id");
+    let month_id = ulid::Ulid::from_string(&month_id_str)
+        .map_err(|e| TransactionError::Repository(format!("invalid month_id ULID: {}", e)))?;
+
+    let month_str: String = row.get("month");
+    let month = BudgetMonth::from_str(&month_str)
+        .map_err(|e| TransactionError::Repository(format!("invalid month: {}", e)))?;
+
+    let category_id_str: String = row.get("category_id");
+    let category_id = ulid::Ulid::from_string(&category_id_str)
+        .map_err(|e| TransactionError::Repository(format!("invalid category_id ULID: {}", e)))?;
+
+    let category_name_str: String = row.get("category_name");
+    let category_name = CategoryName::new(category_name_str.clone()).map_err(|e| {
+        TransactionError::Repository(format!("invalid category name '{}': {}", category_name_str, e))
+    })?;
+
+    Ok(TransactionMatch {
+        transaction,
+        month_id,
+        month,
+        category_id,
+        category_name,
+    })
+}
+
+/// `entry_id` and `total` columns of a per-entry aggregate.
+fn map_entry_totals(
+    rows: &[sqlx::sqlite::SqliteRow],
+) -> Result<HashMap<ulid::Ulid, Money>, TransactionError> {
+    rows.iter()
+        .map(|row| {
+            let entry_id_str: String = row.get("entry_id");
+            let entry_id = ulid::Ulid::from_string(&entry_id_str).map_err(|e| {
+                TransactionError::Repository(format!("invalid entry_id ULID: {}", e))
+            })?;
+            let total: i64 = row.get("total");
+            Ok((entry_id, Money::new(total)))
+        })
+        .collect()
+}
+
+/// Appends the FROM and WHERE clauses selecting the transactions that match
+/// `filter`, with `t`, `e`, `m` and `c` in scope for the select list.
+fn push_filter(qb: &mut QueryBuilder<'_, Sqlite>, filter: &TransactionFilter) {
+    qb.push(
+        " FROM transactions t \
+         JOIN budget_entries e ON e.id = t.entry_id \
+         JOIN months m ON m.id = e.month_id \
+         JOIN categories c ON c.id = e.category_id \
+         WHERE 1 = 1",
+    );
+
+    if let Some(from) = filter.from {
+        qb.push(" AND t.date >= ").push_bind(from.to_string());
+    }
+    if let Some(to) = filter.to {
+        qb.push(" AND t.date <= ").push_bind(to.to_string());
+    }
+    // Bounds apply to the booked amount, which is positive for refunds as well
+    if let Some(min) = filter.min_amount {
+        qb.push(" AND t.amount >= ").push_bind(min.value());
+    }
+    if let Some(max) = filter.max_amount {
+        qb.push(" AND t.amount <= ").push_bind(max.value());
+    }
+    if let Some(ref category) = filter.category {
+        // substr() counts characters, and names may contain non-ASCII alphanumerics
+        let subtree_prefix = format!("{}/", category.as_str());
+        qb.push(" AND (c.name = ")
+            .push_bind(category.as_str().to_string())
+            .push(" OR substr(c.name, 1, ")
+            .push_bind(subtree_prefix.chars().count() as i64)
+            .push(") = ")
+            .push_bind(subtree_prefix)
+            .push(")");
+    }
+    if let Some(ref title) = filter.title {
+        qb.push(" AND t.title REGEXP ")
+            .push_bind(format!("(?i){}", regex::escape(title)));
+    }
+    if let Some(ref tag) = filter.tag {
+        push_tag_filter(qb, tag);
+    }
+}
+
+/// Appends a condition keeping only transactions tagged `tag`.
+fn push_tag_filter(qb: &mut QueryBuilder<'_, Sqlite>, tag: &TagName) {
+    qb.push(
+        " AND EXISTS (SELECT 1 FROM transaction_tags tt JOIN tags g ON g.id = tt.tag_id \
+         WHERE tt.transaction_id = t.id AND g.name = ",
+    )
+    .push_bind(tag.as_str().to_string())
+    .push(")");
+}
+
+/// Appends the FROM and WHERE clauses selecting the transactions of `scope`,
+/// only those tagged `tag` if given, with `t` in scope for the select list.
+fn push_scope(qb: &mut QueryBuilder<'_, Sqlite>, scope: &TransactionScope, tag: Option<&TagName>) {
+    match scope {
+        TransactionScope::Entry(entry_id) => {
+            qb.push(" FROM transactions t WHERE t.entry_id = ")
+                .push_bind(entry_id.to_string());
+        }
+        TransactionScope::Month(month_id) => {
+            qb.push(" FROM transactions t JOIN budget_entries e ON e.id = t.entry_id WHERE e.month_id = ")
+                .push_bind(month_id.to_string());
+        }
+    }
+    if let Some(tag) = tag {
+        push_tag_filter(qb, tag);
+    }
+}
+
+/// ORDER BY for a transaction list; ties fall back to date, creation time and id.
+fn order_by(sort: TransactionSort) -> &'static str {
+    match sort {
+        TransactionSort::DateDesc => " ORDER BY t.date DESC, t.created_at DESC, t.id DESC",
+        TransactionSort::DateAsc => " ORDER BY t.date ASC, t.created_at ASC, t.id ASC",
+        TransactionSort::AmountDesc => {
+            " ORDER BY t.amount DESC, t.date DESC, t.created_at DESC, t.id DESC"
+        }
+        TransactionSort::AmountAsc => {
+            " ORDER BY t.amount ASC, t.date ASC, t.created_at ASC, t.id ASC"
+        }
+    }
+}
+
+/// FTS5 expression matching titles with a word starting with every term.
+/// Each term is quoted, so operators and punctuation in it are taken literally.
+fn fts_match_expression(query: &SearchQuery) -> String {
+    query
+        .terms()
+        .iter()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn map_row_to_split(
+    row: &sqlx::sqlite::SqliteRow,
+    allocations: Vec<Transaction>,
+) -> Result<TransactionSplit, TransactionError> {
+    let id_str: String = row.get("id");
+    let id = ulid::Ulid::from_string(&id_str)
+        .map_err(|e| TransactionError::Repository(format!("invalid ULID: {}", e)))?;
+
+    let amount: i64 = row.get("amount");
+
+    let date_str: String = row.get("date");
+    let date = TransactionDate::from_str(&date_str)
+        .map_err(|e| TransactionError::Repository(format!("invalid date: {}", e)))?;
+
+    let title: Option<String> = row.get("title");
+
+    let payee_id_str: Option<String> = row.get("payee_id");
+    let payee_id = payee_id_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid payee_id ULID: {}", e)))?;
+
+    let account_id_str: Option<String> = row.get("account_id");
+    let account_id = account_id_str
+        .map(|s| ulid::Ulid::from_string(&s))
+        .transpose()
+        .map_err(|e| TransactionError::Repository(format!("invalid account_id ULID: {}", e)))?;
+
+    let created_at_str: String = row.get("created_at");
+    let created_at = DateTime::parse_from_rfc3339(&created_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| TransactionError::Repository(format!("invalid created_at: {}", e)))?;
+
+    let updated_at_str: String = row.get("updated_at");
+    let updated_at = DateTime::parse_from_rfc3339(&updated_at_str)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| TransactionError::Repository(format!("invalid updated_at: {}", e)))?;
+
+    Ok(TransactionSplit {
+        id,
         amount: Money::new(amount),
         date,
         title,
+        payee_id,
+        account_id,
+        allocations,
         created_at,
         updated_at,
     })
 }
 
+/// Links the transaction to the tags named `tags`; names without a tag are skipped.
+async fn insert_tags(
+    conn: &mut SqliteConnection,
+    transaction_id: &ulid::Ulid,
+    tags: &[TagName],
+) -> Result<(), TransactionError> {
+    for tag in tags {
+        sqlx::query(
+            "INSERT OR IGNORE INTO transaction_tags (transaction_id, tag_id) \
+             SELECT ?, id FROM tags WHERE name = ?",
+        )
+        .bind(transaction_id.to_string())
+        .bind(tag.as_str())
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Inserts one payment per allocation of a split, dated and titled like the split.
+async fn insert_allocations(
+    conn: &mut SqliteConnection,
+    split_id: &ulid::Ulid,
+    allocations: &[SplitAllocation],
+) -> Result<(), TransactionError> {
+    let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+
+    for allocation in allocations {
+        let result = sqlx::query(
+            "INSERT INTO transactions \
+             (id, entry_id, kind, amount, date, title, payee_id, account_id, split_id, created_at, updated_at) \
+             SELECT ?, ?, 'payment', ?, s.date, s.title, s.payee_id, s.account_id, s.id, ?, ? \
+             FROM transaction_splits s WHERE s.id = ?",
+        )
+        .bind(ulid::Ulid::new().to_string())
+        .bind(allocation.entry_id.to_string())
+        .bind(allocation.amount.value())
+        .bind(&now)
+        .bind(&now)
+        .bind(split_id.to_string())
+        .execute(&mut *conn)
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(sqlx::Error::Database(ref db_err))
+                if db_err.message().contains("FOREIGN KEY constraint failed") =>
+            {
+                return Err(TransactionError::EntryNotFound);
+            }
+            Err(e) => return Err(TransactionError::Repository(e.to_string())),
+        }
+    }
+
+    Ok(())
+}
+
 #[async_trait]
 impl TransactionRepository for SqliteTransactionRepository {
     async fn list_by_month(
         &self,
         month_id: &ulid::Ulid,
+        tag: Option<&TagName>,
     ) -> Result<Vec<Transaction>, TransactionError> {
-        let rows = sqlx::query(
-            "SELECT t.id, t.entry_id, t.amount, t.date, t.title, t.created_at, t.updated_at \
-             FROM transactions t \
-             JOIN budget_entries e ON t.entry_id = e.id \
-             WHERE e.month_id = ? \
-             ORDER BY t.date DESC, t.created_at DESC",
-        )
-        .bind(month_id.to_string())
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+        let mut qb = QueryBuilder::new(format!("SELECT {}", TRANSACTION_COLUMNS));
+        push_scope(&mut qb, &TransactionScope::Month(*month_id), tag);
+        qb.push(order_by(TransactionSort::DateDesc));
+
+        let rows = qb
+            .build()
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
 
         rows.iter()
             .map(map_row_to_transaction)
@@ -86,9 +406,10 @@ impl TransactionRepository for SqliteTransactionRepository {
         &self,
         id: &ulid::Ulid,
     ) -> Result<Option<Transaction>, TransactionError> {
-        let row = sqlx::query("SELECT * FROM transactions WHERE id = ?")
+        let sql = format!("SELECT {} FROM transactions t WHERE t.id = ?", TRANSACTION_COLUMNS);
+        let row = sqlx::query(&sql)
             .bind(id.to_string())
-            .fetch_optional(&self.pool)
+            .fetch_optional(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| TransactionError::Repository(e.to_string()))?;
 
@@ -105,18 +426,25 @@ impl TransactionRepository for SqliteTransactionRepository {
         let id = ulid::Ulid::new();
         let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
 
+        let mut tx = self.conn.begin().await?;
+
         let result = sqlx::query(
-            "INSERT INTO transactions (id, entry_id, amount, date, title, created_at, updated_at) \
-             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO transactions \
+             (id, entry_id, kind, amount, date, title, refund_of, payee_id, account_id, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(id.to_string())
         .bind(transaction.entry_id.to_string())
+        .bind(transaction.kind.as_str())
         .bind(transaction.amount.value())
         .bind(transaction.date.to_string())
         .bind(&transaction.title)
+        .bind(transaction.refund_of.map(|r| r.to_string()))
+        .bind(transaction.payee_id.map(|p| p.to_string()))
+        .bind(transaction.account_id.map(|a| a.to_string()))
         .bind(&now)
         .bind(&now)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await;
 
         match result {
@@ -129,6 +457,10 @@ impl TransactionRepository for SqliteTransactionRepository {
             Err(e) => return Err(TransactionError::Repository(e.to_string())),
         }
 
+        insert_tags(&mut tx, &id, &transaction.tags).await?;
+
+        tx.commit().await?;
+
         self.find_by_id(&id)
             .await?
             .ok_or_else(|| {
@@ -139,11 +471,18 @@ impl TransactionRepository for SqliteTransactionRepository {
     async fn update(
         &self,
         id: &ulid::Ulid,
-        entry_id: Option<ulid::Ulid>,
-        amount: Option<Money>,
-        date: Option<TransactionDate>,
-        title: Option<Option<String>>,
+        changes: TransactionUpdate,
     ) -> Result<Transaction, TransactionError> {
+        let TransactionUpdate {
+            entry_id,
+            amount,
+            date,
+            title,
+            payee_id,
+            account_id,
+            tags,
+        } = changes;
+
         let mut set_clauses: Vec<String> = Vec::new();
 
         if entry_id.is_some() {
@@ -158,52 +497,89 @@ impl TransactionRepository for SqliteTransactionRepository {
         if title.is_some() {
             set_clauses.push("title = ?".to_string());
         }
+        if payee_id.is_some() {
+            set_clauses.push("payee_id = ?".to_string());
+        }
+        if account_id.is_some() {
+            set_clauses.push("account_id = ?".to_string());
+        }
 
-        if set_clauses.is_empty() {
+        if set_clauses.is_empty() && tags.is_none() {
             return self
                 .find_by_id(id)
                 .await?
                 .ok_or(TransactionError::NotFound);
         }
 
-        let sql = format!(
-            "UPDATE transactions SET {} WHERE id = ?",
-            set_clauses.join(", ")
-        );
+        let mut tx = self.conn.begin().await?;
 
-        let mut query = sqlx::query(&sql);
+        if !set_clauses.is_empty() {
+            let sql = format!(
+                "UPDATE transactions SET {} WHERE id = ?",
+                set_clauses.join(", ")
+            );
 
-        if let Some(ref eid) = entry_id {
-            query = query.bind(eid.to_string());
-        }
-        if let Some(ref a) = amount {
-            query = query.bind(a.value());
-        }
-        if let Some(ref d) = date {
-            query = query.bind(d.to_string());
-        }
-        if let Some(ref t) = title {
-            query = query.bind(t);
-        }
+            let mut query = sqlx::query(&sql);
+
+            if let Some(ref eid) = entry_id {
+                query = query.bind(eid.to_string());
+            }
+            if let Some(ref a) = amount {
+                query = query.bind(a.value());
+            }
+            if let Some(ref d) = date {
+                query = query.bind(d.to_string());
+            }
+            if let Some(ref t) = title {
+                query = query.bind(t);
+            }
+            if let Some(ref p) = payee_id {
+                query = query.bind(p.map(|p| p.to_string()));
+            }
+            if let Some(ref a) = account_id {
+                query = query.bind(a.map(|a| a.to_string()));
+            }
 
-        query = query.bind(id.to_string());
+            query = query.bind(id.to_string());
 
-        let result = query.execute(&self.pool).await;
+            let result = query.execute(&mut *tx).await;
 
-        match result {
-            Ok(r) => {
-                if r.rows_affected() == 0 {
-                    return Err(TransactionError::NotFound);
+            match result {
+                Ok(r) => {
+                    if r.rows_affected() == 0 {
+                        return Err(TransactionError::NotFound);
+                    }
+                }
+                Err(sqlx::Error::Database(ref db_err))
+                    if db_err.message().contains("FOREIGN KEY constraint failed") =>
+                {
+                    return Err(TransactionError::EntryNotFound);
                 }
+                Err(e) => return Err(TransactionError::Repository(e.to_string())),
             }
-            Err(sqlx::Error::Database(ref db_err))
-                if db_err.message().contains("FOREIGN KEY constraint failed") =>
-            {
-                return Err(TransactionError::EntryNotFound);
+        }
+
+        if let Some(ref tags) = tags {
+            let exists = sqlx::query("SELECT 1 FROM transactions WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| TransactionError::Repository(e.to_string()))?;
+            if exists.is_none() {
+                return Err(TransactionError::NotFound);
             }
-            Err(e) => return Err(TransactionError::Repository(e.to_string())),
+
+            sqlx::query("DELETE FROM transaction_tags WHERE transaction_id = ?")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+            insert_tags(&mut tx, id, tags).await?;
         }
 
+        tx.commit().await?;
+
         self.find_by_id(id)
             .await?
             .ok_or_else(|| {
@@ -214,7 +590,7 @@ impl TransactionRepository for SqliteTransactionRepository {
     async fn delete(&self, id: &ulid::Ulid) -> Result<(), TransactionError> {
         let result = sqlx::query("DELETE FROM transactions WHERE id = ?")
             .bind(id.to_string())
-            .execute(&self.pool)
+            .execute(&mut *self.conn.acquire().await?)
             .await
             .map_err(|e| TransactionError::Repository(e.to_string()))?;
 
@@ -228,40 +604,477 @@ impl TransactionRepository for SqliteTransactionRepository {
     async fn list_by_entry(
         &self,
         entry_id: &ulid::Ulid,
+        tag: Option<&TagName>,
         limit: u32,
         offset: u32,
     ) -> Result<Vec<Transaction>, TransactionError> {
-        let rows = sqlx::query(
-            "SELECT id, entry_id, amount, date, title, created_at, updated_at \
-             FROM transactions \
-             WHERE entry_id = ? \
-             ORDER BY date DESC, created_at DESC \
+        let sql = format!(
+            "SELECT {} \
+             FROM transactions t \
+             WHERE t.entry_id = ? \
+             AND (? IS NULL OR EXISTS (SELECT 1 FROM transaction_tags tt JOIN tags g ON g.id = tt.tag_id \
+                  WHERE tt.transaction_id = t.id AND g.name = ?)) \
+             ORDER BY t.date DESC, t.created_at DESC, t.id DESC \
              LIMIT ? OFFSET ?",
-        )
-        .bind(entry_id.to_string())
-        .bind(limit as i64)
-        .bind(offset as i64)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+            TRANSACTION_COLUMNS
+        );
+        let tag = tag.map(|t| t.as_str());
+        let rows = sqlx::query(&sql)
+            .bind(entry_id.to_string())
+            .bind(tag)
+            .bind(tag)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
 
         rows.iter()
             .map(map_row_to_transaction)
             .collect()
     }
 
+    async fn list_by_cursor(
+        &self,
+        scope: &TransactionScope,
+        tag: Option<&TagName>,
+        cursor: Option<&TransactionCursor>,
+        limit: u32,
+    ) -> Result<Vec<Transaction>, TransactionError> {
+        let mut qb = QueryBuilder::new(format!("SELECT {}", TRANSACTION_COLUMNS));
+        push_scope(&mut qb, scope, tag);
+
+        // Pages before the cursor are read oldest first, from the cursor outwards
+        let direction = cursor.map_or(CursorDirection::After, |c| c.direction);
+        if let Some(cursor) = cursor {
+            let comparison = match cursor.direction {
+                CursorDirection::After => " < (",
+                CursorDirection::Before => " > (",
+            };
+            qb.push(" AND (t.date, t.created_at, t.id)")
+                .push(comparison)
+                .push_bind(cursor.date.to_string())
+                .push(", ")
+                .push_bind(cursor.created_at_key())
+                .push(", ")
+                .push_bind(cursor.id.to_string())
+                .push(")");
+        }
+        qb.push(match direction {
+            CursorDirection::After => order_by(TransactionSort::DateDesc),
+            CursorDirection::Before => order_by(TransactionSort::DateAsc),
+        })
+        .push(" LIMIT ")
+        .push_bind(limit);
+
+        let rows = qb
+            .build()
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        let mut transactions = rows
+            .iter()
+            .map(map_row_to_transaction)
+            .collect::<Result<Vec<_>, _>>()?;
+        if direction == CursorDirection::Before {
+            transactions.reverse();
+        }
+        Ok(transactions)
+    }
+
+    async fn list_by_tag(&self, tag: &TagName) -> Result<Vec<Transaction>, TransactionError> {
+        let sql = format!(
+            "SELECT {} \
+             FROM transactions t \
+             JOIN transaction_tags tt ON tt.transaction_id = t.id \
+             JOIN tags g ON g.id = tt.tag_id \
+             WHERE g.name = ? \
+             ORDER BY t.date DESC, t.created_at DESC",
+            TRANSACTION_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(tag.as_str())
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_transaction)
+            .collect()
+    }
+
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: u32,
+    ) -> Result<Vec<TransactionMatch>, TransactionError> {
+        let sql = format!(
+            "SELECT {}, {} \
+             FROM transactions_fts f \
+             JOIN transactions t ON t.id = f.transaction_id \
+             JOIN budget_entries e ON e.id = t.entry_id \
+             JOIN months m ON m.id = e.month_id \
+             JOIN categories c ON c.id = e.category_id \
+             WHERE transactions_fts MATCH ? \
+             ORDER BY f.rank, t.date DESC, t.created_at DESC \
+             LIMIT ?",
+            TRANSACTION_COLUMNS, MATCH_CONTEXT_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(fts_match_expression(query))
+            .bind(limit)
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        rows.iter()
+            .map(map_row_to_match)
+            .collect()
+    }
+
+    async fn list_filtered(
+        &self,
+        filter: &TransactionFilter,
+        limit: u32,
+        offset: u32,
+    ) -> Result<TransactionPage, TransactionError> {
+        // One transaction so the totals and the page see the same rows
+        let mut tx = self.conn.begin().await?;
+
+        let mut totals = QueryBuilder::new(
+            "SELECT COUNT(*) AS total_count, \
+             COALESCE(SUM(CASE WHEN t.kind = 'refund' THEN -t.amount ELSE t.amount END), 0) AS total_amount",
+        );
+        push_filter(&mut totals, filter);
+        let row = totals
+            .build()
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+        let total_count: i64 = row.get("total_count");
+        let total_amount: i64 = row.get("total_amount");
+
+        let mut page = QueryBuilder::new(format!(
+            "SELECT {}, {}",
+            TRANSACTION_COLUMNS, MATCH_CONTEXT_COLUMNS
+        ));
+        push_filter(&mut page, filter);
+        page.push(order_by(filter.sort))
+            .push(" LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+        let rows = page
+            .build()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        let items = rows
+            .iter()
+            .map(map_row_to_match)
+            .collect::<Result<Vec<_>, _>>()?;
+        tx.commit().await.map_err(TransactionError::from)?;
+
+        Ok(TransactionPage {
+            items,
+            total_count,
+            total_amount: Money::new(total_amount),
+        })
+    }
+
     async fn sum_by_entry(
         &self,
         entry_id: &ulid::Ulid,
     ) -> Result<Money, TransactionError> {
         let row =
-            sqlx::query("SELECT COALESCE(SUM(amount), 0) AS total FROM transactions WHERE entry_id = ?")
-                .bind(entry_id.to_string())
-                .fetch_one(&self.pool)
-                .await
-                .map_err(|e| TransactionError::Repository(e.to_string()))?;
+            sqlx::query(
+                "SELECT COALESCE(SUM(CASE WHEN kind = 'refund' THEN -amount ELSE amount END), 0) AS total \
+                 FROM transactions WHERE entry_id = ?",
+            )
+            .bind(entry_id.to_string())
+            .fetch_one(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        let total: i64 = row.get("total");
+        Ok(Money::new(total))
+    }
+
+    async fn sum_by_month(
+        &self,
+        month_id: &ulid::Ulid,
+    ) -> Result<HashMap<ulid::Ulid, Money>, TransactionError> {
+        let rows = sqlx::query(
+            "SELECT t.entry_id, SUM(CASE WHEN t.kind = 'refund' THEN -t.amount ELSE t.amount END) AS total \
+             FROM transactions t \
+             JOIN budget_entries e ON t.entry_id = e.id \
+             WHERE e.month_id = ? \
+             GROUP BY t.entry_id",
+        )
+        .bind(month_id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        map_entry_totals(&rows)
+    }
+
+    async fn sum_by_month_range(
+        &self,
+        from: &BudgetMonth,
+        to: &BudgetMonth,
+    ) -> Result<HashMap<ulid::Ulid, Money>, TransactionError> {
+        let rows = sqlx::query(
+            "SELECT t.entry_id, SUM(CASE WHEN t.kind = 'refund' THEN -t.amount ELSE t.amount END) AS total \
+             FROM transactions t \
+             JOIN budget_entries e ON t.entry_id = e.id \
+             JOIN months m ON e.month_id = m.id \
+             WHERE m.month >= ? AND m.month <= ? \
+             GROUP BY t.entry_id",
+        )
+        .bind(from.to_string())
+        .bind(to.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        map_entry_totals(&rows)
+    }
+
+    async fn sum_by_category(
+        &self,
+        category_id: &ulid::Ulid,
+    ) -> Result<HashMap<ulid::Ulid, Money>, TransactionError> {
+        let rows = sqlx::query(
+            "SELECT t.entry_id, SUM(CASE WHEN t.kind = 'refund' THEN -t.amount ELSE t.amount END) AS total \
+             FROM transactions t \
+             JOIN budget_entries e ON t.entry_id = e.id \
+             WHERE e.category_id = ? \
+             GROUP BY t.entry_id",
+        )
+        .bind(category_id.to_string())
+        .fetch_all(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        map_entry_totals(&rows)
+    }
+
+    async fn refunded_total(
+        &self,
+        original_id: &ulid::Ulid,
+        excluding: Option<&ulid::Ulid>,
+    ) -> Result<Money, TransactionError> {
+        let row = sqlx::query(
+            "SELECT COALESCE(SUM(amount), 0) AS total FROM transactions \
+             WHERE refund_of = ? AND kind = 'refund' AND id IS NOT ?",
+        )
+        .bind(original_id.to_string())
+        .bind(excluding.map(|id| id.to_string()))
+        .fetch_one(&mut *self.conn.acquire().await?)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?;
 
         let total: i64 = row.get("total");
         Ok(Money::new(total))
     }
+
+    async fn create_split(
+        &self,
+        split: NewTransactionSplit,
+    ) -> Result<TransactionSplit, TransactionError> {
+        let id = ulid::Ulid::new();
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+        let date = split.date.to_string();
+
+        let mut tx = self.conn.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO transaction_splits \
+             (id, amount, date, title, payee_id, account_id, created_at, updated_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(split.amount.value())
+        .bind(&date)
+        .bind(&split.title)
+        .bind(split.payee_id.map(|p| p.to_string()))
+        .bind(split.account_id.map(|a| a.to_string()))
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        insert_allocations(&mut tx, &id, &split.allocations).await?;
+
+        tx.commit().await?;
+
+        self.find_split(&id)
+            .await?
+            .ok_or_else(|| {
+                TransactionError::Repository("failed to fetch created split".to_string())
+            })
+    }
+
+    async fn find_split(
+        &self,
+        id: &ulid::Ulid,
+    ) -> Result<Option<TransactionSplit>, TransactionError> {
+        let row = sqlx::query("SELECT * FROM transaction_splits WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let sql = format!(
+            "SELECT {} FROM transactions t WHERE t.split_id = ? ORDER BY t.id",
+            TRANSACTION_COLUMNS
+        );
+        let allocation_rows = sqlx::query(&sql)
+            .bind(id.to_string())
+            .fetch_all(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        let allocations = allocation_rows
+            .iter()
+            .map(map_row_to_transaction)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(map_row_to_split(&row, allocations)?))
+    }
+
+    async fn update_split(
+        &self,
+        id: &ulid::Ulid,
+        changes: TransactionSplitUpdate,
+    ) -> Result<TransactionSplit, TransactionError> {
+        let mut set_clauses: Vec<String> = Vec::new();
+
+        if changes.amount.is_some() {
+            set_clauses.push("amount = ?".to_string());
+        }
+        if changes.date.is_some() {
+            set_clauses.push("date = ?".to_string());
+        }
+        if changes.title.is_some() {
+            set_clauses.push("title = ?".to_string());
+        }
+        if changes.payee_id.is_some() {
+            set_clauses.push("payee_id = ?".to_string());
+        }
+        if changes.account_id.is_some() {
+            set_clauses.push("account_id = ?".to_string());
+        }
+
+        if set_clauses.is_empty() && changes.allocations.is_none() {
+            return self
+                .find_split(id)
+                .await?
+                .ok_or(TransactionError::SplitNotFound);
+        }
+
+        let mut tx = self.conn.begin().await?;
+
+        if !set_clauses.is_empty() {
+            let sql = format!(
+                "UPDATE transaction_splits SET {} WHERE id = ?",
+                set_clauses.join(", ")
+            );
+
+            let mut query = sqlx::query(&sql);
+
+            if let Some(ref a) = changes.amount {
+                query = query.bind(a.value());
+            }
+            if let Some(ref d) = changes.date {
+                query = query.bind(d.to_string());
+            }
+            if let Some(ref t) = changes.title {
+                query = query.bind(t);
+            }
+            if let Some(ref p) = changes.payee_id {
+                query = query.bind(p.map(|p| p.to_string()));
+            }
+            if let Some(ref a) = changes.account_id {
+                query = query.bind(a.map(|a| a.to_string()));
+            }
+
+            query = query.bind(id.to_string());
+
+            let result = query
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(TransactionError::SplitNotFound);
+            }
+        }
+
+        let found = sqlx::query("SELECT COUNT(*) AS cnt FROM transaction_splits WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+        let found: i64 = found.get("cnt");
+        if found == 0 {
+            return Err(TransactionError::SplitNotFound);
+        }
+
+        if let Some(ref allocations) = changes.allocations {
+            sqlx::query("DELETE FROM transactions WHERE split_id = ?")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+            insert_allocations(&mut tx, id, allocations).await?;
+        } else if changes.date.is_some()
+            || changes.title.is_some()
+            || changes.payee_id.is_some()
+            || changes.account_id.is_some()
+        {
+            sqlx::query(
+                "UPDATE transactions \
+                 SET (date, title, payee_id, account_id) = ( \
+                     SELECT s.date, s.title, s.payee_id, s.account_id \
+                     FROM transaction_splits s WHERE s.id = transactions.split_id) \
+                 WHERE split_id = ?",
+            )
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await?;
+
+        self.find_split(id)
+            .await?
+            .ok_or_else(|| {
+                TransactionError::Repository("failed to fetch updated split".to_string())
+            })
+    }
+
+    async fn delete_split(&self, id: &ulid::Ulid) -> Result<(), TransactionError> {
+        let result = sqlx::query("DELETE FROM transaction_splits WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&mut *self.conn.acquire().await?)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(TransactionError::SplitNotFound);
+        }
+
+        Ok(())
+    }
 }
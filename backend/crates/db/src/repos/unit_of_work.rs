@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::sqlite::SqlitePool;
+use tokio::sync::Mutex;
+
+use domain::errors::UnitOfWorkError;
+use domain::ports::{
+    AccountRepository, AttachmentRepository, BudgetEntryRepository, CategoryRepository,
+    MonthRepository, PayeeRepository, RecurringRuleRepository, TagRepository,
+    TransactionRepository, UnitOfWork, UnitOfWorkFactory,
+};
+
+use crate::conn::{Conn, SharedTx};
+use super::{
+    SqliteAccountRepository, SqliteAttachmentRepository, SqliteBudgetEntryRepository, SqliteCategoryRepository,
+    SqliteMonthRepository, SqlitePayeeRepository, SqliteRecurringRuleRepository,
+    SqliteTagRepository, SqliteTransactionRepository,
+};
+
+pub struct SqliteUnitOfWorkFactory {
+    pool: SqlitePool,
+}
+
+impl SqliteUnitOfWorkFactory {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for SqliteUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, UnitOfWorkError> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| UnitOfWorkError(e.to_string()))?;
+
+        let tx: SharedTx = Arc::new(Mutex::new(Some(tx)));
+        let conn = Conn::Tx(tx.clone());
+
+        Ok(Box::new(SqliteUnitOfWork {
+            tx,
+            categories: SqliteCategoryRepository::with_conn(conn.clone()),
+            months: SqliteMonthRepository::with_conn(conn.clone()),
+            entries: SqliteBudgetEntryRepository::with_conn(conn.clone()),
+            transactions: SqliteTransactionRepository::with_conn(conn.clone()),
+            recurring: SqliteRecurringRuleRepository::with_conn(conn.clone()),
+            tags: SqliteTagRepository::with_conn(conn.clone()),
+            payees: SqlitePayeeRepository::with_conn(conn.clone()),
+            accounts: SqliteAccountRepository::with_conn(conn.clone()),
+            attachments: SqliteAttachmentRepository::with_conn(conn),
+        }))
+    }
+}
+
+/// Repositories running on one shared `sqlx::Transaction`.
+///
+/// The transaction is rolled back when the last handle to it is dropped uncommitted.
+struct SqliteUnitOfWork {
+    tx: SharedTx,
+    categories: SqliteCategoryRepository,
+    months: SqliteMonthRepository,
+    entries: SqliteBudgetEntryRepository,
+    transactions: SqliteTransactionRepository,
+    recurring: SqliteRecurringRuleRepository,
+    tags: SqliteTagRepository,
+    payees: SqlitePayeeRepository,
+    accounts: SqliteAccountRepository,
+    attachments: SqliteAttachmentRepository,
+}
+
+#[async_trait]
+impl UnitOfWork for SqliteUnitOfWork {
+    fn categories(&self) -> &dyn CategoryRepository {
+        &self.categories
+    }
+
+    fn months(&self) -> &dyn MonthRepository {
+        &self.months
+    }
+
+    fn entries(&self) -> &dyn BudgetEntryRepository {
+        &self.entries
+    }
+
+    fn transactions(&self) -> &dyn TransactionRepository {
+        &self.transactions
+    }
+
+    fn recurring(&self) -> &dyn RecurringRuleRepository {
+        &self.recurring
+    }
+
+    fn tags(&self) -> &dyn TagRepository {
+        &self.tags
+    }
+
+    fn payees(&self) -> &dyn PayeeRepository {
+        &self.payees
+    }
+
+    fn accounts(&self) -> &dyn AccountRepository {
+        &self.accounts
+    }
+
+    fn attachments(&self) -> &dyn AttachmentRepository {
+        &self.attachments
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError> {
+        let tx = self
+            .tx
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| UnitOfWorkError("unit of work already finished".to_string()))?;
+
+        tx.commit().await.map_err(|e| UnitOfWorkError(e.to_string()))
+    }
+}
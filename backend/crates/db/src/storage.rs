@@ -0,0 +1,82 @@
+// Attachment contents on the local filesystem.
+//
+// Each file is stored flat in one directory, named by its key (the SHA-256 of
+// its contents). Writes go to a temporary file that is renamed into place, so
+// a crash never leaves a truncated file under a valid key.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use domain::errors::AttachmentError;
+use domain::ports::AttachmentStore;
+
+pub struct FsAttachmentStore {
+    dir: PathBuf,
+}
+
+impl FsAttachmentStore {
+    /// Stores files in `dir`, which is created on the first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, AttachmentError> {
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(AttachmentError::Storage(format!("invalid file key '{}'", key)));
+        }
+        Ok(self.dir.join(key))
+    }
+}
+
+fn storage_error(action: &str, path: &Path, e: std::io::Error) -> AttachmentError {
+    AttachmentError::Storage(format!("failed to {} {}: {}", action, path.display(), e))
+}
+
+#[async_trait]
+impl AttachmentStore for FsAttachmentStore {
+    async fn put(&self, key: &str, contents: &[u8]) -> Result<(), AttachmentError> {
+        let path = self.path(key)?;
+        if fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| storage_error("create", &self.dir, e))?;
+
+        let tmp = self.dir.join(format!(".{}.{}.tmp", key, ulid::Ulid::new()));
+        if let Err(e) = fs::write(&tmp, contents).await {
+            let _ = fs::remove_file(&tmp).await;
+            return Err(storage_error("write", &tmp, e));
+        }
+        if let Err(e) = fs::rename(&tmp, &path).await {
+            let _ = fs::remove_file(&tmp).await;
+            return Err(storage_error("move", &tmp, e));
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AttachmentError> {
+        let path = self.path(key)?;
+        fs::read(&path)
+            .await
+            .map_err(|e| storage_error("read", &path, e))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AttachmentError> {
+        let path = self.path(key)?;
+        match fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(storage_error("remove", &path, e)),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use db::repos::{
+    SqliteAttachmentRepository, SqliteBudgetEntryRepository, SqliteCategoryRepository,
+    SqliteMonthRepository, SqliteTransactionRepository, SqliteUnitOfWorkFactory,
+};
+use db::storage::FsAttachmentStore;
+use domain::entities::{NewCategory, NewTransaction};
+use domain::ports::{AttachmentRepository, CategoryRepository, TransactionRepository};
+use domain::services::{AttachmentService, EntryService, MonthService};
+use domain::types::{
+    CategoryName, EntryKind, Money, RolloverMode, TransactionDate, TransactionKind,
+};
+
+const RECEIPT: &[u8] = b"hello receipt";
+const RECEIPT_HASH: &str = "eb4171de4c65382b8aa421156087cacd874841cbfe3cece8521b22aaf7e3e4a8";
+
+struct Fixture {
+    attachments: AttachmentService,
+    repo: Arc<SqliteAttachmentRepository>,
+    dir: PathBuf,
+    transaction_id: ulid::Ulid,
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// One transaction to attach files to, with files stored in a fresh directory.
+async fn setup() -> Fixture {
+    let pool = db::create_pool("sqlite::memory:").await.unwrap();
+    db::run_migrations(&pool).await.unwrap();
+
+    let categories = Arc::new(SqliteCategoryRepository::new(pool.clone()));
+    let months = Arc::new(SqliteMonthRepository::new(pool.clone()));
+    let transactions = Arc::new(SqliteTransactionRepository::new(pool.clone()));
+    let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
+    let repo = Arc::new(SqliteAttachmentRepository::new(pool.clone()));
+
+    let month_service = MonthService::new(months.clone(), unit_of_work.clone());
+    let entry_service = EntryService::new(
+        Arc::new(SqliteBudgetEntryRepository::new(pool)),
+        categories.clone(),
+        months,
+    );
+
+    let category = categories
+        .create(NewCategory {
+            name: CategoryName::new("groceries".to_string()).unwrap(),
+            label: None,
+        })
+        .await
+        .unwrap();
+    let month = month_service
+        .create("2026-03".parse().unwrap(), None, true)
+        .await
+        .unwrap();
+    let entry = entry_service
+        .create(month.id, category.id, EntryKind::Expense, Money::new(1000), RolloverMode::None, None)
+        .await
+        .unwrap();
+    let transaction = transactions
+        .create(NewTransaction {
+            entry_id: entry.id,
+            kind: TransactionKind::Payment,
+            amount: Money::new(500),
+            date: "2026-03-05".parse::<TransactionDate>().unwrap(),
+            title: None,
+            refund_of: None,
+            payee_id: None,
+            account_id: None,
+            tags: vec![],
+        })
+        .await
+        .unwrap();
+
+    let dir = std::env::temp_dir().join(format!("otter-attachments-{}", ulid::Ulid::new()));
+    let attachments = AttachmentService::new(
+        repo.clone(),
+        transactions,
+        Arc::new(FsAttachmentStore::new(&dir)),
+        unit_of_work,
+        1024,
+    );
+
+    Fixture {
+        attachments,
+        repo,
+        dir,
+        transaction_id: transaction.id,
+    }
+}
+
+#[tokio::test]
+async fn test_upload_takes_file_off_purge_queue() {
+    let f = setup().await;
+
+    let first = f
+        .attachments
+        .upload(&f.transaction_id, "a.txt", None, RECEIPT)
+        .await
+        .unwrap();
+    f.attachments.delete(&f.transaction_id, &first.id).await.unwrap();
+    assert_eq!(f.repo.unreferenced_files().await.unwrap(), vec![RECEIPT_HASH.to_string()]);
+
+    // Uploading the same contents before the purge runs keeps the file
+    f.attachments
+        .upload(&f.transaction_id, "b.txt", None, RECEIPT)
+        .await
+        .unwrap();
+    assert!(f.repo.unreferenced_files().await.unwrap().is_empty());
+    assert_eq!(f.attachments.purge_files().await.unwrap(), 0);
+    assert!(f.dir.join(RECEIPT_HASH).exists());
+}
+
+#[tokio::test]
+async fn test_purge_rechecks_references() {
+    let f = setup().await;
+
+    let attachment = f
+        .attachments
+        .upload(&f.transaction_id, "a.txt", None, RECEIPT)
+        .await
+        .unwrap();
+
+    // A queued file that is still used is neither forgotten nor removed
+    let queued = f.attachments.upload(&f.transaction_id, "b.txt", None, RECEIPT).await.unwrap();
+    f.attachments.delete(&f.transaction_id, &queued.id).await.unwrap();
+    assert!(!f.repo.forget_unreferenced_file(RECEIPT_HASH).await.unwrap());
+    assert_eq!(f.attachments.purge_files().await.unwrap(), 0);
+    assert!(f.dir.join(RECEIPT_HASH).exists());
+
+    f.attachments.delete(&f.transaction_id, &attachment.id).await.unwrap();
+    assert_eq!(f.attachments.purge_files().await.unwrap(), 1);
+    assert!(!f.dir.join(RECEIPT_HASH).exists());
+    assert!(f.repo.unreferenced_files().await.unwrap().is_empty());
+}
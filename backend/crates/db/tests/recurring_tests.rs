@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use db::repos::{
+    SqliteBudgetEntryRepository, SqliteCategoryRepository, SqliteMonthRepository,
+    SqliteRecurringRuleRepository, SqliteTransactionRepository, SqliteUnitOfWorkFactory,
+};
+use domain::entities::NewCategory;
+use domain::ports::{CategoryRepository, TransactionRepository};
+use domain::services::{EntryService, MonthService, RecurringService};
+use domain::types::{CategoryName, DueDay, EntryKind, Money, RolloverMode};
+
+struct Fixture {
+    recurring: RecurringService,
+    transactions: SqliteTransactionRepository,
+    month_id: ulid::Ulid,
+}
+
+/// One month "2026-03" with a "rent" entry and a rule booking 1000 on the 15th.
+async fn setup() -> (Fixture, ulid::Ulid) {
+    let pool = db::create_pool("sqlite::memory:").await.unwrap();
+    db::run_migrations(&pool).await.unwrap();
+
+    let categories = Arc::new(SqliteCategoryRepository::new(pool.clone()));
+    let months = Arc::new(SqliteMonthRepository::new(pool.clone()));
+    let unit_of_work = Arc::new(SqliteUnitOfWorkFactory::new(pool.clone()));
+
+    let month_service = MonthService::new(months.clone(), unit_of_work.clone());
+    let entry_service = EntryService::new(
+        Arc::new(SqliteBudgetEntryRepository::new(pool.clone())),
+        categories.clone(),
+        months.clone(),
+    );
+    let recurring = RecurringService::new(
+        Arc::new(SqliteRecurringRuleRepository::new(pool.clone())),
+        categories.clone(),
+        unit_of_work,
+    );
+
+    let rent = categories
+        .create(NewCategory {
+            name: CategoryName::new("rent".to_string()).unwrap(),
+            label: None,
+        })
+        .await
+        .unwrap();
+    let month = month_service
+        .create("2026-03".parse().unwrap(), None, true)
+        .await
+        .unwrap();
+    entry_service
+        .create(month.id, rent.id, EntryKind::Expense, Money::new(1000), RolloverMode::None, None)
+        .await
+        .unwrap();
+
+    let rule = recurring
+        .create(
+            rent.id,
+            Money::new(1000),
+            Some("Rent".to_string()),
+            DueDay::new(15).unwrap(),
+            "2026-01".parse().unwrap(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let fixture = Fixture {
+        recurring,
+        transactions: SqliteTransactionRepository::new(pool),
+        month_id: month.id,
+    };
+    (fixture, rule.id)
+}
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+#[tokio::test]
+async fn test_materialize_due_books_on_due_day_once() {
+    let (f, _) = setup().await;
+
+    let booked = f.recurring.materialize_due(date("2026-03-14")).await.unwrap();
+    assert!(booked.is_empty());
+
+    let booked = f.recurring.materialize_due(date("2026-03-15")).await.unwrap();
+    assert_eq!(booked.len(), 1);
+    assert_eq!(booked[0].date.to_string(), "2026-03-15");
+    assert_eq!(booked[0].title.as_deref(), Some("Rent"));
+
+    // Later runs do not book the month again, even after the payment is deleted
+    let booked = f.recurring.materialize_due(date("2026-03-20")).await.unwrap();
+    assert!(booked.is_empty());
+    f.transactions.delete(&booked_id(&f).await).await.unwrap();
+    let booked = f.recurring.materialize_due(date("2026-03-21")).await.unwrap();
+    assert!(booked.is_empty());
+}
+
+#[tokio::test]
+async fn test_materialize_due_catches_up_previous_month() {
+    let (f, _) = setup().await;
+
+    // No run happened in March after the 15th; the April run still books March
+    let booked = f.recurring.materialize_due(date("2026-04-01")).await.unwrap();
+    assert_eq!(booked.len(), 1);
+    assert_eq!(booked[0].date.to_string(), "2026-03-15");
+}
+
+#[tokio::test]
+async fn test_materialize_due_skips_paused_until_resumed() {
+    let (f, rule_id) = setup().await;
+
+    f.recurring.set_paused(&rule_id, true).await.unwrap();
+    let booked = f.recurring.materialize_due(date("2026-03-20")).await.unwrap();
+    assert!(booked.is_empty());
+
+    f.recurring.set_paused(&rule_id, false).await.unwrap();
+    let booked = f.recurring.materialize_due(date("2026-03-20")).await.unwrap();
+    assert_eq!(booked.len(), 1);
+}
+
+async fn booked_id(f: &Fixture) -> ulid::Ulid {
+    let transactions = f.transactions.list_by_month(&f.month_id, None).await.unwrap();
+    assert_eq!(transactions.len(), 1);
+    transactions[0].id
+}
@@ -0,0 +1,207 @@
+// Compares the grouped `sum_by_month` aggregate against summing each entry with
+// `sum_by_entry`, as the month summary used to, and the month range reads of the
+// range summary against per-month reads. The timing comparison depends on the
+// machine's load, so it only runs on request:
+//
+//   cargo test -p db --test summary_benchmark_tests -- --ignored --nocapture
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use db::repos::{
+    SqliteBudgetEntryRepository, SqliteCategoryRepository, SqliteMonthRepository,
+    SqliteTransactionRepository,
+};
+use domain::entities::{NewBudgetEntry, NewCategory, NewMonth, NewTransaction};
+use domain::ports::{
+    BudgetEntryRepository, CategoryRepository, MonthRepository, TransactionRepository,
+};
+use domain::types::{
+    BudgetMonth, CategoryName, EntryKind, Money, RolloverMode, TransactionDate, TransactionKind,
+};
+
+const ENTRIES: usize = 60;
+const TRANSACTIONS_PER_ENTRY: usize = 10;
+const ROUNDS: u32 = 20;
+
+struct Seeded {
+    month_id: ulid::Ulid,
+    month_ids: Vec<ulid::Ulid>,
+    entries: SqliteBudgetEntryRepository,
+    transactions: SqliteTransactionRepository,
+}
+
+/// Two months with `ENTRIES` entries each; only the first gets transactions
+/// in every entry, the second one in every other entry.
+async fn seed() -> Seeded {
+    let pool = db::create_pool("sqlite::memory:").await.unwrap();
+    db::run_migrations(&pool).await.unwrap();
+
+    let categories = SqliteCategoryRepository::new(pool.clone());
+    let months = SqliteMonthRepository::new(pool.clone());
+    let entries = SqliteBudgetEntryRepository::new(pool.clone());
+    let transactions = SqliteTransactionRepository::new(pool);
+
+    let mut month_ids = Vec::new();
+    for m in ["2026-01", "2026-02"] {
+        let month = months
+            .create(NewMonth { month: m.parse::<BudgetMonth>().unwrap() })
+            .await
+            .unwrap();
+        month_ids.push(month.id);
+    }
+
+    for i in 0..ENTRIES {
+        let category = categories
+            .create(NewCategory {
+                name: CategoryName::new(format!("bench/category-{i}")).unwrap(),
+                label: None,
+            })
+            .await
+            .unwrap();
+
+        for (m, month_id) in month_ids.iter().enumerate() {
+            let entry = entries
+                .create(NewBudgetEntry {
+                    month_id: *month_id,
+                    category_id: category.id,
+                    kind: EntryKind::Expense,
+                    budgeted: Money::new(10_000),
+                    carried_in: Money::new(0),
+                    rollover: RolloverMode::None,
+                    due_day: None,
+                })
+                .await
+                .unwrap();
+
+            if m == 1 && i % 2 == 1 {
+                continue;
+            }
+            for t in 0..TRANSACTIONS_PER_ENTRY {
+                transactions
+                    .create(NewTransaction {
+                        entry_id: entry.id,
+                        kind: TransactionKind::Payment,
+                        amount: Money::new((i * 100 + t) as i64),
+                        date: TransactionDate::new(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()),
+                        title: None,
+                        refund_of: None,
+                        payee_id: None,
+                        account_id: None,
+                        tags: Vec::new(),
+                    })
+                    .await
+                    .unwrap();
+            }
+        }
+    }
+
+    Seeded {
+        month_id: month_ids[0],
+        month_ids,
+        entries,
+        transactions,
+    }
+}
+
+async fn sum_per_entry(seeded: &Seeded) -> HashMap<ulid::Ulid, Money> {
+    let mut totals = HashMap::new();
+    for entry in seeded.entries.list_by_month(&seeded.month_id).await.unwrap() {
+        let paid = seeded.transactions.sum_by_entry(&entry.id).await.unwrap();
+        totals.insert(entry.id, paid);
+    }
+    totals
+}
+
+async fn sum_grouped(seeded: &Seeded) -> HashMap<ulid::Ulid, Money> {
+    // The summary lists entries either way
+    seeded.entries.list_by_month(&seeded.month_id).await.unwrap();
+    seeded.transactions.sum_by_month(&seeded.month_id).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_sum_by_month_matches_per_entry_sums() {
+    let seeded = seed().await;
+
+    let grouped = sum_grouped(&seeded).await;
+    assert_eq!(grouped.len(), ENTRIES);
+    assert_eq!(grouped, sum_per_entry(&seeded).await);
+}
+
+#[tokio::test]
+async fn test_sum_by_category_matches_per_entry_sums() {
+    let seeded = seed().await;
+
+    for entry in seeded.entries.list_by_month(&seeded.month_id).await.unwrap() {
+        let grouped = seeded.transactions.sum_by_category(&entry.category.id).await.unwrap();
+        let mut expected = HashMap::new();
+        for e in seeded.entries.list_by_category(&entry.category.id).await.unwrap() {
+            let paid = seeded.transactions.sum_by_entry(&e.id).await.unwrap();
+            if paid != Money::new(0) {
+                expected.insert(e.id, paid);
+            }
+        }
+        assert_eq!(grouped, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_month_range_matches_per_month_reads() {
+    let seeded = seed().await;
+    let (jan, feb) = ("2026-01".parse().unwrap(), "2026-02".parse().unwrap());
+
+    let mut expected = HashMap::new();
+    for month_id in &seeded.month_ids {
+        expected.extend(seeded.transactions.sum_by_month(month_id).await.unwrap());
+    }
+    assert_eq!(seeded.transactions.sum_by_month_range(&jan, &feb).await.unwrap(), expected);
+    assert_eq!(
+        seeded.transactions.sum_by_month_range(&feb, &feb).await.unwrap(),
+        seeded.transactions.sum_by_month(&seeded.month_ids[1]).await.unwrap()
+    );
+
+    let by_month = seeded.entries.list_by_month_range(&jan, &feb).await.unwrap();
+    assert_eq!(by_month.len(), 2);
+    for month_id in &seeded.month_ids {
+        let ids = |entries: &[domain::entities::BudgetEntryWithCategory]| {
+            entries.iter().map(|e| e.id).collect::<Vec<_>>()
+        };
+        let listed = seeded.entries.list_by_month(month_id).await.unwrap();
+        assert_eq!(ids(&by_month[month_id]), ids(&listed));
+    }
+    assert!(seeded.entries.list_by_month_range(&feb, &jan).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+#[ignore = "wall-clock timing; run with --ignored"]
+async fn test_sum_by_month_outperforms_per_entry_sums() {
+    let seeded = seed().await;
+
+    // Warm up the pool and statement caches before timing
+    sum_per_entry(&seeded).await;
+    sum_grouped(&seeded).await;
+
+    let mut per_entry = Duration::ZERO;
+    let mut grouped = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        sum_per_entry(&seeded).await;
+        per_entry += start.elapsed();
+
+        let start = Instant::now();
+        sum_grouped(&seeded).await;
+        grouped += start.elapsed();
+    }
+
+    println!(
+        "{ENTRIES} entries x {TRANSACTIONS_PER_ENTRY} transactions, {ROUNDS} rounds: \
+         per-entry {:?}/round, grouped {:?}/round",
+        per_entry / ROUNDS,
+        grouped / ROUNDS,
+    );
+    assert!(
+        grouped < per_entry,
+        "grouped query ({grouped:?}) should beat {ENTRIES} per-entry queries ({per_entry:?})"
+    );
+}
@@ -0,0 +1,58 @@
+use db::repos::{SqliteMonthRepository, SqliteUnitOfWorkFactory};
+use domain::entities::NewMonth;
+use domain::ports::{MonthRepository, UnitOfWorkFactory};
+use domain::types::BudgetMonth;
+
+async fn setup() -> (SqliteMonthRepository, SqliteUnitOfWorkFactory) {
+    let pool = db::create_pool("sqlite::memory:").await.unwrap();
+    db::run_migrations(&pool).await.unwrap();
+    (
+        SqliteMonthRepository::new(pool.clone()),
+        SqliteUnitOfWorkFactory::new(pool),
+    )
+}
+
+fn new_month(s: &str) -> NewMonth {
+    NewMonth {
+        month: s.parse::<BudgetMonth>().unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn test_commit_persists_writes() {
+    let (months, factory) = setup().await;
+
+    let uow = factory.begin().await.unwrap();
+    let created = uow.months().create(new_month("2026-01")).await.unwrap();
+    // Writes are visible inside the unit of work before commit
+    assert!(uow.months().find_by_id(&created.id).await.unwrap().is_some());
+    uow.commit().await.unwrap();
+
+    assert!(months.find_by_id(&created.id).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn test_drop_rolls_back_writes() {
+    let (months, factory) = setup().await;
+
+    let uow = factory.begin().await.unwrap();
+    let created = uow.months().create(new_month("2026-01")).await.unwrap();
+    drop(uow);
+
+    assert!(months.find_by_id(&created.id).await.unwrap().is_none());
+    assert!(months.list_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_repository_transactions_join_unit_of_work() {
+    let (months, factory) = setup().await;
+    let existing = months.create(new_month("2026-01")).await.unwrap();
+
+    // `delete` opens its own transaction; inside a unit of work it must not commit early
+    let uow = factory.begin().await.unwrap();
+    uow.months().delete(&existing.id).await.unwrap();
+    assert!(uow.months().find_by_id(&existing.id).await.unwrap().is_none());
+    drop(uow);
+
+    assert!(months.find_by_id(&existing.id).await.unwrap().is_some());
+}
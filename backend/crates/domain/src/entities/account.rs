@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use crate::types::{AccountKind, AccountName, EntryKind, Money, TransactionDate, TransactionKind};
+
+/// Where money is paid from or into, e.g. a bank account, a card or cash.
+///
+/// The balance starts at `opening_balance` and moves with the account's
+/// transactions and transfers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub id: ulid::Ulid,
+    pub name: AccountName,
+    pub kind: AccountKind,
+    pub opening_balance: Money,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAccount {
+    pub name: AccountName,
+    pub kind: AccountKind,
+    pub opening_balance: Money,
+}
+
+/// Money moved from one account to another.
+///
+/// Transfers change account balances only; they are not budget spending.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transfer {
+    pub id: ulid::Ulid,
+    pub from_account_id: ulid::Ulid,
+    pub to_account_id: ulid::Ulid,
+    pub amount: Money,
+    pub date: TransactionDate,
+    pub title: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTransfer {
+    pub from_account_id: ulid::Ulid,
+    pub to_account_id: ulid::Ulid,
+    pub amount: Money,
+    pub date: TransactionDate,
+    pub title: Option<String>,
+}
+
+/// Changes to a transfer; `None` leaves a field as it is.
+#[derive(Debug, Clone, Default)]
+pub struct TransferUpdate {
+    pub from_account_id: Option<ulid::Ulid>,
+    pub to_account_id: Option<ulid::Ulid>,
+    pub amount: Option<Money>,
+    pub date: Option<TransactionDate>,
+    /// `Some(None)` clears the title
+    pub title: Option<Option<String>>,
+}
+
+/// What moved money on an account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountActivityKind {
+    Transaction {
+        entry_id: ulid::Ulid,
+        entry_kind: EntryKind,
+        transaction_kind: TransactionKind,
+    },
+    TransferIn { from_account_id: ulid::Ulid },
+    TransferOut { to_account_id: ulid::Ulid },
+}
+
+/// One transaction or transfer on an account, with its amount as booked (never negative).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountActivity {
+    /// Transaction or transfer ID
+    pub id: ulid::Ulid,
+    pub kind: AccountActivityKind,
+    pub amount: Money,
+    pub date: TransactionDate,
+    pub title: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl AccountActivity {
+    /// How much the activity adds to the account's balance.
+    ///
+    /// Payments on expense entries and refunds on income entries take money out;
+    /// the opposite ones bring it in.
+    pub fn balance_change(&self) -> Money {
+        let incoming = match self.kind {
+            AccountActivityKind::Transaction {
+                entry_kind,
+                transaction_kind,
+                ..
+            } => (entry_kind == EntryKind::Income) == (transaction_kind == TransactionKind::Payment),
+            AccountActivityKind::TransferIn { .. } => true,
+            AccountActivityKind::TransferOut { .. } => false,
+        };
+        if incoming {
+            self.amount
+        } else {
+            Money::new(0) - self.amount
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(kind: AccountActivityKind) -> AccountActivity {
+        AccountActivity {
+            id: ulid::Ulid::new(),
+            kind,
+            amount: Money::new(1500),
+            date: TransactionDate::new(chrono::NaiveDate::from_ymd_opt(2026, 4, 10).unwrap()),
+            title: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    fn transaction(entry_kind: EntryKind, transaction_kind: TransactionKind) -> AccountActivityKind {
+        AccountActivityKind::Transaction {
+            entry_id: ulid::Ulid::new(),
+            entry_kind,
+            transaction_kind,
+        }
+    }
+
+    #[test]
+    fn test_balance_change_of_transactions() {
+        let change = |e, t| activity(transaction(e, t)).balance_change().value();
+        assert_eq!(change(EntryKind::Expense, TransactionKind::Payment), -1500);
+        assert_eq!(change(EntryKind::Expense, TransactionKind::Refund), 1500);
+        assert_eq!(change(EntryKind::Income, TransactionKind::Payment), 1500);
+        assert_eq!(change(EntryKind::Income, TransactionKind::Refund), -1500);
+    }
+
+    #[test]
+    fn test_balance_change_of_transfers() {
+        let other = ulid::Ulid::new();
+        let incoming = activity(AccountActivityKind::TransferIn { from_account_id: other });
+        let outgoing = activity(AccountActivityKind::TransferOut { to_account_id: other });
+        assert_eq!(incoming.balance_change().value(), 1500);
+        assert_eq!(outgoing.balance_change().value(), -1500);
+    }
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of an attachment's file name
+pub const MAX_FILE_NAME_LENGTH: usize = 255;
+
+/// A file kept with a transaction, e.g. a scanned receipt or an invoice.
+///
+/// The contents are stored outside the database under their SHA-256 hash, so
+/// identical files uploaded twice are stored once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: ulid::Ulid,
+    pub transaction_id: ulid::Ulid,
+    pub file_name: String,
+    pub content_type: String,
+    /// Size in bytes
+    pub size: i64,
+    /// Hex-encoded SHA-256 of the contents
+    pub sha256: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub transaction_id: ulid::Ulid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub sha256: String,
+}
@@ -1,12 +1,16 @@
 use serde::{Deserialize, Serialize};
-use crate::types::{Money, DueDay, CategoryName};
+use crate::types::{Money, DueDay, CategoryName, EntryKind, RolloverMode};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BudgetEntry {
     pub id: ulid::Ulid,
     pub month_id: ulid::Ulid,
     pub category_id: ulid::Ulid,
+    pub kind: EntryKind,
     pub budgeted: Money,
+    /// Balance carried over from the previous month's entry, added to `budgeted`
+    pub carried_in: Money,
+    pub rollover: RolloverMode,
     pub due_day: Option<DueDay>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
@@ -17,7 +21,10 @@ pub struct BudgetEntry {
 pub struct BudgetEntryWithCategory {
     pub id: ulid::Ulid,
     pub category: CategorySummary,
+    pub kind: EntryKind,
     pub budgeted: Money,
+    pub carried_in: Money,
+    pub rollover: RolloverMode,
     pub due_day: Option<DueDay>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
@@ -29,12 +36,16 @@ pub struct CategorySummary {
     pub id: ulid::Ulid,
     pub name: CategoryName,
     pub label: Option<String>,
+    pub archived: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct NewBudgetEntry {
     pub month_id: ulid::Ulid,
     pub category_id: ulid::Ulid,
+    pub kind: EntryKind,
     pub budgeted: Money,
+    pub carried_in: Money,
+    pub rollover: RolloverMode,
     pub due_day: Option<DueDay>,
 }
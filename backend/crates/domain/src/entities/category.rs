@@ -6,6 +6,7 @@ pub struct Category {
     pub id: ulid::Ulid,
     pub name: CategoryName,
     pub label: Option<String>,
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
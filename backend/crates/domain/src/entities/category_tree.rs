@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use crate::entities::Category;
+use crate::types::CategoryName;
+
+/// Categories arranged by the segments of their `CategoryName`.
+///
+/// Intermediate segments that have no category of their own (e.g. `utils` when only
+/// `utils/electricity` exists) become group nodes with `category: None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryTree {
+    pub roots: Vec<CategoryTreeNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryTreeNode {
+    /// Full path of this node, e.g. `utils/electricity`
+    pub name: CategoryName,
+    /// Last segment of `name`, e.g. `electricity`
+    pub segment: String,
+    pub category: Option<Category>,
+    /// Child nodes ordered by segment
+    pub children: Vec<CategoryTreeNode>,
+}
+
+impl CategoryTree {
+    pub fn build(categories: Vec<Category>) -> Self {
+        let mut roots = Vec::new();
+        for category in categories {
+            let segments: Vec<String> = category.name.segments().map(str::to_string).collect();
+            insert(&mut roots, &segments, 0, category);
+        }
+        Self { roots }
+    }
+}
+
+fn insert(nodes: &mut Vec<CategoryTreeNode>, segments: &[String], depth: usize, category: Category) {
+    let segment = &segments[depth];
+    let idx = match nodes.binary_search_by(|n| n.segment.as_str().cmp(segment)) {
+        Ok(i) => i,
+        Err(i) => {
+            // Prefixes of a valid name are valid names themselves
+            let name = CategoryName::new(segments[..=depth].join("/"))
+                .expect("prefix of a valid category name");
+            nodes.insert(
+                i,
+                CategoryTreeNode {
+                    name,
+                    segment: segment.clone(),
+                    category: None,
+                    children: Vec::new(),
+                },
+            );
+            i
+        }
+    };
+
+    if depth + 1 == segments.len() {
+        nodes[idx].category = Some(category);
+    } else {
+        insert(&mut nodes[idx].children, segments, depth + 1, category);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(name: &str) -> Category {
+        let now = chrono::Utc::now();
+        Category {
+            id: ulid::Ulid::new(),
+            name: CategoryName::new(name).unwrap(),
+            label: None,
+            archived_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_build_empty() {
+        assert!(CategoryTree::build(vec![]).roots.is_empty());
+    }
+
+    #[test]
+    fn test_build_flat() {
+        let tree = CategoryTree::build(vec![category("rent"), category("food")]);
+        let names: Vec<&str> = tree.roots.iter().map(|n| n.segment.as_str()).collect();
+        assert_eq!(names, vec!["food", "rent"]);
+        assert!(tree.roots.iter().all(|n| n.category.is_some() && n.children.is_empty()));
+    }
+
+    #[test]
+    fn test_build_creates_group_nodes() {
+        let tree = CategoryTree::build(vec![
+            category("utils/gas"),
+            category("utils/electricity"),
+            category("food"),
+        ]);
+        assert_eq!(tree.roots.len(), 2);
+
+        let utils = &tree.roots[1];
+        assert_eq!(utils.name.as_str(), "utils");
+        assert!(utils.category.is_none());
+        let children: Vec<&str> = utils.children.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(children, vec!["utils/electricity", "utils/gas"]);
+    }
+
+    #[test]
+    fn test_build_parent_with_own_category() {
+        // Parent inserted after its child still ends up on the same node
+        let tree = CategoryTree::build(vec![category("utils/gas"), category("utils")]);
+        assert_eq!(tree.roots.len(), 1);
+        assert!(tree.roots[0].category.is_some());
+        assert_eq!(tree.roots[0].children.len(), 1);
+    }
+
+    #[test]
+    fn test_build_deep() {
+        let tree = CategoryTree::build(vec![category("a/b/c")]);
+        let b = &tree.roots[0].children[0];
+        assert_eq!(b.name.as_str(), "a/b");
+        assert_eq!(b.children[0].segment, "c");
+        assert!(b.children[0].category.is_some());
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use crate::types::{ImportProfileName, Money, TransactionDate, TransactionKind};
+
+/// Where a bank's CSV export keeps the fields of a transaction, and how it writes them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CsvMapping {
+    /// Field separator, usually `,` or `;`
+    pub delimiter: char,
+    /// The first line holds column names and is skipped
+    pub has_header: bool,
+    /// Columns are counted from 0
+    pub date_column: usize,
+    pub amount_column: usize,
+    pub description_column: Option<usize>,
+    /// `.` or `,`; the other one, spaces and apostrophes group digits
+    pub decimal_separator: char,
+    /// chrono format of the dates, e.g. `%d.%m.%Y`
+    pub date_format: String,
+}
+
+/// A mapping saved under a name, to reuse for every export of the same bank.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProfile {
+    pub id: ulid::Ulid,
+    pub name: ImportProfileName,
+    pub mapping: CsvMapping,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewImportProfile {
+    pub name: ImportProfileName,
+    pub mapping: CsvMapping,
+}
+
+/// A non-blank row of a CSV file, split into fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord {
+    /// Line of the file the row starts on, from 1
+    pub line: u64,
+    pub fields: Vec<String>,
+}
+
+/// A CSV row read with a mapping.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportRow {
+    /// Line of the file the row starts on, from 1
+    pub line: u64,
+    pub date: TransactionDate,
+    /// Signed as in the file; banks write money going out as negative
+    pub amount: Money,
+    pub description: Option<String>,
+}
+
+/// A CSV row that could not be read with the mapping.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportRowError {
+    pub line: u64,
+    pub reason: String,
+}
+
+/// A CSV file read with a mapping, for checking before anything is booked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportPreview {
+    pub rows: Vec<ImportRow>,
+    pub errors: Vec<ImportRowError>,
+}
+
+/// A previewed row to book on a budget entry of the month of its date.
+#[derive(Debug, Clone)]
+pub struct ImportedTransaction {
+    pub entry_id: ulid::Ulid,
+    pub kind: TransactionKind,
+    pub amount: Money,
+    pub date: TransactionDate,
+    pub title: Option<String>,
+}
@@ -1,9 +1,33 @@
 mod category;
+mod category_tree;
 mod month;
 mod budget_entry;
 mod transaction;
+mod recurring_rule;
+mod tag;
+mod payee;
+mod account;
+mod attachment;
+mod import;
 
 pub use category::{Category, NewCategory};
-pub use month::{Month, NewMonth};
+pub use category_tree::{CategoryTree, CategoryTreeNode};
+pub use month::{Month, NewMonth, MonthSpending};
 pub use budget_entry::{BudgetEntry, NewBudgetEntry, BudgetEntryWithCategory, CategorySummary};
-pub use transaction::{Transaction, NewTransaction, MAX_TITLE_LENGTH};
+pub use transaction::{
+    Transaction, NewTransaction, TransactionUpdate, TransactionSplit, NewTransactionSplit,
+    TransactionSplitUpdate, SplitAllocation, TransactionMatch, TransactionFilter, TransactionPage,
+    TransactionScope, TransactionCursorPage, MAX_TITLE_LENGTH,
+};
+pub use recurring_rule::{RecurringRule, NewRecurringRule};
+pub use tag::{Tag, NewTag};
+pub use payee::{Payee, NewPayee};
+pub use account::{
+    Account, NewAccount, Transfer, NewTransfer, TransferUpdate, AccountActivity,
+    AccountActivityKind,
+};
+pub use attachment::{Attachment, NewAttachment, MAX_FILE_NAME_LENGTH};
+pub use import::{
+    CsvMapping, CsvRecord, ImportProfile, NewImportProfile, ImportRow, ImportRowError, ImportPreview,
+    ImportedTransaction,
+};
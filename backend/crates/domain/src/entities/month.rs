@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::types::BudgetMonth;
+use crate::types::{BudgetMonth, Money};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Month {
@@ -13,3 +13,13 @@ pub struct Month {
 pub struct NewMonth {
     pub month: BudgetMonth,
 }
+
+/// Spending on expense entries within one month, net of refunds, of the
+/// transactions a report selects (e.g. those with one tag).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthSpending {
+    pub month_id: ulid::Ulid,
+    pub month: BudgetMonth,
+    pub spent: Money,
+    pub transaction_count: i64,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use crate::types::PayeeName;
+
+/// Shop, company or person paid, e.g. `Lidl`.
+///
+/// Aliases are other spellings found in titles or bank statements, e.g.
+/// `LIDL sp. z o.o.`. The default category preselects the entry for new
+/// payments to the payee.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Payee {
+    pub id: ulid::Ulid,
+    pub name: PayeeName,
+    pub default_category_id: Option<ulid::Ulid>,
+    /// Ordered by name
+    pub aliases: Vec<PayeeName>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewPayee {
+    pub name: PayeeName,
+    pub default_category_id: Option<ulid::Ulid>,
+    pub aliases: Vec<PayeeName>,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use crate::types::{BudgetMonth, DueDay, Money};
+
+/// A bill or other payment that repeats every month.
+///
+/// For each month from `start_month` through `end_month` (open-ended when unset) the
+/// rule books one payment on the month's entry for `category_id`, dated on
+/// `day_of_month`. Paused rules book nothing until resumed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringRule {
+    pub id: ulid::Ulid,
+    pub category_id: ulid::Ulid,
+    pub amount: Money,
+    pub title: Option<String>,
+    pub day_of_month: DueDay,
+    pub start_month: BudgetMonth,
+    pub end_month: Option<BudgetMonth>,
+    pub paused: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewRecurringRule {
+    pub category_id: ulid::Ulid,
+    pub amount: Money,
+    pub title: Option<String>,
+    pub day_of_month: DueDay,
+    pub start_month: BudgetMonth,
+    pub end_month: Option<BudgetMonth>,
+}
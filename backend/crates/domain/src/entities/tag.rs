@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use crate::types::TagName;
+
+/// Label cutting across categories, e.g. `vacation-2026` or `reimbursable`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: ulid::Ulid,
+    pub name: TagName,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTag {
+    pub name: TagName,
+}
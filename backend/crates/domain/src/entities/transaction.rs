@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use crate::types::{Money, TransactionDate};
+use crate::types::{
+    BudgetMonth, CategoryName, Money, TagName, TransactionCursor, TransactionDate,
+    TransactionKind, TransactionSort,
+};
 
 /// Maximum length for transaction title
 pub const MAX_TITLE_LENGTH: usize = 50;
@@ -8,9 +11,19 @@ pub const MAX_TITLE_LENGTH: usize = 50;
 pub struct Transaction {
     pub id: ulid::Ulid,
     pub entry_id: ulid::Ulid,
+    pub kind: TransactionKind,
     pub amount: Money,
     pub date: TransactionDate,
     pub title: Option<String>,
+    /// Payment this refund returns money from
+    pub refund_of: Option<ulid::Ulid>,
+    /// Split payment this transaction is an allocation of
+    pub split_id: Option<ulid::Ulid>,
+    pub payee_id: Option<ulid::Ulid>,
+    /// Account the money was paid from or into
+    pub account_id: Option<ulid::Ulid>,
+    /// Sorted by name
+    pub tags: Vec<TagName>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -18,7 +31,136 @@ pub struct Transaction {
 #[derive(Debug, Clone)]
 pub struct NewTransaction {
     pub entry_id: ulid::Ulid,
+    pub kind: TransactionKind,
     pub amount: Money,
     pub date: TransactionDate,
     pub title: Option<String>,
+    pub refund_of: Option<ulid::Ulid>,
+    pub payee_id: Option<ulid::Ulid>,
+    pub account_id: Option<ulid::Ulid>,
+    /// Must name existing tags
+    pub tags: Vec<TagName>,
+}
+
+/// A transaction found by a search, with the month and category it was booked in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionMatch {
+    pub transaction: Transaction,
+    pub month_id: ulid::Ulid,
+    pub month: BudgetMonth,
+    pub category_id: ulid::Ulid,
+    pub category_name: CategoryName,
+}
+
+/// Which transactions to list across all months; `None` fields do not filter.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    /// First day, inclusive
+    pub from: Option<TransactionDate>,
+    /// Last day, inclusive
+    pub to: Option<TransactionDate>,
+    /// Smallest booked amount, inclusive. Refunds are compared by their own
+    /// positive amount, although they count against `TransactionPage::total_amount`
+    pub min_amount: Option<Money>,
+    /// Largest booked amount, inclusive; refunds as for `min_amount`
+    pub max_amount: Option<Money>,
+    /// Booked on this category or any category below it
+    pub category: Option<CategoryName>,
+    /// Title contains this text, in either case
+    pub title: Option<String>,
+    pub tag: Option<TagName>,
+    pub sort: TransactionSort,
+}
+
+/// One page of a filtered transaction list, with totals over all matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionPage {
+    pub items: Vec<TransactionMatch>,
+    /// Matching transactions on all pages
+    pub total_count: i64,
+    /// Payments minus refunds of all matching transactions
+    pub total_amount: Money,
+}
+
+/// Which transactions a cursor-paged list goes through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionScope {
+    Entry(ulid::Ulid),
+    /// All entries of the month
+    Month(ulid::Ulid),
+}
+
+/// One page of a newest-first transaction list, with cursors to the pages
+/// around it. A cursor is `None` when there is no page on that side.
+#[derive(Debug, Clone)]
+pub struct TransactionCursorPage {
+    pub items: Vec<Transaction>,
+    /// Older transactions
+    pub next_cursor: Option<TransactionCursor>,
+    /// Newer transactions
+    pub prev_cursor: Option<TransactionCursor>,
+}
+
+/// Changes to a transaction; `None` leaves a field as it is.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionUpdate {
+    pub entry_id: Option<ulid::Ulid>,
+    pub amount: Option<Money>,
+    pub date: Option<TransactionDate>,
+    /// `Some(None)` clears the title
+    pub title: Option<Option<String>>,
+    /// `Some(None)` clears the payee
+    pub payee_id: Option<Option<ulid::Ulid>>,
+    /// `Some(None)` clears the account
+    pub account_id: Option<Option<ulid::Ulid>>,
+    /// Replaces all tags
+    pub tags: Option<Vec<TagName>>,
+}
+
+/// One payment spread over several budget entries of the same month.
+///
+/// Each allocation is a regular payment transaction on its entry, sharing the
+/// split's date, title, payee and account; their amounts add up to the split's amount.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSplit {
+    pub id: ulid::Ulid,
+    pub amount: Money,
+    pub date: TransactionDate,
+    pub title: Option<String>,
+    pub payee_id: Option<ulid::Ulid>,
+    pub account_id: Option<ulid::Ulid>,
+    pub allocations: Vec<Transaction>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTransactionSplit {
+    pub amount: Money,
+    pub date: TransactionDate,
+    pub title: Option<String>,
+    pub payee_id: Option<ulid::Ulid>,
+    pub account_id: Option<ulid::Ulid>,
+    pub allocations: Vec<SplitAllocation>,
+}
+
+/// Changes to a split payment; `None` leaves a field as it is.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionSplitUpdate {
+    pub amount: Option<Money>,
+    pub date: Option<TransactionDate>,
+    /// `Some(None)` clears the title
+    pub title: Option<Option<String>>,
+    /// `Some(None)` clears the payee
+    pub payee_id: Option<Option<ulid::Ulid>>,
+    /// `Some(None)` clears the account
+    pub account_id: Option<Option<ulid::Ulid>>,
+    /// Replaces all allocations
+    pub allocations: Option<Vec<SplitAllocation>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitAllocation {
+    pub entry_id: ulid::Ulid,
+    pub amount: Money,
 }
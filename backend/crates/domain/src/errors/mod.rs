@@ -10,6 +10,28 @@ pub enum DomainError {
     InvalidCategoryName { reason: String },
     #[error("Invalid transaction date: {reason}")]
     InvalidTransactionDate { reason: String },
+    #[error("Invalid entry kind: {value}")]
+    InvalidEntryKind { value: String },
+    #[error("Invalid transaction kind: {value}")]
+    InvalidTransactionKind { value: String },
+    #[error("Invalid rollover mode: {value}")]
+    InvalidRolloverMode { value: String },
+    #[error("Invalid tag name: {reason}")]
+    InvalidTagName { reason: String },
+    #[error("Invalid payee name: {reason}")]
+    InvalidPayeeName { reason: String },
+    #[error("Invalid account name: {reason}")]
+    InvalidAccountName { reason: String },
+    #[error("Invalid account kind: {value}")]
+    InvalidAccountKind { value: String },
+    #[error("Invalid search query: {reason}")]
+    InvalidSearchQuery { reason: String },
+    #[error("Invalid transaction sort: {value}")]
+    InvalidTransactionSort { value: String },
+    #[error("Invalid transaction cursor")]
+    InvalidTransactionCursor,
+    #[error("Invalid import profile name: {reason}")]
+    InvalidImportProfileName { reason: String },
 }
 
 #[derive(Debug, Error)]
@@ -20,6 +42,14 @@ pub enum CategoryError {
     NameAlreadyExists { name: String },
     #[error("Invalid category name format: {reason}")]
     InvalidNameFormat { reason: String },
+    #[error("Category has budget entries")]
+    HasEntries { entry_count: i64 },
+    #[error("Category has recurring rules")]
+    HasRecurringRules { rule_count: i64 },
+    #[error("Cannot merge a category into itself")]
+    MergeIntoSelf,
+    #[error("Cannot merge an income entry with an expense entry in {month}")]
+    MergeKindMismatch { month: String },
     #[error("Repository error: {0}")]
     Repository(String),
 }
@@ -32,6 +62,12 @@ pub enum MonthError {
     InvalidFormat { value: String },
     #[error("Month not found")]
     NotFound,
+    #[error("Month has transactions")]
+    HasTransactions { transaction_count: i64 },
+    #[error("Cannot copy entries of a month into itself")]
+    CopyFromSelf,
+    #[error("Invalid month range: {from} is after {to}")]
+    InvalidRange { from: String, to: String },
     #[error("Repository error: {0}")]
     Repository(String),
 }
@@ -46,6 +82,10 @@ pub enum EntryError {
     HasTransactions { transaction_count: i64 },
     #[error("Invalid due day: {value}")]
     InvalidDueDay { value: u8 },
+    #[error("Invalid entry kind: {value}")]
+    InvalidKind { value: String },
+    #[error("Invalid rollover mode: {value}")]
+    InvalidRollover { value: String },
     #[error("Category not found")]
     CategoryNotFound,
     #[error("Month not found")]
@@ -66,6 +106,219 @@ pub enum TransactionError {
     InvalidDate { value: String },
     #[error("Title too long: {length} characters (max {max})")]
     TitleTooLong { length: usize, max: usize },
+    #[error("Invalid transaction kind: {value}")]
+    InvalidKind { value: String },
+    #[error("Refunded transaction not found")]
+    RefundOriginalNotFound,
+    #[error("Invalid refund: {reason}")]
+    InvalidRefund { reason: String },
+    #[error("Refunds of {refunded} exceed the original amount of {original_amount}")]
+    RefundExceedsOriginal { original_amount: i64, refunded: i64 },
+    #[error("Split payment not found")]
+    SplitNotFound,
+    #[error("Invalid split: {reason}")]
+    InvalidSplit { reason: String },
+    #[error("Allocations of {allocated} do not add up to the split amount of {total}")]
+    SplitTotalMismatch { total: i64, allocated: i64 },
+    #[error("Transaction is an allocation of split payment {split_id}")]
+    PartOfSplit { split_id: String },
+    #[error("Entry is already paid: {paid} of {available}")]
+    EntryAlreadyPaid { available: i64, paid: i64 },
+    #[error("Entry has no due day")]
+    EntryHasNoDueDay,
+    #[error("Income entries are received, not paid")]
+    EntryIsIncome,
+    #[error("Date {date} is outside the entry's month {month}")]
+    DateOutsideMonth { date: String, month: String },
+    #[error("Unknown tag: {name}")]
+    UnknownTag { name: String },
+    #[error("Payee not found")]
+    PayeeNotFound,
+    #[error("Account not found")]
+    AccountNotFound,
+    #[error("Invalid search query: {reason}")]
+    InvalidSearchQuery { reason: String },
+    #[error("Invalid filter: {reason}")]
+    InvalidFilter { reason: String },
+    #[error("Invalid page cursor")]
+    InvalidCursor,
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+#[derive(Debug, Error)]
+pub enum TagError {
+    #[error("Tag not found")]
+    NotFound,
+    #[error("Tag name already exists: {name}")]
+    NameAlreadyExists { name: String },
+    #[error("Invalid tag name: {reason}")]
+    InvalidName { reason: String },
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+#[derive(Debug, Error)]
+pub enum PayeeError {
+    #[error("Payee not found")]
+    NotFound,
+    #[error("Payee name or alias already exists: {name}")]
+    NameAlreadyExists { name: String },
+    #[error("Invalid payee name: {reason}")]
+    InvalidName { reason: String },
+    #[error("Default category not found")]
+    CategoryNotFound,
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+#[derive(Debug, Error)]
+pub enum AccountError {
+    #[error("Account not found")]
+    NotFound,
+    #[error("Account name already exists: {name}")]
+    NameAlreadyExists { name: String },
+    #[error("Invalid account name: {reason}")]
+    InvalidName { reason: String },
+    #[error("Invalid account kind: {value}")]
+    InvalidKind { value: String },
+    #[error("Account has transfers")]
+    HasTransfers { transfer_count: i64 },
+    #[error("Transfer not found")]
+    TransferNotFound,
+    #[error("Invalid amount: {value}")]
+    InvalidAmount { value: i64 },
+    #[error("Invalid date: {value}")]
+    InvalidDate { value: String },
+    #[error("Title too long: {length} characters (max {max})")]
+    TitleTooLong { length: usize, max: usize },
+    #[error("Cannot transfer from an account to itself")]
+    TransferToSelf,
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+#[derive(Debug, Error)]
+pub enum AttachmentError {
+    #[error("Attachment not found")]
+    NotFound,
+    #[error("Transaction not found")]
+    TransactionNotFound,
+    #[error("File is empty")]
+    EmptyFile,
+    #[error("File too large: {size} bytes (max {max})")]
+    FileTooLarge { size: usize, max: usize },
+    #[error("Invalid file name: {reason}")]
+    InvalidFileName { reason: String },
+    #[error("Invalid content type: {value}")]
+    InvalidContentType { value: String },
+    #[error("Storage error: {0}")]
+    Storage(String),
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("Import profile not found")]
+    ProfileNotFound,
+    #[error("Import profile name already exists: {name}")]
+    ProfileNameAlreadyExists { name: String },
+    #[error("Invalid import profile name: {reason}")]
+    InvalidProfileName { reason: String },
+    #[error("Invalid column mapping: {reason}")]
+    InvalidMapping { reason: String },
+    #[error("Invalid CSV file: {reason}")]
+    InvalidFile { reason: String },
+    #[error("Invalid row {row}: {reason}")]
+    InvalidRow { row: usize, reason: String },
+    #[error("Budget entry of row {row} not found")]
+    EntryNotFound { row: usize },
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+#[derive(Debug, Error)]
+pub enum RecurringError {
+    #[error("Recurring rule not found")]
+    NotFound,
+    #[error("Category not found")]
+    CategoryNotFound,
+    #[error("Invalid amount: {value}")]
+    InvalidAmount { value: i64 },
+    #[error("Invalid month format: {value}")]
+    InvalidMonth { value: String },
+    #[error("Invalid day of month: {value}")]
+    InvalidDay { value: u8 },
+    #[error("Invalid rule range: {start} is after {end}")]
+    InvalidRange { start: String, end: String },
+    #[error("Title too long: {length} characters (max {max})")]
+    TitleTooLong { length: usize, max: usize },
     #[error("Repository error: {0}")]
     Repository(String),
 }
+
+/// Failure to begin or commit a unit of work.
+#[derive(Debug, Error)]
+#[error("Unit of work error: {0}")]
+pub struct UnitOfWorkError(pub String);
+
+impl From<UnitOfWorkError> for CategoryError {
+    fn from(e: UnitOfWorkError) -> Self {
+        CategoryError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for MonthError {
+    fn from(e: UnitOfWorkError) -> Self {
+        MonthError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for EntryError {
+    fn from(e: UnitOfWorkError) -> Self {
+        EntryError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for TransactionError {
+    fn from(e: UnitOfWorkError) -> Self {
+        TransactionError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for RecurringError {
+    fn from(e: UnitOfWorkError) -> Self {
+        RecurringError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for TagError {
+    fn from(e: UnitOfWorkError) -> Self {
+        TagError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for PayeeError {
+    fn from(e: UnitOfWorkError) -> Self {
+        PayeeError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for AccountError {
+    fn from(e: UnitOfWorkError) -> Self {
+        AccountError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for AttachmentError {
+    fn from(e: UnitOfWorkError) -> Self {
+        AttachmentError::Repository(e.0)
+    }
+}
+
+impl From<UnitOfWorkError> for ImportError {
+    fn from(e: UnitOfWorkError) -> Self {
+        ImportError::Repository(e.0)
+    }
+}
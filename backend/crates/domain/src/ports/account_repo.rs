@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use crate::entities::{Account, AccountActivity, NewAccount, NewTransfer, Transfer, TransferUpdate};
+use crate::errors::AccountError;
+use crate::types::{AccountKind, AccountName, Money};
+
+#[async_trait]
+pub trait AccountRepository: Send + Sync {
+    async fn list_all(&self) -> Result<Vec<Account>, AccountError>;
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Account>, AccountError>;
+    async fn create(&self, account: NewAccount) -> Result<Account, AccountError>;
+    async fn update(&self, id: &ulid::Ulid, name: Option<AccountName>, kind: Option<AccountKind>, opening_balance: Option<Money>) -> Result<Account, AccountError>;
+    /// Deletes the account; its transactions are kept without an account.
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), AccountError>;
+    /// Number of transfers from or to the account.
+    async fn transfer_count(&self, id: &ulid::Ulid) -> Result<i64, AccountError>;
+    /// Transactions and transfers of the account, oldest first.
+    async fn activity(&self, id: &ulid::Ulid) -> Result<Vec<AccountActivity>, AccountError>;
+    /// All transfers, newest first.
+    async fn list_transfers(&self) -> Result<Vec<Transfer>, AccountError>;
+    async fn find_transfer(&self, id: &ulid::Ulid) -> Result<Option<Transfer>, AccountError>;
+    async fn create_transfer(&self, transfer: NewTransfer) -> Result<Transfer, AccountError>;
+    async fn update_transfer(&self, id: &ulid::Ulid, changes: TransferUpdate) -> Result<Transfer, AccountError>;
+    async fn delete_transfer(&self, id: &ulid::Ulid) -> Result<(), AccountError>;
+}
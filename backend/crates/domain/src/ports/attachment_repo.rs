@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use crate::entities::{Attachment, NewAttachment};
+use crate::errors::AttachmentError;
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Attachments of the transaction, oldest first.
+    async fn list_by_transaction(&self, transaction_id: &ulid::Ulid) -> Result<Vec<Attachment>, AttachmentError>;
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Attachment>, AttachmentError>;
+    /// Fails with `TransactionNotFound` if the transaction does not exist.
+    ///
+    /// Takes the file off the purge queue in the same transaction, so a purge
+    /// that has not started yet keeps it.
+    async fn create(&self, attachment: NewAttachment) -> Result<Attachment, AttachmentError>;
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), AttachmentError>;
+    /// Hashes of files whose attachments were deleted, directly or with their
+    /// transactions, and that no remaining attachment uses.
+    async fn unreferenced_files(&self) -> Result<Vec<String>, AttachmentError>;
+    /// Takes the file `sha256` off the purge queue unless an attachment uses
+    /// it again, returning whether it did. Removing the file belongs in the
+    /// same unit of work, which keeps uploads of the same contents waiting
+    /// until it is done.
+    async fn forget_unreferenced_file(&self, sha256: &str) -> Result<bool, AttachmentError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::errors::AttachmentError;
+
+/// Where the contents of attachments are kept, by key.
+#[async_trait]
+pub trait AttachmentStore: Send + Sync {
+    /// Stores `contents` under `key`; storing the same key again keeps the first copy.
+    async fn put(&self, key: &str, contents: &[u8]) -> Result<(), AttachmentError>;
+    /// Fails with `Storage` if nothing is stored under `key`.
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AttachmentError>;
+    /// Removes `key`; removing a missing key succeeds.
+    async fn delete(&self, key: &str) -> Result<(), AttachmentError>;
+}
@@ -5,9 +5,20 @@ use crate::types::CategoryName;
 
 #[async_trait]
 pub trait CategoryRepository: Send + Sync {
-    async fn list_all(&self) -> Result<Vec<Category>, CategoryError>;
+    async fn list_all(&self, include_archived: bool) -> Result<Vec<Category>, CategoryError>;
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Category>, CategoryError>;
     async fn create(&self, category: NewCategory) -> Result<Category, CategoryError>;
     async fn update_name(&self, id: &ulid::Ulid, name: CategoryName) -> Result<Category, CategoryError>;
     async fn update(&self, id: &ulid::Ulid, name: Option<CategoryName>, label: Option<Option<String>>) -> Result<Category, CategoryError>;
+    async fn set_archived(&self, id: &ulid::Ulid, archived: bool) -> Result<Category, CategoryError>;
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), CategoryError>;
+    async fn entry_count(&self, category_id: &ulid::Ulid) -> Result<i64, CategoryError>;
+    async fn recurring_rule_count(&self, category_id: &ulid::Ulid) -> Result<i64, CategoryError>;
+    /// Moves all budget entries (and so their transactions), recurring rules and payee
+    /// defaults of `source_id` to `target_id` and deletes the source category, atomically.
+    /// Returns the target category.
+    async fn merge(&self, source_id: &ulid::Ulid, target_id: &ulid::Ulid) -> Result<Category, CategoryError>;
+    /// Replaces the `from` prefix with `to` on `from` itself and every `from/...` name,
+    /// atomically. Returns the renamed categories ordered by new name.
+    async fn rename_prefix(&self, from: &CategoryName, to: &CategoryName) -> Result<Vec<Category>, CategoryError>;
 }
@@ -1,14 +1,24 @@
+use std::collections::HashMap;
 use async_trait::async_trait;
 use crate::entities::{BudgetEntry, BudgetEntryWithCategory, NewBudgetEntry};
 use crate::errors::EntryError;
-use crate::types::{Money, DueDay};
+use crate::types::{BudgetMonth, Money, DueDay, RolloverMode};
 
 #[async_trait]
 pub trait BudgetEntryRepository: Send + Sync {
     async fn list_by_month(&self, month_id: &ulid::Ulid) -> Result<Vec<BudgetEntryWithCategory>, EntryError>;
+    /// Entries of every month from `from` to `to` inclusive, in one query, keyed by
+    /// month id and ordered as by `list_by_month`. Months without entries are absent.
+    async fn list_by_month_range(
+        &self,
+        from: &BudgetMonth,
+        to: &BudgetMonth,
+    ) -> Result<HashMap<ulid::Ulid, Vec<BudgetEntryWithCategory>>, EntryError>;
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<BudgetEntry>, EntryError>;
+    /// Entries of the category across all months, in no particular order.
+    async fn list_by_category(&self, category_id: &ulid::Ulid) -> Result<Vec<BudgetEntry>, EntryError>;
     async fn create(&self, entry: NewBudgetEntry) -> Result<BudgetEntryWithCategory, EntryError>;
-    async fn update(&self, id: &ulid::Ulid, budgeted: Option<Money>, due_day: Option<Option<DueDay>>) -> Result<BudgetEntryWithCategory, EntryError>;
+    async fn update(&self, id: &ulid::Ulid, budgeted: Option<Money>, due_day: Option<Option<DueDay>>, rollover: Option<RolloverMode>) -> Result<BudgetEntryWithCategory, EntryError>;
     async fn delete(&self, id: &ulid::Ulid) -> Result<(), EntryError>;
     async fn transaction_count(&self, entry_id: &ulid::Ulid) -> Result<i64, EntryError>;
 }
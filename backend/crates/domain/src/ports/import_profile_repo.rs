@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::entities::{CsvMapping, ImportProfile, NewImportProfile};
+use crate::errors::ImportError;
+use crate::types::ImportProfileName;
+
+#[async_trait]
+pub trait ImportProfileRepository: Send + Sync {
+    async fn list_all(&self) -> Result<Vec<ImportProfile>, ImportError>;
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<ImportProfile>, ImportError>;
+    /// Fails with `ProfileNameAlreadyExists` if another profile has the name.
+    async fn create(&self, profile: NewImportProfile) -> Result<ImportProfile, ImportError>;
+    /// `mapping` replaces the whole mapping.
+    async fn update(&self, id: &ulid::Ulid, name: Option<ImportProfileName>, mapping: Option<CsvMapping>) -> Result<ImportProfile, ImportError>;
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), ImportError>;
+}
@@ -2,8 +2,24 @@ mod category_repo;
 mod month_repo;
 mod entry_repo;
 mod transaction_repo;
+mod recurring_repo;
+mod tag_repo;
+mod payee_repo;
+mod account_repo;
+mod attachment_repo;
+mod attachment_store;
+mod import_profile_repo;
+mod unit_of_work;
 
 pub use category_repo::CategoryRepository;
 pub use month_repo::MonthRepository;
 pub use entry_repo::BudgetEntryRepository;
 pub use transaction_repo::TransactionRepository;
+pub use recurring_repo::RecurringRuleRepository;
+pub use tag_repo::TagRepository;
+pub use payee_repo::PayeeRepository;
+pub use account_repo::AccountRepository;
+pub use attachment_repo::AttachmentRepository;
+pub use attachment_store::AttachmentStore;
+pub use import_profile_repo::ImportProfileRepository;
+pub use unit_of_work::{UnitOfWork, UnitOfWorkFactory};
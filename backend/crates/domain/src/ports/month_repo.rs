@@ -8,7 +8,14 @@ pub trait MonthRepository: Send + Sync {
     async fn list_all(&self) -> Result<Vec<Month>, MonthError>;
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Month>, MonthError>;
     async fn find_by_month(&self, month: &BudgetMonth) -> Result<Option<Month>, MonthError>;
+    /// Months from `from` to `to` inclusive, oldest first.
+    async fn list_in_range(&self, from: &BudgetMonth, to: &BudgetMonth) -> Result<Vec<Month>, MonthError>;
     async fn create(&self, month: NewMonth) -> Result<Month, MonthError>;
     async fn find_latest(&self) -> Result<Option<Month>, MonthError>;
     async fn find_latest_excluding(&self, exclude_id: &ulid::Ulid) -> Result<Option<Month>, MonthError>;
+    async fn transaction_count(&self, month_id: &ulid::Ulid) -> Result<i64, MonthError>;
+    /// Deletes the month together with its entries and their transactions.
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), MonthError>;
+    /// Deletes all entries of the month together with their transactions.
+    async fn delete_entries(&self, month_id: &ulid::Ulid) -> Result<(), MonthError>;
 }
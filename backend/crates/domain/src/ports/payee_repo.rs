@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use crate::entities::{MonthSpending, NewPayee, Payee};
+use crate::errors::PayeeError;
+use crate::types::PayeeName;
+
+#[async_trait]
+pub trait PayeeRepository: Send + Sync {
+    async fn list_all(&self) -> Result<Vec<Payee>, PayeeError>;
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Payee>, PayeeError>;
+    /// Payee whose name or one of whose aliases has the key `PayeeName::key`.
+    async fn find_by_key(&self, key: &str) -> Result<Option<Payee>, PayeeError>;
+    /// Fails with `NameAlreadyExists` if the name or an alias is taken by another payee.
+    async fn create(&self, payee: NewPayee) -> Result<Payee, PayeeError>;
+    /// `aliases` replaces all aliases of the payee.
+    async fn update(&self, id: &ulid::Ulid, name: Option<PayeeName>, default_category_id: Option<Option<ulid::Ulid>>, aliases: Option<Vec<PayeeName>>) -> Result<Payee, PayeeError>;
+    /// Deletes the payee; its transactions are kept without a payee.
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), PayeeError>;
+    /// Spending on expense entries per month with payments to the payee, oldest first.
+    async fn spending_by_month(&self, id: &ulid::Ulid) -> Result<Vec<MonthSpending>, PayeeError>;
+}
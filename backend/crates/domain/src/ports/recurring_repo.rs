@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use crate::entities::{NewRecurringRule, RecurringRule};
+use crate::errors::RecurringError;
+use crate::types::{BudgetMonth, DueDay, Money};
+
+#[async_trait]
+pub trait RecurringRuleRepository: Send + Sync {
+    async fn list_all(&self) -> Result<Vec<RecurringRule>, RecurringError>;
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<RecurringRule>, RecurringError>;
+    async fn create(&self, rule: NewRecurringRule) -> Result<RecurringRule, RecurringError>;
+    async fn update(
+        &self,
+        id: &ulid::Ulid,
+        amount: Option<Money>,
+        title: Option<Option<String>>,
+        day_of_month: Option<DueDay>,
+        start_month: Option<BudgetMonth>,
+        end_month: Option<Option<BudgetMonth>>,
+    ) -> Result<RecurringRule, RecurringError>;
+    async fn set_paused(&self, id: &ulid::Ulid, paused: bool) -> Result<RecurringRule, RecurringError>;
+    /// Deletes the rule. Transactions it already booked are kept.
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), RecurringError>;
+    /// Rules that have not booked a transaction in the month yet, paused ones included.
+    async fn list_unapplied(&self, month_id: &ulid::Ulid) -> Result<Vec<RecurringRule>, RecurringError>;
+    /// Records that the rule booked `transaction_id` in the month, so it is not booked twice.
+    async fn record_run(
+        &self,
+        rule_id: &ulid::Ulid,
+        month_id: &ulid::Ulid,
+        transaction_id: &ulid::Ulid,
+    ) -> Result<(), RecurringError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::entities::{MonthSpending, NewTag, Tag};
+use crate::errors::TagError;
+use crate::types::TagName;
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    async fn list_all(&self) -> Result<Vec<Tag>, TagError>;
+    async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Tag>, TagError>;
+    /// Tags with any of `names`; names without a tag are simply absent.
+    async fn find_by_names(&self, names: &[TagName]) -> Result<Vec<Tag>, TagError>;
+    async fn create(&self, tag: NewTag) -> Result<Tag, TagError>;
+    async fn rename(&self, id: &ulid::Ulid, name: TagName) -> Result<Tag, TagError>;
+    /// Deletes the tag and removes it from all transactions.
+    async fn delete(&self, id: &ulid::Ulid) -> Result<(), TagError>;
+    /// Spending on expense entries per month with tagged transactions, oldest first.
+    async fn spending_by_month(&self, id: &ulid::Ulid) -> Result<Vec<MonthSpending>, TagError>;
+}
@@ -1,15 +1,55 @@
+use std::collections::HashMap;
 use async_trait::async_trait;
-use crate::entities::{Transaction, NewTransaction};
+use crate::entities::{
+    NewTransaction, NewTransactionSplit, Transaction, TransactionFilter, TransactionMatch,
+    TransactionPage, TransactionScope, TransactionSplit, TransactionSplitUpdate, TransactionUpdate,
+};
 use crate::errors::TransactionError;
-use crate::types::{Money, TransactionDate};
+use crate::types::{BudgetMonth, Money, SearchQuery, TagName, TransactionCursor};
 
 #[async_trait]
 pub trait TransactionRepository: Send + Sync {
-    async fn list_by_month(&self, month_id: &ulid::Ulid) -> Result<Vec<Transaction>, TransactionError>;
+    /// Transactions of the month, newest first; only those tagged `tag` if given.
+    async fn list_by_month(&self, month_id: &ulid::Ulid, tag: Option<&TagName>) -> Result<Vec<Transaction>, TransactionError>;
     async fn find_by_id(&self, id: &ulid::Ulid) -> Result<Option<Transaction>, TransactionError>;
     async fn create(&self, transaction: NewTransaction) -> Result<Transaction, TransactionError>;
-    async fn update(&self, id: &ulid::Ulid, entry_id: Option<ulid::Ulid>, amount: Option<Money>, date: Option<TransactionDate>, title: Option<Option<String>>) -> Result<Transaction, TransactionError>;
+    async fn update(&self, id: &ulid::Ulid, changes: TransactionUpdate) -> Result<Transaction, TransactionError>;
     async fn delete(&self, id: &ulid::Ulid) -> Result<(), TransactionError>;
     async fn sum_by_entry(&self, entry_id: &ulid::Ulid) -> Result<Money, TransactionError>;
-    async fn list_by_entry(&self, entry_id: &ulid::Ulid, limit: u32, offset: u32) -> Result<Vec<Transaction>, TransactionError>;
+    /// Paid totals of every entry in the month that has transactions, in one query.
+    /// Entries without transactions are absent from the map.
+    async fn sum_by_month(&self, month_id: &ulid::Ulid) -> Result<HashMap<ulid::Ulid, Money>, TransactionError>;
+    /// Paid totals of every entry of the category across all months, in one query.
+    /// Entries without transactions are absent from the map.
+    async fn sum_by_category(&self, category_id: &ulid::Ulid) -> Result<HashMap<ulid::Ulid, Money>, TransactionError>;
+    /// Paid totals of every entry in the months from `from` to `to` inclusive, in one
+    /// query. Entries without transactions are absent from the map.
+    async fn sum_by_month_range(
+        &self,
+        from: &BudgetMonth,
+        to: &BudgetMonth,
+    ) -> Result<HashMap<ulid::Ulid, Money>, TransactionError>;
+    /// Sum of the refunds linked to `original_id`, leaving out the refund `excluding`.
+    async fn refunded_total(&self, original_id: &ulid::Ulid, excluding: Option<&ulid::Ulid>) -> Result<Money, TransactionError>;
+    /// Inserts the split and one payment per allocation.
+    async fn create_split(&self, split: NewTransactionSplit) -> Result<TransactionSplit, TransactionError>;
+    async fn find_split(&self, id: &ulid::Ulid) -> Result<Option<TransactionSplit>, TransactionError>;
+    /// Date, title, payee and account changes carry over to the allocations;
+    /// `allocations` replaces them all.
+    async fn update_split(&self, id: &ulid::Ulid, changes: TransactionSplitUpdate) -> Result<TransactionSplit, TransactionError>;
+    /// Deletes the split together with its allocations.
+    async fn delete_split(&self, id: &ulid::Ulid) -> Result<(), TransactionError>;
+    /// One page of the entry's transactions, only those tagged `tag` if given.
+    async fn list_by_entry(&self, entry_id: &ulid::Ulid, tag: Option<&TagName>, limit: u32, offset: u32) -> Result<Vec<Transaction>, TransactionError>;
+    /// Up to `limit` transactions of `scope` on the cursor's side of its position,
+    /// the first ones if there is no cursor; only those tagged `tag` if given.
+    /// Returned newest first, whichever way the cursor pages.
+    async fn list_by_cursor(&self, scope: &TransactionScope, tag: Option<&TagName>, cursor: Option<&TransactionCursor>, limit: u32) -> Result<Vec<Transaction>, TransactionError>;
+    /// Transactions tagged `tag` across all months, newest first.
+    async fn list_by_tag(&self, tag: &TagName) -> Result<Vec<Transaction>, TransactionError>;
+    /// Transactions across all months whose title has a word starting with each
+    /// term of `query`, best matches first.
+    async fn search(&self, query: &SearchQuery, limit: u32) -> Result<Vec<TransactionMatch>, TransactionError>;
+    /// One page of the transactions matching `filter` in its sort order, with totals over all matches.
+    async fn list_filtered(&self, filter: &TransactionFilter, limit: u32, offset: u32) -> Result<TransactionPage, TransactionError>;
 }
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use crate::errors::UnitOfWorkError;
+use crate::ports::{
+    AccountRepository, AttachmentRepository, BudgetEntryRepository, CategoryRepository, MonthRepository,
+    PayeeRepository, RecurringRuleRepository, TagRepository, TransactionRepository,
+};
+
+/// Starts units of work. Held by services that need several writes to succeed or fail together.
+#[async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, UnitOfWorkError>;
+}
+
+/// Repositories sharing one database transaction.
+///
+/// Writes made through them are visible to each other but to nobody else until
+/// `commit`. Dropping the unit of work without committing rolls them back, so a
+/// service can simply return early with `?` on any error.
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn categories(&self) -> &dyn CategoryRepository;
+    fn months(&self) -> &dyn MonthRepository;
+    fn entries(&self) -> &dyn BudgetEntryRepository;
+    fn transactions(&self) -> &dyn TransactionRepository;
+    fn recurring(&self) -> &dyn RecurringRuleRepository;
+    fn tags(&self) -> &dyn TagRepository;
+    fn payees(&self) -> &dyn PayeeRepository;
+    fn accounts(&self) -> &dyn AccountRepository;
+    fn attachments(&self) -> &dyn AttachmentRepository;
+    async fn commit(self: Box<Self>) -> Result<(), UnitOfWorkError>;
+}
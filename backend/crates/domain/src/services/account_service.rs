@@ -0,0 +1,333 @@
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::entities::{
+    Account, AccountActivity, NewAccount, NewTransfer, Transfer, TransferUpdate, MAX_TITLE_LENGTH,
+};
+use crate::errors::AccountError;
+use crate::ports::AccountRepository;
+use crate::services::transaction_service::normalize_title;
+use crate::types::{AccountKind, AccountName, Money};
+
+/// Account with its current balance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountBalance {
+    pub account: Account,
+    /// Opening balance plus all transactions and transfers
+    pub balance: Money,
+}
+
+/// One transaction or transfer of a ledger and the balance right after it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerLine {
+    pub activity: AccountActivity,
+    /// Signed amount added to the balance
+    pub change: Money,
+    pub balance: Money,
+}
+
+/// Everything that moved money on one account, with running balances.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountLedger {
+    pub account: Account,
+    /// Oldest first
+    pub lines: Vec<LedgerLine>,
+    pub balance: Money,
+}
+
+/// Adds up `activity` (oldest first) starting from `opening`, one line per item.
+fn running_balances(opening: Money, activity: Vec<AccountActivity>) -> Vec<LedgerLine> {
+    let mut balance = opening;
+    activity
+        .into_iter()
+        .map(|activity| {
+            let change = activity.balance_change();
+            balance = balance + change;
+            LedgerLine {
+                activity,
+                change,
+                balance,
+            }
+        })
+        .collect()
+}
+
+fn validate_transfer_amount(amount: Money) -> Result<(), AccountError> {
+    if amount.value() <= 0 {
+        return Err(AccountError::InvalidAmount {
+            value: amount.value(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_title_length(title: &Option<String>) -> Result<(), AccountError> {
+    if let Some(t) = title
+        && t.len() > MAX_TITLE_LENGTH
+    {
+        return Err(AccountError::TitleTooLong {
+            length: t.len(),
+            max: MAX_TITLE_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+pub struct AccountService {
+    account_repo: Arc<dyn AccountRepository>,
+}
+
+impl AccountService {
+    pub fn new(account_repo: Arc<dyn AccountRepository>) -> Self {
+        Self { account_repo }
+    }
+
+    async fn find(&self, id: &Ulid) -> Result<Account, AccountError> {
+        self.account_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(AccountError::NotFound)
+    }
+
+    async fn balance_of(&self, account: Account) -> Result<AccountBalance, AccountError> {
+        let activity = self.account_repo.activity(&account.id).await?;
+        let balance = activity
+            .iter()
+            .map(|a| a.balance_change())
+            .fold(account.opening_balance, |total, change| total + change);
+        Ok(AccountBalance { account, balance })
+    }
+
+    /// Lists accounts ordered by name, with their current balances.
+    pub async fn list_all(&self) -> Result<Vec<AccountBalance>, AccountError> {
+        let mut balances = Vec::new();
+        for account in self.account_repo.list_all().await? {
+            balances.push(self.balance_of(account).await?);
+        }
+        Ok(balances)
+    }
+
+    /// Gets an account with its current balance.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::NotFound` - Account does not exist
+    /// * `AccountError::Repository` - Database error
+    pub async fn get(&self, id: &Ulid) -> Result<AccountBalance, AccountError> {
+        let account = self.find(id).await?;
+        self.balance_of(account).await
+    }
+
+    /// Creates an account.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::NameAlreadyExists` - Another account has this name
+    /// * `AccountError::Repository` - Database error
+    pub async fn create(
+        &self,
+        name: AccountName,
+        kind: AccountKind,
+        opening_balance: Money,
+    ) -> Result<AccountBalance, AccountError> {
+        let account = self
+            .account_repo
+            .create(NewAccount {
+                name,
+                kind,
+                opening_balance,
+            })
+            .await?;
+        self.balance_of(account).await
+    }
+
+    /// Updates an account; a new opening balance shifts all running balances.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::NotFound` - Account does not exist
+    /// * `AccountError::NameAlreadyExists` - Another account has this name
+    /// * `AccountError::Repository` - Database error
+    pub async fn update(
+        &self,
+        id: &Ulid,
+        name: Option<AccountName>,
+        kind: Option<AccountKind>,
+        opening_balance: Option<Money>,
+    ) -> Result<AccountBalance, AccountError> {
+        let account = self
+            .account_repo
+            .update(id, name, kind, opening_balance)
+            .await?;
+        self.balance_of(account).await
+    }
+
+    /// Deletes an account; its transactions are kept without an account.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::NotFound` - Account does not exist
+    /// * `AccountError::HasTransfers` - Transfers from or to the account exist
+    /// * `AccountError::Repository` - Database error
+    pub async fn delete(&self, id: &Ulid) -> Result<(), AccountError> {
+        let count = self.account_repo.transfer_count(id).await?;
+        if count > 0 {
+            return Err(AccountError::HasTransfers {
+                transfer_count: count,
+            });
+        }
+        self.account_repo.delete(id).await
+    }
+
+    /// Transactions and transfers of an account, oldest first, each with the
+    /// balance after it.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::NotFound` - Account does not exist
+    /// * `AccountError::Repository` - Database error
+    pub async fn ledger(&self, id: &Ulid) -> Result<AccountLedger, AccountError> {
+        let account = self.find(id).await?;
+        let activity = self.account_repo.activity(id).await?;
+        let lines = running_balances(account.opening_balance, activity);
+        let balance = lines
+            .last()
+            .map(|l| l.balance)
+            .unwrap_or(account.opening_balance);
+
+        Ok(AccountLedger {
+            account,
+            lines,
+            balance,
+        })
+    }
+
+    /// Lists all transfers, newest first.
+    pub async fn list_transfers(&self) -> Result<Vec<Transfer>, AccountError> {
+        self.account_repo.list_transfers().await
+    }
+
+    /// Moves money between two accounts.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::InvalidAmount` - Amount is not positive
+    /// * `AccountError::TitleTooLong` - Title exceeds maximum length
+    /// * `AccountError::TransferToSelf` - Both sides are the same account
+    /// * `AccountError::NotFound` - Either account does not exist
+    /// * `AccountError::Repository` - Database error
+    pub async fn create_transfer(&self, transfer: NewTransfer) -> Result<Transfer, AccountError> {
+        validate_transfer_amount(transfer.amount)?;
+        let title = normalize_title(transfer.title);
+        validate_title_length(&title)?;
+
+        if transfer.from_account_id == transfer.to_account_id {
+            return Err(AccountError::TransferToSelf);
+        }
+        self.find(&transfer.from_account_id).await?;
+        self.find(&transfer.to_account_id).await?;
+
+        self.account_repo
+            .create_transfer(NewTransfer { title, ..transfer })
+            .await
+    }
+
+    /// Updates a transfer.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::TransferNotFound` - Transfer does not exist
+    /// * `AccountError::InvalidAmount` - Amount is not positive
+    /// * `AccountError::TitleTooLong` - Title exceeds maximum length
+    /// * `AccountError::TransferToSelf` - Both sides would be the same account
+    /// * `AccountError::NotFound` - Either new account does not exist
+    /// * `AccountError::Repository` - Database error
+    pub async fn update_transfer(
+        &self,
+        id: &Ulid,
+        mut changes: TransferUpdate,
+    ) -> Result<Transfer, AccountError> {
+        let current = self
+            .account_repo
+            .find_transfer(id)
+            .await?
+            .ok_or(AccountError::TransferNotFound)?;
+
+        if let Some(amount) = changes.amount {
+            validate_transfer_amount(amount)?;
+        }
+        changes.title = changes.title.map(normalize_title);
+        if let Some(ref t) = changes.title {
+            validate_title_length(t)?;
+        }
+
+        let from = changes.from_account_id.unwrap_or(current.from_account_id);
+        let to = changes.to_account_id.unwrap_or(current.to_account_id);
+        if from == to {
+            return Err(AccountError::TransferToSelf);
+        }
+        for account_id in [changes.from_account_id, changes.to_account_id].iter().flatten() {
+            self.find(account_id).await?;
+        }
+
+        self.account_repo.update_transfer(id, changes).await
+    }
+
+    /// Deletes a transfer.
+    ///
+    /// # Errors
+    ///
+    /// * `AccountError::TransferNotFound` - Transfer does not exist
+    /// * `AccountError::Repository` - Database error
+    pub async fn delete_transfer(&self, id: &Ulid) -> Result<(), AccountError> {
+        self.account_repo.delete_transfer(id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::AccountActivityKind;
+    use crate::types::{EntryKind, TransactionDate, TransactionKind};
+
+    fn activity(kind: AccountActivityKind, amount: i64, day: u32) -> AccountActivity {
+        AccountActivity {
+            id: Ulid::new(),
+            kind,
+            amount: Money::new(amount),
+            date: TransactionDate::new(chrono::NaiveDate::from_ymd_opt(2026, 4, day).unwrap()),
+            title: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    fn payment(entry_kind: EntryKind) -> AccountActivityKind {
+        AccountActivityKind::Transaction {
+            entry_id: Ulid::new(),
+            entry_kind,
+            transaction_kind: TransactionKind::Payment,
+        }
+    }
+
+    #[test]
+    fn test_running_balances() {
+        let lines = running_balances(
+            Money::new(10000),
+            vec![
+                activity(payment(EntryKind::Income), 500000, 1),
+                activity(payment(EntryKind::Expense), 4000, 2),
+                activity(AccountActivityKind::TransferOut { to_account_id: Ulid::new() }, 100000, 3),
+            ],
+        );
+        let changes: Vec<i64> = lines.iter().map(|l| l.change.value()).collect();
+        let balances: Vec<i64> = lines.iter().map(|l| l.balance.value()).collect();
+        assert_eq!(changes, vec![500000, -4000, -100000]);
+        assert_eq!(balances, vec![510000, 506000, 406000]);
+    }
+
+    #[test]
+    fn test_running_balances_empty() {
+        assert!(running_balances(Money::new(10000), Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+use sha2::{Digest, Sha256};
+use ulid::Ulid;
+
+use crate::entities::{Attachment, NewAttachment, MAX_FILE_NAME_LENGTH};
+use crate::errors::AttachmentError;
+use crate::ports::{AttachmentRepository, AttachmentStore, TransactionRepository, UnitOfWorkFactory};
+
+/// Content type stored when the upload does not name one
+pub const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+pub struct AttachmentService {
+    repo: Arc<dyn AttachmentRepository>,
+    transaction_repo: Arc<dyn TransactionRepository>,
+    store: Arc<dyn AttachmentStore>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    max_size: usize,
+}
+
+impl AttachmentService {
+    pub fn new(
+        repo: Arc<dyn AttachmentRepository>,
+        transaction_repo: Arc<dyn TransactionRepository>,
+        store: Arc<dyn AttachmentStore>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
+        max_size: usize,
+    ) -> Self {
+        Self {
+            repo,
+            transaction_repo,
+            store,
+            unit_of_work,
+            max_size,
+        }
+    }
+
+    /// Largest accepted file, in bytes.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Attaches a file to a transaction.
+    ///
+    /// Only the last path component of `file_name` is kept. The contents are
+    /// written after the metadata row is inserted but before it is committed:
+    /// a purge of the same file either finishes first or sees the new row, and
+    /// a failed write leaves no attachment behind.
+    ///
+    /// # Errors
+    ///
+    /// * `AttachmentError::TransactionNotFound` - Transaction does not exist
+    /// * `AttachmentError::EmptyFile` - No contents
+    /// * `AttachmentError::FileTooLarge` - Contents larger than the configured maximum
+    /// * `AttachmentError::InvalidFileName` - Name empty or too long
+    /// * `AttachmentError::InvalidContentType` - Not a `type/subtype` value
+    /// * `AttachmentError::Storage` - Writing the file failed
+    pub async fn upload(
+        &self,
+        transaction_id: &Ulid,
+        file_name: &str,
+        content_type: Option<&str>,
+        contents: &[u8],
+    ) -> Result<Attachment, AttachmentError> {
+        if contents.is_empty() {
+            return Err(AttachmentError::EmptyFile);
+        }
+        if contents.len() > self.max_size {
+            return Err(AttachmentError::FileTooLarge {
+                size: contents.len(),
+                max: self.max_size,
+            });
+        }
+        let file_name = normalize_file_name(file_name)?;
+        let content_type = normalize_content_type(content_type)?;
+
+        self.transaction_repo
+            .find_by_id(transaction_id)
+            .await
+            .map_err(|e| AttachmentError::Repository(e.to_string()))?
+            .ok_or(AttachmentError::TransactionNotFound)?;
+
+        let sha256 = format!("{:x}", Sha256::digest(contents));
+
+        let uow = self.unit_of_work.begin().await?;
+        let attachment = uow
+            .attachments()
+            .create(NewAttachment {
+                transaction_id: *transaction_id,
+                file_name,
+                content_type,
+                size: contents.len() as i64,
+                sha256,
+            })
+            .await?;
+        self.store.put(&attachment.sha256, contents).await?;
+        uow.commit().await?;
+
+        Ok(attachment)
+    }
+
+    /// Lists the attachments of a transaction, oldest first.
+    pub async fn list(&self, transaction_id: &Ulid) -> Result<Vec<Attachment>, AttachmentError> {
+        self.transaction_repo
+            .find_by_id(transaction_id)
+            .await
+            .map_err(|e| AttachmentError::Repository(e.to_string()))?
+            .ok_or(AttachmentError::TransactionNotFound)?;
+
+        self.repo.list_by_transaction(transaction_id).await
+    }
+
+    /// Returns an attachment of the transaction together with its contents.
+    pub async fn download(
+        &self,
+        transaction_id: &Ulid,
+        id: &Ulid,
+    ) -> Result<(Attachment, Vec<u8>), AttachmentError> {
+        let attachment = self.find(transaction_id, id).await?;
+        let contents = self.store.get(&attachment.sha256).await?;
+        Ok((attachment, contents))
+    }
+
+    /// Deletes an attachment of the transaction. The file itself is removed
+    /// by the next [`purge_files`](Self::purge_files).
+    pub async fn delete(&self, transaction_id: &Ulid, id: &Ulid) -> Result<(), AttachmentError> {
+        self.find(transaction_id, id).await?;
+        self.repo.delete(id).await
+    }
+
+    /// Removes files no attachment refers to any more, e.g. after their
+    /// transactions were deleted. Returns how many files were removed.
+    ///
+    /// Each file is checked again and removed in its own unit of work, so one
+    /// uploaded again since the listing is kept. Stops at the first storage
+    /// error; the remaining files stay queued for the next run.
+    pub async fn purge_files(&self) -> Result<usize, AttachmentError> {
+        let mut removed = 0;
+        for sha256 in self.repo.unreferenced_files().await? {
+            let uow = self.unit_of_work.begin().await?;
+            if uow.attachments().forget_unreferenced_file(&sha256).await? {
+                self.store.delete(&sha256).await?;
+                removed += 1;
+            }
+            uow.commit().await?;
+        }
+        Ok(removed)
+    }
+
+    async fn find(&self, transaction_id: &Ulid, id: &Ulid) -> Result<Attachment, AttachmentError> {
+        match self.repo.find_by_id(id).await? {
+            Some(a) if a.transaction_id == *transaction_id => Ok(a),
+            _ => Err(AttachmentError::NotFound),
+        }
+    }
+}
+
+/// Keeps the last path component of an uploaded file name, trimmed.
+fn normalize_file_name(value: &str) -> Result<String, AttachmentError> {
+    let name = value
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(AttachmentError::InvalidFileName {
+            reason: "file name cannot be empty".to_string(),
+        });
+    }
+    if name.chars().any(char::is_control) {
+        return Err(AttachmentError::InvalidFileName {
+            reason: "file name cannot contain control characters".to_string(),
+        });
+    }
+    let length = name.chars().count();
+    if length > MAX_FILE_NAME_LENGTH {
+        return Err(AttachmentError::InvalidFileName {
+            reason: format!(
+                "file name too long: {} characters (max {})",
+                length, MAX_FILE_NAME_LENGTH
+            ),
+        });
+    }
+
+    Ok(name.to_string())
+}
+
+/// Lower-cases a `type/subtype` content type, dropping any parameters.
+fn normalize_content_type(value: Option<&str>) -> Result<String, AttachmentError> {
+    let Some(value) = value else {
+        return Ok(DEFAULT_CONTENT_TYPE.to_string());
+    };
+
+    let essence = value.split(';').next().unwrap_or_default().trim();
+    let valid_part = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+    };
+    match essence.split_once('/') {
+        Some((kind, subtype)) if valid_part(kind) && valid_part(subtype) => {
+            Ok(essence.to_ascii_lowercase())
+        }
+        _ => Err(AttachmentError::InvalidContentType {
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_name_keeps_last_component() {
+        assert_eq!(normalize_file_name("receipt.pdf").unwrap(), "receipt.pdf");
+        assert_eq!(normalize_file_name("../../etc/passwd").unwrap(), "passwd");
+        assert_eq!(normalize_file_name("C:\\scans\\ paragon.jpg ").unwrap(), "paragon.jpg");
+    }
+
+    #[test]
+    fn file_name_rejects_empty_and_long() {
+        assert!(normalize_file_name("").is_err());
+        assert!(normalize_file_name("dir/").is_err());
+        assert!(normalize_file_name("..").is_err());
+        assert!(normalize_file_name("a\nb").is_err());
+        assert!(normalize_file_name(&"a".repeat(MAX_FILE_NAME_LENGTH)).is_ok());
+        assert!(normalize_file_name(&"a".repeat(MAX_FILE_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn content_type_defaults_and_normalizes() {
+        assert_eq!(normalize_content_type(None).unwrap(), DEFAULT_CONTENT_TYPE);
+        assert_eq!(normalize_content_type(Some("Image/JPEG")).unwrap(), "image/jpeg");
+        assert_eq!(
+            normalize_content_type(Some("text/plain; charset=utf-8")).unwrap(),
+            "text/plain"
+        );
+        assert_eq!(
+            normalize_content_type(Some("application/vnd.ms-excel")).unwrap(),
+            "application/vnd.ms-excel"
+        );
+    }
+
+    #[test]
+    fn content_type_rejects_malformed() {
+        assert!(normalize_content_type(Some("")).is_err());
+        assert!(normalize_content_type(Some("pdf")).is_err());
+        assert!(normalize_content_type(Some("text/")).is_err());
+        assert!(normalize_content_type(Some("text/html\r\nX: y")).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use ulid::Ulid;
 
-use crate::entities::{Category, NewCategory};
+use crate::entities::{Category, CategoryTree, NewCategory};
 use crate::errors::CategoryError;
 use crate::ports::CategoryRepository;
 use crate::types::CategoryName;
@@ -15,8 +15,15 @@ impl CategoryService {
         Self { repo }
     }
 
-    pub async fn list_all(&self) -> Result<Vec<Category>, CategoryError> {
-        self.repo.list_all().await
+    /// Lists categories ordered by name. Archived ones are left out unless `include_archived` is set.
+    pub async fn list_all(&self, include_archived: bool) -> Result<Vec<Category>, CategoryError> {
+        self.repo.list_all(include_archived).await
+    }
+
+    /// Categories arranged by name segments; see `CategoryTree`.
+    pub async fn tree(&self, include_archived: bool) -> Result<CategoryTree, CategoryError> {
+        let categories = self.repo.list_all(include_archived).await?;
+        Ok(CategoryTree::build(categories))
     }
 
     pub async fn create(&self, name: CategoryName, label: Option<String>) -> Result<Category, CategoryError> {
@@ -31,4 +38,68 @@ impl CategoryService {
     pub async fn update(&self, id: &Ulid, name: Option<CategoryName>, label: Option<Option<String>>) -> Result<Category, CategoryError> {
         self.repo.update(id, name, label).await
     }
+
+    /// Hides a category from lists and from entry copying on month creation.
+    /// Existing budget entries keep pointing at it.
+    pub async fn archive(&self, id: &Ulid) -> Result<Category, CategoryError> {
+        self.repo.set_archived(id, true).await
+    }
+
+    pub async fn unarchive(&self, id: &Ulid) -> Result<Category, CategoryError> {
+        self.repo.set_archived(id, false).await
+    }
+
+    /// Permanently removes a category that no budget entry or recurring rule references.
+    ///
+    /// # Errors
+    ///
+    /// * `CategoryError::HasEntries` - Category is used by at least one budget entry; archive it instead
+    /// * `CategoryError::HasRecurringRules` - Category is used by at least one recurring rule;
+    ///   delete the rules or merge the category instead
+    /// * `CategoryError::NotFound` - Category does not exist
+    pub async fn delete(&self, id: &Ulid) -> Result<(), CategoryError> {
+        let count = self.repo.entry_count(id).await?;
+        if count > 0 {
+            return Err(CategoryError::HasEntries { entry_count: count });
+        }
+        let rule_count = self.repo.recurring_rule_count(id).await?;
+        if rule_count > 0 {
+            return Err(CategoryError::HasRecurringRules { rule_count });
+        }
+        self.repo.delete(id).await
+    }
+
+    /// Renames or moves a whole subtree, e.g. `utils` -> `utilities` turns
+    /// `utils/gas` into `utilities/gas`. The prefix does not need a category of its own.
+    ///
+    /// # Errors
+    ///
+    /// * `CategoryError::NotFound` - No category is named `from` or lies below it
+    /// * `CategoryError::NameAlreadyExists` - A renamed category would collide with a category outside the subtree;
+    ///   nothing is renamed in that case
+    pub async fn move_subtree(&self, from: &CategoryName, to: &CategoryName) -> Result<Vec<Category>, CategoryError> {
+        self.repo.rename_prefix(from, to).await
+    }
+
+    /// Folds `source` into `target` and deletes `source`.
+    ///
+    /// Entries of `source` in months where `target` has no entry are re-pointed to `target`.
+    /// Where both have an entry in the same month, budgeted and carried-in amounts are
+    /// summed into the target entry, the source entry's transactions are moved to it and
+    /// the source entry is removed. The target entry keeps its due day and rollover mode,
+    /// so from then on the merged balance rolls over the way the target's did. Everything
+    /// happens in one database transaction.
+    ///
+    /// # Errors
+    ///
+    /// * `CategoryError::MergeIntoSelf` - `source` and `target` are the same category
+    /// * `CategoryError::NotFound` - Either category does not exist
+    /// * `CategoryError::MergeKindMismatch` - In some month one category has an income
+    ///   entry and the other an expense entry, which cannot be summed
+    pub async fn merge(&self, source: &Ulid, target: &Ulid) -> Result<Category, CategoryError> {
+        if source == target {
+            return Err(CategoryError::MergeIntoSelf);
+        }
+        self.repo.merge(source, target).await
+    }
 }
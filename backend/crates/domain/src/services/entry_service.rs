@@ -4,7 +4,7 @@ use ulid::Ulid;
 use crate::entities::{BudgetEntryWithCategory, NewBudgetEntry};
 use crate::errors::EntryError;
 use crate::ports::{BudgetEntryRepository, CategoryRepository, MonthRepository};
-use crate::types::{DueDay, Money};
+use crate::types::{DueDay, EntryKind, Money, RolloverMode};
 
 pub struct EntryService {
     entry_repo: Arc<dyn BudgetEntryRepository>,
@@ -43,7 +43,9 @@ impl EntryService {
         &self,
         month_id: Ulid,
         category_id: Ulid,
+        kind: EntryKind,
         budgeted: Money,
+        rollover: RolloverMode,
         due_day: Option<DueDay>,
     ) -> Result<BudgetEntryWithCategory, EntryError> {
         // Verify month exists
@@ -63,7 +65,10 @@ impl EntryService {
         let new_entry = NewBudgetEntry {
             month_id,
             category_id,
+            kind,
             budgeted,
+            carried_in: Money::new(0),
+            rollover,
             due_day,
         };
 
@@ -75,8 +80,9 @@ impl EntryService {
         id: &Ulid,
         budgeted: Option<Money>,
         due_day: Option<Option<DueDay>>,
+        rollover: Option<RolloverMode>,
     ) -> Result<BudgetEntryWithCategory, EntryError> {
-        self.entry_repo.update(id, budgeted, due_day).await
+        self.entry_repo.update(id, budgeted, due_day, rollover).await
     }
 
     pub async fn delete(&self, id: &Ulid) -> Result<(), EntryError> {
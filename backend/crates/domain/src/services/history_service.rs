@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::entities::Category;
+use crate::errors::CategoryError;
+use crate::ports::{BudgetEntryRepository, CategoryRepository, MonthRepository, TransactionRepository};
+use crate::services::summary_service::{derive_status, BudgetStatus};
+use crate::types::{BudgetMonth, Money};
+
+/// Budgeted vs paid of one category over time, with statistics on the paid amounts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryHistory {
+    pub category: Category,
+    /// One point per month the category has an entry in, oldest first
+    pub points: Vec<CategoryHistoryPoint>,
+    /// `None` when there are no points
+    pub stats: Option<HistoryStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryHistoryPoint {
+    pub month_id: Ulid,
+    pub month: BudgetMonth,
+    pub entry_id: Ulid,
+    pub budgeted: Money,
+    pub carried_in: Money,
+    pub paid: Money,
+    pub remaining: Money,
+    pub status: BudgetStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryStats {
+    pub average_budgeted: Money,
+    pub average_paid: Money,
+    pub min_paid: HistoryExtreme,
+    pub max_paid: HistoryExtreme,
+    pub trend: Trend,
+}
+
+/// Smallest or largest paid amount and the month it occurred in (the earliest on ties).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryExtreme {
+    pub month: BudgetMonth,
+    pub amount: Money,
+}
+
+/// Least-squares slope of paid amounts over consecutive points.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trend {
+    /// Change in paid amount per point, rounded to whole minor units
+    pub per_month: Money,
+    pub direction: TrendDirection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendDirection {
+    Rising,
+    Falling,
+    Flat,
+}
+
+/// Slopes smaller than this share of the average paid amount count as flat.
+const FLAT_TREND_RATIO: f64 = 0.01;
+
+fn average(values: impl Iterator<Item = i64>) -> Money {
+    let (sum, count) = values.fold((0i64, 0i64), |(s, c), v| (s + v, c + 1));
+    if count == 0 {
+        return Money::new(0);
+    }
+    Money::new((sum as f64 / count as f64).round() as i64)
+}
+
+fn trend(paid: &[i64]) -> Trend {
+    let n = paid.len() as f64;
+    if paid.len() < 2 {
+        return Trend {
+            per_month: Money::new(0),
+            direction: TrendDirection::Flat,
+        };
+    }
+
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = paid.iter().sum::<i64>() as f64 / n;
+    let (mut num, mut den) = (0.0, 0.0);
+    for (x, &y) in paid.iter().enumerate() {
+        let dx = x as f64 - mean_x;
+        num += dx * (y as f64 - mean_y);
+        den += dx * dx;
+    }
+    let slope = num / den;
+
+    let direction = if slope.abs() <= mean_y.abs() * FLAT_TREND_RATIO {
+        TrendDirection::Flat
+    } else if slope > 0.0 {
+        TrendDirection::Rising
+    } else {
+        TrendDirection::Falling
+    };
+
+    Trend {
+        per_month: Money::new(slope.round() as i64),
+        direction,
+    }
+}
+
+fn compute_stats(points: &[CategoryHistoryPoint]) -> Option<HistoryStats> {
+    let first = points.first()?;
+
+    let mut min_paid = HistoryExtreme { month: first.month, amount: first.paid };
+    let mut max_paid = min_paid.clone();
+    for p in &points[1..] {
+        if p.paid.value() < min_paid.amount.value() {
+            min_paid = HistoryExtreme { month: p.month, amount: p.paid };
+        }
+        if p.paid.value() > max_paid.amount.value() {
+            max_paid = HistoryExtreme { month: p.month, amount: p.paid };
+        }
+    }
+
+    let paid: Vec<i64> = points.iter().map(|p| p.paid.value()).collect();
+
+    Some(HistoryStats {
+        average_budgeted: average(points.iter().map(|p| p.budgeted.value())),
+        average_paid: average(paid.iter().copied()),
+        min_paid,
+        max_paid,
+        trend: trend(&paid),
+    })
+}
+
+pub struct HistoryService {
+    category_repo: Arc<dyn CategoryRepository>,
+    month_repo: Arc<dyn MonthRepository>,
+    entry_repo: Arc<dyn BudgetEntryRepository>,
+    transaction_repo: Arc<dyn TransactionRepository>,
+}
+
+impl HistoryService {
+    pub fn new(
+        category_repo: Arc<dyn CategoryRepository>,
+        month_repo: Arc<dyn MonthRepository>,
+        entry_repo: Arc<dyn BudgetEntryRepository>,
+        transaction_repo: Arc<dyn TransactionRepository>,
+    ) -> Self {
+        Self {
+            category_repo,
+            month_repo,
+            entry_repo,
+            transaction_repo,
+        }
+    }
+
+    /// History of a category within the `months` most recent budget months, or over
+    /// all months when `months` is `None`. Months without an entry for the category
+    /// produce no point.
+    pub async fn category_history(
+        &self,
+        category_id: &Ulid,
+        months: Option<usize>,
+    ) -> Result<CategoryHistory, CategoryError> {
+        let category = self
+            .category_repo
+            .find_by_id(category_id)
+            .await?
+            .ok_or(CategoryError::NotFound)?;
+
+        let mut all_months = self
+            .month_repo
+            .list_all()
+            .await
+            .map_err(|e| CategoryError::Repository(format!("Failed to list months: {}", e)))?;
+        all_months.sort_by_key(|m| std::cmp::Reverse(m.month));
+        if let Some(n) = months {
+            all_months.truncate(n);
+        }
+        let window: HashMap<Ulid, BudgetMonth> =
+            all_months.into_iter().map(|m| (m.id, m.month)).collect();
+
+        let entries = self
+            .entry_repo
+            .list_by_category(category_id)
+            .await
+            .map_err(|e| CategoryError::Repository(format!("Failed to list entries: {}", e)))?;
+
+        let paid_by_entry = self
+            .transaction_repo
+            .sum_by_category(category_id)
+            .await
+            .map_err(|e| CategoryError::Repository(format!("Failed to sum transactions: {}", e)))?;
+
+        let mut points = Vec::new();
+        for entry in entries {
+            let Some(&month) = window.get(&entry.month_id) else {
+                continue;
+            };
+            let paid = paid_by_entry.get(&entry.id).copied().unwrap_or(Money::new(0));
+
+            points.push(CategoryHistoryPoint {
+                month_id: entry.month_id,
+                month,
+                entry_id: entry.id,
+                budgeted: entry.budgeted,
+                carried_in: entry.carried_in,
+                paid,
+                remaining: entry.budgeted + entry.carried_in - paid,
+                status: derive_status(entry.budgeted + entry.carried_in, paid),
+            });
+        }
+        points.sort_by_key(|p| p.month);
+
+        let stats = compute_stats(&points);
+        Ok(CategoryHistory {
+            category,
+            points,
+            stats,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(month: &str, budgeted: i64, paid: i64) -> CategoryHistoryPoint {
+        CategoryHistoryPoint {
+            month_id: Ulid::new(),
+            month: month.parse().unwrap(),
+            entry_id: Ulid::new(),
+            budgeted: Money::new(budgeted),
+            carried_in: Money::new(0),
+            paid: Money::new(paid),
+            remaining: Money::new(budgeted - paid),
+            status: derive_status(Money::new(budgeted), Money::new(paid)),
+        }
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        assert!(compute_stats(&[]).is_none());
+    }
+
+    #[test]
+    fn test_stats_single_point() {
+        let stats = compute_stats(&[point("2026-01", 1000, 700)]).unwrap();
+        assert_eq!(stats.average_paid.value(), 700);
+        assert_eq!(stats.min_paid.amount.value(), 700);
+        assert_eq!(stats.max_paid.amount.value(), 700);
+        assert_eq!(stats.trend.direction, TrendDirection::Flat);
+        assert_eq!(stats.trend.per_month.value(), 0);
+    }
+
+    #[test]
+    fn test_stats_average_min_max() {
+        let stats = compute_stats(&[
+            point("2026-01", 1000, 900),
+            point("2026-02", 1000, 500),
+            point("2026-03", 1100, 1200),
+        ])
+        .unwrap();
+        assert_eq!(stats.average_budgeted.value(), 1033);
+        assert_eq!(stats.average_paid.value(), 867);
+        assert_eq!(stats.min_paid.month.to_string(), "2026-02");
+        assert_eq!(stats.min_paid.amount.value(), 500);
+        assert_eq!(stats.max_paid.month.to_string(), "2026-03");
+        assert_eq!(stats.max_paid.amount.value(), 1200);
+    }
+
+    #[test]
+    fn test_stats_extremes_prefer_earliest_on_ties() {
+        let stats = compute_stats(&[point("2026-01", 0, 100), point("2026-02", 0, 100)]).unwrap();
+        assert_eq!(stats.min_paid.month.to_string(), "2026-01");
+        assert_eq!(stats.max_paid.month.to_string(), "2026-01");
+    }
+
+    #[test]
+    fn test_trend_rising_and_falling() {
+        assert_eq!(trend(&[100, 200, 300]).direction, TrendDirection::Rising);
+        assert_eq!(trend(&[100, 200, 300]).per_month.value(), 100);
+        assert_eq!(trend(&[300, 200, 100]).direction, TrendDirection::Falling);
+        assert_eq!(trend(&[300, 200, 100]).per_month.value(), -100);
+    }
+
+    #[test]
+    fn test_trend_flat_within_tolerance() {
+        // Slope of 5 on an average of ~1000 is below 1%
+        assert_eq!(trend(&[1000, 1005, 1010]).direction, TrendDirection::Flat);
+        assert_eq!(trend(&[0, 0, 0]).direction, TrendDirection::Flat);
+    }
+}
@@ -0,0 +1,489 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+use chrono::format::{Item, StrftimeItems};
+use chrono::NaiveDate;
+use ulid::Ulid;
+
+use crate::entities::{
+    CsvMapping, CsvRecord, ImportPreview, ImportProfile, ImportRow, ImportRowError, ImportedTransaction,
+    NewImportProfile, NewTransaction, Transaction, MAX_TITLE_LENGTH,
+};
+use crate::errors::ImportError;
+use crate::ports::{ImportProfileRepository, UnitOfWorkFactory};
+use crate::services::transaction_service::normalize_title;
+use crate::types::{BudgetMonth, ImportProfileName, Money, TransactionDate};
+
+/// Checks that a mapping can read a file at all: the separators are usable,
+/// date and amount come from different columns, and the date format writes
+/// and reads back a full date.
+///
+/// # Errors
+///
+/// * `ImportError::InvalidMapping` - Any of the above does not hold
+fn validate_mapping(mapping: &CsvMapping) -> Result<(), ImportError> {
+    let invalid = |reason: String| Err(ImportError::InvalidMapping { reason });
+
+    let delimiter = mapping.delimiter;
+    if !delimiter.is_ascii() || delimiter.is_ascii_alphanumeric() || matches!(delimiter, '"' | '\r' | '\n') {
+        return invalid(format!("delimiter {:?} cannot separate fields", delimiter));
+    }
+    if !matches!(mapping.decimal_separator, '.' | ',') {
+        return invalid(format!(
+            "decimal separator must be '.' or ',', got {:?}",
+            mapping.decimal_separator
+        ));
+    }
+    if mapping.date_column == mapping.amount_column {
+        return invalid("date and amount must be in different columns".to_string());
+    }
+
+    // Writing with an unknown specifier fails instead of panicking once it is caught here
+    let items: Vec<Item> = StrftimeItems::new(&mapping.date_format).collect();
+    if items.iter().any(|i| matches!(i, Item::Error)) {
+        return invalid(format!("unknown specifier in date format {:?}", mapping.date_format));
+    }
+    let sample = NaiveDate::from_ymd_opt(2024, 12, 31).expect("valid date");
+    let mut written = String::new();
+    let round_trip = write!(written, "{}", sample.format_with_items(items.into_iter()))
+        .ok()
+        .and_then(|_| NaiveDate::parse_from_str(&written, &mapping.date_format).ok());
+    if round_trip != Some(sample) {
+        return invalid(format!(
+            "date format {:?} must contain the day, month and year",
+            mapping.date_format
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads an amount in minor units with `decimal_places` digits after the
+/// separator at most. A currency code after the number is ignored.
+fn parse_amount(raw: &str, decimal_separator: char, decimal_places: u8) -> Result<Money, String> {
+    let grouping = if decimal_separator == ',' { '.' } else { ',' };
+    let cleaned: String = raw
+        .trim()
+        .trim_end_matches(char::is_alphabetic)
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'' && *c != grouping)
+        .collect();
+    let (negative, unsigned) = match cleaned.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, cleaned.strip_prefix('+').unwrap_or(&cleaned)),
+    };
+    let (whole, fraction) = unsigned.split_once(decimal_separator).unwrap_or((unsigned, ""));
+
+    let digits_only = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() && fraction.is_empty() || !digits_only(whole) || !digits_only(fraction) {
+        return Err(format!("invalid amount {:?}", raw));
+    }
+    if fraction.len() > decimal_places as usize {
+        return Err(format!(
+            "amount {:?} has more than {} decimal places",
+            raw, decimal_places
+        ));
+    }
+
+    let too_large = || format!("amount {:?} is too large", raw);
+    let whole: i64 = match whole {
+        "" => 0,
+        digits => digits.parse().map_err(|_| too_large())?,
+    };
+    // Right-padded to the currency's decimal places, so "5" after the separator is 50 minor units
+    let fraction: i64 = format!("{:0<width$}", fraction, width = decimal_places as usize)
+        .parse()
+        .unwrap_or(0);
+    let minor = 10i64
+        .checked_pow(u32::from(decimal_places))
+        .and_then(|scale| whole.checked_mul(scale))
+        .and_then(|w| w.checked_add(fraction))
+        .ok_or_else(too_large)?;
+
+    Ok(Money::new(if negative { -minor } else { minor }))
+}
+
+/// Description as a transaction title: trimmed, blank as none, and cut to the
+/// title length limit on a character boundary.
+fn description_title(raw: &str) -> Option<String> {
+    let mut title = normalize_title(Some(raw.to_string()))?;
+    if title.len() > MAX_TITLE_LENGTH {
+        let mut end = MAX_TITLE_LENGTH;
+        while !title.is_char_boundary(end) {
+            end -= 1;
+        }
+        title.truncate(end);
+        title.truncate(title.trim_end().len());
+    }
+    Some(title)
+}
+
+fn read_row(
+    record: &CsvRecord,
+    mapping: &CsvMapping,
+    decimal_places: u8,
+) -> Result<(TransactionDate, Money, Option<String>), String> {
+    let field = |column: usize, what: &str| {
+        record.fields.get(column).ok_or_else(|| {
+            format!("no {} column {} in a row of {} fields", what, column, record.fields.len())
+        })
+    };
+
+    let raw_date = field(mapping.date_column, "date")?.trim();
+    let date = NaiveDate::parse_from_str(raw_date, &mapping.date_format)
+        .map(TransactionDate::new)
+        .map_err(|_| format!("date {:?} does not match {:?}", raw_date, mapping.date_format))?;
+    let amount = parse_amount(
+        field(mapping.amount_column, "amount")?,
+        mapping.decimal_separator,
+        decimal_places,
+    )?;
+    let description = match mapping.description_column {
+        Some(column) => description_title(field(column, "description")?),
+        None => None,
+    };
+
+    Ok((date, amount, description))
+}
+
+/// Reads every record of a CSV file with `mapping`. Records that cannot be
+/// read are reported with their line instead of failing the whole file.
+///
+/// # Errors
+///
+/// * `ImportError::InvalidFile` - No records
+fn read_records(
+    records: &[CsvRecord],
+    mapping: &CsvMapping,
+    decimal_places: u8,
+) -> Result<ImportPreview, ImportError> {
+    if records.is_empty() {
+        return Err(ImportError::InvalidFile {
+            reason: "file has no rows".to_string(),
+        });
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for record in records {
+        let line = record.line;
+        match read_row(record, mapping, decimal_places) {
+            Ok((date, amount, description)) => rows.push(ImportRow {
+                line,
+                date,
+                amount,
+                description,
+            }),
+            Err(reason) => errors.push(ImportRowError { line, reason }),
+        }
+    }
+    Ok(ImportPreview { rows, errors })
+}
+
+pub struct ImportService {
+    profile_repo: Arc<dyn ImportProfileRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    /// Digits after the decimal separator of the configured currency
+    decimal_places: u8,
+}
+
+impl ImportService {
+    pub fn new(
+        profile_repo: Arc<dyn ImportProfileRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
+        decimal_places: u8,
+    ) -> Self {
+        Self {
+            profile_repo,
+            unit_of_work,
+            decimal_places,
+        }
+    }
+
+    /// Lists import profiles ordered by name.
+    pub async fn list_profiles(&self) -> Result<Vec<ImportProfile>, ImportError> {
+        self.profile_repo.list_all().await
+    }
+
+    pub async fn get_profile(&self, id: &Ulid) -> Result<ImportProfile, ImportError> {
+        self.profile_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(ImportError::ProfileNotFound)
+    }
+
+    /// Saves a mapping under a name.
+    ///
+    /// # Errors
+    ///
+    /// * `ImportError::InvalidMapping` - Mapping cannot read a file
+    /// * `ImportError::ProfileNameAlreadyExists` - Another profile has the name
+    /// * `ImportError::Repository` - Database error
+    pub async fn create_profile(
+        &self,
+        name: ImportProfileName,
+        mapping: CsvMapping,
+    ) -> Result<ImportProfile, ImportError> {
+        validate_mapping(&mapping)?;
+        self.profile_repo
+            .create(NewImportProfile { name, mapping })
+            .await
+    }
+
+    /// Renames a profile or replaces its mapping.
+    ///
+    /// # Errors
+    ///
+    /// * `ImportError::ProfileNotFound` - Profile does not exist
+    /// * `ImportError::InvalidMapping` - Mapping cannot read a file
+    /// * `ImportError::ProfileNameAlreadyExists` - Another profile has the name
+    /// * `ImportError::Repository` - Database error
+    pub async fn update_profile(
+        &self,
+        id: &Ulid,
+        name: Option<ImportProfileName>,
+        mapping: Option<CsvMapping>,
+    ) -> Result<ImportProfile, ImportError> {
+        if let Some(ref mapping) = mapping {
+            validate_mapping(mapping)?;
+        }
+        self.profile_repo.update(id, name, mapping).await
+    }
+
+    pub async fn delete_profile(&self, id: &Ulid) -> Result<(), ImportError> {
+        self.profile_repo.delete(id).await
+    }
+
+    /// Checks that `mapping` can read a file, before the file is split into records with it.
+    ///
+    /// # Errors
+    ///
+    /// * `ImportError::InvalidMapping` - Mapping cannot read a file
+    pub fn validate_mapping(&self, mapping: &CsvMapping) -> Result<(), ImportError> {
+        validate_mapping(mapping)
+    }
+
+    /// Reads the records of a CSV file with `mapping` without booking anything.
+    ///
+    /// # Errors
+    ///
+    /// * `ImportError::InvalidMapping` - Mapping cannot read a file
+    /// * `ImportError::InvalidFile` - No records
+    pub fn preview(
+        &self,
+        records: &[CsvRecord],
+        mapping: &CsvMapping,
+    ) -> Result<ImportPreview, ImportError> {
+        validate_mapping(mapping)?;
+        read_records(records, mapping, self.decimal_places)
+    }
+
+    /// Books previewed rows as transactions, all or none of them.
+    ///
+    /// # Errors
+    ///
+    /// * `ImportError::EntryNotFound` - A row's budget entry does not exist
+    /// * `ImportError::InvalidRow` - A negative amount, a title too long, or a
+    ///   date outside the month of the row's entry
+    /// * `ImportError::Repository` - Database error
+    pub async fn commit(
+        &self,
+        rows: Vec<ImportedTransaction>,
+    ) -> Result<Vec<Transaction>, ImportError> {
+        let uow = self.unit_of_work.begin().await?;
+        let mut month_of_entry: HashMap<Ulid, BudgetMonth> = HashMap::new();
+        let mut created = Vec::with_capacity(rows.len());
+
+        for (row, transaction) in rows.into_iter().enumerate() {
+            let invalid = |reason: String| ImportError::InvalidRow { row, reason };
+
+            if transaction.amount.value() < 0 {
+                return Err(invalid(format!(
+                    "amount cannot be negative, got {}",
+                    transaction.amount.value()
+                )));
+            }
+            let title = normalize_title(transaction.title);
+            if let Some(ref t) = title
+                && t.len() > MAX_TITLE_LENGTH
+            {
+                return Err(invalid(format!(
+                    "title is {} bytes long (max {})",
+                    t.len(),
+                    MAX_TITLE_LENGTH
+                )));
+            }
+
+            let month = match month_of_entry.get(&transaction.entry_id) {
+                Some(month) => *month,
+                None => {
+                    let entry = uow
+                        .entries()
+                        .find_by_id(&transaction.entry_id)
+                        .await
+                        .map_err(|e| ImportError::Repository(e.to_string()))?
+                        .ok_or(ImportError::EntryNotFound { row })?;
+                    let month = uow
+                        .months()
+                        .find_by_id(&entry.month_id)
+                        .await
+                        .map_err(|e| ImportError::Repository(e.to_string()))?
+                        .ok_or_else(|| ImportError::Repository("entry without month".to_string()))?
+                        .month;
+                    month_of_entry.insert(transaction.entry_id, month);
+                    month
+                }
+            };
+            if BudgetMonth::of_date(transaction.date.value()).ok() != Some(month) {
+                return Err(invalid(format!(
+                    "date {} is outside {}, the month of its entry",
+                    transaction.date, month
+                )));
+            }
+
+            let booked = uow
+                .transactions()
+                .create(NewTransaction {
+                    entry_id: transaction.entry_id,
+                    kind: transaction.kind,
+                    amount: transaction.amount,
+                    date: transaction.date,
+                    title,
+                    refund_of: None,
+                    payee_id: None,
+                    account_id: None,
+                    tags: Vec::new(),
+                })
+                .await
+                .map_err(|e| ImportError::Repository(e.to_string()))?;
+            created.push(booked);
+        }
+
+        uow.commit().await?;
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping() -> CsvMapping {
+        CsvMapping {
+            delimiter: ';',
+            has_header: true,
+            date_column: 0,
+            amount_column: 2,
+            description_column: Some(1),
+            decimal_separator: ',',
+            date_format: "%d.%m.%Y".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_mapping_accepts_common_formats() {
+        assert!(validate_mapping(&mapping()).is_ok());
+        for format in ["%Y-%m-%d", "%d/%m/%Y", "%m/%d/%Y", "%Y%m%d"] {
+            let m = CsvMapping { date_format: format.to_string(), ..mapping() };
+            assert!(validate_mapping(&m).is_ok(), "{format}");
+        }
+    }
+
+    #[test]
+    fn test_validate_mapping_rejects_unusable_mappings() {
+        let invalid = [
+            CsvMapping { delimiter: '"', ..mapping() },
+            CsvMapping { delimiter: 'a', ..mapping() },
+            CsvMapping { delimiter: 'ł', ..mapping() },
+            CsvMapping { decimal_separator: ' ', ..mapping() },
+            CsvMapping { amount_column: 0, ..mapping() },
+            CsvMapping { date_format: "%d.%m".to_string(), ..mapping() },
+            CsvMapping { date_format: "%Q".to_string(), ..mapping() },
+            CsvMapping { date_format: "".to_string(), ..mapping() },
+        ];
+        for m in invalid {
+            assert!(
+                matches!(validate_mapping(&m), Err(ImportError::InvalidMapping { .. })),
+                "{m:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("-1 234,56", ',', 2), Ok(Money::new(-123456)));
+        assert_eq!(parse_amount("1.234,5", ',', 2), Ok(Money::new(123450)));
+        assert_eq!(parse_amount("+12", ',', 2), Ok(Money::new(1200)));
+        assert_eq!(parse_amount(" -12,30 PLN ", ',', 2), Ok(Money::new(-1230)));
+        assert_eq!(parse_amount("1,234.56", '.', 2), Ok(Money::new(123456)));
+        assert_eq!(parse_amount("1'000.5", '.', 2), Ok(Money::new(100050)));
+        assert_eq!(parse_amount(",5", ',', 2), Ok(Money::new(50)));
+        assert_eq!(parse_amount("7", ',', 0), Ok(Money::new(7)));
+        assert_eq!(parse_amount("1\u{a0}000,00", ',', 2), Ok(Money::new(100000)));
+    }
+
+    #[test]
+    fn test_parse_amount_invalid() {
+        for raw in ["", "-", ",", "12,345", "1,2,3", "abc", "12x5", "99999999999999999999"] {
+            assert!(parse_amount(raw, ',', 2).is_err(), "{raw}");
+        }
+        assert!(parse_amount("1,5", ',', 0).is_err());
+    }
+
+    #[test]
+    fn test_description_title() {
+        assert_eq!(description_title("  "), None);
+        assert_eq!(description_title(" Lidl "), Some("Lidl".to_string()));
+        let long = format!("{} Żabka", "a".repeat(MAX_TITLE_LENGTH - 4));
+        let title = description_title(&long).unwrap();
+        assert!(title.len() <= MAX_TITLE_LENGTH);
+        assert!(long.starts_with(&title));
+    }
+
+    fn record(line: u64, fields: &[&str]) -> CsvRecord {
+        CsvRecord {
+            line,
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_read_records() {
+        let records = [
+            record(2, &["14.03.2026", "Lidl; Warszawa", "-45,99"]),
+            record(4, &["15.03.2026", "Zwrot", "12,00"]),
+            record(5, &["2026-03-16", "Bad date", "1,00"]),
+            record(6, &["17.03.2026", "Short"]),
+        ];
+        let preview = read_records(&records, &mapping(), 2).unwrap();
+        assert_eq!(
+            preview.rows,
+            vec![
+                ImportRow {
+                    line: 2,
+                    date: "2026-03-14".parse().unwrap(),
+                    amount: Money::new(-4599),
+                    description: Some("Lidl; Warszawa".to_string()),
+                },
+                ImportRow {
+                    line: 4,
+                    date: "2026-03-15".parse().unwrap(),
+                    amount: Money::new(1200),
+                    description: Some("Zwrot".to_string()),
+                },
+            ]
+        );
+        assert_eq!(
+            preview.errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![5, 6]
+        );
+    }
+
+    #[test]
+    fn test_read_records_empty() {
+        assert!(matches!(
+            read_records(&[], &mapping(), 2),
+            Err(ImportError::InvalidFile { .. })
+        ));
+    }
+}
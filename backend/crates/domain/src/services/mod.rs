@@ -3,9 +3,30 @@ mod month_service;
 mod entry_service;
 mod transaction_service;
 mod summary_service;
+mod history_service;
+mod recurring_service;
+mod tag_service;
+mod payee_service;
+mod account_service;
+mod attachment_service;
+mod import_service;
 
 pub use category_service::CategoryService;
 pub use month_service::MonthService;
 pub use entry_service::EntryService;
-pub use transaction_service::TransactionService;
-pub use summary_service::{SummaryService, MonthSummary, CategoryBudgetSummary, BudgetStatus};
+pub use transaction_service::{PaymentDate, TransactionService};
+pub use recurring_service::RecurringService;
+pub use tag_service::{TagService, TagReport};
+pub use payee_service::{PayeeService, PayeeMatch, PayeeReport};
+pub use account_service::{AccountService, AccountBalance, AccountLedger, LedgerLine};
+pub use attachment_service::{AttachmentService, DEFAULT_CONTENT_TYPE};
+pub use import_service::ImportService;
+pub use history_service::{
+    HistoryService, CategoryHistory, CategoryHistoryPoint, HistoryStats, HistoryExtreme, Trend,
+    TrendDirection,
+};
+pub use summary_service::{
+    SummaryService, MonthSummary, CategoryBudgetSummary, CategoryGroupSummary, BudgetStatus,
+    RangeSummary, MonthTotals, YearTotals, CategorySeries, CategorySeriesPoint, DueBills, DueBill,
+    DueStatus,
+};
@@ -1,24 +1,26 @@
 use std::sync::Arc;
+use chrono::Utc;
 use ulid::Ulid;
 
 use crate::entities::{Month, NewMonth, NewBudgetEntry};
 use crate::errors::MonthError;
-use crate::ports::{MonthRepository, BudgetEntryRepository};
-use crate::types::BudgetMonth;
+use crate::ports::{MonthRepository, UnitOfWork, UnitOfWorkFactory};
+use crate::services::recurring_service::materialize;
+use crate::types::{BudgetMonth, EntryKind, Money};
 
 pub struct MonthService {
     month_repo: Arc<dyn MonthRepository>,
-    entry_repo: Arc<dyn BudgetEntryRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl MonthService {
     pub fn new(
         month_repo: Arc<dyn MonthRepository>,
-        entry_repo: Arc<dyn BudgetEntryRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
     ) -> Self {
         Self {
             month_repo,
-            entry_repo,
+            unit_of_work,
         }
     }
 
@@ -33,31 +35,37 @@ impl MonthService {
             .ok_or(MonthError::NotFound)
     }
 
+    /// Creates a month, copying entries from `copy_from` or the latest existing month
+    /// unless `empty` is set, and books the payments of recurring rules that are already
+    /// due in it. The month is only created if the copy succeeds.
     pub async fn create(
         &self,
         month: BudgetMonth,
         copy_from: Option<&Ulid>,
         empty: bool,
     ) -> Result<Month, MonthError> {
+        let uow = self.unit_of_work.begin().await?;
+
         let new_month = NewMonth { month };
-        let created = self.month_repo.create(new_month).await?;
+        let created = uow.months().create(new_month).await?;
 
         // If empty is true, don't copy any entries
         if empty {
+            uow.commit().await?;
             return Ok(created);
         }
 
         // Determine source month for copying entries
         let source_month_id = if let Some(source_id) = copy_from {
             // Verify the source month exists
-            self.month_repo
+            uow.months()
                 .find_by_id(source_id)
                 .await?
                 .ok_or(MonthError::NotFound)?;
             Some(*source_id)
         } else {
             // Find the latest existing month (excluding the one we just created)
-            self.month_repo
+            uow.months()
                 .find_latest_excluding(&created.id)
                 .await?
                 .map(|m| m.id)
@@ -65,23 +73,123 @@ impl MonthService {
 
         // Copy entries from source month if one exists
         if let Some(source_id) = source_month_id {
-            let entries = self.entry_repo.list_by_month(&source_id).await.map_err(|e| {
-                MonthError::Repository(format!("Failed to list entries for copy: {}", e))
-            })?;
-
-            for entry in entries {
-                let new_entry = NewBudgetEntry {
-                    month_id: created.id,
-                    category_id: entry.category.id,
-                    budgeted: entry.budgeted,
-                    due_day: entry.due_day,
-                };
-                self.entry_repo.create(new_entry).await.map_err(|e| {
-                    MonthError::Repository(format!("Failed to copy entry: {}", e))
-                })?;
-            }
+            copy_entries(uow.as_ref(), &source_id, &created.id).await?;
         }
 
+        materialize(uow.as_ref(), &created, Utc::now().date_naive())
+            .await
+            .map_err(|e| MonthError::Repository(format!("Failed to book recurring payments: {}", e)))?;
+
+        uow.commit().await?;
         Ok(created)
     }
+
+    /// Deletes a month and its entries. The transaction check and the delete run
+    /// as one unit of work, so a transaction booked in between is not lost.
+    ///
+    /// # Errors
+    ///
+    /// * `MonthError::NotFound` - Month does not exist
+    /// * `MonthError::HasTransactions` - Month has transactions and `cascade` is not set;
+    ///   with `cascade` they are deleted too
+    pub async fn delete(&self, id: &Ulid, cascade: bool) -> Result<(), MonthError> {
+        let uow = self.unit_of_work.begin().await?;
+        uow.months()
+            .find_by_id(id)
+            .await?
+            .ok_or(MonthError::NotFound)?;
+
+        if !cascade {
+            ensure_no_transactions(uow.months(), id).await?;
+        }
+
+        uow.months().delete(id).await?;
+        uow.commit().await?;
+        Ok(())
+    }
+
+    /// Replaces all entries of a month with copies of another month's entries.
+    ///
+    /// Without `copy_from` the latest other month is used, as in `create`; if there is
+    /// none the month is simply cleared. Runs as a single unit of work, so a failed copy
+    /// leaves the original entries in place.
+    ///
+    /// # Errors
+    ///
+    /// * `MonthError::NotFound` - Month or source month does not exist
+    /// * `MonthError::CopyFromSelf` - `copy_from` is the month being reset
+    /// * `MonthError::HasTransactions` - Month already has transactions
+    pub async fn reset(&self, id: &Ulid, copy_from: Option<&Ulid>) -> Result<Month, MonthError> {
+        let month = self.find_by_id(id).await?;
+
+        let source_month_id = if let Some(source_id) = copy_from {
+            if source_id == id {
+                return Err(MonthError::CopyFromSelf);
+            }
+            self.find_by_id(source_id).await?;
+            Some(*source_id)
+        } else {
+            self.month_repo
+                .find_latest_excluding(id)
+                .await?
+                .map(|m| m.id)
+        };
+
+        let uow = self.unit_of_work.begin().await?;
+        ensure_no_transactions(uow.months(), id).await?;
+        uow.months().delete_entries(id).await?;
+
+        if let Some(source_id) = source_month_id {
+            copy_entries(uow.as_ref(), &source_id, id).await?;
+        }
+
+        uow.commit().await?;
+        Ok(month)
+    }
+}
+
+async fn ensure_no_transactions(months: &dyn MonthRepository, id: &Ulid) -> Result<(), MonthError> {
+    let count = months.transaction_count(id).await?;
+    if count > 0 {
+        return Err(MonthError::HasTransactions {
+            transaction_count: count,
+        });
+    }
+    Ok(())
+}
+
+/// Copies budgeted amounts, due days and rollover settings of `source_id`'s entries
+/// into `target_id`, skipping archived categories. Expense entries carry their
+/// balance over as their rollover mode says; income entries never carry anything.
+async fn copy_entries(uow: &dyn UnitOfWork, source_id: &Ulid, target_id: &Ulid) -> Result<(), MonthError> {
+    let entries = uow.entries().list_by_month(source_id).await.map_err(|e| {
+        MonthError::Repository(format!("Failed to list entries for copy: {}", e))
+    })?;
+
+    let paid_by_entry = uow.transactions().sum_by_month(source_id).await.map_err(|e| {
+        MonthError::Repository(format!("Failed to sum transactions for copy: {}", e))
+    })?;
+
+    for entry in entries.into_iter().filter(|e| !e.category.archived) {
+        let paid = paid_by_entry.get(&entry.id).copied().unwrap_or(Money::new(0));
+        let carried_in = match entry.kind {
+            EntryKind::Expense => entry.rollover.carry(entry.budgeted + entry.carried_in, paid),
+            EntryKind::Income => Money::new(0),
+        };
+
+        let new_entry = NewBudgetEntry {
+            month_id: *target_id,
+            category_id: entry.category.id,
+            kind: entry.kind,
+            budgeted: entry.budgeted,
+            carried_in,
+            rollover: entry.rollover,
+            due_day: entry.due_day,
+        };
+        uow.entries().create(new_entry).await.map_err(|e| {
+            MonthError::Repository(format!("Failed to copy entry: {}", e))
+        })?;
+    }
+
+    Ok(())
 }
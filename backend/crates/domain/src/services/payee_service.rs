@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::entities::{MonthSpending, NewPayee, Payee};
+use crate::errors::PayeeError;
+use crate::ports::{BudgetEntryRepository, CategoryRepository, PayeeRepository};
+use crate::types::{Money, PayeeName};
+
+/// Payee found by name or alias, with the entry to preselect for a payment to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayeeMatch {
+    pub payee: Payee,
+    /// Entry of the payee's default category in the requested month, if it has one
+    pub entry_id: Option<Ulid>,
+}
+
+/// Spending paid to one payee, overall and per month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayeeReport {
+    pub payee: Payee,
+    pub total_spent: Money,
+    /// Months with payments to the payee, oldest first
+    pub months: Vec<MonthSpending>,
+}
+
+/// Drops aliases with the same key as the name or an earlier alias.
+fn dedup_aliases(name: &PayeeName, aliases: Vec<PayeeName>) -> Vec<PayeeName> {
+    let mut seen = HashSet::from([name.key()]);
+    aliases
+        .into_iter()
+        .filter(|a| seen.insert(a.key()))
+        .collect()
+}
+
+pub struct PayeeService {
+    payee_repo: Arc<dyn PayeeRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    entry_repo: Arc<dyn BudgetEntryRepository>,
+}
+
+impl PayeeService {
+    pub fn new(
+        payee_repo: Arc<dyn PayeeRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        entry_repo: Arc<dyn BudgetEntryRepository>,
+    ) -> Self {
+        Self {
+            payee_repo,
+            category_repo,
+            entry_repo,
+        }
+    }
+
+    async fn check_category(&self, category_id: &Ulid) -> Result<(), PayeeError> {
+        self.category_repo
+            .find_by_id(category_id)
+            .await
+            .map_err(|e| PayeeError::Repository(e.to_string()))?
+            .ok_or(PayeeError::CategoryNotFound)?;
+        Ok(())
+    }
+
+    /// Lists payees ordered by name.
+    pub async fn list_all(&self) -> Result<Vec<Payee>, PayeeError> {
+        self.payee_repo.list_all().await
+    }
+
+    /// Creates a payee. Aliases repeating the name or each other are dropped.
+    ///
+    /// # Errors
+    ///
+    /// * `PayeeError::CategoryNotFound` - Default category does not exist
+    /// * `PayeeError::NameAlreadyExists` - Name or an alias is taken by another payee
+    /// * `PayeeError::Repository` - Database error
+    pub async fn create(
+        &self,
+        name: PayeeName,
+        default_category_id: Option<Ulid>,
+        aliases: Vec<PayeeName>,
+    ) -> Result<Payee, PayeeError> {
+        if let Some(ref category_id) = default_category_id {
+            self.check_category(category_id).await?;
+        }
+
+        let aliases = dedup_aliases(&name, aliases);
+        self.payee_repo
+            .create(NewPayee {
+                name,
+                default_category_id,
+                aliases,
+            })
+            .await
+    }
+
+    /// Updates a payee; `aliases` replaces all aliases.
+    ///
+    /// # Errors
+    ///
+    /// * `PayeeError::NotFound` - Payee does not exist
+    /// * `PayeeError::CategoryNotFound` - Default category does not exist
+    /// * `PayeeError::NameAlreadyExists` - Name or an alias is taken by another payee
+    /// * `PayeeError::Repository` - Database error
+    pub async fn update(
+        &self,
+        id: &Ulid,
+        name: Option<PayeeName>,
+        default_category_id: Option<Option<Ulid>>,
+        aliases: Option<Vec<PayeeName>>,
+    ) -> Result<Payee, PayeeError> {
+        let current = self
+            .payee_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(PayeeError::NotFound)?;
+
+        if let Some(Some(ref category_id)) = default_category_id {
+            self.check_category(category_id).await?;
+        }
+
+        let aliases = aliases.map(|a| dedup_aliases(name.as_ref().unwrap_or(&current.name), a));
+        self.payee_repo
+            .update(id, name, default_category_id, aliases)
+            .await
+    }
+
+    /// Deletes a payee; its transactions are kept without a payee.
+    pub async fn delete(&self, id: &Ulid) -> Result<(), PayeeError> {
+        self.payee_repo.delete(id).await
+    }
+
+    /// Finds the payee named `name` or having it as an alias, ignoring case and spacing.
+    ///
+    /// With `month_id`, also picks the month's entry of the payee's default category.
+    ///
+    /// # Errors
+    ///
+    /// * `PayeeError::NotFound` - No payee has this name or alias
+    /// * `PayeeError::Repository` - Database error
+    pub async fn lookup(
+        &self,
+        name: &PayeeName,
+        month_id: Option<&Ulid>,
+    ) -> Result<PayeeMatch, PayeeError> {
+        let payee = self
+            .payee_repo
+            .find_by_key(&name.key())
+            .await?
+            .ok_or(PayeeError::NotFound)?;
+
+        let entry_id = match (month_id, payee.default_category_id) {
+            (Some(month_id), Some(category_id)) => self
+                .entry_repo
+                .list_by_month(month_id)
+                .await
+                .map_err(|e| PayeeError::Repository(e.to_string()))?
+                .into_iter()
+                .find(|e| e.category.id == category_id)
+                .map(|e| e.id),
+            _ => None,
+        };
+
+        Ok(PayeeMatch { payee, entry_id })
+    }
+
+    /// Spending on expense entries paid to the payee `id`, net of refunds.
+    ///
+    /// # Errors
+    ///
+    /// * `PayeeError::NotFound` - Payee does not exist
+    /// * `PayeeError::Repository` - Database error
+    pub async fn report(&self, id: &Ulid) -> Result<PayeeReport, PayeeError> {
+        let payee = self
+            .payee_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(PayeeError::NotFound)?;
+        let months = self.payee_repo.spending_by_month(id).await?;
+        let total_spent = months.iter().map(|m| m.spent).sum();
+
+        Ok(PayeeReport {
+            payee,
+            total_spent,
+            months,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(value: &str) -> PayeeName {
+        PayeeName::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_dedup_aliases_drops_name_and_repeats() {
+        let aliases = dedup_aliases(
+            &name("Lidl"),
+            vec![name("LIDL"), name("Lidl sp. z o.o."), name("lidl  SP. Z O.O."), name("Lidl PL")],
+        );
+        let names: Vec<&str> = aliases.iter().map(|a| a.as_str()).collect();
+        assert_eq!(names, vec!["Lidl sp. z o.o.", "Lidl PL"]);
+    }
+
+    #[test]
+    fn test_dedup_aliases_empty() {
+        assert!(dedup_aliases(&name("Lidl"), Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::NaiveDate;
+use ulid::Ulid;
+
+use crate::entities::{Month, NewRecurringRule, NewTransaction, RecurringRule, Transaction, MAX_TITLE_LENGTH};
+use crate::errors::RecurringError;
+use crate::ports::{CategoryRepository, RecurringRuleRepository, UnitOfWork, UnitOfWorkFactory};
+use crate::services::transaction_service::normalize_title;
+use crate::types::{BudgetMonth, DueDay, Money, TransactionDate, TransactionKind};
+
+fn validate_amount(amount: Money) -> Result<(), RecurringError> {
+    if amount.value() < 0 {
+        return Err(RecurringError::InvalidAmount {
+            value: amount.value(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_title_length(title: &Option<String>) -> Result<(), RecurringError> {
+    if let Some(t) = title
+        && t.len() > MAX_TITLE_LENGTH
+    {
+        return Err(RecurringError::TitleTooLong {
+            length: t.len(),
+            max: MAX_TITLE_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+fn validate_range(start: BudgetMonth, end: Option<BudgetMonth>) -> Result<(), RecurringError> {
+    if let Some(end) = end
+        && end < start
+    {
+        return Err(RecurringError::InvalidRange {
+            start: start.to_string(),
+            end: end.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Date the rule books its payment in `month`, if it should be booked by `today`.
+///
+/// `None` when the rule is paused, `month` is outside the rule's range, or the
+/// payment is not due yet. Due days past the end of a short month fall on its last day.
+fn booking_date(rule: &RecurringRule, month: BudgetMonth, today: NaiveDate) -> Option<NaiveDate> {
+    if rule.paused || month < rule.start_month {
+        return None;
+    }
+    if let Some(end) = rule.end_month
+        && month > end
+    {
+        return None;
+    }
+    let date = month.date_on(rule.day_of_month);
+    (date <= today).then_some(date)
+}
+
+/// Books the payments of all rules due in `month` by `today` that have not been booked there yet.
+///
+/// A rule whose category has no entry in the month is skipped and tried again on the
+/// next run, so adding the entry later still gets the payment booked.
+pub(crate) async fn materialize(
+    uow: &dyn UnitOfWork,
+    month: &Month,
+    today: NaiveDate,
+) -> Result<Vec<Transaction>, RecurringError> {
+    let rules = uow.recurring().list_unapplied(&month.id).await?;
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let entry_by_category: HashMap<Ulid, Ulid> = uow
+        .entries()
+        .list_by_month(&month.id)
+        .await
+        .map_err(|e| RecurringError::Repository(e.to_string()))?
+        .into_iter()
+        .map(|e| (e.category.id, e.id))
+        .collect();
+
+    let mut booked = Vec::new();
+    for rule in rules {
+        let Some(date) = booking_date(&rule, month.month, today) else {
+            continue;
+        };
+        let Some(entry_id) = entry_by_category.get(&rule.category_id) else {
+            continue;
+        };
+
+        let transaction = uow
+            .transactions()
+            .create(NewTransaction {
+                entry_id: *entry_id,
+                kind: TransactionKind::Payment,
+                amount: rule.amount,
+                date: TransactionDate::new(date),
+                title: rule.title.clone(),
+                refund_of: None,
+                payee_id: None,
+                account_id: None,
+                tags: Vec::new(),
+            })
+            .await
+            .map_err(|e| RecurringError::Repository(e.to_string()))?;
+        uow.recurring()
+            .record_run(&rule.id, &month.id, &transaction.id)
+            .await?;
+        booked.push(transaction);
+    }
+
+    Ok(booked)
+}
+
+pub struct RecurringService {
+    rule_repo: Arc<dyn RecurringRuleRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl RecurringService {
+    pub fn new(
+        rule_repo: Arc<dyn RecurringRuleRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            rule_repo,
+            category_repo,
+            unit_of_work,
+        }
+    }
+
+    pub async fn list_all(&self) -> Result<Vec<RecurringRule>, RecurringError> {
+        self.rule_repo.list_all().await
+    }
+
+    pub async fn find_by_id(&self, id: &Ulid) -> Result<RecurringRule, RecurringError> {
+        self.rule_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(RecurringError::NotFound)
+    }
+
+    /// Creates a recurring rule. Nothing is booked until the next run of
+    /// `materialize_due` or until a month in the rule's range is created.
+    ///
+    /// # Errors
+    ///
+    /// * `RecurringError::InvalidAmount` - Amount is negative
+    /// * `RecurringError::TitleTooLong` - Title exceeds maximum length
+    /// * `RecurringError::InvalidRange` - `end_month` is before `start_month`
+    /// * `RecurringError::CategoryNotFound` - Category does not exist
+    /// * `RecurringError::Repository` - Database error
+    pub async fn create(
+        &self,
+        category_id: Ulid,
+        amount: Money,
+        title: Option<String>,
+        day_of_month: DueDay,
+        start_month: BudgetMonth,
+        end_month: Option<BudgetMonth>,
+    ) -> Result<RecurringRule, RecurringError> {
+        validate_amount(amount)?;
+        let title = normalize_title(title);
+        validate_title_length(&title)?;
+        validate_range(start_month, end_month)?;
+
+        self.category_repo
+            .find_by_id(&category_id)
+            .await
+            .map_err(|e| RecurringError::Repository(e.to_string()))?
+            .ok_or(RecurringError::CategoryNotFound)?;
+
+        self.rule_repo
+            .create(NewRecurringRule {
+                category_id,
+                amount,
+                title,
+                day_of_month,
+                start_month,
+                end_month,
+            })
+            .await
+    }
+
+    /// Updates a rule. Payments it already booked are left as they are.
+    ///
+    /// # Errors
+    ///
+    /// * `RecurringError::NotFound` - Rule does not exist
+    /// * `RecurringError::InvalidAmount` - Amount is negative
+    /// * `RecurringError::TitleTooLong` - Title exceeds maximum length
+    /// * `RecurringError::InvalidRange` - The resulting end month is before the start month
+    /// * `RecurringError::Repository` - Database error
+    pub async fn update(
+        &self,
+        id: &Ulid,
+        amount: Option<Money>,
+        title: Option<Option<String>>,
+        day_of_month: Option<DueDay>,
+        start_month: Option<BudgetMonth>,
+        end_month: Option<Option<BudgetMonth>>,
+    ) -> Result<RecurringRule, RecurringError> {
+        let current = self.find_by_id(id).await?;
+
+        if let Some(amount) = amount {
+            validate_amount(amount)?;
+        }
+        let title = title.map(normalize_title);
+        if let Some(ref t) = title {
+            validate_title_length(t)?;
+        }
+        validate_range(
+            start_month.unwrap_or(current.start_month),
+            end_month.unwrap_or(current.end_month),
+        )?;
+
+        self.rule_repo
+            .update(id, amount, title, day_of_month, start_month, end_month)
+            .await
+    }
+
+    /// Pauses or resumes a rule. A resumed rule also books payments that fell due while
+    /// it was paused, for the months `materialize_due` looks at.
+    pub async fn set_paused(&self, id: &Ulid, paused: bool) -> Result<RecurringRule, RecurringError> {
+        self.find_by_id(id).await?;
+        self.rule_repo.set_paused(id, paused).await
+    }
+
+    pub async fn delete(&self, id: &Ulid) -> Result<(), RecurringError> {
+        self.find_by_id(id).await?;
+        self.rule_repo.delete(id).await
+    }
+
+    /// Books all payments due by `today` in its month and the month before.
+    ///
+    /// Meant to be run periodically. Looking one month back means a rule due at the end
+    /// of a month is still booked when no run happened between its due day and the
+    /// turn of the month. Months that do not exist are skipped.
+    pub async fn materialize_due(&self, today: NaiveDate) -> Result<Vec<Transaction>, RecurringError> {
+        let current = BudgetMonth::of_date(today)
+            .map_err(|e| RecurringError::Repository(e.to_string()))?;
+        let previous = match current.month() {
+            1 => BudgetMonth::new(current.year() - 1, 12),
+            m => BudgetMonth::new(current.year(), m - 1),
+        }
+        .ok();
+
+        let uow = self.unit_of_work.begin().await?;
+        let mut booked = Vec::new();
+        for budget_month in previous.into_iter().chain([current]) {
+            let month = uow
+                .months()
+                .find_by_month(&budget_month)
+                .await
+                .map_err(|e| RecurringError::Repository(e.to_string()))?;
+            if let Some(month) = month {
+                booked.extend(materialize(uow.as_ref(), &month, today).await?);
+            }
+        }
+        uow.commit().await?;
+        Ok(booked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(start: &str, end: Option<&str>, day: u8) -> RecurringRule {
+        RecurringRule {
+            id: Ulid::new(),
+            category_id: Ulid::new(),
+            amount: Money::new(1000),
+            title: None,
+            day_of_month: DueDay::new(day).unwrap(),
+            start_month: start.parse().unwrap(),
+            end_month: end.map(|e| e.parse().unwrap()),
+            paused: false,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_booking_date_due() {
+        let r = rule("2026-01", None, 10);
+        let month = "2026-03".parse().unwrap();
+        assert_eq!(booking_date(&r, month, date("2026-03-10")), Some(date("2026-03-10")));
+        assert_eq!(booking_date(&r, month, date("2026-04-01")), Some(date("2026-03-10")));
+    }
+
+    #[test]
+    fn test_booking_date_not_due_yet() {
+        let r = rule("2026-01", None, 10);
+        assert_eq!(booking_date(&r, "2026-03".parse().unwrap(), date("2026-03-09")), None);
+    }
+
+    #[test]
+    fn test_booking_date_outside_range() {
+        let r = rule("2026-02", Some("2026-04"), 1);
+        let today = date("2026-12-31");
+        assert_eq!(booking_date(&r, "2026-01".parse().unwrap(), today), None);
+        assert!(booking_date(&r, "2026-02".parse().unwrap(), today).is_some());
+        assert!(booking_date(&r, "2026-04".parse().unwrap(), today).is_some());
+        assert_eq!(booking_date(&r, "2026-05".parse().unwrap(), today), None);
+    }
+
+    #[test]
+    fn test_booking_date_paused() {
+        let mut r = rule("2026-01", None, 1);
+        r.paused = true;
+        assert_eq!(booking_date(&r, "2026-03".parse().unwrap(), date("2026-03-31")), None);
+    }
+
+    #[test]
+    fn test_booking_date_clamped_to_short_month() {
+        let r = rule("2026-01", None, 31);
+        let month = "2026-02".parse().unwrap();
+        assert_eq!(booking_date(&r, month, date("2026-02-27")), None);
+        assert_eq!(booking_date(&r, month, date("2026-02-28")), Some(date("2026-02-28")));
+    }
+
+    #[test]
+    fn test_validate_range() {
+        let jan = "2026-01".parse().unwrap();
+        let feb = "2026-02".parse().unwrap();
+        assert!(validate_range(jan, None).is_ok());
+        assert!(validate_range(jan, Some(jan)).is_ok());
+        assert!(validate_range(jan, Some(feb)).is_ok());
+        assert!(matches!(
+            validate_range(feb, Some(jan)),
+            Err(RecurringError::InvalidRange { .. })
+        ));
+    }
+}
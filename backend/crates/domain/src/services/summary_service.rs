@@ -1,26 +1,129 @@
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
-use crate::entities::CategorySummary;
+use crate::entities::{BudgetEntryWithCategory, CategorySummary, Month};
 use crate::errors::MonthError;
-use crate::ports::{BudgetEntryRepository, MonthRepository, TransactionRepository};
-use crate::types::{BudgetMonth, Money};
+use crate::ports::{BudgetEntryRepository, MonthRepository, TransactionRepository, UnitOfWorkFactory};
+use crate::types::{BudgetMonth, CategoryName, EntryKind, Money};
 
+/// `total_budgeted`, `total_carried_in`, `total_paid` and `remaining` cover expense
+/// entries only; income entries are totalled separately.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonthSummary {
     pub month: BudgetMonth,
     pub total_budgeted: Money,
+    /// Balances rolled over from the previous month
+    pub total_carried_in: Money,
     pub total_paid: Money,
+    /// `total_budgeted + total_carried_in - total_paid`
     pub remaining: Money,
+    /// Budgeted amount of income entries
+    pub expected_income: Money,
+    /// Received on income entries
+    pub total_income: Money,
+    /// Paid on expense entries, same as `total_paid`
+    pub total_spending: Money,
+    /// `total_income - total_spending`
+    pub net_savings: Money,
     pub categories: Vec<CategoryBudgetSummary>,
+    /// Per parent node totals, present only when rollups were requested
+    pub groups: Option<Vec<CategoryGroupSummary>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CategoryBudgetSummary {
     pub entry_id: ulid::Ulid,
     pub category: CategorySummary,
+    pub kind: EntryKind,
+    /// Planned spending, or expected income for income entries
     pub budgeted: Money,
+    /// Balance rolled over from the previous month; negative after overspending
+    pub carried_in: Money,
+    /// `budgeted + carried_in`, which `remaining` and `status` are measured against
+    pub available: Money,
+    /// Spent, or received for income entries
+    pub paid: Money,
+    pub remaining: Money,
+    pub status: BudgetStatus,
+}
+
+/// Totals of every expense entry whose category is `name` or lies below it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryGroupSummary {
+    pub name: CategoryName,
+    pub budgeted: Money,
+    pub carried_in: Money,
+    pub paid: Money,
+    pub remaining: Money,
+    pub status: BudgetStatus,
+    pub entry_count: usize,
+}
+
+/// Totals over an inclusive range of months, e.g. a whole year.
+///
+/// Only months that exist are included; there are no placeholders for gaps.
+/// Rolled over balances only move money between months, so the range and year
+/// totals leave them out; per month totals include them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RangeSummary {
+    pub from: BudgetMonth,
+    pub to: BudgetMonth,
+    pub total_budgeted: Money,
+    pub total_paid: Money,
+    pub remaining: Money,
+    pub total_income: Money,
+    pub net_savings: Money,
+    /// Per month totals, oldest first
+    pub months: Vec<MonthTotals>,
+    /// Per calendar year totals, oldest first
+    pub years: Vec<YearTotals>,
+    /// One series per category budgeted in the range, ordered by category name
+    pub categories: Vec<CategorySeries>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthTotals {
+    pub month_id: Ulid,
+    pub month: BudgetMonth,
+    pub total_budgeted: Money,
+    pub total_carried_in: Money,
+    pub total_paid: Money,
+    pub remaining: Money,
+    pub status: BudgetStatus,
+    pub total_income: Money,
+    pub net_savings: Money,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YearTotals {
+    pub year: i32,
+    pub total_budgeted: Money,
+    pub total_paid: Money,
+    pub remaining: Money,
+    pub status: BudgetStatus,
+    pub total_income: Money,
+    pub net_savings: Money,
+    pub month_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategorySeries {
+    pub category: CategorySummary,
+    pub total_budgeted: Money,
+    pub total_paid: Money,
+    pub remaining: Money,
+    /// One point per month the category has an entry in, oldest first
+    pub points: Vec<CategorySeriesPoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategorySeriesPoint {
+    pub month: BudgetMonth,
+    pub budgeted: Money,
+    pub carried_in: Money,
     pub paid: Money,
     pub remaining: Money,
     pub status: BudgetStatus,
@@ -35,7 +138,7 @@ pub enum BudgetStatus {
     Overspent,
 }
 
-fn derive_status(budgeted: Money, paid: Money) -> BudgetStatus {
+pub(crate) fn derive_status(budgeted: Money, paid: Money) -> BudgetStatus {
     let b = budgeted.value();
     let p = paid.value();
 
@@ -53,10 +156,233 @@ fn derive_status(budgeted: Money, paid: Money) -> BudgetStatus {
     }
 }
 
+/// Bills of a month: every expense entry with a due day, in due date order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueBills {
+    pub month_id: Ulid,
+    pub month: BudgetMonth,
+    /// Date the bills were classified against
+    pub today: NaiveDate,
+    pub bills: Vec<DueBill>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueBill {
+    pub entry_id: Ulid,
+    pub category: CategorySummary,
+    /// The entry's due day in this month, moved to the last day of shorter months
+    pub due_date: NaiveDate,
+    /// Days from `today` to `due_date`; negative once the bill is overdue
+    pub days_until: i64,
+    /// `budgeted + carried_in`, the amount that settles the bill
+    pub available: Money,
+    pub paid: Money,
+    pub remaining: Money,
+    pub status: DueStatus,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DueStatus {
+    /// Paid in full, whatever the due date
+    Paid,
+    /// Not paid and due later than the due-soon window
+    Upcoming,
+    DueSoon,
+    DueToday,
+    Overdue,
+}
+
+/// Classifies a bill due on `due_date`. It counts as paid once `paid` covers `available`;
+/// otherwise by how `due_date` relates to `today`, with bills due within `soon_days`
+/// after today being due soon.
+pub(crate) fn derive_due_status(
+    due_date: NaiveDate,
+    today: NaiveDate,
+    soon_days: u32,
+    available: Money,
+    paid: Money,
+) -> DueStatus {
+    if paid.value() >= available.value() {
+        return DueStatus::Paid;
+    }
+    let days_until = (due_date - today).num_days();
+    if days_until < 0 {
+        DueStatus::Overdue
+    } else if days_until == 0 {
+        DueStatus::DueToday
+    } else if days_until <= i64::from(soon_days) {
+        DueStatus::DueSoon
+    } else {
+        DueStatus::Upcoming
+    }
+}
+
+/// Sums of a month's entries, split by kind.
+#[derive(Debug, Clone, Copy)]
+struct KindTotals {
+    budgeted: Money,
+    carried_in: Money,
+    paid: Money,
+    expected_income: Money,
+    income: Money,
+}
+
+impl KindTotals {
+    fn of(categories: &[CategoryBudgetSummary]) -> Self {
+        let sum = |kind: EntryKind, amount: fn(&CategoryBudgetSummary) -> Money| -> Money {
+            categories.iter().filter(|c| c.kind == kind).map(amount).sum()
+        };
+        Self {
+            budgeted: sum(EntryKind::Expense, |c| c.budgeted),
+            carried_in: sum(EntryKind::Expense, |c| c.carried_in),
+            paid: sum(EntryKind::Expense, |c| c.paid),
+            expected_income: sum(EntryKind::Income, |c| c.budgeted),
+            income: sum(EntryKind::Income, |c| c.paid),
+        }
+    }
+}
+
+/// Rolls entry totals up into every parent node of the category hierarchy.
+///
+/// Only names that are a proper ancestor of some entry's category produce a group;
+/// an entry for the parent category itself (e.g. `utils` next to `utils/gas`)
+/// is counted in that parent's group. Income entries are left out. Groups are
+/// ordered by name.
+fn rollup_groups(categories: &[CategoryBudgetSummary]) -> Vec<CategoryGroupSummary> {
+    let categories: Vec<&CategoryBudgetSummary> =
+        categories.iter().filter(|c| c.kind == EntryKind::Expense).collect();
+    let parents: BTreeMap<String, CategoryName> = categories
+        .iter()
+        .flat_map(|c| c.category.name.ancestors())
+        .map(|a| (a.as_str().to_string(), a))
+        .collect();
+
+    parents
+        .into_values()
+        .map(|name| {
+            let members: Vec<&&CategoryBudgetSummary> = categories
+                .iter()
+                .filter(|c| c.category.name == name || c.category.name.is_descendant_of(&name))
+                .collect();
+            let budgeted: Money = members.iter().map(|c| c.budgeted).sum();
+            let carried_in: Money = members.iter().map(|c| c.carried_in).sum();
+            let paid: Money = members.iter().map(|c| c.paid).sum();
+            CategoryGroupSummary {
+                name,
+                budgeted,
+                carried_in,
+                paid,
+                remaining: budgeted + carried_in - paid,
+                status: derive_status(budgeted + carried_in, paid),
+                entry_count: members.len(),
+            }
+        })
+        .collect()
+}
+
+/// Aggregates per-entry summaries of several months, which must be ordered oldest first.
+fn build_range_summary(
+    from: BudgetMonth,
+    to: BudgetMonth,
+    months: Vec<(Month, Vec<CategoryBudgetSummary>)>,
+) -> RangeSummary {
+    let mut month_totals = Vec::with_capacity(months.len());
+    let mut years: Vec<YearTotals> = Vec::new();
+    let mut series: BTreeMap<Ulid, CategorySeries> = BTreeMap::new();
+
+    for (month, categories) in months {
+        let totals = KindTotals::of(&categories);
+        let (budgeted, paid, income) = (totals.budgeted, totals.paid, totals.income);
+        let available = budgeted + totals.carried_in;
+
+        month_totals.push(MonthTotals {
+            month_id: month.id,
+            month: month.month,
+            total_budgeted: budgeted,
+            total_carried_in: totals.carried_in,
+            total_paid: paid,
+            remaining: available - paid,
+            status: derive_status(available, paid),
+            total_income: income,
+            net_savings: income - paid,
+        });
+
+        match years.last_mut() {
+            Some(y) if y.year == month.month.year() => {
+                y.total_budgeted = y.total_budgeted + budgeted;
+                y.total_paid = y.total_paid + paid;
+                y.total_income = y.total_income + income;
+                y.month_count += 1;
+            }
+            _ => years.push(YearTotals {
+                year: month.month.year(),
+                total_budgeted: budgeted,
+                total_paid: paid,
+                remaining: Money::new(0),
+                status: BudgetStatus::OnBudget,
+                total_income: income,
+                net_savings: Money::new(0),
+                month_count: 1,
+            }),
+        }
+
+        for c in categories {
+            let s = series.entry(c.category.id).or_insert_with(|| CategorySeries {
+                category: c.category.clone(),
+                total_budgeted: Money::new(0),
+                total_paid: Money::new(0),
+                remaining: Money::new(0),
+                points: Vec::new(),
+            });
+            // Later months carry the current name and archive state
+            s.category = c.category;
+            s.total_budgeted = s.total_budgeted + c.budgeted;
+            s.total_paid = s.total_paid + c.paid;
+            s.remaining = s.total_budgeted - s.total_paid;
+            s.points.push(CategorySeriesPoint {
+                month: month.month,
+                budgeted: c.budgeted,
+                carried_in: c.carried_in,
+                paid: c.paid,
+                remaining: c.remaining,
+                status: c.status,
+            });
+        }
+    }
+
+    for y in &mut years {
+        y.remaining = y.total_budgeted - y.total_paid;
+        y.status = derive_status(y.total_budgeted, y.total_paid);
+        y.net_savings = y.total_income - y.total_paid;
+    }
+
+    let mut categories: Vec<CategorySeries> = series.into_values().collect();
+    categories.sort_by(|a, b| a.category.name.as_str().cmp(b.category.name.as_str()));
+
+    let total_budgeted: Money = month_totals.iter().map(|m| m.total_budgeted).sum();
+    let total_paid: Money = month_totals.iter().map(|m| m.total_paid).sum();
+    let total_income: Money = month_totals.iter().map(|m| m.total_income).sum();
+
+    RangeSummary {
+        from,
+        to,
+        total_budgeted,
+        total_paid,
+        remaining: total_budgeted - total_paid,
+        total_income,
+        net_savings: total_income - total_paid,
+        months: month_totals,
+        years,
+        categories,
+    }
+}
+
 pub struct SummaryService {
     entry_repo: Arc<dyn BudgetEntryRepository>,
     transaction_repo: Arc<dyn TransactionRepository>,
     month_repo: Arc<dyn MonthRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl SummaryService {
@@ -64,15 +390,100 @@ impl SummaryService {
         entry_repo: Arc<dyn BudgetEntryRepository>,
         transaction_repo: Arc<dyn TransactionRepository>,
         month_repo: Arc<dyn MonthRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
     ) -> Self {
         Self {
             entry_repo,
             transaction_repo,
             month_repo,
+            unit_of_work,
         }
     }
 
-    pub async fn get_month_summary(&self, month_id: &Ulid) -> Result<MonthSummary, MonthError> {
+    /// Budgeted vs paid for every entry of the month. With `rollup` set, also
+    /// returns totals per parent category node (see `CategoryGroupSummary`).
+    pub async fn get_month_summary(&self, month_id: &Ulid, rollup: bool) -> Result<MonthSummary, MonthError> {
+        let month = self
+            .month_repo
+            .find_by_id(month_id)
+            .await?
+            .ok_or(MonthError::NotFound)?;
+
+        let categories = self.summarize_entries(month_id).await?;
+        let totals = KindTotals::of(&categories);
+        let groups = rollup.then(|| rollup_groups(&categories));
+
+        Ok(MonthSummary {
+            month: month.month,
+            total_budgeted: totals.budgeted,
+            total_carried_in: totals.carried_in,
+            total_paid: totals.paid,
+            remaining: totals.budgeted + totals.carried_in - totals.paid,
+            expected_income: totals.expected_income,
+            total_income: totals.income,
+            total_spending: totals.paid,
+            net_savings: totals.income - totals.paid,
+            categories,
+            groups,
+        })
+    }
+
+    /// Per-month and per-category totals of every month between `from` and `to`
+    /// inclusive.
+    ///
+    /// Months, entries and paid totals are read with one query each, inside one
+    /// unit of work so they come from the same snapshot.
+    ///
+    /// # Errors
+    ///
+    /// * `MonthError::InvalidRange` - `from` is after `to`
+    pub async fn get_range_summary(
+        &self,
+        from: BudgetMonth,
+        to: BudgetMonth,
+    ) -> Result<RangeSummary, MonthError> {
+        if from > to {
+            return Err(MonthError::InvalidRange {
+                from: from.to_string(),
+                to: to.to_string(),
+            });
+        }
+
+        let uow = self.unit_of_work.begin().await?;
+        let months = uow.months().list_in_range(&from, &to).await?;
+        let mut entries_by_month = uow
+            .entries()
+            .list_by_month_range(&from, &to)
+            .await
+            .map_err(|e| MonthError::Repository(format!("Failed to list entries: {}", e)))?;
+        let paid_by_entry = uow
+            .transactions()
+            .sum_by_month_range(&from, &to)
+            .await
+            .map_err(|e| MonthError::Repository(format!("Failed to sum transactions: {}", e)))?;
+        // Read-only; dropping the unit of work just ends its transaction
+        drop(uow);
+
+        let summaries = months
+            .into_iter()
+            .map(|month| {
+                let entries = entries_by_month.remove(&month.id).unwrap_or_default();
+                let categories = summarize(entries, &paid_by_entry);
+                (month, categories)
+            })
+            .collect();
+
+        Ok(build_range_summary(from, to, summaries))
+    }
+
+    /// Classifies the month's bills against `today`. Only expense entries with a due
+    /// day are bills; see `derive_due_status` for the classification.
+    pub async fn get_due_bills(
+        &self,
+        month_id: &Ulid,
+        today: NaiveDate,
+        soon_days: u32,
+    ) -> Result<DueBills, MonthError> {
         let month = self
             .month_repo
             .find_by_id(month_id)
@@ -85,45 +496,86 @@ impl SummaryService {
             .await
             .map_err(|e| MonthError::Repository(format!("Failed to list entries: {}", e)))?;
 
-        let mut categories = Vec::with_capacity(entries.len());
-        let mut total_budgeted = Money::new(0);
-        let mut total_paid = Money::new(0);
-
-        for entry in entries {
-            let paid = self
-                .transaction_repo
-                .sum_by_entry(&entry.id)
-                .await
-                .map_err(|e| {
-                    MonthError::Repository(format!("Failed to sum transactions: {}", e))
-                })?;
-
-            let remaining = entry.budgeted - paid;
-            let status = derive_status(entry.budgeted, paid);
-
-            total_budgeted = total_budgeted + entry.budgeted;
-            total_paid = total_paid + paid;
-
-            categories.push(CategoryBudgetSummary {
-                entry_id: entry.id,
-                category: entry.category,
-                budgeted: entry.budgeted,
-                paid,
-                remaining,
-                status,
-            });
-        }
+        let paid_by_entry = self
+            .transaction_repo
+            .sum_by_month(month_id)
+            .await
+            .map_err(|e| MonthError::Repository(format!("Failed to sum transactions: {}", e)))?;
 
-        let remaining = total_budgeted - total_paid;
+        // Entries come ordered by due day, which clamping to the month's length keeps
+        let bills = entries
+            .into_iter()
+            .filter(|e| e.kind == EntryKind::Expense)
+            .filter_map(|entry| {
+                let due_date = month.month.date_on(entry.due_day?);
+                let paid = paid_by_entry.get(&entry.id).copied().unwrap_or(Money::new(0));
+                let available = entry.budgeted + entry.carried_in;
+                Some(DueBill {
+                    entry_id: entry.id,
+                    category: entry.category,
+                    due_date,
+                    days_until: (due_date - today).num_days(),
+                    available,
+                    paid,
+                    remaining: available - paid,
+                    status: derive_due_status(due_date, today, soon_days, available, paid),
+                })
+            })
+            .collect();
 
-        Ok(MonthSummary {
+        Ok(DueBills {
+            month_id: month.id,
             month: month.month,
-            total_budgeted,
-            total_paid,
-            remaining,
-            categories,
+            today,
+            bills,
         })
     }
+
+    async fn summarize_entries(&self, month_id: &Ulid) -> Result<Vec<CategoryBudgetSummary>, MonthError> {
+        let entries = self
+            .entry_repo
+            .list_by_month(month_id)
+            .await
+            .map_err(|e| MonthError::Repository(format!("Failed to list entries: {}", e)))?;
+
+        let paid_by_entry = self
+            .transaction_repo
+            .sum_by_month(month_id)
+            .await
+            .map_err(|e| MonthError::Repository(format!("Failed to sum transactions: {}", e)))?;
+
+        Ok(summarize(entries, &paid_by_entry))
+    }
+}
+
+/// Budgeted vs paid of each entry, given the paid totals by entry id.
+fn summarize(
+    entries: Vec<BudgetEntryWithCategory>,
+    paid_by_entry: &HashMap<Ulid, Money>,
+) -> Vec<CategoryBudgetSummary> {
+    let mut categories = Vec::with_capacity(entries.len());
+
+    for entry in entries {
+        let paid = paid_by_entry.get(&entry.id).copied().unwrap_or(Money::new(0));
+
+        let available = entry.budgeted + entry.carried_in;
+        let remaining = available - paid;
+        let status = derive_status(available, paid);
+
+        categories.push(CategoryBudgetSummary {
+            entry_id: entry.id,
+            category: entry.category,
+            kind: entry.kind,
+            budgeted: entry.budgeted,
+            carried_in: entry.carried_in,
+            available,
+            paid,
+            remaining,
+            status,
+        });
+    }
+
+    categories
 }
 
 #[cfg(test)]
@@ -169,4 +621,236 @@ mod tests {
         // budgeted == 0, paid > 0 -> Overspent
         assert_eq!(status(0, 500), BudgetStatus::Overspent);
     }
+
+    fn summary(name: &str, budgeted: i64, paid: i64) -> CategoryBudgetSummary {
+        CategoryBudgetSummary {
+            entry_id: Ulid::new(),
+            kind: EntryKind::Expense,
+            category: CategorySummary {
+                id: Ulid::new(),
+                name: CategoryName::new(name).unwrap(),
+                label: None,
+                archived: false,
+            },
+            budgeted: Money::new(budgeted),
+            carried_in: Money::new(0),
+            available: Money::new(budgeted),
+            paid: Money::new(paid),
+            remaining: Money::new(budgeted - paid),
+            status: status(budgeted, paid),
+        }
+    }
+
+    fn carried(name: &str, budgeted: i64, carried_in: i64, paid: i64) -> CategoryBudgetSummary {
+        CategoryBudgetSummary {
+            carried_in: Money::new(carried_in),
+            available: Money::new(budgeted + carried_in),
+            remaining: Money::new(budgeted + carried_in - paid),
+            status: status(budgeted + carried_in, paid),
+            ..summary(name, budgeted, paid)
+        }
+    }
+
+    #[test]
+    fn test_rollup_no_hierarchy() {
+        let groups = rollup_groups(&[summary("food", 100, 50), summary("rent", 200, 200)]);
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn test_rollup_sums_children() {
+        let groups = rollup_groups(&[
+            summary("utils/electricity", 300, 300),
+            summary("utils/gas", 200, 50),
+            summary("food", 1000, 0),
+        ]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].name.as_str(), "utils");
+        assert_eq!(groups[0].budgeted.value(), 500);
+        assert_eq!(groups[0].paid.value(), 350);
+        assert_eq!(groups[0].remaining.value(), 150);
+        assert_eq!(groups[0].status, BudgetStatus::Underspent);
+        assert_eq!(groups[0].entry_count, 2);
+    }
+
+    #[test]
+    fn test_rollup_nested_levels_and_parent_entry() {
+        let groups = rollup_groups(&[
+            summary("home", 100, 0),
+            summary("home/utils/gas", 200, 0),
+            summary("home/utils/water", 50, 0),
+        ]);
+        let names: Vec<&str> = groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["home", "home/utils"]);
+        assert_eq!(groups[0].budgeted.value(), 350);
+        assert_eq!(groups[0].entry_count, 3);
+        assert_eq!(groups[1].budgeted.value(), 250);
+        assert_eq!(groups[1].entry_count, 2);
+    }
+
+    fn month(s: &str) -> Month {
+        let now = chrono::Utc::now();
+        Month {
+            id: Ulid::new(),
+            month: s.parse().unwrap(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_range_summary_empty() {
+        let from: BudgetMonth = "2026-01".parse().unwrap();
+        let to: BudgetMonth = "2026-12".parse().unwrap();
+        let range = build_range_summary(from, to, vec![]);
+        assert!(range.months.is_empty());
+        assert!(range.years.is_empty());
+        assert!(range.categories.is_empty());
+        assert_eq!(range.total_budgeted.value(), 0);
+    }
+
+    #[test]
+    fn test_range_summary_totals_and_series() {
+        let food = summary("food", 1000, 800);
+        let mut food_feb = summary("food", 1000, 1200);
+        food_feb.category = food.category.clone();
+
+        let range = build_range_summary(
+            "2025-12".parse().unwrap(),
+            "2026-02".parse().unwrap(),
+            vec![
+                (month("2025-12"), vec![summary("rent", 2000, 2000)]),
+                (month("2026-01"), vec![food]),
+                (month("2026-02"), vec![food_feb]),
+            ],
+        );
+
+        assert_eq!(range.total_budgeted.value(), 4000);
+        assert_eq!(range.total_paid.value(), 4000);
+        assert_eq!(range.remaining.value(), 0);
+
+        let months: Vec<String> = range.months.iter().map(|m| m.month.to_string()).collect();
+        assert_eq!(months, vec!["2025-12", "2026-01", "2026-02"]);
+        assert_eq!(range.months[1].status, BudgetStatus::Underspent);
+        assert_eq!(range.months[2].status, BudgetStatus::Overspent);
+
+        assert_eq!(range.years.len(), 2);
+        assert_eq!(range.years[0].year, 2025);
+        assert_eq!(range.years[0].month_count, 1);
+        assert_eq!(range.years[1].year, 2026);
+        assert_eq!(range.years[1].month_count, 2);
+        assert_eq!(range.years[1].total_budgeted.value(), 2000);
+        assert_eq!(range.years[1].total_paid.value(), 2000);
+        assert_eq!(range.years[1].status, BudgetStatus::OnBudget);
+
+        let names: Vec<&str> = range.categories.iter().map(|c| c.category.name.as_str()).collect();
+        assert_eq!(names, vec!["food", "rent"]);
+        let food = &range.categories[0];
+        assert_eq!(food.points.len(), 2);
+        assert_eq!(food.points[1].month.to_string(), "2026-02");
+        assert_eq!(food.points[1].status, BudgetStatus::Overspent);
+        assert_eq!(food.total_paid.value(), 2000);
+        assert_eq!(food.remaining.value(), 0);
+    }
+
+    fn income(name: &str, expected: i64, received: i64) -> CategoryBudgetSummary {
+        CategoryBudgetSummary {
+            kind: EntryKind::Income,
+            ..summary(name, expected, received)
+        }
+    }
+
+    #[test]
+    fn test_kind_totals_split_income() {
+        let totals = KindTotals::of(&[
+            summary("rent", 2000, 2000),
+            summary("food", 1000, 400),
+            income("salary", 5000, 5200),
+        ]);
+        assert_eq!(totals.budgeted.value(), 3000);
+        assert_eq!(totals.paid.value(), 2400);
+        assert_eq!(totals.expected_income.value(), 5000);
+        assert_eq!(totals.income.value(), 5200);
+    }
+
+    #[test]
+    fn test_rollup_ignores_income() {
+        let groups = rollup_groups(&[
+            income("work/salary", 5000, 5000),
+            summary("work/lunch", 300, 100),
+        ]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].budgeted.value(), 300);
+        assert_eq!(groups[0].entry_count, 1);
+    }
+
+    #[test]
+    fn test_rollup_includes_carried_in() {
+        let groups = rollup_groups(&[
+            carried("utils/gas", 200, 50, 240),
+            carried("utils/water", 100, -30, 60),
+        ]);
+        assert_eq!(groups[0].carried_in.value(), 20);
+        assert_eq!(groups[0].remaining.value(), 20);
+        assert_eq!(groups[0].status, BudgetStatus::Underspent);
+    }
+
+    #[test]
+    fn test_range_summary_carried_in_per_month_only() {
+        let food = summary("food", 1000, 800);
+        let mut food_feb = carried("food", 1000, 200, 1100);
+        food_feb.category = food.category.clone();
+
+        let range = build_range_summary(
+            "2026-01".parse().unwrap(),
+            "2026-02".parse().unwrap(),
+            vec![(month("2026-01"), vec![food]), (month("2026-02"), vec![food_feb])],
+        );
+        assert_eq!(range.months[1].total_carried_in.value(), 200);
+        assert_eq!(range.months[1].remaining.value(), 100);
+        assert_eq!(range.months[1].status, BudgetStatus::Underspent);
+        assert_eq!(range.remaining.value(), 100);
+        assert_eq!(range.categories[0].points[1].carried_in.value(), 200);
+    }
+
+    #[test]
+    fn test_range_summary_income_and_savings() {
+        let range = build_range_summary(
+            "2026-01".parse().unwrap(),
+            "2026-02".parse().unwrap(),
+            vec![
+                (month("2026-01"), vec![summary("rent", 2000, 2000), income("salary", 5000, 5000)]),
+                (month("2026-02"), vec![summary("rent", 2000, 2500), income("salary", 5000, 0)]),
+            ],
+        );
+        assert_eq!(range.total_budgeted.value(), 4000);
+        assert_eq!(range.total_income.value(), 5000);
+        assert_eq!(range.net_savings.value(), 500);
+        assert_eq!(range.months[0].net_savings.value(), 3000);
+        assert_eq!(range.months[1].net_savings.value(), -2500);
+        assert_eq!(range.years[0].total_income.value(), 5000);
+        assert_eq!(range.years[0].net_savings.value(), 500);
+    }
+
+    fn due(due: &str, today: &str, available: i64, paid: i64) -> DueStatus {
+        let parse = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        derive_due_status(parse(due), parse(today), 3, Money::new(available), Money::new(paid))
+    }
+
+    #[test]
+    fn test_due_status_paid_regardless_of_date() {
+        assert_eq!(due("2026-03-10", "2026-03-20", 1000, 1000), DueStatus::Paid);
+        assert_eq!(due("2026-03-10", "2026-03-01", 1000, 1200), DueStatus::Paid);
+        // Nothing left to pay after overspending in earlier months
+        assert_eq!(due("2026-03-10", "2026-03-20", -500, 0), DueStatus::Paid);
+    }
+
+    #[test]
+    fn test_due_status_unpaid() {
+        assert_eq!(due("2026-03-10", "2026-03-11", 1000, 0), DueStatus::Overdue);
+        assert_eq!(due("2026-03-10", "2026-03-10", 1000, 999), DueStatus::DueToday);
+        assert_eq!(due("2026-03-10", "2026-03-09", 1000, 0), DueStatus::DueSoon);
+        assert_eq!(due("2026-03-10", "2026-03-07", 1000, 0), DueStatus::DueSoon);
+        assert_eq!(due("2026-03-10", "2026-03-06", 1000, 0), DueStatus::Upcoming);
+    }
 }
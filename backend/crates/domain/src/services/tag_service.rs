@@ -0,0 +1,64 @@
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::entities::{MonthSpending, NewTag, Tag};
+use crate::errors::TagError;
+use crate::ports::TagRepository;
+use crate::types::{Money, TagName};
+
+/// Spending tagged with one tag, overall and per month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagReport {
+    pub tag: Tag,
+    pub total_spent: Money,
+    /// Months with tagged spending, oldest first
+    pub months: Vec<MonthSpending>,
+}
+
+pub struct TagService {
+    repo: Arc<dyn TagRepository>,
+}
+
+impl TagService {
+    pub fn new(repo: Arc<dyn TagRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Lists tags ordered by name.
+    pub async fn list_all(&self) -> Result<Vec<Tag>, TagError> {
+        self.repo.list_all().await
+    }
+
+    pub async fn create(&self, name: TagName) -> Result<Tag, TagError> {
+        self.repo.create(NewTag { name }).await
+    }
+
+    /// Renames a tag; tagged transactions carry the new name from then on.
+    pub async fn rename(&self, id: &Ulid, name: TagName) -> Result<Tag, TagError> {
+        self.repo.rename(id, name).await
+    }
+
+    /// Deletes a tag and removes it from all transactions, which are kept.
+    pub async fn delete(&self, id: &Ulid) -> Result<(), TagError> {
+        self.repo.delete(id).await
+    }
+
+    /// Spending on expense entries tagged `id`, net of refunds.
+    ///
+    /// # Errors
+    ///
+    /// * `TagError::NotFound` - Tag does not exist
+    /// * `TagError::Repository` - Database error
+    pub async fn report(&self, id: &Ulid) -> Result<TagReport, TagError> {
+        let tag = self.repo.find_by_id(id).await?.ok_or(TagError::NotFound)?;
+        let months = self.repo.spending_by_month(id).await?;
+        let total_spent = months.iter().map(|m| m.spent).sum();
+
+        Ok(TagReport {
+            tag,
+            total_spent,
+            months,
+        })
+    }
+}
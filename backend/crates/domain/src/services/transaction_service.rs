@@ -1,10 +1,41 @@
 use std::sync::Arc;
+use chrono::NaiveDate;
 use ulid::Ulid;
 
-use crate::entities::{NewTransaction, Transaction, MAX_TITLE_LENGTH};
+use std::collections::HashSet;
+
+use crate::entities::{
+    NewTransaction, NewTransactionSplit, SplitAllocation, Transaction, TransactionFilter,
+    TransactionCursorPage, TransactionMatch, TransactionPage, TransactionScope, TransactionSplit,
+    TransactionSplitUpdate, TransactionUpdate, MAX_TITLE_LENGTH,
+};
 use crate::errors::TransactionError;
-use crate::ports::{BudgetEntryRepository, TransactionRepository};
-use crate::types::{Money, TransactionDate};
+use crate::ports::{TransactionRepository, UnitOfWork, UnitOfWorkFactory};
+use crate::services::summary_service::{derive_status, BudgetStatus};
+use crate::types::{
+    BudgetMonth, CursorDirection, EntryKind, Money, SearchQuery, TagName, TransactionCursor,
+    TransactionDate, TransactionKind,
+};
+
+/// Which date `TransactionService::pay_entry` books the payment on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentDate {
+    /// Only while the entry's month is the current one
+    Today,
+    /// The entry's due day in its month, clamped to the month's last day
+    DueDate,
+}
+
+/// Fills in a title template for `pay_entry`.
+///
+/// `{category}`, `{month}` ("YYYY-MM") and `{date}` ("YYYY-MM-DD") are replaced;
+/// anything else is kept as written.
+fn render_title(template: &str, category: &str, month: BudgetMonth, date: NaiveDate) -> String {
+    template
+        .replace("{category}", category)
+        .replace("{month}", &month.to_string())
+        .replace("{date}", &date.format("%Y-%m-%d").to_string())
+}
 
 /// Normalizes title by trimming whitespace and converting empty strings to None.
 ///
@@ -15,7 +46,7 @@ use crate::types::{Money, TransactionDate};
 /// # Returns
 ///
 /// Normalized title: `None` if input was `None`, empty, or whitespace-only; otherwise `Some` with trimmed string
-fn normalize_title(title: Option<String>) -> Option<String> {
+pub(crate) fn normalize_title(title: Option<String>) -> Option<String> {
     title.and_then(|t| {
         let trimmed = t.trim();
         if trimmed.is_empty() {
@@ -51,37 +82,316 @@ fn validate_title_length(title: &Option<String>) -> Result<(), TransactionError>
     Ok(())
 }
 
+async fn check_payee(uow: &dyn UnitOfWork, payee_id: &Ulid) -> Result<(), TransactionError> {
+    uow.payees()
+        .find_by_id(payee_id)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?
+        .ok_or(TransactionError::PayeeNotFound)?;
+    Ok(())
+}
+
+async fn check_account(uow: &dyn UnitOfWork, account_id: &Ulid) -> Result<(), TransactionError> {
+    uow.accounts()
+        .find_by_id(account_id)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?
+        .ok_or(TransactionError::AccountNotFound)?;
+    Ok(())
+}
+
+/// Sorts and deduplicates `tags` and checks that each of them exists.
+///
+/// # Errors
+///
+/// Returns `TransactionError::UnknownTag` naming the first tag that does not exist
+async fn resolve_tags(uow: &dyn UnitOfWork, mut tags: Vec<TagName>) -> Result<Vec<TagName>, TransactionError> {
+    tags.sort();
+    tags.dedup();
+    if tags.is_empty() {
+        return Ok(tags);
+    }
+
+    let existing: HashSet<TagName> = uow
+        .tags()
+        .find_by_names(&tags)
+        .await
+        .map_err(|e| TransactionError::Repository(e.to_string()))?
+        .into_iter()
+        .map(|t| t.name)
+        .collect();
+    if let Some(missing) = tags.iter().find(|t| !existing.contains(*t)) {
+        return Err(TransactionError::UnknownTag {
+            name: missing.as_str().to_string(),
+        });
+    }
+    Ok(tags)
+}
+
+/// Checks that a refund booked on `entry_id` may reference `original`.
+///
+/// # Errors
+///
+/// Returns `TransactionError::InvalidRefund` if the original is itself a refund or
+/// belongs to a different budget entry
+fn validate_refund_target(original: &Transaction, entry_id: &Ulid) -> Result<(), TransactionError> {
+    if original.kind == TransactionKind::Refund {
+        return Err(TransactionError::InvalidRefund {
+            reason: "a refund cannot be refunded".to_string(),
+        });
+    }
+    if original.entry_id != *entry_id {
+        return Err(TransactionError::InvalidRefund {
+            reason: "refund must be booked on the entry of the original payment".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that refunds of `refunded` in total do not exceed `original_amount`.
+///
+/// # Errors
+///
+/// Returns `TransactionError::RefundExceedsOriginal` if they do
+fn validate_refund_total(original_amount: Money, refunded: Money) -> Result<(), TransactionError> {
+    if refunded.value() > original_amount.value() {
+        return Err(TransactionError::RefundExceedsOriginal {
+            original_amount: original_amount.value(),
+            refunded: refunded.value(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that the ranges of a filter are not empty.
+///
+/// # Errors
+///
+/// * `TransactionError::InvalidFilter` - `from` after `to`, `min_amount` above `max_amount`,
+///   or a negative amount bound
+fn validate_filter(filter: &TransactionFilter) -> Result<(), TransactionError> {
+    match (filter.from, filter.to) {
+        (Some(from), Some(to)) if from.value() > to.value() => {
+            return Err(TransactionError::InvalidFilter {
+                reason: format!("from ({}) is after to ({})", from, to),
+            });
+        }
+        _ => {}
+    }
+
+    if let Some(negative) = [filter.min_amount, filter.max_amount]
+        .into_iter()
+        .flatten()
+        .find(|a| a.value() < 0)
+    {
+        return Err(TransactionError::InvalidFilter {
+            reason: format!("amount bounds cannot be negative, got {}", negative.value()),
+        });
+    }
+
+    match (filter.min_amount, filter.max_amount) {
+        (Some(min), Some(max)) if min.value() > max.value() => {
+            return Err(TransactionError::InvalidFilter {
+                reason: format!(
+                    "min_amount ({}) is above max_amount ({})",
+                    min.value(),
+                    max.value()
+                ),
+            });
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn cursor_at(direction: CursorDirection, transaction: &Transaction) -> TransactionCursor {
+    TransactionCursor::new(
+        direction,
+        transaction.date,
+        transaction.created_at,
+        transaction.id,
+    )
+}
+
+/// Cuts one page out of `fetched`, the newest-first rows read for `cursor`
+/// with one row beyond `limit` to tell whether more follow on that side.
+/// A request with a cursor is taken to have come from a page on its other side.
+fn cursor_page(
+    mut fetched: Vec<Transaction>,
+    cursor: Option<&TransactionCursor>,
+    limit: u32,
+) -> TransactionCursorPage {
+    let limit = limit as usize;
+    let more = fetched.len() > limit;
+    let (has_newer, has_older) = match cursor.map(|c| c.direction) {
+        None => (false, more),
+        Some(CursorDirection::After) => (true, more),
+        Some(CursorDirection::Before) => (more, true),
+    };
+    if more {
+        match cursor.map(|c| c.direction) {
+            Some(CursorDirection::Before) => {
+                fetched.drain(..fetched.len() - limit);
+            }
+            _ => fetched.truncate(limit),
+        }
+    }
+
+    let next_cursor = match fetched.last() {
+        Some(last) if has_older => Some(cursor_at(CursorDirection::After, last)),
+        _ => None,
+    };
+    let prev_cursor = match fetched.first() {
+        Some(first) if has_newer => Some(cursor_at(CursorDirection::Before, first)),
+        _ => None,
+    };
+    TransactionCursorPage {
+        items: fetched,
+        next_cursor,
+        prev_cursor,
+    }
+}
+
+/// Checks the amounts of a split payment.
+///
+/// # Errors
+///
+/// * `TransactionError::InvalidAmount` - Total or an allocation is negative
+/// * `TransactionError::InvalidSplit` - Fewer than two allocations, or an entry is allocated twice
+/// * `TransactionError::SplitTotalMismatch` - Allocations do not add up to the total
+fn validate_split(amount: Money, allocations: &[SplitAllocation]) -> Result<(), TransactionError> {
+    if let Some(negative) = std::iter::once(amount)
+        .chain(allocations.iter().map(|a| a.amount))
+        .find(|a| a.value() < 0)
+    {
+        return Err(TransactionError::InvalidAmount {
+            value: negative.value(),
+        });
+    }
+
+    if allocations.len() < 2 {
+        return Err(TransactionError::InvalidSplit {
+            reason: "a split needs at least two allocations".to_string(),
+        });
+    }
+
+    let mut entries = HashSet::new();
+    if !allocations.iter().all(|a| entries.insert(a.entry_id)) {
+        return Err(TransactionError::InvalidSplit {
+            reason: "each entry can only be allocated once".to_string(),
+        });
+    }
+
+    let allocated: Money = allocations.iter().map(|a| a.amount).sum();
+    if allocated != amount {
+        return Err(TransactionError::SplitTotalMismatch {
+            total: amount.value(),
+            allocated: allocated.value(),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that all allocated entries exist, plan expenses and belong to the same month.
+async fn validate_split_entries(
+    uow: &dyn UnitOfWork,
+    allocations: &[SplitAllocation],
+) -> Result<(), TransactionError> {
+    let mut month_id = None;
+    for allocation in allocations {
+        let entry = uow
+            .entries()
+            .find_by_id(&allocation.entry_id)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?
+            .ok_or(TransactionError::EntryNotFound)?;
+
+        if entry.kind == EntryKind::Income {
+            return Err(TransactionError::InvalidSplit {
+                reason: "allocations cannot go to income entries".to_string(),
+            });
+        }
+        if *month_id.get_or_insert(entry.month_id) != entry.month_id {
+            return Err(TransactionError::InvalidSplit {
+                reason: "all allocations must be in the same month".to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
 pub struct TransactionService {
     transaction_repo: Arc<dyn TransactionRepository>,
-    entry_repo: Arc<dyn BudgetEntryRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl TransactionService {
     pub fn new(
         transaction_repo: Arc<dyn TransactionRepository>,
-        entry_repo: Arc<dyn BudgetEntryRepository>,
+        unit_of_work: Arc<dyn UnitOfWorkFactory>,
     ) -> Self {
         Self {
             transaction_repo,
-            entry_repo,
+            unit_of_work,
         }
     }
 
+    /// Transactions of the month, only those tagged `tag` if given.
     pub async fn list_by_month(
         &self,
         month_id: &Ulid,
+        tag: Option<&TagName>,
     ) -> Result<Vec<Transaction>, TransactionError> {
-        self.transaction_repo.list_by_month(month_id).await
+        self.transaction_repo.list_by_month(month_id, tag).await
+    }
+
+    pub async fn list_by_tag(&self, tag: &TagName) -> Result<Vec<Transaction>, TransactionError> {
+        self.transaction_repo.list_by_tag(tag).await
+    }
+
+    /// A page of the transactions of all months matching `filter`, with the
+    /// count and net amount of all matches. A blank title filter is ignored.
+    ///
+    /// # Errors
+    ///
+    /// * `TransactionError::InvalidFilter` - Empty date or amount range
+    /// * `TransactionError::Repository` - Database error
+    pub async fn list_filtered(
+        &self,
+        mut filter: TransactionFilter,
+        limit: u32,
+        offset: u32,
+    ) -> Result<TransactionPage, TransactionError> {
+        filter.title = normalize_title(filter.title);
+        validate_filter(&filter)?;
+        self.transaction_repo.list_filtered(&filter, limit, offset).await
+    }
+
+    /// Searches transaction titles in all months, best matches first, at most
+    /// `limit` of them.
+    pub async fn search(
+        &self,
+        query: &SearchQuery,
+        limit: u32,
+    ) -> Result<Vec<TransactionMatch>, TransactionError> {
+        self.transaction_repo.search(query, limit).await
     }
 
     /// Creates a new transaction.
     ///
     /// # Arguments
     ///
-    /// * `entry_id` - Budget entry this transaction belongs to
-    /// * `amount` - Transaction amount (must be non-negative)
-    /// * `date` - Transaction date
-    /// * `title` - Optional transaction title (max 50 characters)
+    /// * `transaction` - The transaction to book:
+    ///   * `entry_id` - Budget entry this transaction belongs to
+    ///   * `kind` - Payment, or refund reducing what was paid on the entry
+    ///   * `amount` - Transaction amount (must be non-negative, also for refunds)
+    ///   * `date` - Transaction date
+    ///   * `title` - Optional transaction title (max 50 characters)
+    ///   * `refund_of` - Optional payment this refund returns money from
+    ///   * `payee_id` - Optional payee paid
+    ///   * `account_id` - Optional account paid from or into
+    ///   * `tags` - Names of existing tags; duplicates are ignored
     ///
     /// # Returns
     ///
@@ -92,14 +402,27 @@ impl TransactionService {
     /// * `TransactionError::InvalidAmount` - Amount is negative
     /// * `TransactionError::EntryNotFound` - Budget entry does not exist
     /// * `TransactionError::TitleTooLong` - Title exceeds maximum length
+    /// * `TransactionError::RefundOriginalNotFound` - Referenced payment does not exist
+    /// * `TransactionError::InvalidRefund` - `refund_of` given for a payment, or the
+    ///   original is a refund or on another entry
+    /// * `TransactionError::RefundExceedsOriginal` - Refunds would exceed the original amount
+    /// * `TransactionError::PayeeNotFound` - Payee does not exist
+    /// * `TransactionError::AccountNotFound` - Account does not exist
+    /// * `TransactionError::UnknownTag` - A tag does not exist
     /// * `TransactionError::Repository` - Database error
-    pub async fn create(
-        &self,
-        entry_id: Ulid,
-        amount: Money,
-        date: TransactionDate,
-        title: Option<String>,
-    ) -> Result<Transaction, TransactionError> {
+    pub async fn create(&self, transaction: NewTransaction) -> Result<Transaction, TransactionError> {
+        let NewTransaction {
+            entry_id,
+            kind,
+            amount,
+            date,
+            title,
+            refund_of,
+            payee_id,
+            account_id,
+            tags,
+        } = transaction;
+
         // Validate amount >= 0
         if amount.value() < 0 {
             return Err(TransactionError::InvalidAmount {
@@ -111,32 +434,77 @@ impl TransactionService {
         let normalized_title = normalize_title(title);
         validate_title_length(&normalized_title)?;
 
+        if refund_of.is_some() && kind != TransactionKind::Refund {
+            return Err(TransactionError::InvalidRefund {
+                reason: "only refunds can reference an original transaction".to_string(),
+            });
+        }
+
+        // Checking the refunded total and inserting must not interleave with another refund
+        let uow = self.unit_of_work.begin().await?;
+
         // Check entry exists
-        self.entry_repo
+        uow.entries()
             .find_by_id(&entry_id)
             .await
             .map_err(|e| TransactionError::Repository(e.to_string()))?
             .ok_or(TransactionError::EntryNotFound)?;
 
+        if let Some(ref original_id) = refund_of {
+            let original = uow
+                .transactions()
+                .find_by_id(original_id)
+                .await?
+                .ok_or(TransactionError::RefundOriginalNotFound)?;
+            validate_refund_target(&original, &entry_id)?;
+
+            let refunded = uow.transactions().refunded_total(original_id, None).await?;
+            validate_refund_total(original.amount, refunded + amount)?;
+        }
+
+        if let Some(ref payee_id) = payee_id {
+            check_payee(uow.as_ref(), payee_id).await?;
+        }
+        if let Some(ref account_id) = account_id {
+            check_account(uow.as_ref(), account_id).await?;
+        }
+        let tags = resolve_tags(uow.as_ref(), tags).await?;
+
         let new_transaction = NewTransaction {
             entry_id,
+            kind,
             amount,
             date,
             title: normalized_title,
+            refund_of,
+            payee_id,
+            account_id,
+            tags,
         };
 
-        self.transaction_repo.create(new_transaction).await
+        let created = uow.transactions().create(new_transaction).await?;
+        uow.commit().await?;
+        Ok(created)
     }
 
     /// Updates an existing transaction.
     ///
+    /// Allocations of a split payment can only be changed through the split.
+    /// Kind and refund link are fixed at creation. A linked refund stays within what
+    /// is left of its original; a payment that has refunds keeps its entry and cannot
+    /// drop below the refunded total.
+    ///
     /// # Arguments
     ///
     /// * `id` - Transaction ID to update
-    /// * `entry_id` - Optional new budget entry ID
-    /// * `amount` - Optional new amount (must be non-negative)
-    /// * `date` - Optional new date
-    /// * `title` - Optional title update: `None` = don't change, `Some(None)` = clear, `Some(Some(v))` = set value
+    /// * `changes` - Fields to change:
+    ///   * `entry_id` - Optional new budget entry ID
+    ///   * `amount` - Optional new amount (must be non-negative)
+    ///   * `date` - Optional new date
+    ///   * `title` - Optional title update: `None` = don't change, `Some(None)` = clear, `Some(Some(v))` = set value
+    ///   * `payee_id` - Optional payee update, like `title`
+    ///   * `account_id` - Optional account update, like `title`
+    ///   * `tags` - Optional replacement for all tags of the transaction
     ///
     /// # Returns
     ///
@@ -148,18 +516,35 @@ impl TransactionService {
     /// * `TransactionError::InvalidAmount` - Amount is negative
     /// * `TransactionError::EntryNotFound` - Budget entry does not exist
     /// * `TransactionError::TitleTooLong` - Title exceeds maximum length
+    /// * `TransactionError::PartOfSplit` - Transaction is an allocation of a split payment
+    /// * `TransactionError::InvalidRefund` - Change would separate a refund from its original
+    /// * `TransactionError::RefundExceedsOriginal` - Refunds would exceed the original amount
+    /// * `TransactionError::PayeeNotFound` - Payee does not exist
+    /// * `TransactionError::AccountNotFound` - Account does not exist
+    /// * `TransactionError::UnknownTag` - A tag does not exist
     /// * `TransactionError::Repository` - Database error
     pub async fn update(
         &self,
         id: &Ulid,
-        entry_id: Option<Ulid>,
-        amount: Option<Money>,
-        date: Option<TransactionDate>,
-        title: Option<Option<String>>,
+        mut changes: TransactionUpdate,
     ) -> Result<Transaction, TransactionError> {
+        let uow = self.unit_of_work.begin().await?;
+
+        let current = uow
+            .transactions()
+            .find_by_id(id)
+            .await?
+            .ok_or(TransactionError::NotFound)?;
+
+        if let Some(split_id) = current.split_id {
+            return Err(TransactionError::PartOfSplit {
+                split_id: split_id.to_string(),
+            });
+        }
+
         // If entry_id provided, verify it exists
-        if let Some(ref eid) = entry_id {
-            self.entry_repo
+        if let Some(ref eid) = changes.entry_id {
+            uow.entries()
                 .find_by_id(eid)
                 .await
                 .map_err(|e| TransactionError::Repository(e.to_string()))?
@@ -167,7 +552,7 @@ impl TransactionService {
         }
 
         // If amount provided, validate >= 0
-        if let Some(ref amt) = amount
+        if let Some(ref amt) = changes.amount
             && amt.value() < 0
         {
             return Err(TransactionError::InvalidAmount {
@@ -176,26 +561,349 @@ impl TransactionService {
         }
 
         // If title provided, normalize and validate
-        let normalized_title = title.map(normalize_title);
-        if let Some(ref t) = normalized_title {
+        changes.title = changes.title.map(normalize_title);
+        if let Some(ref t) = changes.title {
             validate_title_length(t)?;
         }
 
-        self.transaction_repo.update(id, entry_id, amount, date, normalized_title).await
+        let new_entry_id = changes.entry_id.unwrap_or(current.entry_id);
+        let new_amount = changes.amount.unwrap_or(current.amount);
+
+        if let Some(ref original_id) = current.refund_of {
+            // The original may have been deleted since, which unlinks the refund
+            if let Some(original) = uow.transactions().find_by_id(original_id).await? {
+                validate_refund_target(&original, &new_entry_id)?;
+                let others = uow.transactions().refunded_total(original_id, Some(id)).await?;
+                validate_refund_total(original.amount, others + new_amount)?;
+            }
+        } else if current.kind == TransactionKind::Payment {
+            let refunded = uow.transactions().refunded_total(id, None).await?;
+            if refunded.value() > 0 {
+                if new_entry_id != current.entry_id {
+                    return Err(TransactionError::InvalidRefund {
+                        reason: "a payment with refunds cannot move to another entry".to_string(),
+                    });
+                }
+                validate_refund_total(new_amount, refunded)?;
+            }
+        }
+
+        if let Some(Some(ref payee_id)) = changes.payee_id {
+            check_payee(uow.as_ref(), payee_id).await?;
+        }
+        if let Some(Some(ref account_id)) = changes.account_id {
+            check_account(uow.as_ref(), account_id).await?;
+        }
+        changes.tags = match changes.tags {
+            Some(tags) => Some(resolve_tags(uow.as_ref(), tags).await?),
+            None => None,
+        };
+
+        let updated = uow.transactions().update(id, changes).await?;
+        uow.commit().await?;
+        Ok(updated)
+    }
+
+    /// Books a payment of everything left on an entry, marking the bill as paid.
+    ///
+    /// The amount is the entry's remaining amount as in the month summary
+    /// (`budgeted + carried_in - paid`).
+    ///
+    /// # Arguments
+    ///
+    /// * `month_id` - Month the entry must belong to
+    /// * `entry_id` - Budget entry to pay
+    /// * `on` - Book on `today` or on the entry's due date
+    /// * `today` - Current date
+    /// * `title_template` - Optional title, see `render_title` for placeholders
+    ///
+    /// # Errors
+    ///
+    /// * `TransactionError::EntryNotFound` - Entry does not exist or is in another month
+    /// * `TransactionError::EntryIsIncome` - Entry plans income, which is received rather than paid
+    /// * `TransactionError::EntryAlreadyPaid` - Entry is on budget or overspent already
+    /// * `TransactionError::EntryHasNoDueDay` - `on` is `DueDate` but the entry has no due day
+    /// * `TransactionError::DateOutsideMonth` - `on` is `Today` but `today` is not in the entry's month
+    /// * `TransactionError::TitleTooLong` - Rendered title exceeds maximum length
+    /// * `TransactionError::Repository` - Database error
+    pub async fn pay_entry(
+        &self,
+        month_id: &Ulid,
+        entry_id: &Ulid,
+        on: PaymentDate,
+        today: NaiveDate,
+        title_template: Option<String>,
+    ) -> Result<Transaction, TransactionError> {
+        // Reading the paid total and booking the rest must not interleave with other payments
+        let uow = self.unit_of_work.begin().await?;
+
+        let entry = uow
+            .entries()
+            .find_by_id(entry_id)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?
+            .filter(|e| e.month_id == *month_id)
+            .ok_or(TransactionError::EntryNotFound)?;
+        if entry.kind == EntryKind::Income {
+            return Err(TransactionError::EntryIsIncome);
+        }
+
+        let available = entry.budgeted + entry.carried_in;
+        let paid = uow.transactions().sum_by_entry(entry_id).await?;
+        if matches!(derive_status(available, paid), BudgetStatus::OnBudget | BudgetStatus::Overspent) {
+            return Err(TransactionError::EntryAlreadyPaid {
+                available: available.value(),
+                paid: paid.value(),
+            });
+        }
+
+        let month = uow
+            .months()
+            .find_by_id(month_id)
+            .await
+            .map_err(|e| TransactionError::Repository(e.to_string()))?
+            .ok_or(TransactionError::EntryNotFound)?;
+
+        let date = match on {
+            PaymentDate::Today => today,
+            PaymentDate::DueDate => month
+                .month
+                .date_on(entry.due_day.ok_or(TransactionError::EntryHasNoDueDay)?),
+        };
+        if BudgetMonth::of_date(date).ok() != Some(month.month) {
+            return Err(TransactionError::DateOutsideMonth {
+                date: date.to_string(),
+                month: month.month.to_string(),
+            });
+        }
+
+        let title = match title_template {
+            Some(template) => {
+                let category = uow
+                    .categories()
+                    .find_by_id(&entry.category_id)
+                    .await
+                    .map_err(|e| TransactionError::Repository(e.to_string()))?
+                    .ok_or_else(|| TransactionError::Repository("entry category missing".to_string()))?;
+                normalize_title(Some(render_title(&template, category.name.as_str(), month.month, date)))
+            }
+            None => None,
+        };
+        validate_title_length(&title)?;
+
+        let created = uow
+            .transactions()
+            .create(NewTransaction {
+                entry_id: *entry_id,
+                kind: TransactionKind::Payment,
+                amount: available - paid,
+                date: TransactionDate::new(date),
+                title,
+                refund_of: None,
+                payee_id: None,
+                account_id: None,
+                tags: Vec::new(),
+            })
+            .await?;
+        uow.commit().await?;
+        Ok(created)
     }
 
+    /// A page of the entry's transactions, only those tagged `tag` if given.
     pub async fn list_by_entry(
         &self,
         entry_id: &Ulid,
+        tag: Option<&TagName>,
         limit: u32,
         offset: u32,
     ) -> Result<Vec<Transaction>, TransactionError> {
-        self.transaction_repo.list_by_entry(entry_id, limit, offset).await
+        self.transaction_repo
+            .list_by_entry(entry_id, tag, limit, offset)
+            .await
     }
 
+    /// A page of the transactions of `scope`, newest first, only those tagged
+    /// `tag` if given. Without a cursor this is the first page.
+    pub async fn list_page(
+        &self,
+        scope: &TransactionScope,
+        tag: Option<&TagName>,
+        cursor: Option<&TransactionCursor>,
+        limit: u32,
+    ) -> Result<TransactionCursorPage, TransactionError> {
+        let fetched = self
+            .transaction_repo
+            .list_by_cursor(scope, tag, cursor, limit.saturating_add(1))
+            .await?;
+        Ok(cursor_page(fetched, cursor, limit))
+    }
+
+    /// Deletes a transaction. Allocations of a split payment are deleted with their split.
+    ///
+    /// # Errors
+    ///
+    /// * `TransactionError::NotFound` - Transaction does not exist
+    /// * `TransactionError::PartOfSplit` - Transaction is an allocation of a split payment
+    /// * `TransactionError::Repository` - Database error
     pub async fn delete(&self, id: &Ulid) -> Result<(), TransactionError> {
+        if let Some(transaction) = self.transaction_repo.find_by_id(id).await?
+            && let Some(split_id) = transaction.split_id
+        {
+            return Err(TransactionError::PartOfSplit {
+                split_id: split_id.to_string(),
+            });
+        }
         self.transaction_repo.delete(id).await
     }
+
+    /// Creates a payment split across several budget entries of one month.
+    ///
+    /// # Arguments
+    ///
+    /// * `split` - The payment to book:
+    ///   * `amount` - Total amount of the payment
+    ///   * `date` - Payment date, shared by all allocations
+    ///   * `title` - Optional title, shared by all allocations
+    ///   * `payee_id` - Optional payee paid, shared by all allocations
+    ///   * `account_id` - Optional account paid from, shared by all allocations
+    ///   * `allocations` - Entry and amount of each part; amounts must add up to `amount`
+    ///
+    /// # Errors
+    ///
+    /// * `TransactionError::InvalidAmount` - Total or an allocation is negative
+    /// * `TransactionError::InvalidSplit` - Fewer than two allocations, an entry allocated
+    ///   twice, an income entry, or entries from different months
+    /// * `TransactionError::SplitTotalMismatch` - Allocations do not add up to the total
+    /// * `TransactionError::EntryNotFound` - An allocated entry does not exist
+    /// * `TransactionError::TitleTooLong` - Title exceeds maximum length
+    /// * `TransactionError::PayeeNotFound` - Payee does not exist
+    /// * `TransactionError::AccountNotFound` - Account does not exist
+    /// * `TransactionError::Repository` - Database error
+    pub async fn create_split(&self, split: NewTransactionSplit) -> Result<TransactionSplit, TransactionError> {
+        let normalized_title = normalize_title(split.title);
+        validate_title_length(&normalized_title)?;
+        validate_split(split.amount, &split.allocations)?;
+
+        let uow = self.unit_of_work.begin().await?;
+        validate_split_entries(uow.as_ref(), &split.allocations).await?;
+        if let Some(ref payee_id) = split.payee_id {
+            check_payee(uow.as_ref(), payee_id).await?;
+        }
+        if let Some(ref account_id) = split.account_id {
+            check_account(uow.as_ref(), account_id).await?;
+        }
+
+        let split = uow
+            .transactions()
+            .create_split(NewTransactionSplit {
+                title: normalized_title,
+                ..split
+            })
+            .await?;
+        uow.commit().await?;
+        Ok(split)
+    }
+
+    pub async fn get_split(&self, id: &Ulid) -> Result<TransactionSplit, TransactionError> {
+        self.transaction_repo
+            .find_split(id)
+            .await?
+            .ok_or(TransactionError::SplitNotFound)
+    }
+
+    /// Updates a split payment as a unit.
+    ///
+    /// Date, title, payee and account changes apply to every allocation. Given
+    /// `allocations` replace the current ones; either way the allocations must add
+    /// up to the (new) amount.
+    ///
+    /// # Errors
+    ///
+    /// * `TransactionError::SplitNotFound` - Split does not exist
+    /// * `TransactionError::InvalidSplit` - Allocations are invalid, or replaced while
+    ///   one of them has refunds or attachments
+    /// * `TransactionError::SplitTotalMismatch` - Allocations do not add up to the total
+    /// * see [`TransactionService::create_split`] for the remaining validation errors
+    pub async fn update_split(
+        &self,
+        id: &Ulid,
+        changes: TransactionSplitUpdate,
+    ) -> Result<TransactionSplit, TransactionError> {
+        let normalized_title = changes.title.map(normalize_title);
+        if let Some(ref t) = normalized_title {
+            validate_title_length(t)?;
+        }
+
+        let uow = self.unit_of_work.begin().await?;
+        if let Some(Some(ref payee_id)) = changes.payee_id {
+            check_payee(uow.as_ref(), payee_id).await?;
+        }
+        if let Some(Some(ref account_id)) = changes.account_id {
+            check_account(uow.as_ref(), account_id).await?;
+        }
+
+        let current = uow
+            .transactions()
+            .find_split(id)
+            .await?
+            .ok_or(TransactionError::SplitNotFound)?;
+
+        let new_amount = changes.amount.unwrap_or(current.amount);
+        match changes.allocations {
+            Some(ref new_allocations) => {
+                validate_split(new_amount, new_allocations)?;
+                validate_split_entries(uow.as_ref(), new_allocations).await?;
+
+                // Replacing allocations would unlink their refunds and delete their attachments
+                for allocation in &current.allocations {
+                    if uow.transactions().refunded_total(&allocation.id, None).await?.value() > 0 {
+                        return Err(TransactionError::InvalidSplit {
+                            reason: "allocations with refunds cannot be replaced".to_string(),
+                        });
+                    }
+                    let attachments = uow
+                        .attachments()
+                        .list_by_transaction(&allocation.id)
+                        .await
+                        .map_err(|e| TransactionError::Repository(e.to_string()))?;
+                    if !attachments.is_empty() {
+                        return Err(TransactionError::InvalidSplit {
+                            reason: "allocations with attachments cannot be replaced".to_string(),
+                        });
+                    }
+                }
+            }
+            None => {
+                let current_allocations: Vec<SplitAllocation> = current
+                    .allocations
+                    .iter()
+                    .map(|t| SplitAllocation {
+                        entry_id: t.entry_id,
+                        amount: t.amount,
+                    })
+                    .collect();
+                validate_split(new_amount, &current_allocations)?;
+            }
+        }
+
+        let split = uow
+            .transactions()
+            .update_split(
+                id,
+                TransactionSplitUpdate {
+                    title: normalized_title,
+                    ..changes
+                },
+            )
+            .await?;
+        uow.commit().await?;
+        Ok(split)
+    }
+
+    /// Deletes a split payment together with its allocations.
+    pub async fn delete_split(&self, id: &Ulid) -> Result<(), TransactionError> {
+        self.transaction_repo.delete_split(id).await
+    }
 }
 
 #[cfg(test)]
@@ -251,6 +959,151 @@ mod tests {
         assert!(validate_title_length(&title).is_ok());
     }
 
+    fn payment(entry_id: Ulid, amount: i64) -> Transaction {
+        Transaction {
+            id: Ulid::new(),
+            entry_id,
+            kind: TransactionKind::Payment,
+            amount: Money::new(amount),
+            date: "2026-01-15".parse().unwrap(),
+            title: None,
+            refund_of: None,
+            split_id: None,
+            payee_id: None,
+            account_id: None,
+            tags: Vec::new(),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_validate_refund_target_same_entry() {
+        let entry_id = Ulid::new();
+        assert!(validate_refund_target(&payment(entry_id, 1000), &entry_id).is_ok());
+    }
+
+    #[test]
+    fn test_validate_refund_target_other_entry() {
+        let result = validate_refund_target(&payment(Ulid::new(), 1000), &Ulid::new());
+        assert!(matches!(result, Err(TransactionError::InvalidRefund { .. })));
+    }
+
+    #[test]
+    fn test_validate_refund_target_refund_of_refund() {
+        let entry_id = Ulid::new();
+        let mut refund = payment(entry_id, 1000);
+        refund.kind = TransactionKind::Refund;
+        let result = validate_refund_target(&refund, &entry_id);
+        assert!(matches!(result, Err(TransactionError::InvalidRefund { .. })));
+    }
+
+    #[test]
+    fn test_validate_refund_total() {
+        assert!(validate_refund_total(Money::new(1000), Money::new(1000)).is_ok());
+        match validate_refund_total(Money::new(1000), Money::new(1001)) {
+            Err(TransactionError::RefundExceedsOriginal { original_amount, refunded }) => {
+                assert_eq!(original_amount, 1000);
+                assert_eq!(refunded, 1001);
+            }
+            _ => panic!("Expected RefundExceedsOriginal error"),
+        }
+    }
+
+    fn allocation(amount: i64) -> SplitAllocation {
+        SplitAllocation {
+            entry_id: Ulid::new(),
+            amount: Money::new(amount),
+        }
+    }
+
+    #[test]
+    fn test_validate_split_valid() {
+        assert!(validate_split(Money::new(1000), &[allocation(600), allocation(400)]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_split_total_mismatch() {
+        match validate_split(Money::new(1000), &[allocation(600), allocation(300)]) {
+            Err(TransactionError::SplitTotalMismatch { total, allocated }) => {
+                assert_eq!(total, 1000);
+                assert_eq!(allocated, 900);
+            }
+            _ => panic!("Expected SplitTotalMismatch error"),
+        }
+    }
+
+    #[test]
+    fn test_validate_split_needs_two_allocations() {
+        let result = validate_split(Money::new(1000), &[allocation(1000)]);
+        assert!(matches!(result, Err(TransactionError::InvalidSplit { .. })));
+    }
+
+    #[test]
+    fn test_validate_split_duplicate_entry() {
+        let first = allocation(500);
+        let second = SplitAllocation { entry_id: first.entry_id, amount: Money::new(500) };
+        let result = validate_split(Money::new(1000), &[first, second]);
+        assert!(matches!(result, Err(TransactionError::InvalidSplit { .. })));
+    }
+
+    #[test]
+    fn test_validate_split_negative_allocation() {
+        let result = validate_split(Money::new(1000), &[allocation(1100), allocation(-100)]);
+        assert!(matches!(result, Err(TransactionError::InvalidAmount { value: -100 })));
+    }
+
+    #[test]
+    fn test_render_title() {
+        let month = BudgetMonth::new(2026, 4).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 4, 30).unwrap();
+        assert_eq!(
+            render_title("{category} for {month}, paid {date}", "rent", month, date),
+            "rent for 2026-04, paid 2026-04-30"
+        );
+        assert_eq!(render_title("Rent {unknown}", "rent", month, date), "Rent {unknown}");
+    }
+
+    fn date(s: &str) -> TransactionDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_validate_filter_accepts_open_and_single_day_ranges() {
+        assert!(validate_filter(&TransactionFilter::default()).is_ok());
+        let filter = TransactionFilter {
+            from: Some(date("2026-03-01")),
+            to: Some(date("2026-03-01")),
+            min_amount: Some(Money::new(0)),
+            max_amount: Some(Money::new(0)),
+            ..Default::default()
+        };
+        assert!(validate_filter(&filter).is_ok());
+    }
+
+    #[test]
+    fn test_validate_filter_rejects_empty_ranges() {
+        let dates = TransactionFilter {
+            from: Some(date("2026-03-02")),
+            to: Some(date("2026-03-01")),
+            ..Default::default()
+        };
+        assert!(matches!(validate_filter(&dates), Err(TransactionError::InvalidFilter { .. })));
+
+        let amounts = TransactionFilter {
+            min_amount: Some(Money::new(500)),
+            max_amount: Some(Money::new(499)),
+            ..Default::default()
+        };
+        assert!(matches!(validate_filter(&amounts), Err(TransactionError::InvalidFilter { .. })));
+
+        let negative = TransactionFilter {
+            max_amount: Some(Money::new(-1)),
+            ..Default::default()
+        };
+        assert!(matches!(validate_filter(&negative), Err(TransactionError::InvalidFilter { .. })));
+    }
+
     #[test]
     fn test_validate_title_length_too_long() {
         let title = Some("a".repeat(MAX_TITLE_LENGTH + 1));
@@ -264,4 +1117,60 @@ mod tests {
             _ => panic!("Expected TitleTooLong error"),
         }
     }
+
+    /// Newest-first payments of one day, created a second apart.
+    fn payments(count: usize) -> Vec<Transaction> {
+        let start = chrono::Utc::now();
+        (0..count)
+            .rev()
+            .map(|i| Transaction {
+                created_at: start + chrono::Duration::seconds(i as i64),
+                ..payment(Ulid::new(), 100)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cursor_page_first_page() {
+        let rows = payments(3);
+        let page = cursor_page(rows.clone(), None, 2);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].id, rows[0].id);
+        assert!(page.prev_cursor.is_none());
+        let next = page.next_cursor.unwrap();
+        assert_eq!(next.direction, CursorDirection::After);
+        assert_eq!(next.id, rows[1].id);
+
+        let page = cursor_page(payments(2), None, 2);
+        assert!(page.next_cursor.is_none());
+        assert!(page.prev_cursor.is_none());
+    }
+
+    #[test]
+    fn test_cursor_page_after() {
+        let rows = payments(2);
+        let cursor = cursor_at(CursorDirection::After, &payment(Ulid::new(), 100));
+        let page = cursor_page(rows.clone(), Some(&cursor), 2);
+        assert!(page.next_cursor.is_none());
+        let prev = page.prev_cursor.unwrap();
+        assert_eq!(prev.direction, CursorDirection::Before);
+        assert_eq!(prev.id, rows[0].id);
+    }
+
+    #[test]
+    fn test_cursor_page_before_keeps_rows_next_to_cursor() {
+        let rows = payments(3);
+        let cursor = cursor_at(CursorDirection::Before, &payment(Ulid::new(), 100));
+        let page = cursor_page(rows.clone(), Some(&cursor), 2);
+        assert_eq!(
+            page.items.iter().map(|t| t.id).collect::<Vec<_>>(),
+            vec![rows[1].id, rows[2].id]
+        );
+        assert_eq!(page.prev_cursor.unwrap().id, rows[1].id);
+        assert_eq!(page.next_cursor.unwrap().id, rows[2].id);
+
+        let page = cursor_page(rows[1..].to_vec(), Some(&cursor), 2);
+        assert!(page.prev_cursor.is_none());
+        assert!(page.next_cursor.is_some());
+    }
 }
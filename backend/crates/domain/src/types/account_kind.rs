@@ -0,0 +1,72 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Where the money of an account is held.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountKind {
+    #[default]
+    Checking,
+    Savings,
+    /// Balance usually negative: what is owed on the card
+    CreditCard,
+    Cash,
+}
+
+impl AccountKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountKind::Checking => "checking",
+            AccountKind::Savings => "savings",
+            AccountKind::CreditCard => "credit_card",
+            AccountKind::Cash => "cash",
+        }
+    }
+}
+
+impl fmt::Display for AccountKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AccountKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "checking" => Ok(AccountKind::Checking),
+            "savings" => Ok(AccountKind::Savings),
+            "credit_card" => Ok(AccountKind::CreditCard),
+            "cash" => Ok(AccountKind::Cash),
+            _ => Err(DomainError::InvalidAccountKind {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for kind in [
+            AccountKind::Checking,
+            AccountKind::Savings,
+            AccountKind::CreditCard,
+            AccountKind::Cash,
+        ] {
+            assert_eq!(kind.as_str().parse::<AccountKind>().unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn test_invalid_kind() {
+        assert!("card".parse::<AccountKind>().is_err());
+        assert!("Cash".parse::<AccountKind>().is_err());
+    }
+}
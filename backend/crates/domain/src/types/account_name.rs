@@ -0,0 +1,61 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of an account name
+pub const MAX_ACCOUNT_NAME_LENGTH: usize = 50;
+
+/// Name of an account, e.g. `Joint account` or `Cash`.
+///
+/// Surrounding whitespace is trimmed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountName(String);
+
+impl AccountName {
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into().trim().to_string();
+
+        if value.is_empty() {
+            return Err(DomainError::InvalidAccountName {
+                reason: "account name must not be empty".to_string(),
+            });
+        }
+
+        let length = value.chars().count();
+        if length > MAX_ACCOUNT_NAME_LENGTH {
+            return Err(DomainError::InvalidAccountName {
+                reason: format!(
+                    "account name must be at most {} characters, got {}",
+                    MAX_ACCOUNT_NAME_LENGTH, length
+                ),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_whitespace() {
+        assert_eq!(AccountName::new(" Cash  ").unwrap().as_str(), "Cash");
+    }
+
+    #[test]
+    fn test_invalid_empty() {
+        assert!(AccountName::new("").is_err());
+        assert!(AccountName::new("  ").is_err());
+    }
+
+    #[test]
+    fn test_length_limit_counts_characters() {
+        assert!(AccountName::new("ł".repeat(MAX_ACCOUNT_NAME_LENGTH)).is_ok());
+        assert!(AccountName::new("a".repeat(MAX_ACCOUNT_NAME_LENGTH + 1)).is_err());
+    }
+}
@@ -1,4 +1,6 @@
 use crate::errors::DomainError;
+use crate::types::DueDay;
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
@@ -31,6 +33,31 @@ impl BudgetMonth {
     pub fn month(&self) -> u8 {
         self.month
     }
+
+    /// The month a date falls in.
+    pub fn of_date(date: NaiveDate) -> Result<Self, DomainError> {
+        Self::new(date.year(), date.month() as u8)
+    }
+
+    /// Number of days in the month.
+    pub fn days(&self) -> u8 {
+        let first = self.first_day();
+        let next = first
+            .checked_add_months(chrono::Months::new(1))
+            .expect("month within supported range");
+        (next - first).num_days() as u8
+    }
+
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month as u32, 1).expect("valid month")
+    }
+
+    /// The date of `day` in this month, clamped to the last day for short months
+    /// (a due day of 31 falls on the 30th in April and on the 28th or 29th in February).
+    pub fn date_on(&self, day: DueDay) -> NaiveDate {
+        let day = day.value().min(self.days());
+        NaiveDate::from_ymd_opt(self.year, self.month as u32, day as u32).expect("clamped day")
+    }
 }
 
 impl fmt::Display for BudgetMonth {
@@ -223,8 +250,8 @@ mod tests {
         let a = BudgetMonth::new(2026, 1).unwrap();
         let b = BudgetMonth::new(2026, 1).unwrap();
         assert_eq!(a, b);
-        assert!(!(a < b));
-        assert!(!(a > b));
+        assert!(a >= b);
+        assert!(a <= b);
     }
 
     #[test]
@@ -236,6 +263,45 @@ mod tests {
         assert_eq!(bm, deserialized);
     }
 
+    #[test]
+    fn test_of_date() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 17).unwrap();
+        assert_eq!(BudgetMonth::of_date(date).unwrap(), BudgetMonth::new(2026, 3).unwrap());
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(BudgetMonth::new(2026, 1).unwrap().days(), 31);
+        assert_eq!(BudgetMonth::new(2026, 4).unwrap().days(), 30);
+        assert_eq!(BudgetMonth::new(2026, 2).unwrap().days(), 28);
+        assert_eq!(BudgetMonth::new(2024, 2).unwrap().days(), 29);
+        assert_eq!(BudgetMonth::new(2026, 12).unwrap().days(), 31);
+    }
+
+    #[test]
+    fn test_date_on_within_month() {
+        let bm = BudgetMonth::new(2026, 5).unwrap();
+        let date = bm.date_on(DueDay::new(15).unwrap());
+        assert_eq!(date, NaiveDate::from_ymd_opt(2026, 5, 15).unwrap());
+    }
+
+    #[test]
+    fn test_date_on_clamps_to_short_month() {
+        let day = DueDay::new(31).unwrap();
+        assert_eq!(
+            BudgetMonth::new(2026, 4).unwrap().date_on(day),
+            NaiveDate::from_ymd_opt(2026, 4, 30).unwrap()
+        );
+        assert_eq!(
+            BudgetMonth::new(2026, 2).unwrap().date_on(day),
+            NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()
+        );
+        assert_eq!(
+            BudgetMonth::new(2024, 2).unwrap().date_on(day),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+    }
+
     #[test]
     fn test_serde_as_string_format() {
         let bm = BudgetMonth::new(2026, 2).unwrap();
@@ -59,6 +59,26 @@ impl CategoryName {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Slash-separated segments, e.g. `["utils", "electricity"]` for `utils/electricity`.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split('/')
+    }
+
+    /// All proper prefixes, outermost first: `a/b/c` yields `a`, `a/b`.
+    pub fn ancestors(&self) -> Vec<CategoryName> {
+        self.0
+            .match_indices('/')
+            .map(|(i, _)| CategoryName(self.0[..i].to_string()))
+            .collect()
+    }
+
+    /// True if `self` sits somewhere below `ancestor` in the hierarchy.
+    pub fn is_descendant_of(&self, ancestor: &CategoryName) -> bool {
+        self.0.len() > ancestor.0.len()
+            && self.0.starts_with(&ancestor.0)
+            && self.0.as_bytes()[ancestor.0.len()] == b'/'
+    }
 }
 
 #[cfg(test)]
@@ -146,4 +166,27 @@ mod tests {
         let name = CategoryName::new("housing/rent").unwrap();
         assert_eq!(name.as_str(), "housing/rent");
     }
+
+    #[test]
+    fn test_segments() {
+        let name = CategoryName::new("a/b/c").unwrap();
+        assert_eq!(name.segments().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let name = CategoryName::new("a/b/c").unwrap();
+        let ancestors: Vec<String> = name.ancestors().iter().map(|a| a.as_str().to_string()).collect();
+        assert_eq!(ancestors, vec!["a", "a/b"]);
+        assert!(CategoryName::new("a").unwrap().ancestors().is_empty());
+    }
+
+    #[test]
+    fn test_is_descendant_of() {
+        let utils = CategoryName::new("utils").unwrap();
+        assert!(CategoryName::new("utils/gas").unwrap().is_descendant_of(&utils));
+        assert!(CategoryName::new("utils/gas/meter").unwrap().is_descendant_of(&utils));
+        assert!(!CategoryName::new("utils").unwrap().is_descendant_of(&utils));
+        assert!(!CategoryName::new("utilities/gas").unwrap().is_descendant_of(&utils));
+    }
 }
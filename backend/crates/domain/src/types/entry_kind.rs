@@ -0,0 +1,68 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Direction of money planned by a budget entry.
+///
+/// Transactions against an `Income` entry record money received rather than spent;
+/// amounts stay positive either way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    #[default]
+    Expense,
+    Income,
+}
+
+impl EntryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntryKind::Expense => "expense",
+            EntryKind::Income => "income",
+        }
+    }
+}
+
+impl fmt::Display for EntryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EntryKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "expense" => Ok(EntryKind::Expense),
+            "income" => Ok(EntryKind::Income),
+            _ => Err(DomainError::InvalidEntryKind {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for kind in [EntryKind::Expense, EntryKind::Income] {
+            assert_eq!(kind.as_str().parse::<EntryKind>().unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn test_default_is_expense() {
+        assert_eq!(EntryKind::default(), EntryKind::Expense);
+    }
+
+    #[test]
+    fn test_invalid_kind() {
+        assert!("Income".parse::<EntryKind>().is_err());
+        assert!("".parse::<EntryKind>().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of an import profile name
+pub const MAX_IMPORT_PROFILE_NAME_LENGTH: usize = 50;
+
+/// Name of a CSV import profile, usually the bank it reads exports of, e.g. `mBank`.
+///
+/// Surrounding whitespace is trimmed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportProfileName(String);
+
+impl ImportProfileName {
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into().trim().to_string();
+
+        if value.is_empty() {
+            return Err(DomainError::InvalidImportProfileName {
+                reason: "profile name must not be empty".to_string(),
+            });
+        }
+
+        let length = value.chars().count();
+        if length > MAX_IMPORT_PROFILE_NAME_LENGTH {
+            return Err(DomainError::InvalidImportProfileName {
+                reason: format!(
+                    "profile name must be at most {} characters, got {}",
+                    MAX_IMPORT_PROFILE_NAME_LENGTH, length
+                ),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_whitespace() {
+        assert_eq!(ImportProfileName::new(" mBank  ").unwrap().as_str(), "mBank");
+    }
+
+    #[test]
+    fn test_invalid_empty() {
+        assert!(ImportProfileName::new("").is_err());
+        assert!(ImportProfileName::new("  ").is_err());
+    }
+
+    #[test]
+    fn test_length_limit_counts_characters() {
+        assert!(ImportProfileName::new("ł".repeat(MAX_IMPORT_PROFILE_NAME_LENGTH)).is_ok());
+        assert!(ImportProfileName::new("a".repeat(MAX_IMPORT_PROFILE_NAME_LENGTH + 1)).is_err());
+    }
+}
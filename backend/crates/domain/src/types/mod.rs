@@ -3,9 +3,31 @@ mod budget_month;
 mod due_day;
 mod category_name;
 mod transaction_date;
+mod entry_kind;
+mod transaction_kind;
+mod rollover_mode;
+mod tag_name;
+mod payee_name;
+mod account_name;
+mod account_kind;
+mod search_query;
+mod transaction_sort;
+mod transaction_cursor;
+mod import_profile_name;
 
 pub use money::Money;
 pub use budget_month::BudgetMonth;
 pub use due_day::DueDay;
 pub use category_name::CategoryName;
 pub use transaction_date::TransactionDate;
+pub use entry_kind::EntryKind;
+pub use transaction_kind::TransactionKind;
+pub use rollover_mode::RolloverMode;
+pub use tag_name::{TagName, MAX_TAG_NAME_LENGTH};
+pub use payee_name::{PayeeName, MAX_PAYEE_NAME_LENGTH};
+pub use account_name::{AccountName, MAX_ACCOUNT_NAME_LENGTH};
+pub use account_kind::AccountKind;
+pub use search_query::{SearchQuery, MAX_SEARCH_QUERY_LENGTH};
+pub use transaction_sort::TransactionSort;
+pub use transaction_cursor::{CursorDirection, TransactionCursor};
+pub use import_profile_name::{ImportProfileName, MAX_IMPORT_PROFILE_NAME_LENGTH};
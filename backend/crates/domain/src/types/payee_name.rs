@@ -0,0 +1,80 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a payee name or alias
+pub const MAX_PAYEE_NAME_LENGTH: usize = 100;
+
+/// Name of a payee or one of its aliases, e.g. `Lidl` or `LIDL sp. z o.o.`.
+///
+/// Surrounding whitespace is trimmed. Names are compared by `key`, so `Lidl` and
+/// `  LIDL ` refer to the same payee.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayeeName(String);
+
+impl PayeeName {
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into().trim().to_string();
+
+        if value.is_empty() {
+            return Err(DomainError::InvalidPayeeName {
+                reason: "payee name must not be empty".to_string(),
+            });
+        }
+
+        let length = value.chars().count();
+        if length > MAX_PAYEE_NAME_LENGTH {
+            return Err(DomainError::InvalidPayeeName {
+                reason: format!(
+                    "payee name must be at most {} characters, got {}",
+                    MAX_PAYEE_NAME_LENGTH, length
+                ),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Lowercased with runs of whitespace collapsed to one space; unique across
+    /// all payee names and aliases.
+    pub fn key(&self) -> String {
+        self.0
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trims_whitespace() {
+        assert_eq!(PayeeName::new("  Lidl ").unwrap().as_str(), "Lidl");
+    }
+
+    #[test]
+    fn test_invalid_empty() {
+        assert!(PayeeName::new("").is_err());
+        assert!(PayeeName::new("   ").is_err());
+    }
+
+    #[test]
+    fn test_length_limit_counts_characters() {
+        assert!(PayeeName::new("ż".repeat(MAX_PAYEE_NAME_LENGTH)).is_ok());
+        assert!(PayeeName::new("a".repeat(MAX_PAYEE_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_key_ignores_case_and_spacing() {
+        let a = PayeeName::new("LIDL  Sp. z o.o.").unwrap();
+        let b = PayeeName::new("lidl sp. Z O.O.").unwrap();
+        assert_eq!(a.key(), b.key());
+        assert_eq!(PayeeName::new("Żabka").unwrap().key(), "żabka");
+    }
+}
@@ -0,0 +1,102 @@
+use crate::errors::DomainError;
+use crate::types::Money;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// What an expense entry passes on to its copy in the next month.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RolloverMode {
+    /// Every month starts from the budgeted amount
+    #[default]
+    None,
+    /// Unspent money is added to next month, overspending is taken from it
+    CarryRemaining,
+    /// Only overspending is taken from next month; unspent money is dropped
+    CarryOverspend,
+}
+
+impl RolloverMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RolloverMode::None => "none",
+            RolloverMode::CarryRemaining => "carry_remaining",
+            RolloverMode::CarryOverspend => "carry_overspend",
+        }
+    }
+
+    /// Amount carried into the next month given what was `available`
+    /// (budgeted plus carried in) and what was `paid` this month.
+    pub fn carry(&self, available: Money, paid: Money) -> Money {
+        let remaining = available - paid;
+        match self {
+            RolloverMode::None => Money::new(0),
+            RolloverMode::CarryRemaining => remaining,
+            RolloverMode::CarryOverspend => Money::new(remaining.value().min(0)),
+        }
+    }
+}
+
+impl fmt::Display for RolloverMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RolloverMode {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(RolloverMode::None),
+            "carry_remaining" => Ok(RolloverMode::CarryRemaining),
+            "carry_overspend" => Ok(RolloverMode::CarryOverspend),
+            _ => Err(DomainError::InvalidRolloverMode {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for mode in [
+            RolloverMode::None,
+            RolloverMode::CarryRemaining,
+            RolloverMode::CarryOverspend,
+        ] {
+            assert_eq!(mode.as_str().parse::<RolloverMode>().unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn test_invalid_mode() {
+        assert!("carry".parse::<RolloverMode>().is_err());
+        assert!("".parse::<RolloverMode>().is_err());
+    }
+
+    #[test]
+    fn test_carry_none() {
+        assert_eq!(RolloverMode::None.carry(Money::new(1000), Money::new(400)).value(), 0);
+        assert_eq!(RolloverMode::None.carry(Money::new(1000), Money::new(1400)).value(), 0);
+    }
+
+    #[test]
+    fn test_carry_remaining() {
+        let mode = RolloverMode::CarryRemaining;
+        assert_eq!(mode.carry(Money::new(1000), Money::new(400)).value(), 600);
+        assert_eq!(mode.carry(Money::new(1000), Money::new(1400)).value(), -400);
+    }
+
+    #[test]
+    fn test_carry_overspend() {
+        let mode = RolloverMode::CarryOverspend;
+        assert_eq!(mode.carry(Money::new(1000), Money::new(400)).value(), 0);
+        assert_eq!(mode.carry(Money::new(1000), Money::new(1400)).value(), -400);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a search query
+pub const MAX_SEARCH_QUERY_LENGTH: usize = 100;
+
+/// Words to look up in transaction titles, e.g. `biedr zakupy`.
+///
+/// The query is split on whitespace; words without any letter or digit are
+/// dropped since they cannot match a title.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchQuery(Vec<String>);
+
+impl SearchQuery {
+    pub fn new(value: impl AsRef<str>) -> Result<Self, DomainError> {
+        let value = value.as_ref().trim();
+
+        let length = value.chars().count();
+        if length > MAX_SEARCH_QUERY_LENGTH {
+            return Err(DomainError::InvalidSearchQuery {
+                reason: format!(
+                    "search query must be at most {} characters, got {}",
+                    MAX_SEARCH_QUERY_LENGTH, length
+                ),
+            });
+        }
+
+        let terms: Vec<String> = value
+            .split_whitespace()
+            .filter(|term| term.chars().any(char::is_alphanumeric))
+            .map(str::to_string)
+            .collect();
+        if terms.is_empty() {
+            return Err(DomainError::InvalidSearchQuery {
+                reason: "search query must contain a letter or digit".to_string(),
+            });
+        }
+
+        Ok(Self(terms))
+    }
+
+    /// Words of the query, in the order given
+    pub fn terms(&self) -> &[String] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_on_whitespace() {
+        let query = SearchQuery::new("  biedr \t zakupy ").unwrap();
+        assert_eq!(query.terms(), ["biedr", "zakupy"]);
+    }
+
+    #[test]
+    fn test_drops_words_without_letters_or_digits() {
+        let query = SearchQuery::new("- rent * 2026").unwrap();
+        assert_eq!(query.terms(), ["rent", "2026"]);
+    }
+
+    #[test]
+    fn test_invalid_empty() {
+        assert!(SearchQuery::new("").is_err());
+        assert!(SearchQuery::new("  ").is_err());
+        assert!(SearchQuery::new("\"* -").is_err());
+    }
+
+    #[test]
+    fn test_length_limit_counts_characters() {
+        assert!(SearchQuery::new("ż".repeat(MAX_SEARCH_QUERY_LENGTH)).is_ok());
+        assert!(SearchQuery::new("a".repeat(MAX_SEARCH_QUERY_LENGTH + 1)).is_err());
+    }
+}
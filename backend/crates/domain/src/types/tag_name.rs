@@ -0,0 +1,82 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a tag name
+pub const MAX_TAG_NAME_LENGTH: usize = 50;
+
+/// Name of a transaction tag, e.g. `vacation-2026`.
+///
+/// Only alphanumeric characters, hyphens and underscores are allowed, so names can
+/// be listed comma-separated and used as query parameters as they are.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TagName(String);
+
+impl TagName {
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into();
+
+        if value.is_empty() {
+            return Err(DomainError::InvalidTagName {
+                reason: "tag name must not be empty".to_string(),
+            });
+        }
+
+        if value.len() > MAX_TAG_NAME_LENGTH {
+            return Err(DomainError::InvalidTagName {
+                reason: format!(
+                    "tag name must be at most {} characters, got {}",
+                    MAX_TAG_NAME_LENGTH,
+                    value.len()
+                ),
+            });
+        }
+
+        if !value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(DomainError::InvalidTagName {
+                reason: format!(
+                    "tag '{}' contains invalid characters; only alphanumeric, hyphens, and underscores are allowed",
+                    value
+                ),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_tag_names() {
+        assert_eq!(TagName::new("kids").unwrap().as_str(), "kids");
+        assert_eq!(TagName::new("vacation-2026").unwrap().as_str(), "vacation-2026");
+        assert_eq!(TagName::new("to_reimburse").unwrap().as_str(), "to_reimburse");
+    }
+
+    #[test]
+    fn test_invalid_empty() {
+        assert!(TagName::new("").is_err());
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert!(TagName::new("two words").is_err());
+        assert!(TagName::new("a,b").is_err());
+        assert!(TagName::new("a/b").is_err());
+    }
+
+    #[test]
+    fn test_length_limit() {
+        assert!(TagName::new("a".repeat(MAX_TAG_NAME_LENGTH)).is_ok());
+        assert!(TagName::new("a".repeat(MAX_TAG_NAME_LENGTH + 1)).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::errors::DomainError;
+use crate::types::TransactionDate;
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// Format of `created_at` in a cursor; the same precision the database stores.
+const CREATED_AT_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+/// Which side of its position a cursor pages to, in newest-first order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// Older transactions, the next page
+    After,
+    /// Newer transactions, the previous page
+    Before,
+}
+
+/// Position in a newest-first transaction list, keyed by date, creation time
+/// and ID so that rows inserted while paging neither shift nor repeat a page.
+///
+/// Clients get it as an opaque string; its layout is not part of the API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionCursor {
+    pub direction: CursorDirection,
+    pub date: TransactionDate,
+    pub created_at: DateTime<Utc>,
+    pub id: ulid::Ulid,
+}
+
+impl TransactionCursor {
+    pub fn new(
+        direction: CursorDirection,
+        date: TransactionDate,
+        created_at: DateTime<Utc>,
+        id: ulid::Ulid,
+    ) -> Self {
+        Self {
+            direction,
+            date,
+            created_at,
+            id,
+        }
+    }
+
+    /// `created_at` as stored, for comparing against the database column.
+    pub fn created_at_key(&self) -> String {
+        self.created_at.format(CREATED_AT_FORMAT).to_string()
+    }
+
+    fn invalid() -> DomainError {
+        DomainError::InvalidTransactionCursor
+    }
+}
+
+impl fmt::Display for TransactionCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            CursorDirection::After => 'a',
+            CursorDirection::Before => 'b',
+        };
+        let raw = format!(
+            "{}|{}|{}|{}",
+            direction,
+            self.date,
+            self.created_at_key(),
+            self.id
+        );
+        for byte in raw.bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TransactionCursor {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.len().is_multiple_of(2) || !s.is_ascii() {
+            return Err(Self::invalid());
+        }
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| Self::invalid())?;
+        let raw = String::from_utf8(bytes).map_err(|_| Self::invalid())?;
+
+        let parts: Vec<&str> = raw.split('|').collect();
+        let [direction, date, created_at, id] = parts[..] else {
+            return Err(Self::invalid());
+        };
+        let direction = match direction {
+            "a" => CursorDirection::After,
+            "b" => CursorDirection::Before,
+            _ => return Err(Self::invalid()),
+        };
+        let date = date.parse().map_err(|_| Self::invalid())?;
+        let created_at = DateTime::parse_from_rfc3339(created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|_| Self::invalid())?;
+        let id = ulid::Ulid::from_string(id).map_err(|_| Self::invalid())?;
+
+        Ok(Self::new(direction, date, created_at, id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor(direction: CursorDirection) -> TransactionCursor {
+        TransactionCursor::new(
+            direction,
+            "2025-03-14".parse().unwrap(),
+            DateTime::parse_from_rfc3339("2025-03-14T09:26:53.589Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            ulid::Ulid::new(),
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for direction in [CursorDirection::After, CursorDirection::Before] {
+            let cursor = cursor(direction);
+            assert_eq!(cursor.to_string().parse::<TransactionCursor>().unwrap(), cursor);
+        }
+    }
+
+    #[test]
+    fn test_encoded_is_url_safe() {
+        let encoded = cursor(CursorDirection::After).to_string();
+        assert!(encoded.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_created_at_key_matches_stored_format() {
+        assert_eq!(
+            cursor(CursorDirection::After).created_at_key(),
+            "2025-03-14T09:26:53.589Z"
+        );
+    }
+
+    #[test]
+    fn test_invalid_cursor() {
+        assert!("".parse::<TransactionCursor>().is_err());
+        assert!("abc".parse::<TransactionCursor>().is_err());
+        assert!("zz".parse::<TransactionCursor>().is_err());
+        // "x|2025-03-14": wrong direction and missing fields
+        assert!("787c323032352d30332d3134".parse::<TransactionCursor>().is_err());
+        assert!("é1".parse::<TransactionCursor>().is_err());
+    }
+}
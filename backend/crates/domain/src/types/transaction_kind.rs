@@ -0,0 +1,68 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Whether a transaction moves money against its entry or returns some of it.
+///
+/// Amounts are stored positive for both kinds; a `Refund` is subtracted when
+/// computing what was paid on the entry.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionKind {
+    #[default]
+    Payment,
+    Refund,
+}
+
+impl TransactionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionKind::Payment => "payment",
+            TransactionKind::Refund => "refund",
+        }
+    }
+}
+
+impl fmt::Display for TransactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TransactionKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "payment" => Ok(TransactionKind::Payment),
+            "refund" => Ok(TransactionKind::Refund),
+            _ => Err(DomainError::InvalidTransactionKind {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for kind in [TransactionKind::Payment, TransactionKind::Refund] {
+            assert_eq!(kind.as_str().parse::<TransactionKind>().unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn test_default_is_payment() {
+        assert_eq!(TransactionKind::default(), TransactionKind::Payment);
+    }
+
+    #[test]
+    fn test_invalid_kind() {
+        assert!("Refund".parse::<TransactionKind>().is_err());
+        assert!("expense".parse::<TransactionKind>().is_err());
+    }
+}
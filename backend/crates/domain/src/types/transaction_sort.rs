@@ -0,0 +1,79 @@
+use crate::errors::DomainError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Order of a filtered transaction list. Ties are broken by date, then by
+/// creation time, in the same direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionSort {
+    /// Newest first
+    #[default]
+    DateDesc,
+    DateAsc,
+    /// Largest first
+    AmountDesc,
+    AmountAsc,
+}
+
+impl TransactionSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionSort::DateDesc => "date_desc",
+            TransactionSort::DateAsc => "date_asc",
+            TransactionSort::AmountDesc => "amount_desc",
+            TransactionSort::AmountAsc => "amount_asc",
+        }
+    }
+}
+
+impl fmt::Display for TransactionSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TransactionSort {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "date_desc" => Ok(TransactionSort::DateDesc),
+            "date_asc" => Ok(TransactionSort::DateAsc),
+            "amount_desc" => Ok(TransactionSort::AmountDesc),
+            "amount_asc" => Ok(TransactionSort::AmountAsc),
+            _ => Err(DomainError::InvalidTransactionSort {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for sort in [
+            TransactionSort::DateDesc,
+            TransactionSort::DateAsc,
+            TransactionSort::AmountDesc,
+            TransactionSort::AmountAsc,
+        ] {
+            assert_eq!(sort.as_str().parse::<TransactionSort>().unwrap(), sort);
+        }
+    }
+
+    #[test]
+    fn test_invalid_sort() {
+        assert!("date".parse::<TransactionSort>().is_err());
+        assert!("DATE_DESC".parse::<TransactionSort>().is_err());
+    }
+
+    #[test]
+    fn test_default_is_newest_first() {
+        assert_eq!(TransactionSort::default(), TransactionSort::DateDesc);
+    }
+}
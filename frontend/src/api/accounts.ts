@@ -0,0 +1,24 @@
+import { client } from './client'
+import type {
+  Account,
+  AccountLedger,
+  CreateAccountRequest,
+  UpdateAccountRequest,
+  Transfer,
+  CreateTransferRequest,
+  UpdateTransferRequest,
+} from './types'
+
+export const accountsApi = {
+  list: () => client.get<Account[]>('/accounts'),
+  get: (id: string) => client.get<Account>(`/accounts/${id}`),
+  create: (data: CreateAccountRequest) => client.post<Account>('/accounts', data),
+  update: (id: string, data: UpdateAccountRequest) => client.patch<Account>(`/accounts/${id}`, data),
+  delete: (id: string) => client.delete(`/accounts/${id}`),
+  ledger: (id: string) => client.get<AccountLedger>(`/accounts/${id}/ledger`),
+  listTransfers: () => client.get<Transfer[]>('/accounts/transfers'),
+  createTransfer: (data: CreateTransferRequest) => client.post<Transfer>('/accounts/transfers', data),
+  updateTransfer: (id: string, data: UpdateTransferRequest) =>
+    client.patch<Transfer>(`/accounts/transfers/${id}`, data),
+  deleteTransfer: (id: string) => client.delete(`/accounts/transfers/${id}`),
+}
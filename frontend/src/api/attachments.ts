@@ -0,0 +1,16 @@
+import { client } from './client'
+import type { Attachment } from './types'
+
+export const attachmentsApi = {
+  list: (transactionId: string) =>
+    client.get<Attachment[]>(`/transactions/${transactionId}/attachments`),
+  upload: (transactionId: string, file: File) => {
+    const form = new FormData()
+    form.append('file', file)
+    return client.upload<Attachment>(`/transactions/${transactionId}/attachments`, form)
+  },
+  downloadUrl: (transactionId: string, id: string) =>
+    client.url(`/transactions/${transactionId}/attachments/${id}`),
+  delete: (transactionId: string, id: string) =>
+    client.delete(`/transactions/${transactionId}/attachments/${id}`),
+}
@@ -1,8 +1,29 @@
 import { client } from './client'
-import type { Category, CreateCategoryRequest, UpdateCategoryRequest } from './types'
+import type {
+  Category,
+  CategoryHistory,
+  CategoryTreeNode,
+  CreateCategoryRequest,
+  UpdateCategoryRequest,
+} from './types'
 
 export const categoriesApi = {
-  list: () => client.get<Category[]>('/categories'),
+  list: (includeArchived = false) =>
+    client.get<Category[]>('/categories', includeArchived ? { include_archived: 'true' } : undefined),
+  tree: (includeArchived = false) =>
+    client.get<CategoryTreeNode[]>('/categories/tree', includeArchived ? { include_archived: 'true' } : undefined),
   create: (data: CreateCategoryRequest) => client.post<Category>('/categories', data),
   update: (id: string, data: UpdateCategoryRequest) => client.patch<Category>(`/categories/${id}`, data),
+  archive: (id: string) => client.post<Category>(`/categories/${id}/archive`),
+  unarchive: (id: string) => client.post<Category>(`/categories/${id}/unarchive`),
+  delete: (id: string) => client.delete(`/categories/${id}`),
+  moveSubtree: (from: string, to: string) =>
+    client.post<Category[]>('/categories/move', { from, to }),
+  merge: (id: string, targetId: string) =>
+    client.post<Category>(`/categories/${id}/merge`, { target_id: targetId }),
+  history: (id: string, months?: number) =>
+    client.get<CategoryHistory>(
+      `/categories/${id}/history`,
+      months !== undefined ? { months: String(months) } : undefined,
+    ),
 }
@@ -72,6 +72,21 @@ export const client = {
     return handleResponse<T>(response)
   },
 
+  // Sends a multipart form; the browser sets the Content-Type with its boundary.
+  async upload<T>(path: string, form: FormData): Promise<T> {
+    const response = await fetchWithErrorHandling(BASE_URL + path, {
+      method: 'POST',
+      headers: { 'Accept': 'application/json' },
+      body: form,
+    })
+    return handleResponse<T>(response)
+  },
+
+  // Absolute URL of an API path, for links the browser follows itself (downloads).
+  url(path: string): string {
+    return BASE_URL + path
+  },
+
   async patch<T>(path: string, body: unknown): Promise<T> {
     const response = await fetchWithErrorHandling(BASE_URL + path, {
       method: 'PATCH',
@@ -1,5 +1,5 @@
 import { client } from './client'
-import type { Entry, CreateEntryRequest, UpdateEntryRequest } from './types'
+import type { Entry, CreateEntryRequest, PayEntryRequest, Transaction, UpdateEntryRequest } from './types'
 
 export const entriesApi = {
   list: (monthId: string) => client.get<Entry[]>(`/months/${monthId}/entries`),
@@ -8,4 +8,6 @@ export const entriesApi = {
     client.patch<Entry>(`/months/${monthId}/entries/${entryId}`, data),
   delete: (monthId: string, entryId: string) =>
     client.delete(`/months/${monthId}/entries/${entryId}`),
+  pay: (monthId: string, entryId: string, data: PayEntryRequest = {}) =>
+    client.post<Transaction>(`/months/${monthId}/entries/${entryId}/pay`, data),
 }
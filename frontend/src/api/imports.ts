@@ -0,0 +1,33 @@
+import { client } from './client'
+import type {
+  CsvMapping,
+  ImportPreview,
+  ImportProfile,
+  CreateImportProfileRequest,
+  UpdateImportProfileRequest,
+  ImportTransactionRequest,
+  Transaction,
+} from './types'
+
+export const importsApi = {
+  listProfiles: () => client.get<ImportProfile[]>('/imports/profiles'),
+  createProfile: (data: CreateImportProfileRequest) =>
+    client.post<ImportProfile>('/imports/profiles', data),
+  updateProfile: (id: string, data: UpdateImportProfileRequest) =>
+    client.patch<ImportProfile>(`/imports/profiles/${id}`, data),
+  deleteProfile: (id: string) => client.delete(`/imports/profiles/${id}`),
+  // Reads the file with a saved profile or an unsaved mapping; books nothing
+  preview: (file: File, source: { profileId: string } | { mapping: CsvMapping }) => {
+    const form = new FormData()
+    if ('profileId' in source) {
+      form.append('profile_id', source.profileId)
+    } else {
+      form.append('mapping', JSON.stringify(source.mapping))
+    }
+    form.append('file', file)
+    return client.upload<ImportPreview>('/imports/preview', form)
+  },
+  // All rows are booked, or none are
+  commit: (rows: ImportTransactionRequest[]) =>
+    client.post<Transaction[]>('/imports/commit', { rows }),
+}
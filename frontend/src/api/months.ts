@@ -5,4 +5,8 @@ export const monthsApi = {
   list: () => client.get<Month[]>('/months'),
   get: (id: string) => client.get<Month>(`/months/${id}`),
   create: (data: CreateMonthRequest) => client.post<Month>('/months', data),
+  delete: (id: string, cascade = false) =>
+    client.delete(cascade ? `/months/${id}?cascade=true` : `/months/${id}`),
+  reset: (id: string, copyFrom?: string) =>
+    client.post<Month>(`/months/${id}/reset`, { copy_from: copyFrom }),
 }
@@ -0,0 +1,12 @@
+import { client } from './client'
+import type { CreatePayeeRequest, Payee, PayeeMatch, PayeeReport, UpdatePayeeRequest } from './types'
+
+export const payeesApi = {
+  list: () => client.get<Payee[]>('/payees'),
+  create: (data: CreatePayeeRequest) => client.post<Payee>('/payees', data),
+  update: (id: string, data: UpdatePayeeRequest) => client.patch<Payee>(`/payees/${id}`, data),
+  delete: (id: string) => client.delete(`/payees/${id}`),
+  lookup: (name: string, monthId?: string) =>
+    client.get<PayeeMatch>('/payees/lookup', monthId ? { name, month: monthId } : { name }),
+  report: (id: string) => client.get<PayeeReport>(`/payees/${id}/report`),
+}
@@ -0,0 +1,17 @@
+import { client } from './client'
+import type {
+  CreateRecurringRuleRequest,
+  RecurringRule,
+  UpdateRecurringRuleRequest,
+} from './types'
+
+export const recurringApi = {
+  list: () => client.get<RecurringRule[]>('/recurring-rules'),
+  create: (data: CreateRecurringRuleRequest) =>
+    client.post<RecurringRule>('/recurring-rules', data),
+  update: (id: string, data: UpdateRecurringRuleRequest) =>
+    client.patch<RecurringRule>(`/recurring-rules/${id}`, data),
+  pause: (id: string) => client.post<RecurringRule>(`/recurring-rules/${id}/pause`),
+  resume: (id: string) => client.post<RecurringRule>(`/recurring-rules/${id}/resume`),
+  delete: (id: string) => client.delete(`/recurring-rules/${id}`),
+}
@@ -1,6 +1,12 @@
 import { client } from './client'
-import type { MonthSummary } from './types'
+import type { DueBills, MonthSummary, RangeSummary } from './types'
 
 export const summaryApi = {
   get: (monthId: string) => client.get<MonthSummary>(`/months/${monthId}/summary`),
+  range: (from: string, to: string) => client.get<RangeSummary>('/summary', { from, to }),
+  due: (monthId: string, soonDays?: number) =>
+    client.get<DueBills>(
+      `/months/${monthId}/due`,
+      soonDays !== undefined ? { soon_days: String(soonDays) } : undefined,
+    ),
 }
@@ -0,0 +1,10 @@
+import { client } from './client'
+import type { Tag, TagReport } from './types'
+
+export const tagsApi = {
+  list: () => client.get<Tag[]>('/tags'),
+  create: (name: string) => client.post<Tag>('/tags', { name }),
+  rename: (id: string, name: string) => client.patch<Tag>(`/tags/${id}`, { name }),
+  delete: (id: string) => client.delete(`/tags/${id}`),
+  report: (id: string) => client.get<TagReport>(`/tags/${id}/report`),
+}
@@ -1,16 +1,54 @@
 import { client } from './client'
-import type { Transaction, CreateTransactionRequest, UpdateTransactionRequest, PaginatedTransactionsResponse } from './types'
+import type {
+  Transaction,
+  TransactionMatch,
+  CreateTransactionRequest,
+  UpdateTransactionRequest,
+  PaginatedTransactionsResponse,
+  FilteredTransactionsResponse,
+  TransactionFilter,
+  TransactionSplit,
+  CreateTransactionSplitRequest,
+  UpdateTransactionSplitRequest,
+} from './types'
 
 export const transactionsApi = {
   list: (monthId: string) => client.get<Transaction[]>('/transactions', { month: monthId }),
-  listByEntry: (entryId: string, limit: number, offset: number) =>
+  listByTag: (tag: string) => client.get<Transaction[]>('/transactions', { tag }),
+  // At least one filter field other than `tag` must be set to get filtered mode.
+  listFiltered: (filter: TransactionFilter, limit: number, offset: number) => {
+    const params: Record<string, string> = { limit: String(limit), offset: String(offset) }
+    Object.entries(filter).forEach(([key, value]) => {
+      if (value !== undefined && value !== '') params[key] = String(value)
+    })
+    return client.get<FilteredTransactionsResponse>('/transactions', params)
+  },
+  search: (q: string, limit?: number) =>
+    client.get<TransactionMatch[]>(
+      '/transactions/search',
+      limit === undefined ? { q } : { q, limit: String(limit) },
+    ),
+  // Pass a page's next_cursor or prev_cursor to get the page next to it; none for the first page.
+  listByEntry: (entryId: string, limit: number, cursor?: string) =>
     client.get<PaginatedTransactionsResponse>('/transactions', {
       entry_id: entryId,
       limit: String(limit),
-      offset: String(offset),
+      ...(cursor ? { cursor } : {}),
+    }),
+  listMonthPage: (monthId: string, limit: number, cursor?: string) =>
+    client.get<PaginatedTransactionsResponse>('/transactions', {
+      month: monthId,
+      limit: String(limit),
+      ...(cursor ? { cursor } : {}),
     }),
   create: (data: CreateTransactionRequest) => client.post<Transaction>('/transactions', data),
   update: (id: string, data: UpdateTransactionRequest) =>
     client.patch<Transaction>(`/transactions/${id}`, data),
   delete: (id: string) => client.delete(`/transactions/${id}`),
+  createSplit: (data: CreateTransactionSplitRequest) =>
+    client.post<TransactionSplit>('/transactions/splits', data),
+  getSplit: (id: string) => client.get<TransactionSplit>(`/transactions/splits/${id}`),
+  updateSplit: (id: string, data: UpdateTransactionSplitRequest) =>
+    client.patch<TransactionSplit>(`/transactions/splits/${id}`, data),
+  deleteSplit: (id: string) => client.delete(`/transactions/splits/${id}`),
 }
@@ -2,6 +2,7 @@ export interface Category {
   id: string
   name: string
   label?: string | null
+  archived_at: string | null
   created_at: string
   updated_at: string
 }
@@ -17,44 +18,192 @@ export interface CategorySummary {
   id: string
   name: string
   label?: string | null
+  archived: boolean
 }
 
+export type EntryKind = 'expense' | 'income'
+
+export type RolloverMode = 'none' | 'carry_remaining' | 'carry_overspend'
+
 export interface Entry {
   id: string
   category: CategorySummary
+  kind: EntryKind
   budgeted: number
+  carried_in: number  // balance rolled over from the previous month
+  rollover: RolloverMode
   due_day: number | null
   created_at: string
   updated_at: string
 }
 
+export type TransactionKind = 'payment' | 'refund'
+
 export interface Transaction {
   id: string
   entry_id: string
-  amount: number
+  kind: TransactionKind
+  amount: number  // positive for refunds too
   date: string  // "YYYY-MM-DD"
   title: string | null
+  refund_of: string | null
+  split_id: string | null  // set on allocations of a split payment
+  payee_id: string | null
+  account_id: string | null
+  tags: string[]  // tag names, sorted
   created_at: string
   updated_at: string
 }
 
+export interface TransactionMatch {
+  transaction: Transaction
+  month_id: string
+  month: string  // "YYYY-MM"
+  category_id: string
+  category_name: string
+}
+
 export interface MonthSummary {
   month: string
-  total_budgeted: number
+  total_budgeted: number  // expense entries only
+  total_carried_in: number
   total_paid: number
-  remaining: number
+  remaining: number  // total_budgeted + total_carried_in - total_paid
+  expected_income: number
+  total_income: number
+  total_spending: number
+  net_savings: number
   categories: CategoryBudgetSummary[]
+  groups?: CategoryGroupSummary[]  // only with ?rollup=true
 }
 
 export interface CategoryBudgetSummary {
   entry_id: string
   category: CategorySummary
+  kind: EntryKind
   budgeted: number
+  carried_in: number
+  available: number  // budgeted + carried_in
   paid: number
   remaining: number
   status: 'unpaid' | 'underspent' | 'on_budget' | 'overspent'
 }
 
+export interface CategoryGroupSummary {
+  name: string
+  budgeted: number
+  carried_in: number
+  paid: number
+  remaining: number
+  status: CategoryBudgetSummary['status']
+  entry_count: number
+}
+
+export type DueStatus = 'paid' | 'upcoming' | 'due_soon' | 'due_today' | 'overdue'
+
+export interface DueBills {
+  month_id: string
+  month: string
+  today: string  // "YYYY-MM-DD"
+  bills: DueBill[]  // ordered by due date
+}
+
+export interface DueBill {
+  entry_id: string
+  category: CategorySummary
+  due_date: string  // clamped to the month's last day
+  days_until: number  // negative once overdue
+  available: number
+  paid: number
+  remaining: number
+  status: DueStatus
+}
+
+export interface CategoryHistory {
+  category: Category
+  points: CategoryHistoryPoint[]  // months with an entry only, oldest first
+  stats: HistoryStats | null
+}
+
+export interface CategoryHistoryPoint {
+  month_id: string
+  month: string
+  entry_id: string
+  budgeted: number
+  carried_in: number
+  paid: number
+  remaining: number
+  status: CategoryBudgetSummary['status']
+}
+
+export interface HistoryStats {
+  average_budgeted: number
+  average_paid: number
+  min_paid: { month: string; amount: number }
+  max_paid: { month: string; amount: number }
+  trend: { per_month: number; direction: 'rising' | 'falling' | 'flat' }
+}
+
+export interface RangeSummary {
+  from: string
+  to: string
+  total_budgeted: number
+  total_paid: number
+  remaining: number
+  total_income: number
+  net_savings: number
+  months: MonthTotals[]  // existing months only, oldest first
+  years: YearTotals[]
+  categories: CategorySeries[]
+}
+
+export interface MonthTotals {
+  month_id: string
+  month: string
+  total_budgeted: number
+  total_carried_in: number
+  total_paid: number
+  remaining: number
+  status: CategoryBudgetSummary['status']
+  total_income: number
+  net_savings: number
+}
+
+export interface YearTotals {
+  year: number
+  total_budgeted: number
+  total_paid: number
+  remaining: number
+  status: CategoryBudgetSummary['status']
+  total_income: number
+  net_savings: number
+  month_count: number
+}
+
+export interface CategorySeries {
+  category: CategorySummary
+  total_budgeted: number
+  total_paid: number
+  remaining: number
+  points: CategorySeriesPoint[]  // months with an entry only
+}
+
+export interface CategorySeriesPoint {
+  month: string
+  budgeted: number
+  carried_in: number
+  paid: number
+  remaining: number
+  status: CategoryBudgetSummary['status']
+}
+
+export interface CategoryTreeNode {
+  name: string
+  segment: string
+  category: Category | null
+  children: CategoryTreeNode[]
+}
+
 // Request types
 export interface CreateCategoryRequest {
   name: string
@@ -74,20 +223,68 @@ export interface CreateMonthRequest {
 
 export interface CreateEntryRequest {
   category_id: string
+  kind?: EntryKind  // defaults to 'expense'
   budgeted: number
+  rollover?: RolloverMode  // defaults to 'none'
   due_day?: number | null
 }
 
 export interface UpdateEntryRequest {
   budgeted?: number
   due_day?: number | null
+  rollover?: RolloverMode
+}
+
+export interface PayEntryRequest {
+  on?: 'today' | 'due_date'  // default 'today', which only works in the current month
+  title?: string  // template; {category}, {month} and {date} are filled in
 }
 
 export interface CreateTransactionRequest {
+  entry_id: string
+  kind?: TransactionKind
+  amount: number
+  date: string
+  title?: string | null
+  refund_of?: string | null
+  payee_id?: string | null
+  account_id?: string | null
+  tags?: string[]  // names of existing tags
+}
+
+export interface TransactionSplit {
+  id: string
+  amount: number
+  date: string  // "YYYY-MM-DD"
+  title: string | null
+  payee_id: string | null
+  account_id: string | null  // copied onto every allocation
+  allocations: Transaction[]
+  created_at: string
+  updated_at: string
+}
+
+export interface SplitAllocationRequest {
   entry_id: string
   amount: number
+}
+
+export interface CreateTransactionSplitRequest {
+  amount: number
   date: string
   title?: string | null
+  payee_id?: string | null
+  account_id?: string | null
+  allocations: SplitAllocationRequest[]  // amounts must add up to `amount`
+}
+
+export interface UpdateTransactionSplitRequest {
+  amount?: number
+  date?: string
+  title?: string | null
+  payee_id?: string | null  // null clears the payee
+  account_id?: string | null  // null clears the account
+  allocations?: SplitAllocationRequest[]  // replaces all allocations
 }
 
 export interface UpdateTransactionRequest {
@@ -95,11 +292,244 @@ export interface UpdateTransactionRequest {
   amount?: number
   date?: string
   title?: string | null
+  payee_id?: string | null  // null clears the payee
+  account_id?: string | null  // null clears the account
+  tags?: string[]  // replaces all tags
+}
+
+export interface RecurringRule {
+  id: string
+  category_id: string
+  amount: number
+  title: string | null
+  day_of_month: number  // clamped to the last day of shorter months
+  start_month: string  // "YYYY-MM"
+  end_month: string | null  // null when open-ended
+  paused: boolean
+  created_at: string
+  updated_at: string
+}
+
+export interface CreateRecurringRuleRequest {
+  category_id: string
+  amount: number
+  title?: string | null
+  day_of_month: number
+  start_month: string
+  end_month?: string | null
+}
+
+export interface UpdateRecurringRuleRequest {
+  amount?: number
+  title?: string | null
+  day_of_month?: number
+  start_month?: string
+  end_month?: string | null  // null makes the rule open-ended
+}
+
+export interface Tag {
+  id: string
+  name: string
+  created_at: string
+  updated_at: string
+}
+
+export interface MonthSpending {
+  month_id: string
+  month: string
+  spent: number
+  transaction_count: number
+}
+
+export interface TagReport {
+  tag: Tag
+  total_spent: number  // expense entries only, net of refunds
+  months: MonthSpending[]  // oldest first
+}
+
+export interface Payee {
+  id: string
+  name: string
+  default_category_id: string | null
+  aliases: string[]
+  created_at: string
+  updated_at: string
+}
+
+export interface CreatePayeeRequest {
+  name: string
+  default_category_id?: string | null
+  aliases?: string[]
+}
+
+export interface UpdatePayeeRequest {
+  name?: string
+  default_category_id?: string | null  // null clears the default category
+  aliases?: string[]  // replaces all aliases
+}
+
+export interface PayeeMatch {
+  payee: Payee
+  entry_id: string | null  // entry of the default category in the requested month
+}
+
+export interface PayeeReport {
+  payee: Payee
+  total_spent: number  // expense entries only, net of refunds
+  months: MonthSpending[]  // oldest first
+}
+
+export type AccountKind = 'checking' | 'savings' | 'credit_card' | 'cash'
+
+export interface Account {
+  id: string
+  name: string
+  kind: AccountKind
+  opening_balance: number
+  balance: number  // opening balance plus all transactions and transfers
+  created_at: string
+  updated_at: string
+}
+
+export interface CreateAccountRequest {
+  name: string
+  kind?: AccountKind
+  opening_balance?: number
+}
+
+export interface UpdateAccountRequest {
+  name?: string
+  kind?: AccountKind
+  opening_balance?: number
+}
+
+export interface Transfer {
+  id: string
+  from_account_id: string
+  to_account_id: string
+  amount: number
+  date: string  // "YYYY-MM-DD"
+  title: string | null
+  created_at: string
+  updated_at: string
+}
+
+export interface CreateTransferRequest {
+  from_account_id: string
+  to_account_id: string
+  amount: number
+  date: string
+  title?: string | null
+}
+
+export interface UpdateTransferRequest {
+  from_account_id?: string
+  to_account_id?: string
+  amount?: number
+  date?: string
+  title?: string | null
+}
+
+export interface LedgerLine {
+  id: string  // transaction or transfer id
+  kind: 'transaction' | 'transfer_in' | 'transfer_out'
+  entry_id: string | null  // set for transactions
+  counterpart_account_id: string | null  // set for transfers
+  amount: number  // as booked, never negative
+  change: number  // signed amount added to the balance
+  balance: number  // balance after this line
+  date: string
+  title: string | null
+}
+
+export interface AccountLedger {
+  account: Account
+  lines: LedgerLine[]  // oldest first
+}
+
+export interface Attachment {
+  id: string
+  transaction_id: string
+  file_name: string
+  content_type: string
+  size: number  // bytes
+  sha256: string
+  created_at: string
+}
+
+export interface CsvMapping {
+  delimiter: string  // one character
+  has_header: boolean
+  date_column: number  // counted from 0
+  amount_column: number
+  description_column: number | null
+  decimal_separator: '.' | ','
+  date_format: string  // chrono format, e.g. "%d.%m.%Y"
+}
+
+export interface ImportProfile {
+  id: string
+  name: string
+  mapping: CsvMapping
+  created_at: string
+  updated_at: string
+}
+
+export interface CreateImportProfileRequest {
+  name: string
+  mapping: Partial<CsvMapping> & Pick<CsvMapping, 'date_column' | 'amount_column'>
+}
+
+export interface UpdateImportProfileRequest {
+  name?: string
+  mapping?: CsvMapping  // replaces the whole mapping
+}
+
+export interface ImportRow {
+  line: number  // line of the file, from 1
+  date: string  // "YYYY-MM-DD"
+  amount: number  // signed as in the file
+  description: string | null
+}
+
+export interface ImportPreview {
+  rows: ImportRow[]
+  errors: { line: number; reason: string }[]  // rows that could not be read
+}
+
+export interface ImportTransactionRequest {
+  entry_id: string  // entry of the month the date falls in
+  kind?: TransactionKind
+  amount: number
+  date: string
+  title?: string | null
 }
 
 export interface PaginatedTransactionsResponse {
   items: Transaction[]
   has_more: boolean
+  next_cursor: string | null  // older transactions; null on the last page
+  prev_cursor: string | null  // newer transactions; null on the first page
+}
+
+export type TransactionSort = 'date_desc' | 'date_asc' | 'amount_desc' | 'amount_asc'
+
+export interface TransactionFilter {
+  from?: string  // "YYYY-MM-DD", inclusive
+  to?: string  // "YYYY-MM-DD", inclusive
+  min_amount?: number  // refunds match by their positive amount
+  max_amount?: number
+  category?: string  // also matches categories below it
+  title?: string  // text the title contains, in either case
+  tag?: string
+  sort?: TransactionSort
+}
+
+export interface FilteredTransactionsResponse {
+  items: TransactionMatch[]
+  total_count: number  // matches on all pages
+  total_amount: number  // payments minus refunds of all matches
+  has_more: boolean
 }
 
 // Error types
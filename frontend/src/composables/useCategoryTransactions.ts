@@ -5,7 +5,7 @@ import { INITIAL_TRANSACTION_COUNT, TRANSACTION_BATCH_SIZE } from '@/constants'
 
 interface CacheEntry {
   transactions: Transaction[]
-  offset: number
+  nextCursor: string | null
   hasMore: boolean
   loading: boolean
   initialLoaded: boolean
@@ -18,7 +18,7 @@ function getOrCreate(entryId: string): CacheEntry {
   if (!cache.has(entryId)) {
     cache.set(entryId, {
       transactions: [],
-      offset: 0,
+      nextCursor: null,
       hasMore: false,
       loading: false,
       initialLoaded: false,
@@ -34,9 +34,9 @@ export function useCategoryTransactions() {
     if (entry.initialLoaded) return
     entry.loading = true
     try {
-      const result = await transactionsApi.listByEntry(entryId, INITIAL_TRANSACTION_COUNT, 0)
+      const result = await transactionsApi.listByEntry(entryId, INITIAL_TRANSACTION_COUNT)
       entry.transactions = result.items
-      entry.offset = result.items.length
+      entry.nextCursor = result.next_cursor
       entry.hasMore = result.has_more
       entry.initialLoaded = true
     } finally {
@@ -46,13 +46,13 @@ export function useCategoryTransactions() {
 
   async function loadMore(entryId: string): Promise<void> {
     const entry = getOrCreate(entryId)
-    if (entry.loading || !entry.hasMore) return
+    if (entry.loading || !entry.hasMore || !entry.nextCursor) return
     entry.loading = true
     entry.showMoreClicked = true
     try {
-      const result = await transactionsApi.listByEntry(entryId, TRANSACTION_BATCH_SIZE, entry.offset)
+      const result = await transactionsApi.listByEntry(entryId, TRANSACTION_BATCH_SIZE, entry.nextCursor)
       entry.transactions = [...entry.transactions, ...result.items]
-      entry.offset += result.items.length
+      entry.nextCursor = result.next_cursor
       entry.hasMore = result.has_more
     } finally {
       entry.loading = false
@@ -61,7 +61,9 @@ export function useCategoryTransactions() {
 
   async function invalidate(entryId: string): Promise<void> {
     const entry = cache.get(entryId)
-    const previousOffset = entry ? entry.offset : INITIAL_TRANSACTION_COUNT
+    const previousCount = entry
+      ? Math.max(entry.transactions.length, INITIAL_TRANSACTION_COUNT)
+      : INITIAL_TRANSACTION_COUNT
     const wasShowMoreClicked = entry ? entry.showMoreClicked : false
 
     // Clear the cache entry
@@ -71,9 +73,9 @@ export function useCategoryTransactions() {
     const newEntry = getOrCreate(entryId)
     newEntry.loading = true
     try {
-      const result = await transactionsApi.listByEntry(entryId, previousOffset, 0)
+      const result = await transactionsApi.listByEntry(entryId, previousCount)
       newEntry.transactions = result.items
-      newEntry.offset = result.items.length
+      newEntry.nextCursor = result.next_cursor
       newEntry.hasMore = result.has_more
       newEntry.initialLoaded = true
       newEntry.showMoreClicked = wasShowMoreClicked